@@ -0,0 +1,45 @@
+use rational::BigInt;
+
+/// Decimal struct.
+///
+/// Represents `coeff * 10^exp`, positive infinity, negative infinity or NaN. The coefficient has at
+/// most `digits(precision)` decimal digits, so that a decimal with 53 bits of precision holds 16
+/// significant digits. Finite numbers are normalized: the coefficient has no trailing zeros and
+/// zero is `0 * 10^0`, so that every value has a unique representation. The special values have
+/// `SPECIAL_EXP` as their exponent and `1`, `-1` or `0` as their coefficient, respectively.
+#[derive(Clone, Debug)]
+pub struct Decimal {
+    /// Coefficient.
+    pub coeff: BigInt,
+    /// Decimal exponent.
+    pub exp: isize,
+    /// Precision in bits.
+    pub precision: usize,
+}
+
+/// Exponent of the infinities and NaN.
+pub const SPECIAL_EXP: isize = isize::MAX;
+
+/// Number of extra bits that intermediate rationals are computed with before they are rounded to
+/// a decimal.
+pub const GUARD_BITS: usize = 16;
+
+/// Returns the number of decimal digits of coefficients with given precision in bits, that is
+/// `ceil(precision * log10(2))`, but at least one.
+#[inline]
+pub fn digits(precision: usize) -> usize {
+    // 30103 / 100000 is slightly larger than log10(2), which is fine below 10^5 bits.
+    ((precision * 30103).div_ceil(100_000)).max(1)
+}
+
+/// Returns the number of decimal digits of the magnitude of `n`, which is one for zero.
+#[inline]
+pub fn num_digits(n: &BigInt) -> usize {
+    n.clone().abs().to_string().len()
+}
+
+/// Computes `10^n`.
+#[inline]
+pub fn pow10(n: usize) -> BigInt {
+    BigInt::from(10u64).pow(n as u32)
+}
@@ -0,0 +1,197 @@
+use super::def::{digits, num_digits, pow10, Decimal, SPECIAL_EXP};
+
+use fp::{Float, Round, Sign};
+use rational::{BigInt, ParseRationalError, Rational};
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Precision of decimals that are constructed without an explicit precision.
+const DEFAULT_PRECISION: usize = 53;
+
+impl Decimal {
+    /// Constructs `coeff * 10^exp` with given precision and removes the trailing zeros of the
+    /// coefficient. The coefficient is not rounded.
+    pub fn normalized(coeff: BigInt, exp: isize, precision: usize) -> Self {
+        if coeff.is_zero() {
+            return Self::zero(precision);
+        }
+        let ten = BigInt::from(10u64);
+        let (mut coeff, mut exp) = (coeff, exp);
+        loop {
+            let (q, r) = coeff.div_rem(&ten);
+            if !r.is_zero() {
+                break;
+            }
+            coeff = q;
+            exp += 1;
+        }
+        Decimal {
+            coeff,
+            exp,
+            precision,
+        }
+    }
+
+    /// Constructs a special value with the sign of `sign`, which is NaN for zero.
+    #[inline]
+    pub fn special(sign: Sign, precision: usize) -> Self {
+        Decimal {
+            coeff: BigInt::from(sign as i8 as i64),
+            exp: SPECIAL_EXP,
+            precision,
+        }
+    }
+
+    /// Rounds `value * 10^exp` to a decimal with given precision, and tells whether the result is
+    /// exact.
+    ///
+    /// If `sticky` is set, the exact magnitude is strictly between `|value|` and `|value| + 1` in
+    /// units of `10^exp`, and `value` must have more digits than the precision allows.
+    pub fn round(
+        value: BigInt,
+        exp: isize,
+        sticky: bool,
+        precision: usize,
+        rounding: Round,
+    ) -> (Self, bool) {
+        let n = num_digits(&value);
+        let max = digits(precision);
+        debug_assert!(!sticky || n > max);
+        if n <= max {
+            return (Self::normalized(value, exp, precision), true);
+        }
+        let positive = value.sign() == Sign::Positive;
+        let shift = n - max;
+        let unit = pow10(shift);
+        let (q, r) = value.abs().div_rem(&unit);
+        let exact = r.is_zero() && !sticky;
+        let away = match rounding {
+            Round::Down => !positive && !exact,
+            Round::Up => positive && !exact,
+            Round::TowardsZero => false,
+            Round::AwayFromZero => !exact,
+            Round::Nearest => match r.shl(1).cmp(&unit) {
+                Ordering::Less => false,
+                Ordering::Equal => sticky || q.is_odd(),
+                Ordering::Greater => true,
+            },
+        };
+        let q = if away { &q + &BigInt::one() } else { q };
+        let q = if positive { q } else { -q };
+        (Self::normalized(q, exp + shift as isize, precision), exact)
+    }
+
+    /// Rounds `x` to a decimal with given precision, and tells whether the result is exact.
+    pub fn from_rational(x: &Rational, precision: usize, rounding: Round) -> (Self, bool) {
+        if !x.is_finite() {
+            return (Self::special(x.sign(), precision), true);
+        }
+        if x.is_zero() {
+            return (Self::zero(precision), true);
+        }
+        // Since `|x| >= 2^log2`, the quotient below has more digits than the precision allows.
+        let log2 = x.num.bits() as isize - x.den.bits() as isize - 1;
+        let exp = (log2 * 30103).div_euclid(100_000) - digits(precision) as isize - 2;
+        let (num, den) = if exp >= 0 {
+            (x.num.clone(), &x.den * &pow10(exp as usize))
+        } else {
+            (&x.num * &pow10(exp.unsigned_abs()), x.den.clone())
+        };
+        let (q, r) = num.div_rem(&den);
+        Self::round(q, exp, !r.is_zero(), precision, rounding)
+    }
+
+    /// Converts `self` exactly to a rational with given precision.
+    pub fn to_rational(&self, precision: usize) -> Rational {
+        if self.is_nan() {
+            Rational::nan(precision)
+        } else if self.is_infinity() {
+            Rational::infinity(precision)
+        } else if self.is_neg_infinity() {
+            Rational::neg_infinity(precision)
+        } else if self.exp >= 0 {
+            Rational::from_integer(&self.coeff * &pow10(self.exp as usize), precision)
+        } else {
+            Rational::new(
+                self.coeff.clone(),
+                pow10(self.exp.unsigned_abs()),
+                precision,
+            )
+        }
+    }
+
+    /// Constructs a decimal by parsing a string with given precision.
+    ///
+    /// Accepts the same syntax as `Rational::from_str_with_prec`, and rounds the result to the
+    /// nearest decimal.
+    pub fn from_str_with_prec(s: &str, precision: usize) -> Result<Self, ParseRationalError> {
+        let x = Rational::from_str_with_prec(s, precision)?;
+        Ok(Self::from_rational(&x, precision, Round::Nearest).0)
+    }
+}
+
+impl Display for Decimal {
+    /// Formats `self` in positional notation if its decimal exponent is between -6 and 20, and in
+    /// scientific notation such as `1.5e-10` otherwise.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.is_nan() {
+            return f.pad("NaN");
+        } else if self.is_infinity() {
+            return f.pad("inf");
+        } else if self.is_neg_infinity() {
+            return f.pad("-inf");
+        }
+        let sign = if self.sign() == Sign::Negative {
+            "-"
+        } else {
+            ""
+        };
+        let digits = self.coeff.clone().abs().to_string();
+        let n = digits.len() as isize;
+        let adjusted = self.exp + n - 1;
+        let s = if !(-6..21).contains(&adjusted) {
+            let (int, frac) = digits.split_at(1);
+            if frac.is_empty() {
+                format!("{}{}e{}", sign, int, adjusted)
+            } else {
+                format!("{}{}.{}e{}", sign, int, frac, adjusted)
+            }
+        } else if self.exp >= 0 {
+            format!("{}{}{}", sign, digits, "0".repeat(self.exp as usize))
+        } else if adjusted >= 0 {
+            let (int, frac) = digits.split_at((n + self.exp) as usize);
+            format!("{}{}.{}", sign, int, frac)
+        } else {
+            let zeros = "0".repeat((-adjusted - 1) as usize);
+            format!("{}0.{}{}", sign, zeros, digits)
+        };
+        f.pad(&s)
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = ParseRationalError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_with_prec(s, DEFAULT_PRECISION)
+    }
+}
+
+impl From<f64> for Decimal {
+    #[inline]
+    fn from(val: f64) -> Self {
+        let x = Rational::from_f64_with_prec(val, DEFAULT_PRECISION);
+        Self::from_rational(&x, DEFAULT_PRECISION, Round::Nearest).0
+    }
+}
+
+impl From<Decimal> for f64 {
+    #[inline]
+    fn from(val: Decimal) -> Self {
+        f64::from(val.to_rational(53))
+    }
+}
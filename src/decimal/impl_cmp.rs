@@ -0,0 +1,73 @@
+use super::def::{num_digits, pow10, Decimal};
+
+use fp;
+use fp::{Float, Sign};
+
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+impl Decimal {
+    /// Compares the magnitudes of nonzero finite `self` and `other`.
+    fn cmp_magnitude(&self, other: &Decimal) -> Ordering {
+        let (a, b) = (num_digits(&self.coeff), num_digits(&other.coeff));
+        // The leading digit of a magnitude has the weight `10^(exp + digits - 1)`.
+        match (self.exp + a as isize).cmp(&(other.exp + b as isize)) {
+            Ordering::Equal => {}
+            ord => return ord,
+        }
+        let x = self.coeff.clone().abs();
+        let y = other.coeff.clone().abs();
+        if self.exp >= other.exp {
+            (&x * &pow10((self.exp - other.exp) as usize)).cmp(&y)
+        } else {
+            x.cmp(&(&y * &pow10((other.exp - self.exp) as usize)))
+        }
+    }
+}
+
+impl PartialEq<Decimal> for Decimal {
+    #[inline]
+    fn eq(&self, other: &Decimal) -> bool {
+        // Values are normalized, so equal values have equal representations.
+        !self.is_nan() && self.coeff == other.coeff && self.exp == other.exp
+    }
+}
+
+impl PartialOrd<Decimal> for Decimal {
+    fn partial_cmp(&self, other: &Decimal) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            return None; // self or other is NaN and they cannot be compared
+        }
+        let sign = self.sign();
+        let ord = (sign as i8).cmp(&(other.sign() as i8));
+        if ord != Ordering::Equal || sign == Sign::Zero {
+            return Some(ord);
+        }
+        let ord = match (self.is_finite(), other.is_finite()) {
+            (true, true) => self.cmp_magnitude(other),
+            (finite, other_finite) => other_finite.cmp(&finite),
+        };
+        Some(if sign == Sign::Negative {
+            ord.reverse()
+        } else {
+            ord
+        })
+    }
+}
+
+impl fp::TotalOrd for Decimal {
+    fn cmp_total(&self, rhs: &Decimal) -> Ordering {
+        match (self.is_nan(), rhs.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => self.partial_cmp(rhs).unwrap(),
+        }
+    }
+
+    /// Hashes the coefficient and the exponent, which are unique for every value.
+    fn hash_total<H: Hasher>(&self, state: &mut H) {
+        self.coeff.hash(state);
+        self.exp.hash(state);
+    }
+}
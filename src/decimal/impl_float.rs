@@ -0,0 +1,346 @@
+use super::def::{digits, num_digits, pow10, Decimal, GUARD_BITS, SPECIAL_EXP};
+
+use fp;
+use fp::{Float, Round, Sign};
+use rational::{BigInt, ParseRationalError, Rational};
+
+/// Exponent of the step that `next_up` and `next_down` take from zero.
+///
+/// There is no smallest positive decimal, so the smallest positive subnormal of IEEE 754
+/// `decimal64` is used.
+const ZERO_ULP_EXP: isize = -398;
+
+impl Decimal {
+    /// Applies `f` to the exact value of `self` and rounds the result with `rounding`.
+    fn map_rational<F>(self, rounding: Round, f: F) -> Self
+    where
+        F: FnOnce(Rational) -> Rational,
+    {
+        let precision = self.precision;
+        let x = f(self.to_rational(precision + GUARD_BITS));
+        Self::from_rational(&x, precision, rounding).0
+    }
+
+    /// Applies `f` to the exact values of `self` and `rhs` and rounds the result with `rounding`.
+    fn map_rational2<F>(self, rhs: Self, rounding: Round, f: F) -> Self
+    where
+        F: FnOnce(Rational, Rational) -> Rational,
+    {
+        let precision = self.precision.max(rhs.precision);
+        let x = self.to_rational(precision + GUARD_BITS);
+        let y = rhs.to_rational(precision + GUARD_BITS);
+        Self::from_rational(&f(x, y), precision, rounding).0
+    }
+
+    /// Returns the coefficient of `self` padded with zeros to the number of digits that the
+    /// precision allows, together with the matching exponent.
+    fn widened(&self) -> (BigInt, isize) {
+        let pad = digits(self.precision).saturating_sub(num_digits(&self.coeff));
+        (&self.coeff * &pow10(pad), self.exp - pad as isize)
+    }
+}
+
+impl fp::From<f64> for Decimal {
+    #[inline]
+    fn from_lo(val: f64, precision: usize) -> Self {
+        let x = Rational::from_f64_with_prec(val, precision);
+        Self::from_rational(&x, precision, Round::Down).0
+    }
+
+    #[inline]
+    fn from_hi(val: f64, precision: usize) -> Self {
+        let x = Rational::from_f64_with_prec(val, precision);
+        Self::from_rational(&x, precision, Round::Up).0
+    }
+}
+
+impl fp::FromStr for Decimal {
+    #[inline]
+    fn from_str_lo(s: &str, precision: usize) -> Result<Self, ParseRationalError> {
+        let x = Rational::from_str_with_prec(s, precision)?;
+        Ok(Self::from_rational(&x, precision, Round::Down).0)
+    }
+
+    #[inline]
+    fn from_str_hi(s: &str, precision: usize) -> Result<Self, ParseRationalError> {
+        let x = Rational::from_str_with_prec(s, precision)?;
+        Ok(Self::from_rational(&x, precision, Round::Up).0)
+    }
+}
+
+impl fp::Into<f64> for Decimal {
+    #[inline]
+    fn into_lo(self) -> f64 {
+        fp::Into::into_lo(self.to_rational(53))
+    }
+
+    #[inline]
+    fn into_hi(self) -> f64 {
+        fp::Into::into_hi(self.to_rational(53))
+    }
+}
+
+impl fp::MinMax for Decimal {
+    #[inline]
+    fn min(self, rhs: Self) -> Self {
+        if rhs.is_nan() || self <= rhs {
+            self
+        } else {
+            rhs
+        }
+    }
+
+    #[inline]
+    fn max(self, rhs: Self) -> Self {
+        if rhs.is_nan() || self >= rhs {
+            self
+        } else {
+            rhs
+        }
+    }
+}
+
+impl fp::Abs for Decimal {
+    #[inline]
+    fn abs(self) -> Self {
+        Decimal {
+            coeff: self.coeff.abs(),
+            ..self
+        }
+    }
+}
+
+impl fp::Integral for Decimal {
+    #[inline]
+    fn floor(self) -> Self {
+        if !self.is_finite() || self.exp >= 0 {
+            return self;
+        }
+        self.map_rational(Round::Nearest, fp::Integral::floor)
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        if !self.is_finite() || self.exp >= 0 {
+            return self;
+        }
+        self.map_rational(Round::Nearest, fp::Integral::ceil)
+    }
+
+    #[inline]
+    fn round_ties_even(self) -> Self {
+        if !self.is_finite() || self.exp >= 0 {
+            return self;
+        }
+        self.map_rational(Round::Nearest, fp::Integral::round_ties_even)
+    }
+
+    #[inline]
+    fn round_ties_away(self) -> Self {
+        if !self.is_finite() || self.exp >= 0 {
+            return self;
+        }
+        self.map_rational(Round::Nearest, fp::Integral::round_ties_away)
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        if !self.is_finite() || self.exp >= 0 {
+            return self;
+        }
+        self.map_rational(Round::Nearest, fp::Integral::trunc)
+    }
+
+    #[inline]
+    fn frac(self) -> Self {
+        if self.is_finite() && self.exp >= 0 {
+            return Self::zero(self.precision);
+        }
+        self.map_rational(Round::Nearest, fp::Integral::frac)
+    }
+}
+
+impl fp::Rem for Decimal {
+    #[inline]
+    fn fmod_lo(self, rhs: Self) -> Self {
+        self.map_rational2(rhs, Round::Down, fp::Rem::fmod_lo)
+    }
+
+    #[inline]
+    fn fmod_hi(self, rhs: Self) -> Self {
+        self.map_rational2(rhs, Round::Up, fp::Rem::fmod_hi)
+    }
+
+    #[inline]
+    fn remainder_lo(self, rhs: Self) -> Self {
+        self.map_rational2(rhs, Round::Down, fp::Rem::remainder_lo)
+    }
+
+    #[inline]
+    fn remainder_hi(self, rhs: Self) -> Self {
+        self.map_rational2(rhs, Round::Up, fp::Rem::remainder_hi)
+    }
+}
+
+impl fp::Hypot for Decimal {
+    #[inline]
+    fn hypot_r(self, rhs: Self, round: Round) -> Self {
+        self.map_rational2(rhs, round, |x, y| fp::Hypot::hypot_r(x, y, round))
+    }
+}
+
+impl Float for Decimal {
+    #[inline]
+    fn zero(precision: usize) -> Self {
+        Decimal {
+            coeff: BigInt::zero(),
+            exp: 0,
+            precision,
+        }
+    }
+
+    #[inline]
+    fn neg_zero(precision: usize) -> Self {
+        Self::zero(precision) // decimals have a single zero
+    }
+
+    #[inline]
+    fn one(precision: usize) -> Self {
+        Decimal {
+            coeff: BigInt::one(),
+            exp: 0,
+            precision,
+        }
+    }
+
+    #[inline]
+    fn infinity(precision: usize) -> Self {
+        Self::special(Sign::Positive, precision)
+    }
+
+    #[inline]
+    fn neg_infinity(precision: usize) -> Self {
+        Self::special(Sign::Negative, precision)
+    }
+
+    #[inline]
+    fn nan(precision: usize) -> Self {
+        Self::special(Sign::Zero, precision)
+    }
+
+    #[inline]
+    fn sign(&self) -> Sign {
+        self.coeff.sign()
+    }
+
+    #[inline]
+    fn precision(&self) -> usize {
+        self.precision
+    }
+
+    #[inline]
+    fn is_finite(&self) -> bool {
+        self.exp != SPECIAL_EXP
+    }
+
+    #[inline]
+    fn is_infinite(&self) -> bool {
+        self.exp == SPECIAL_EXP && !self.coeff.is_zero()
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.exp != SPECIAL_EXP && self.coeff.is_zero()
+    }
+
+    #[inline]
+    fn is_infinity(&self) -> bool {
+        self.exp == SPECIAL_EXP && self.coeff.sign() == Sign::Positive
+    }
+
+    #[inline]
+    fn is_neg_infinity(&self) -> bool {
+        self.exp == SPECIAL_EXP && self.coeff.sign() == Sign::Negative
+    }
+
+    #[inline]
+    fn is_nan(&self) -> bool {
+        self.exp == SPECIAL_EXP && self.coeff.is_zero()
+    }
+
+    #[inline]
+    fn has_odd_denominator(&self) -> bool {
+        // The denominator of `coeff / 10^-exp` is odd when `coeff` has all the factors of two.
+        self.is_finite()
+            && (self.exp >= 0 || self.coeff.trailing_zeros() >= self.exp.unsigned_abs())
+    }
+
+    /// Returns the next decimal with `precision` bits above `self`.
+    ///
+    /// There is no finite decimal below all others, so negative infinity is returned as is.
+    fn next_up(self) -> Self {
+        if !self.is_finite() {
+            return self;
+        }
+        let precision = self.precision;
+        match self.sign() {
+            Sign::Zero => Self::normalized(BigInt::one(), ZERO_ULP_EXP, precision),
+            Sign::Negative => -(-self).next_down(),
+            Sign::Positive => {
+                let (coeff, exp) = self.widened();
+                Self::normalized(&coeff + &BigInt::one(), exp, precision)
+            }
+        }
+    }
+
+    /// Returns the next decimal with `precision` bits below `self`.
+    ///
+    /// There is no finite decimal above all others, so positive infinity is returned as is.
+    fn next_down(self) -> Self {
+        if !self.is_finite() {
+            return self;
+        }
+        let precision = self.precision;
+        match self.sign() {
+            Sign::Zero => -Self::normalized(BigInt::one(), ZERO_ULP_EXP, precision),
+            Sign::Negative => -(-self).next_up(),
+            Sign::Positive => {
+                let (coeff, exp) = self.widened();
+                if coeff == pow10(digits(precision) - 1) {
+                    // Below a power of ten, the decimals are ten times denser.
+                    let coeff = &(&coeff * &BigInt::from(10u64)) - &BigInt::one();
+                    Self::normalized(coeff, exp - 1, precision)
+                } else {
+                    Self::normalized(&coeff - &BigInt::one(), exp, precision)
+                }
+            }
+        }
+    }
+
+    /// Splits `self` into a mantissa and an exponent as in `frexp`.
+    ///
+    /// The mantissa is rounded to the nearest decimal, so `self == m * 2^e` holds only when the
+    /// mantissa is representable.
+    fn frexp(self) -> (Self, isize) {
+        if !self.is_finite() || self.is_zero() {
+            return (self, 0);
+        }
+        let e = self.to_rational(self.precision).frexp().1;
+        (self.ldexp(-e), e)
+    }
+
+    fn ldexp(self, exp: isize) -> Self {
+        if !self.is_finite() || self.is_zero() {
+            return self;
+        }
+        let shift = exp.unsigned_abs();
+        let (coeff, e) = if exp >= 0 {
+            (self.coeff.shl(shift), self.exp)
+        } else {
+            // `2^-shift == 5^shift * 10^-shift`.
+            let five = BigInt::from(5u64).pow(shift as u32);
+            (&self.coeff * &five, self.exp - shift as isize)
+        };
+        Self::round(coeff, e, false, self.precision, Round::Nearest).0
+    }
+}
@@ -0,0 +1,229 @@
+use super::def::{digits, num_digits, pow10, Decimal, GUARD_BITS};
+
+use fp;
+use fp::{Float, Round};
+use rational::{BigInt, Rational};
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+impl Decimal {
+    /// Returns an `f64` that behaves like `self` under IEEE 754 arithmetic with zeros, infinities
+    /// and NaN: the special value itself, or the sign of a nonzero finite value.
+    #[inline]
+    fn class(&self) -> f64 {
+        if self.is_nan() {
+            f64::NAN
+        } else {
+            self.sign() as i8 as f64 * if self.is_finite() { 1.0 } else { f64::INFINITY }
+        }
+    }
+
+    /// Computes `op` on the classes of `self` and `rhs`, for operations whose result is zero, an
+    /// infinity or NaN.
+    #[inline]
+    fn special_op<F>(&self, rhs: &Self, op: F) -> (Self, bool)
+    where
+        F: FnOnce(f64, f64) -> f64,
+    {
+        let precision = self.precision.max(rhs.precision);
+        let x = op(self.class(), rhs.class());
+        let res = if x.is_nan() {
+            Self::nan(precision)
+        } else if x == 0.0 {
+            Self::zero(precision)
+        } else if x > 0.0 {
+            Self::infinity(precision)
+        } else {
+            Self::neg_infinity(precision)
+        };
+        (res, true)
+    }
+
+    /// Computes `self + rhs`, rounds the result, and tells whether the result is exact.
+    fn add_with(self, rhs: Self, rounding: Round) -> (Self, bool) {
+        if !self.is_finite() || !rhs.is_finite() {
+            return self.special_op(&rhs, |x, y| x + y);
+        }
+        let precision = self.precision.max(rhs.precision);
+        let (a, b) = if self.exp >= rhs.exp {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        // A zero operand leaves the other one to be rounded, so the exponents are not aligned.
+        if b.is_zero() {
+            return Self::round(a.coeff, a.exp, false, precision, rounding);
+        }
+        if a.is_zero() {
+            return Self::round(b.coeff, b.exp, false, precision, rounding);
+        }
+        let gap = (a.exp - b.exp) as usize;
+        let guard = digits(precision) + 2;
+        if gap >= num_digits(&b.coeff) + guard {
+            // `b` is smaller than one unit of `a.coeff * 10^guard`, so it only decides the
+            // direction of rounding, and aligning the exponents would be wasteful.
+            let scaled = &a.coeff * &pow10(guard);
+            let value = if a.sign() == b.sign() {
+                scaled
+            } else {
+                &scaled - &BigInt::from(a.sign() as i8 as i64)
+            };
+            return Self::round(value, a.exp - guard as isize, true, precision, rounding);
+        }
+        let value = &(&a.coeff * &pow10(gap)) + &b.coeff;
+        Self::round(value, b.exp, false, precision, rounding)
+    }
+
+    /// Computes `self * rhs`, rounds the result, and tells whether the result is exact.
+    fn mul_with(self, rhs: Self, rounding: Round) -> (Self, bool) {
+        if !self.is_finite() || !rhs.is_finite() {
+            return self.special_op(&rhs, |x, y| x * y);
+        }
+        let precision = self.precision.max(rhs.precision);
+        let value = &self.coeff * &rhs.coeff;
+        Self::round(value, self.exp + rhs.exp, false, precision, rounding)
+    }
+
+    /// Computes `self / rhs`, rounds the result, and tells whether the result is exact.
+    fn div_with(self, rhs: Self, rounding: Round) -> (Self, bool) {
+        if !self.is_finite() || !rhs.is_finite() || self.is_zero() || rhs.is_zero() {
+            return self.special_op(&rhs, |x, y| x / y);
+        }
+        let precision = self.precision.max(rhs.precision);
+        // Scale the dividend so that the quotient has more digits than the precision allows.
+        let k = (digits(precision) + 2 + num_digits(&rhs.coeff))
+            .saturating_sub(num_digits(&self.coeff));
+        let (q, r) = (&self.coeff * &pow10(k)).div_rem(&rhs.coeff);
+        let exp = self.exp - rhs.exp - k as isize;
+        Self::round(q, exp, !r.is_zero(), precision, rounding)
+    }
+}
+
+impl Neg for Decimal {
+    type Output = Decimal;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Decimal {
+            coeff: -self.coeff,
+            ..self
+        }
+    }
+}
+
+impl Add for Decimal {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.add_with(rhs, Round::Nearest).0
+    }
+}
+
+impl Sub for Decimal {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.add_with(-rhs, Round::Nearest).0
+    }
+}
+
+impl Mul for Decimal {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_with(rhs, Round::Nearest).0
+    }
+}
+
+impl Div for Decimal {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_with(rhs, Round::Nearest).0
+    }
+}
+
+impl fp::Add for Decimal {
+    #[inline]
+    fn add_r(self, rhs: Self, round: Round) -> Self {
+        self.add_with(rhs, round).0
+    }
+
+    #[inline]
+    fn add_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        self.add_with(rhs, round)
+    }
+}
+
+impl fp::Sub for Decimal {
+    #[inline]
+    fn sub_r(self, rhs: Self, round: Round) -> Self {
+        self.add_with(-rhs, round).0
+    }
+
+    #[inline]
+    fn sub_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        self.add_with(-rhs, round)
+    }
+}
+
+impl fp::Mul for Decimal {
+    #[inline]
+    fn mul_r(self, rhs: Self, round: Round) -> Self {
+        self.mul_with(rhs, round).0
+    }
+
+    #[inline]
+    fn mul_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        self.mul_with(rhs, round)
+    }
+}
+
+impl fp::Div for Decimal {
+    #[inline]
+    fn div_r(self, rhs: Self, round: Round) -> Self {
+        self.div_with(rhs, round).0
+    }
+
+    #[inline]
+    fn div_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        self.div_with(rhs, round)
+    }
+}
+
+impl fp::Fma for Decimal {
+    fn fma_r_exact(self, a: Self, b: Self, round: Round) -> (Self, bool) {
+        let precision = self.precision.max(a.precision).max(b.precision);
+        let x = self.to_rational(precision + GUARD_BITS) * a.to_rational(precision + GUARD_BITS)
+            + b.to_rational(precision + GUARD_BITS);
+        Self::from_rational(&x, precision, round)
+    }
+}
+
+impl fp::Sum for Decimal {
+    fn sum_r(xs: &[Self], precision: usize, round: Round) -> Self {
+        let xs: Vec<_> = xs
+            .iter()
+            .map(|x| x.to_rational(precision + GUARD_BITS))
+            .collect();
+        let x = Rational::sum_r(&xs, precision + GUARD_BITS, round);
+        Self::from_rational(&x, precision, round).0
+    }
+
+    fn dot_r(xs: &[Self], ys: &[Self], precision: usize, round: Round) -> Self {
+        let xs: Vec<_> = xs
+            .iter()
+            .map(|x| x.to_rational(precision + GUARD_BITS))
+            .collect();
+        let ys: Vec<_> = ys
+            .iter()
+            .map(|y| y.to_rational(precision + GUARD_BITS))
+            .collect();
+        let x = Rational::dot_r(&xs, &ys, precision + GUARD_BITS, round);
+        Self::from_rational(&x, precision, round).0
+    }
+}
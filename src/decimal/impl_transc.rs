@@ -0,0 +1,88 @@
+use super::def::{Decimal, GUARD_BITS};
+
+use fp;
+use fp::Round;
+use rational::Rational;
+use transc::Transc;
+
+impl Decimal {
+    /// Applies `f` to the exact value of `self` and rounds the result in the direction of `round`.
+    /// The result is exact if `f` tells so and the rounding is exact.
+    fn with_rational<F>(self, round: Round, f: F) -> (Self, bool)
+    where
+        F: FnOnce(Rational) -> (Rational, bool),
+    {
+        let precision = self.precision;
+        let (x, exact) = f(self.to_rational(precision + GUARD_BITS));
+        let (res, res_exact) = Self::from_rational(&x, precision, round);
+        (res, exact && res_exact)
+    }
+
+    /// Computes `self^rhs` through rationals, rounds the result in the direction of `round`, and
+    /// tells whether the result is exact.
+    fn pow_with(self, rhs: Self, round: Round) -> (Self, bool) {
+        let precision = self.precision.max(rhs.precision);
+        let y = rhs.to_rational(precision + GUARD_BITS);
+        let x = Decimal { precision, ..self };
+        x.with_rational(round, |x| fp::Transc::pow_r_exact(x, y, round))
+    }
+}
+
+impl Transc for Decimal {
+    type Output = Self;
+
+    #[inline]
+    fn log(self) -> Self::Output {
+        let precision = self.precision;
+        let x = self.to_rational(precision + GUARD_BITS).log();
+        Self::from_rational(&x, precision, Round::Nearest).0
+    }
+
+    #[inline]
+    fn exp(self) -> Self::Output {
+        let precision = self.precision;
+        let x = self.to_rational(precision + GUARD_BITS).exp();
+        Self::from_rational(&x, precision, Round::Nearest).0
+    }
+
+    #[inline]
+    fn pow(self, rhs: Self) -> Self::Output {
+        let precision = self.precision.max(rhs.precision);
+        let x = self
+            .to_rational(precision + GUARD_BITS)
+            .pow(rhs.to_rational(precision + GUARD_BITS));
+        Self::from_rational(&x, precision, Round::Nearest).0
+    }
+}
+
+impl fp::Transc for Decimal {
+    #[inline]
+    fn log_r(self, round: Round) -> Self {
+        self.log_r_exact(round).0
+    }
+
+    #[inline]
+    fn exp_r(self, round: Round) -> Self {
+        self.exp_r_exact(round).0
+    }
+
+    #[inline]
+    fn pow_r(self, rhs: Self, round: Round) -> Self {
+        self.pow_r_exact(rhs, round).0
+    }
+
+    #[inline]
+    fn log_r_exact(self, round: Round) -> (Self, bool) {
+        self.with_rational(round, |x| fp::Transc::log_r_exact(x, round))
+    }
+
+    #[inline]
+    fn exp_r_exact(self, round: Round) -> (Self, bool) {
+        self.with_rational(round, |x| fp::Transc::exp_r_exact(x, round))
+    }
+
+    #[inline]
+    fn pow_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        self.pow_with(rhs, round)
+    }
+}
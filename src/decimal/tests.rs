@@ -0,0 +1,272 @@
+use super::Decimal;
+
+use fp;
+use fp::{Float, Integral, Sign};
+use transc::Transc;
+
+use std::str::FromStr;
+
+const PREC: usize = 53;
+
+macro_rules! assert_str_eq {
+    ($x:expr, $y:expr) => { assert_eq!($x, format!("{}", $y)) };
+    ($x:expr, $y:expr, $($arg:tt)+) => { assert_eq!($x, format!("{}", $y), $($arg)+) };
+}
+
+macro_rules! d {
+    ($v:expr) => {
+        Decimal::from_str_with_prec($v, PREC).unwrap()
+    };
+}
+
+#[test]
+fn test_from_str() {
+    assert_str_eq!("0.1", d!("0.1"));
+    assert_str_eq!("-123.45", d!("-123.450"));
+    assert_str_eq!("1200", d!("12e2"));
+    assert_str_eq!("0", d!("-0.000"));
+    assert_str_eq!("0.3333333333333333", d!("1/3"));
+    assert_str_eq!("0.6666666666666667", d!("2/3"));
+    assert_str_eq!("1.234567890123457e25", d!("12345678901234567890123456"));
+    assert_str_eq!("1e-7", d!("0.0000001"));
+    assert_str_eq!("0.000001", d!("0.000001"));
+    assert_str_eq!("1e21", d!("1e21"));
+    assert_str_eq!("100000000000000000000", d!("1e20"));
+    assert_str_eq!("inf", d!("inf"));
+    assert_str_eq!("-inf", d!("-inf"));
+    assert_str_eq!("NaN", d!("NaN"));
+    assert!(Decimal::from_str("1.2.3").is_err());
+    assert!(Decimal::from_str("<1, 2>").is_err());
+    let lo: Decimal = fp::FromStr::from_str_lo("1/3", PREC).unwrap();
+    let hi: Decimal = fp::FromStr::from_str_hi("1/3", PREC).unwrap();
+    assert_str_eq!("0.3333333333333333", lo);
+    assert_str_eq!("0.3333333333333334", hi);
+    let lo: Decimal = fp::FromStr::from_str_lo("-2/3", PREC).unwrap();
+    assert_str_eq!("-0.6666666666666667", lo);
+    let lo: Decimal = fp::FromStr::from_str_lo("0.1", PREC).unwrap();
+    assert_str_eq!("0.1", lo);
+    assert_str_eq!(
+        "0.33333333",
+        Decimal::from_str_with_prec("1/3", 24).unwrap()
+    );
+}
+
+#[test]
+fn test_f64() {
+    assert_str_eq!("0.1", Decimal::from(0.1));
+    assert_str_eq!("0.1", <Decimal as fp::From<f64>>::from_lo(0.1, PREC));
+    assert_str_eq!(
+        "0.1000000000000001",
+        <Decimal as fp::From<f64>>::from_hi(0.1, PREC)
+    );
+    assert_str_eq!("1e300", Decimal::from(1e300));
+    assert_eq!(0.1, f64::from(d!("0.1")));
+    assert_eq!(0.1, fp::Into::<f64>::into_hi(d!("0.1")));
+    assert_eq!(Float::next_down(0.1), fp::Into::<f64>::into_lo(d!("0.1")));
+    assert_eq!(f64::INFINITY, f64::from(d!("inf")));
+    assert!(f64::from(d!("NaN")).is_nan());
+}
+
+#[test]
+fn test_cmp() {
+    assert!(d!("0.1") < d!("0.2"));
+    assert!(d!("0.99") < d!("1"));
+    assert!(d!("-0.99") > d!("-1"));
+    assert!(d!("1e-5") > d!("-1e5"));
+    assert!(d!("120") == d!("1.2e2"));
+    assert!(d!("-inf") < d!("-1e300"));
+    assert!(d!("inf") > d!("1e300"));
+    assert!(d!("inf") == d!("inf"));
+    assert!(d!("NaN") != d!("NaN"));
+    assert!(!(d!("NaN") < d!("1")));
+    assert!(!(d!("NaN") >= d!("1")));
+    assert_eq!(Sign::Zero, d!("NaN").sign());
+    assert_eq!(Sign::Negative, d!("-inf").sign());
+}
+
+#[test]
+fn test_add_sub() {
+    use fp::{Add, Sub};
+    assert_str_eq!("0.3", d!("0.1") + d!("0.2"));
+    assert_str_eq!("-0.1", d!("0.1") - d!("0.2"));
+    assert_str_eq!("0", d!("0.1") - d!("0.1"));
+    assert_eq!((d!("0.3"), true), d!("0.1").add_lo_exact(d!("0.2")));
+    assert_str_eq!("1", d!("1") + d!("1e-20"));
+    assert_str_eq!("1", d!("1").add_lo(d!("1e-20")));
+    assert_str_eq!("1.000000000000001", d!("1").add_hi(d!("1e-20")));
+    assert_str_eq!("0.9999999999999999", d!("1").sub_lo(d!("1e-20")));
+    assert_str_eq!("1", d!("1").sub_hi(d!("1e-20")));
+    assert_str_eq!("1e400", d!("1e400").sub_hi(d!("1e-400")));
+    assert_str_eq!("9.999999999999999e399", d!("1e400").sub_lo(d!("1e-400")));
+    let tiny = Decimal::normalized(::rational::BigInt::one(), -1_000_000_000, PREC);
+    assert_eq!((tiny.clone(), true), d!("0").add_lo_exact(tiny.clone()));
+    assert_eq!((-tiny.clone(), true), d!("0").sub_hi_exact(tiny));
+    assert_str_eq!("10000000000000000", d!("9999999999999999") + d!("1"));
+    assert_str_eq!("9999999999999999", d!("9999999999999999").add_lo(d!("0.5")));
+    assert_str_eq!(
+        "10000000000000000",
+        d!("9999999999999999").add_hi(d!("0.5"))
+    );
+    assert_str_eq!("NaN", d!("inf") - d!("inf"));
+    assert_str_eq!("-inf", d!("1") - d!("inf"));
+}
+
+#[test]
+fn test_mul_div() {
+    use fp::{Div, Mul};
+    assert_str_eq!("0.02", d!("0.1") * d!("0.2"));
+    assert_eq!((d!("0.02"), true), d!("0.1").mul_lo_exact(d!("0.2")));
+    assert_str_eq!("0.3333333333333333", d!("1").div_lo(d!("3")));
+    assert_str_eq!("0.3333333333333334", d!("1").div_hi(d!("3")));
+    assert_str_eq!("-0.3333333333333334", d!("-1").div_lo(d!("3")));
+    assert_str_eq!("-0.3333333333333333", d!("1").div_hi(d!("-3")));
+    assert_str_eq!("0.6666666666666667", d!("2") / d!("3"));
+    assert_eq!((d!("0.25"), true), d!("1").div_hi_exact(d!("4")));
+    assert_eq!((d!("12.5"), true), d!("100").div_lo_exact(d!("8")));
+    let (x, exact) = d!("1").div_lo_exact(d!("3"));
+    assert_str_eq!("0.3333333333333333", x);
+    assert!(!exact);
+    let x = d!("1.111111111111111");
+    assert_str_eq!("1.234567901234567", x.clone().mul_lo(x.clone()));
+    assert_str_eq!("1.234567901234568", x.clone().mul_hi(x.clone()));
+    assert_str_eq!("-1.234567901234568", x.clone().mul_lo(-x.clone()));
+    assert_str_eq!("1e600", d!("1e300") * d!("1e300"));
+    assert_str_eq!("inf", d!("1") / d!("0"));
+    assert_str_eq!("-inf", d!("-1") / d!("0"));
+    assert_str_eq!("NaN", d!("0") / d!("0"));
+    assert_str_eq!("0", d!("0") / d!("3"));
+    assert_str_eq!("0", d!("1") / d!("-inf"));
+    assert_str_eq!("NaN", d!("0") * d!("inf"));
+}
+
+#[test]
+fn test_round() {
+    use fp::{Div, Round, Transc};
+    let (one, three) = (d!("1"), d!("3"));
+    assert_str_eq!(
+        "0.3333333333333333",
+        one.clone().div_r(three.clone(), Round::Nearest)
+    );
+    assert_str_eq!(
+        "-0.3333333333333333",
+        (-one.clone()).div_r(three.clone(), Round::TowardsZero)
+    );
+    assert_str_eq!(
+        "-0.3333333333333334",
+        (-one.clone()).div_r(three.clone(), Round::AwayFromZero)
+    );
+    assert_str_eq!(
+        "0.3333333333333334",
+        one.clone().div_r(three, Round::AwayFromZero)
+    );
+    assert_eq!(
+        (d!("0.25"), true),
+        one.clone().div_r_exact(d!("4"), Round::Nearest)
+    );
+    assert_str_eq!("2.718281828459045", one.clone().exp_r(Round::Nearest));
+    assert_str_eq!("2.718281828459046", one.exp_r(Round::AwayFromZero));
+}
+
+#[test]
+fn test_fma_sum() {
+    use fp::{Fma, Sum};
+    let third = d!("0.3333333333333333");
+    assert_eq!(
+        (d!("-1e-16"), true),
+        d!("3").fma_lo_exact(third.clone(), d!("-1"))
+    );
+    let xs = [d!("1e20"), d!("1"), d!("-1e20")];
+    assert_str_eq!("1", Decimal::sum_lo(&xs, PREC));
+    let (xs, ys) = ([d!("1"), d!("1e-20")], [d!("1"), d!("1")]);
+    assert_str_eq!("1", Decimal::dot_lo(&xs, &ys, PREC));
+    assert_str_eq!("1.000000000000001", Decimal::dot_hi(&xs, &ys, PREC));
+}
+
+#[test]
+fn test_integral() {
+    let check = |x: &str, floor: &str, ceil: &str, even: &str, away: &str, trunc: &str| {
+        assert_str_eq!(floor, d!(x).floor(), "floor({})", x);
+        assert_str_eq!(ceil, d!(x).ceil(), "ceil({})", x);
+        assert_str_eq!(even, d!(x).round_ties_even(), "round_ties_even({})", x);
+        assert_str_eq!(away, d!(x).round_ties_away(), "round_ties_away({})", x);
+        assert_str_eq!(trunc, d!(x).trunc(), "trunc({})", x);
+    };
+    check("2.3", "2", "3", "2", "2", "2");
+    check("-2.3", "-3", "-2", "-2", "-2", "-2");
+    check("2.5", "2", "3", "2", "3", "2");
+    check("-2.5", "-3", "-2", "-2", "-3", "-2");
+    check(
+        "1e20",
+        "100000000000000000000",
+        "100000000000000000000",
+        "100000000000000000000",
+        "100000000000000000000",
+        "100000000000000000000",
+    );
+    check("-inf", "-inf", "-inf", "-inf", "-inf", "-inf");
+    assert_str_eq!("0.3", d!("2.3").frac());
+    assert_str_eq!("-0.3", d!("-2.3").frac());
+    assert_str_eq!("0", d!("1e20").frac());
+    assert_str_eq!("NaN", d!("inf").frac());
+}
+
+#[test]
+fn test_rem_hypot() {
+    use fp::{Hypot, Rem};
+    assert_str_eq!("0.1", d!("1.1").fmod_lo(d!("0.5")));
+    assert_str_eq!("-0.1", d!("-1.1").fmod_hi(d!("0.5")));
+    assert_str_eq!("-0.1", d!("1.9").remainder_lo(d!("0.5")));
+    assert_str_eq!("1.1", d!("1.1").fmod_lo(d!("-inf")));
+    assert_str_eq!("NaN", d!("1.1").remainder_lo(d!("0")));
+    assert_str_eq!("0.5", d!("0.3").hypot_lo(d!("-0.4")));
+    assert_str_eq!("1.414213562373095", d!("1").hypot_lo(d!("1")));
+    assert_str_eq!("1.414213562373096", d!("1").hypot_hi(d!("1")));
+}
+
+#[test]
+fn test_transc() {
+    use fp::Transc as FpTransc;
+    assert_str_eq!("0.6931471805599453", d!("2").log_lo());
+    assert_str_eq!("0.6931471805599454", d!("2").log_hi());
+    assert_str_eq!("0.6931471805599453", d!("2").log());
+    assert_str_eq!("2.718281828459045", d!("1").exp_lo());
+    assert_str_eq!("2.718281828459046", d!("1").exp_hi());
+    assert_eq!((d!("0"), true), d!("1").log_lo_exact());
+    assert_eq!((d!("1.5"), true), d!("2.25").pow_hi_exact(d!("0.5")));
+    assert_eq!((d!("1.21"), true), d!("1.1").pow_lo_exact(d!("2")));
+    assert_str_eq!("1.414213562373095", d!("2").pow_lo(d!("0.5")));
+    assert_str_eq!("1.414213562373096", d!("2").pow_hi(d!("0.5")));
+    assert_str_eq!("NaN", d!("-2").pow_lo(d!("0.5")));
+    assert_str_eq!("-inf", d!("0").log_lo());
+    assert_str_eq!("0", d!("-inf").exp_hi());
+}
+
+#[test]
+fn test_float() {
+    assert_str_eq!("1e-398", Decimal::zero(PREC).next_up());
+    assert_str_eq!("-1e-398", Decimal::zero(PREC).next_down());
+    assert_str_eq!("1.000000000000001", d!("1").next_up());
+    assert_str_eq!("0.9999999999999999", d!("1").next_down());
+    assert_str_eq!("-0.9999999999999999", d!("-1").next_up());
+    assert_str_eq!("-1.000000000000001", d!("-1").next_down());
+    assert_str_eq!("0.1000000000000001", d!("0.1").next_up());
+    assert_str_eq!("9999999999999999", d!("1e16").next_down());
+    assert_str_eq!("inf", Decimal::infinity(PREC).next_down());
+    assert_str_eq!("NaN", Decimal::nan(PREC).next_up());
+    assert!(Decimal::nan(PREC).is_nan());
+    assert!(!Decimal::nan(PREC).is_finite());
+    assert_str_eq!("-inf", -Decimal::infinity(PREC));
+    assert_str_eq!("NaN", -Decimal::nan(PREC));
+    assert_str_eq!("1.5", fp::Abs::abs(d!("-1.5")));
+    assert!(d!("3").has_odd_denominator());
+    assert!(d!("0.2").has_odd_denominator());
+    assert!(d!("1e5").has_odd_denominator());
+    assert!(!d!("0.5").has_odd_denominator());
+    assert!(!d!("0.1").has_odd_denominator());
+    let (m, e) = d!("3").frexp();
+    assert_str_eq!("0.75", m);
+    assert_eq!(2, e);
+    assert_str_eq!("0.75", d!("3").ldexp(-2));
+    assert_str_eq!("12", d!("3").ldexp(2));
+    assert_str_eq!("0.3333333333333333", d!("0.6666666666666666").ldexp(-1));
+}
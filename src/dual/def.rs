@@ -0,0 +1,13 @@
+/// Dual number struct for forward-mode automatic differentiation.
+///
+/// Represents a function value together with its partial derivatives with respect to a number of
+/// independent variables. A missing element of `gradient` is a zero derivative, so that constants
+/// have an empty gradient. For `Dual<Interval<BOUND>>`, the value and every derivative are
+/// intervals that enclose the ranges of the function and its partial derivatives over the inputs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dual<T> {
+    /// Function value.
+    pub value: T,
+    /// Partial derivatives of the function value.
+    pub gradient: Vec<T>,
+}
@@ -0,0 +1,67 @@
+use super::def::Dual;
+
+use fp::Bound;
+use interval::Interval;
+
+impl<BOUND: Bound> Dual<Interval<BOUND>> {
+    /// Constructs a dual number from a value and its partial derivatives.
+    #[inline]
+    pub fn new(value: Interval<BOUND>, gradient: Vec<Interval<BOUND>>) -> Self {
+        Dual { value, gradient }
+    }
+
+    /// Constructs a constant, whose derivatives are all zero.
+    #[inline]
+    pub fn constant(value: Interval<BOUND>) -> Self {
+        Self::new(value, vec![])
+    }
+
+    /// Constructs the only independent variable of a univariate function, whose derivative is one.
+    #[inline]
+    pub fn variable(value: Interval<BOUND>) -> Self {
+        let precision = value.precision();
+        Self::new(value, vec![Interval::one(precision)])
+    }
+
+    /// Constructs independent variables of a multivariate function from their values, so that the
+    /// gradient of the `i`-th variable is the `i`-th unit vector.
+    pub fn variables(values: Vec<Interval<BOUND>>) -> Vec<Self> {
+        let n = values.len();
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(i, x)| {
+                let precision = x.precision();
+                let mut gradient = vec![Interval::zero(precision); n];
+                gradient[i] = Interval::one(precision);
+                Self::new(x, gradient)
+            })
+            .collect()
+    }
+
+    /// Returns the partial derivative with respect to the `i`-th variable.
+    pub fn derivative(&self, i: usize) -> Interval<BOUND> {
+        match self.gradient.get(i) {
+            Some(d) => d.clone(),
+            None => Interval::zero(self.precision()),
+        }
+    }
+
+    /// Returns the precision of the value.
+    #[inline]
+    pub fn precision(&self) -> usize {
+        self.value.precision()
+    }
+
+    /// Whether the value or any derivative is NaN.
+    pub fn is_nan(&self) -> bool {
+        self.value.is_nan() || self.gradient.iter().any(Interval::is_nan)
+    }
+}
+
+impl<BOUND: Bound> From<Interval<BOUND>> for Dual<Interval<BOUND>> {
+    #[inline]
+    fn from(value: Interval<BOUND>) -> Self {
+        Self::constant(value)
+    }
+}
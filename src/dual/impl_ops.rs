@@ -0,0 +1,172 @@
+use super::def::Dual;
+
+use fp::{Bound, Float};
+use interval::Interval;
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Multiplies the derivative `d` by `factor` from the chain rule, or returns `None` if `d` is zero.
+///
+/// A zero derivative contributes nothing, even where `factor` is NaN or unbounded, as for the
+/// derivative of `pow` with respect to a constant exponent.
+fn scale<BOUND, F>(d: Option<&Interval<BOUND>>, factor: F) -> Option<Interval<BOUND>>
+where
+    BOUND: Bound,
+    F: FnOnce(Interval<BOUND>) -> Interval<BOUND>,
+{
+    match d {
+        Some(d) if !d.is_zero() => Some(factor(d.clone())),
+        _ => None,
+    }
+}
+
+/// Combines the gradients `xs` and `ys` of the arguments of a binary operation into the gradient
+/// `fx * xs + fy * ys` of its result, where `fx` and `fy` are the partial derivatives of the
+/// operation.
+fn chain<BOUND, FX, FY>(
+    xs: &[Interval<BOUND>],
+    fx: FX,
+    ys: &[Interval<BOUND>],
+    fy: FY,
+    precision: usize,
+) -> Vec<Interval<BOUND>>
+where
+    BOUND: Bound,
+    FX: Fn(Interval<BOUND>) -> Interval<BOUND>,
+    FY: Fn(Interval<BOUND>) -> Interval<BOUND>,
+{
+    let n = xs.len().max(ys.len());
+    (0..n)
+        .map(|i| match (scale(xs.get(i), &fx), scale(ys.get(i), &fy)) {
+            (Some(x), Some(y)) => x + y,
+            (Some(x), None) => x,
+            (None, Some(y)) => y,
+            (None, None) => Interval::zero(precision),
+        })
+        .collect()
+}
+
+impl<BOUND: Bound> Dual<Interval<BOUND>> {
+    /// Applies a univariate function `f` to `self`, given an enclosure `value` of the range of `f`
+    /// and an enclosure `derivative` of the range of its derivative over `self.value`.
+    ///
+    /// This extends dual numbers to functions other than the arithmetic operators and those of
+    /// `transc::Transc`.
+    pub fn compose(self, value: Interval<BOUND>, derivative: Interval<BOUND>) -> Self {
+        let precision = value.precision();
+        let gradient = chain(
+            &self.gradient,
+            |d| d * derivative.clone(),
+            &[],
+            |d| d,
+            precision,
+        );
+        Self::new(value, gradient)
+    }
+}
+
+impl<BOUND: Bound> Neg for Dual<Interval<BOUND>> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::new(
+            -self.value,
+            self.gradient.into_iter().map(Neg::neg).collect(),
+        )
+    }
+}
+
+impl<BOUND: Bound> Add<Self> for Dual<Interval<BOUND>> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let precision = self.precision();
+        let gradient = chain(&self.gradient, |d| d, &rhs.gradient, |d| d, precision);
+        Self::new(self.value + rhs.value, gradient)
+    }
+}
+
+impl<BOUND: Bound> Sub<Self> for Dual<Interval<BOUND>> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let precision = self.precision();
+        let gradient = chain(&self.gradient, |d| d, &rhs.gradient, Neg::neg, precision);
+        Self::new(self.value - rhs.value, gradient)
+    }
+}
+
+impl<BOUND: Bound> Mul<Self> for Dual<Interval<BOUND>> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let precision = self.precision();
+        let (x, y) = (self.value, rhs.value);
+        let gradient = chain(
+            &self.gradient,
+            |d| d * y.clone(),
+            &rhs.gradient,
+            |d| x.clone() * d,
+            precision,
+        );
+        Self::new(x * y, gradient)
+    }
+}
+
+impl<BOUND: Float> Div<Self> for Dual<Interval<BOUND>> {
+    type Output = Self;
+
+    /// Computes the derivative of `x / y` as `(dx - (x / y) * dy) / y`.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        let precision = self.precision();
+        let q = self.value / rhs.value.clone();
+        let y = rhs.value;
+        let gradient = chain(
+            &self.gradient,
+            |d| d / y.clone(),
+            &rhs.gradient,
+            |d| -(q.clone() * d) / y.clone(),
+            precision,
+        );
+        Self::new(q, gradient)
+    }
+}
+
+impl<BOUND: Bound> Add<Interval<BOUND>> for Dual<Interval<BOUND>> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Interval<BOUND>) -> Self::Output {
+        Self::new(self.value + rhs, self.gradient)
+    }
+}
+
+impl<BOUND: Bound> Sub<Interval<BOUND>> for Dual<Interval<BOUND>> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Interval<BOUND>) -> Self::Output {
+        Self::new(self.value - rhs, self.gradient)
+    }
+}
+
+impl<BOUND: Bound> Mul<Interval<BOUND>> for Dual<Interval<BOUND>> {
+    type Output = Self;
+
+    fn mul(self, rhs: Interval<BOUND>) -> Self::Output {
+        let value = self.value.clone() * rhs.clone();
+        self.compose(value, rhs)
+    }
+}
+
+impl<BOUND: Float> Div<Interval<BOUND>> for Dual<Interval<BOUND>> {
+    type Output = Self;
+
+    fn div(self, rhs: Interval<BOUND>) -> Self::Output {
+        let precision = self.precision();
+        let gradient = chain(&self.gradient, |d| d / rhs.clone(), &[], |d| d, precision);
+        Self::new(self.value / rhs, gradient)
+    }
+}
@@ -0,0 +1,38 @@
+use super::def::Dual;
+
+use fp::Float;
+use interval::Interval;
+use transc::Transc;
+
+impl<BOUND: Float> Transc for Dual<Interval<BOUND>> {
+    type Output = Self;
+
+    fn log(self) -> Self::Output {
+        let precision = self.precision();
+        let value = self.value.clone().log();
+        let derivative = Interval::one(precision) / self.value.clone();
+        self.compose(value, derivative)
+    }
+
+    fn exp(self) -> Self::Output {
+        let value = self.value.clone().exp();
+        self.compose(value.clone(), value)
+    }
+
+    /// Computes the derivative of `x^y` as `y * x^(y - 1) * dx + log(|x|) * x^y * dy`.
+    ///
+    /// `Interval::pow` extends `x^y` to negative `x` as `-|x|^y` and `|x|^y`, whose derivatives
+    /// with respect to `x` are enclosed in `y * x^(y - 1)` by the same extension, and those with
+    /// respect to `y` are the powers times `log(|x|)`. A constant exponent, as in `x^2`,
+    /// contributes no derivative, so that the logarithm of a base containing zero does not matter.
+    fn pow(self, rhs: Self) -> Self::Output {
+        let precision = self.precision();
+        let (x, y) = (self.value.clone(), rhs.value.clone());
+        let value = x.clone().pow(y.clone());
+        let fx = y.clone() * x.clone().pow(y - Interval::one(precision));
+        let fy = value.clone() * x.abs().log();
+        let dx = self.compose(value.clone(), fx);
+        let dy = rhs.compose(value.clone(), fy);
+        Dual::new(value, (dx + dy).gradient)
+    }
+}
@@ -0,0 +1,9 @@
+mod def;
+mod impl_basic;
+mod impl_ops;
+mod impl_transc;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::def::Dual;
@@ -0,0 +1,132 @@
+use super::Dual;
+
+use interval::{Interval, RootKind};
+use mpfr::Mpfr;
+use transc::Transc;
+
+type B = Mpfr;
+type IV = Interval<B>;
+type D = Dual<IV>;
+
+const PREC: usize = 53;
+
+macro_rules! assert_str_eq {
+    ($x:expr, $y:expr) => { assert_eq!($x, format!("{}", $y)) };
+    ($x:expr, $y:expr, $($arg:tt)+) => { assert_eq!($x, format!("{}", $y), $($arg)+) };
+}
+
+macro_rules! iv {
+    ($s:expr) => {
+        IV::from_str_with_prec($s, PREC).unwrap()
+    };
+}
+
+macro_rules! c {
+    ($s:expr) => {
+        D::constant(iv!($s))
+    };
+}
+
+fn encloses(x: &IV, y: &IV) -> bool {
+    x.lo <= y.lo && y.hi <= x.hi
+}
+
+#[test]
+fn test_constructors() {
+    let x = D::variable(iv!("<1, 2>"));
+    assert_str_eq!("<1, 2>", x.value);
+    assert_str_eq!("1", x.derivative(0));
+    assert_str_eq!("0", x.derivative(1));
+    assert!(c!("3").gradient.is_empty());
+    assert_eq!(c!("3"), D::from(iv!("3")));
+    let xs = D::variables(vec![iv!("1"), iv!("2")]);
+    assert_str_eq!("1", xs[0].derivative(0));
+    assert_str_eq!("0", xs[0].derivative(1));
+    assert_str_eq!("0", xs[1].derivative(0));
+    assert_str_eq!("1", xs[1].derivative(1));
+    assert!(D::variable(iv!("NaN")).is_nan());
+    assert!(!x.is_nan());
+}
+
+#[test]
+fn test_ops() {
+    let x = D::variable(iv!("<1, 2>"));
+    let y = x.clone() * x.clone() - iv!("2");
+    assert_str_eq!("<-1, 2>", y.value);
+    assert_str_eq!("<2, 4>", y.derivative(0));
+    let y = -(x.clone() + c!("1")) * iv!("3");
+    assert_str_eq!("<-9, -6>", y.value);
+    assert_str_eq!("-3", y.derivative(0));
+    let y = c!("1") / D::variable(iv!("2"));
+    assert_str_eq!("0.5", y.value);
+    assert_str_eq!("-0.25", y.derivative(0));
+    let y = D::variable(iv!("3")) / iv!("2") + iv!("1");
+    assert_str_eq!("2.5", y.value);
+    assert_str_eq!("0.5", y.derivative(0));
+    let y = x.clone() - x;
+    assert_str_eq!("<-1, 1>", y.value);
+    assert_str_eq!("0", y.derivative(0));
+}
+
+#[test]
+fn test_gradient() {
+    let xs = D::variables(vec![iv!("3"), iv!("4")]);
+    let f = xs[0].clone() * xs[1].clone() + xs[0].clone() / xs[1].clone();
+    assert_str_eq!("12.75", f.value);
+    assert_str_eq!("4.25", f.derivative(0));
+    assert_str_eq!("2.8125", f.derivative(1));
+    assert_eq!(2, f.gradient.len());
+}
+
+#[test]
+fn test_transc() {
+    let x = D::variable(iv!("1"));
+    let y = x.clone().exp().log();
+    assert!(encloses(&y.value, &iv!("1")));
+    assert!(encloses(&y.derivative(0), &iv!("1")));
+    assert!(y.derivative(0).rad() < iv!("1e-15").lo);
+    let y = D::variable(iv!("2")).log();
+    assert_str_eq!("0.5", y.derivative(0));
+    let y = x.exp();
+    assert_eq!(y.value, y.derivative(0));
+    assert!(D::variable(iv!("-1")).log().is_nan());
+}
+
+#[test]
+fn test_pow() {
+    let y = D::variable(iv!("<-2, -1>")).pow(c!("3"));
+    assert_str_eq!("<-8, -1>", y.value);
+    assert_str_eq!("<3, 12>", y.derivative(0));
+    let y = D::variable(iv!("<0, 1>")).pow(c!("2"));
+    assert_str_eq!("<0, 1>", y.value);
+    assert_str_eq!("<0, 2>", y.derivative(0));
+    let xs = D::variables(vec![iv!("2"), iv!("3")]);
+    let y = xs[0].clone().pow(xs[1].clone());
+    assert_str_eq!("8", y.value);
+    assert_str_eq!("12", y.derivative(0));
+    assert!(encloses(&y.derivative(1), &(iv!("8") * iv!("2").log())));
+    let y = c!("2").pow(D::variable(iv!("<-1, 1>")));
+    assert_str_eq!("<0.5, 2>", y.value);
+    assert!(encloses(
+        &y.derivative(0),
+        &(iv!("<0.5, 2>") * iv!("2").log())
+    ));
+    let y = D::variable(iv!("<-2, 2>")).pow(D::variable(iv!("<1, 2>")));
+    assert!(!y.is_nan());
+}
+
+#[test]
+fn test_compose_newton() {
+    let x = D::variable(iv!("<1, 2>"));
+    let y = x.clone().compose(iv!("<3, 4>"), iv!("2")) * x;
+    assert_str_eq!("<3, 8>", y.value);
+    assert_str_eq!("<5, 8>", y.derivative(0));
+    let f = |x: D| x.clone() * x.clone() * x - iv!("2");
+    let roots = iv!("<0, 2>").newton(
+        |x| f(D::constant(x)).value,
+        |x| f(D::variable(x)).derivative(0),
+    );
+    assert_eq!(1, roots.len());
+    assert_eq!(RootKind::Unique, roots[0].kind);
+    assert!(roots[0].interval.rad() < iv!("1e-15").lo);
+}
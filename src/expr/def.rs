@@ -0,0 +1,65 @@
+use intervalset::IntervalSet;
+
+/// Expression parsing error enum.
+#[derive(Debug, PartialEq)]
+pub enum ParseExprError {
+    /// A character cannot start any token.
+    UnexpectedCharacter,
+    /// A token is not allowed where it appears.
+    UnexpectedToken,
+    /// The string ends in the middle of an expression or a constant.
+    UnexpectedEnd,
+    /// A function other than `log`, `exp` and `pow` is called.
+    UnknownFunction,
+    /// A function is called with a wrong number of arguments.
+    InvalidNumberOfArguments,
+    /// There was an error while parsing a constant.
+    ConstantParseError,
+}
+
+/// Expression evaluation error enum.
+#[derive(Debug, PartialEq)]
+pub enum EvalError {
+    /// The variable of the given name has no value.
+    UnboundVariable(String),
+}
+
+/// Expression tree enum.
+///
+/// Represents an arithmetic expression over named variables with the operators of `Interval` and
+/// the functions of `transc::Transc`.
+#[derive(Clone, Debug)]
+pub enum Expr<BOUND: PartialOrd> {
+    /// Constant interval set.
+    Constant(IntervalSet<BOUND>),
+    /// Variable of the given name.
+    Variable(String),
+    /// Negation.
+    Neg(Box<Expr<BOUND>>),
+    /// Addition.
+    Add(Box<Expr<BOUND>>, Box<Expr<BOUND>>),
+    /// Subtraction.
+    Sub(Box<Expr<BOUND>>, Box<Expr<BOUND>>),
+    /// Multiplication.
+    Mul(Box<Expr<BOUND>>, Box<Expr<BOUND>>),
+    /// Division.
+    Div(Box<Expr<BOUND>>, Box<Expr<BOUND>>),
+    /// Power, written `x^y` or `pow(x, y)`.
+    Pow(Box<Expr<BOUND>>, Box<Expr<BOUND>>),
+    /// Natural logarithm.
+    Log(Box<Expr<BOUND>>),
+    /// Natural exponential.
+    Exp(Box<Expr<BOUND>>),
+}
+
+/// Constraint struct.
+///
+/// Represents the constraint that the value of `expr` lies in `target`, such as `x^2 + y^2` in
+/// `1` or `x - y` in `<-inf, 0>`.
+#[derive(Clone, Debug)]
+pub struct Constraint<BOUND: PartialOrd> {
+    /// Constrained expression.
+    pub expr: Expr<BOUND>,
+    /// Set of allowed values of `expr`.
+    pub target: IntervalSet<BOUND>,
+}
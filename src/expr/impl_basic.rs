@@ -0,0 +1,100 @@
+use super::def::Expr;
+
+use fp::Bound;
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// Precedence of sums and differences.
+const PREC_SUM: u8 = 1;
+/// Precedence of products and quotients.
+const PREC_PRODUCT: u8 = 2;
+/// Precedence of negations, including negative constants.
+const PREC_NEG: u8 = 3;
+/// Precedence of powers.
+const PREC_POW: u8 = 4;
+/// Precedence of variables, function calls and other constants.
+const PREC_ATOM: u8 = 5;
+
+impl<BOUND: Bound> Expr<BOUND> {
+    /// Returns the names of the variables in `self`.
+    pub fn variables(&self) -> BTreeSet<String> {
+        let mut names = BTreeSet::new();
+        self.collect_variables(&mut names);
+        names
+    }
+
+    fn collect_variables(&self, names: &mut BTreeSet<String>) {
+        match *self {
+            Expr::Constant(_) => {}
+            Expr::Variable(ref name) => {
+                names.insert(name.clone());
+            }
+            Expr::Neg(ref x) | Expr::Log(ref x) | Expr::Exp(ref x) => x.collect_variables(names),
+            Expr::Add(ref x, ref y)
+            | Expr::Sub(ref x, ref y)
+            | Expr::Mul(ref x, ref y)
+            | Expr::Div(ref x, ref y)
+            | Expr::Pow(ref x, ref y) => {
+                x.collect_variables(names);
+                y.collect_variables(names);
+            }
+        }
+    }
+
+    /// Returns the precedence of `self` in the grammar of `Expr::from_str_with_prec`.
+    fn precedence(&self) -> u8 {
+        match *self {
+            Expr::Constant(ref c) => {
+                if format!("{}", c).starts_with('-') {
+                    PREC_NEG
+                } else {
+                    PREC_ATOM
+                }
+            }
+            Expr::Variable(_) | Expr::Log(_) | Expr::Exp(_) => PREC_ATOM,
+            Expr::Neg(_) => PREC_NEG,
+            Expr::Add(_, _) | Expr::Sub(_, _) => PREC_SUM,
+            Expr::Mul(_, _) | Expr::Div(_, _) => PREC_PRODUCT,
+            Expr::Pow(_, _) => PREC_POW,
+        }
+    }
+
+    /// Writes `self`, in parentheses if its precedence is less than `min`.
+    fn fmt_operand(&self, min: u8, f: &mut Formatter) -> fmt::Result {
+        if self.precedence() < min {
+            write!(f, "({})", self)
+        } else {
+            Display::fmt(self, f)
+        }
+    }
+}
+
+/// Writes expressions that parse back into the same tree, with only the necessary parentheses.
+impl<BOUND: Bound> Display for Expr<BOUND> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let (x, op, y, prec) = match *self {
+            Expr::Constant(ref c) => return Display::fmt(c, f),
+            Expr::Variable(ref name) => return f.write_str(name),
+            Expr::Log(ref x) => return write!(f, "log({})", x),
+            Expr::Exp(ref x) => return write!(f, "exp({})", x),
+            Expr::Neg(ref x) => {
+                f.write_str("-")?;
+                return x.fmt_operand(PREC_NEG, f);
+            }
+            Expr::Add(ref x, ref y) => (x, " + ", y, PREC_SUM),
+            Expr::Sub(ref x, ref y) => (x, " - ", y, PREC_SUM),
+            Expr::Mul(ref x, ref y) => (x, " * ", y, PREC_PRODUCT),
+            Expr::Div(ref x, ref y) => (x, " / ", y, PREC_PRODUCT),
+            Expr::Pow(ref x, ref y) => {
+                x.fmt_operand(PREC_ATOM, f)?;
+                f.write_str("^")?;
+                return y.fmt_operand(PREC_NEG, f);
+            }
+        };
+        x.fmt_operand(prec, f)?;
+        f.write_str(op)?;
+        y.fmt_operand(prec + 1, f)
+    }
+}
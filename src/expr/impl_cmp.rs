@@ -0,0 +1,22 @@
+use super::def::Expr;
+
+use fp::Bound;
+
+impl<BOUND: Bound> PartialEq for Expr<BOUND> {
+    /// Whether `self` and `rhs` are the same tree with equal constants.
+    fn eq(&self, rhs: &Self) -> bool {
+        match (self, rhs) {
+            (Expr::Constant(x), Expr::Constant(y)) => x == y,
+            (Expr::Variable(x), Expr::Variable(y)) => x == y,
+            (Expr::Neg(x), Expr::Neg(y))
+            | (Expr::Log(x), Expr::Log(y))
+            | (Expr::Exp(x), Expr::Exp(y)) => x == y,
+            (Expr::Add(a, b), Expr::Add(c, d))
+            | (Expr::Sub(a, b), Expr::Sub(c, d))
+            | (Expr::Mul(a, b), Expr::Mul(c, d))
+            | (Expr::Div(a, b), Expr::Div(c, d))
+            | (Expr::Pow(a, b), Expr::Pow(c, d)) => a == c && b == d,
+            _ => false,
+        }
+    }
+}
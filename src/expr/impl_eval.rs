@@ -0,0 +1,68 @@
+use super::def::{EvalError, Expr};
+
+use fp::Float;
+use interval::Interval;
+use intervalset::IntervalSet;
+use transc::Transc;
+
+use std::collections::HashMap;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+impl<BOUND: Float> Expr<BOUND> {
+    /// Evaluates `self` over values of any type with the arithmetic operators and
+    /// `transc::Transc`, such as `Dual` or `Slope`, given the values of the variables in `vars`.
+    ///
+    /// `constant` converts each constant into a value.
+    pub fn eval_with<T, C>(&self, vars: &HashMap<String, T>, constant: &C) -> Result<T, EvalError>
+    where
+        T: Clone
+            + Neg<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Transc<Output = T>,
+        C: Fn(&IntervalSet<BOUND>) -> T,
+    {
+        let eval = |x: &Self| x.eval_with(vars, constant);
+        Ok(match *self {
+            Expr::Constant(ref c) => constant(c),
+            Expr::Variable(ref name) => match vars.get(name) {
+                Some(x) => x.clone(),
+                None => return Err(EvalError::UnboundVariable(name.clone())),
+            },
+            Expr::Neg(ref x) => -eval(x)?,
+            Expr::Add(ref x, ref y) => eval(x)? + eval(y)?,
+            Expr::Sub(ref x, ref y) => eval(x)? - eval(y)?,
+            Expr::Mul(ref x, ref y) => eval(x)? * eval(y)?,
+            Expr::Div(ref x, ref y) => eval(x)? / eval(y)?,
+            Expr::Pow(ref x, ref y) => eval(x)?.pow(eval(y)?),
+            Expr::Log(ref x) => eval(x)?.log(),
+            Expr::Exp(ref x) => eval(x)?.exp(),
+        })
+    }
+
+    /// Evaluates `self` over intervals, given the values of the variables in `vars`.
+    ///
+    /// Constants that are sets of more than one interval are replaced by their minimal covers.
+    /// Returns `EvalError::UnboundVariable` if a variable of `self` is not in `vars`.
+    pub fn eval(
+        &self,
+        vars: &HashMap<String, Interval<BOUND>>,
+    ) -> Result<Interval<BOUND>, EvalError> {
+        self.eval_with(vars, &|c: &IntervalSet<BOUND>| {
+            let precision = c.intervals.first().map_or(53, Interval::precision);
+            Interval::minimal_cover(c.intervals.clone(), precision)
+        })
+    }
+
+    /// Evaluates `self` over interval sets, given the values of the variables in `vars`.
+    ///
+    /// Returns `EvalError::UnboundVariable` if a variable of `self` is not in `vars`.
+    pub fn eval_set(
+        &self,
+        vars: &HashMap<String, IntervalSet<BOUND>>,
+    ) -> Result<IntervalSet<BOUND>, EvalError> {
+        self.eval_with(vars, &IntervalSet::clone)
+    }
+}
@@ -0,0 +1,236 @@
+use super::def::{Constraint, EvalError, Expr};
+
+use fp::Float;
+use interval::Interval;
+use intervalset::IntervalSet;
+use transc::Transc;
+
+use std::collections::HashMap;
+
+/// Default maximum number of rounds over all constraints made by `Constraint::propagate`.
+pub const DEFAULT_PROPAGATE_LIMIT: usize = 1000;
+
+/// Value of an expression node from the forward evaluation, with the values of its operands.
+struct Node<BOUND: PartialOrd> {
+    value: IntervalSet<BOUND>,
+    operands: Vec<Node<BOUND>>,
+}
+
+/// Returns the intersection of `x` and `y`.
+fn intersect<BOUND: Float>(x: &IntervalSet<BOUND>, y: &IntervalSet<BOUND>) -> IntervalSet<BOUND> {
+    let mut intervals = vec![];
+    for i in &x.intervals {
+        for j in &y.intervals {
+            intervals.push(i.clone().intersection(j.clone()));
+        }
+    }
+    IntervalSet::from_intervals(intervals)
+}
+
+/// Applies the reverse operation `op` to each pair of intervals of `x` and `z`, where `op` takes
+/// a prior enclosure of the unknown operand, the known operand and the result.
+fn rev<BOUND, F>(x: &IntervalSet<BOUND>, z: &IntervalSet<BOUND>, op: F) -> IntervalSet<BOUND>
+where
+    BOUND: Float,
+    F: Fn(Interval<BOUND>, Interval<BOUND>, Interval<BOUND>) -> IntervalSet<BOUND>,
+{
+    let mut intervals = vec![];
+    for i in &z.intervals {
+        for j in &x.intervals {
+            let whole = Interval::whole(i.precision());
+            intervals.append(&mut op(whole, j.clone(), i.clone()).intervals);
+        }
+    }
+    IntervalSet::from_intervals(intervals)
+}
+
+impl<BOUND: Float> Expr<BOUND> {
+    /// Evaluates `self` over interval sets and keeps the value of every node.
+    fn forward(
+        &self,
+        domains: &HashMap<String, IntervalSet<BOUND>>,
+    ) -> Result<Node<BOUND>, EvalError> {
+        let operands = match *self {
+            Expr::Constant(_) | Expr::Variable(_) => vec![],
+            Expr::Neg(ref x) | Expr::Log(ref x) | Expr::Exp(ref x) => vec![x.forward(domains)?],
+            Expr::Add(ref x, ref y)
+            | Expr::Sub(ref x, ref y)
+            | Expr::Mul(ref x, ref y)
+            | Expr::Div(ref x, ref y)
+            | Expr::Pow(ref x, ref y) => vec![x.forward(domains)?, y.forward(domains)?],
+        };
+        let value = {
+            let v = |i: usize| operands[i].value.clone();
+            match *self {
+                Expr::Constant(ref c) => c.clone(),
+                Expr::Variable(ref name) => match domains.get(name) {
+                    Some(x) => x.clone(),
+                    None => return Err(EvalError::UnboundVariable(name.clone())),
+                },
+                Expr::Neg(_) => -v(0),
+                Expr::Add(_, _) => v(0) + v(1),
+                Expr::Sub(_, _) => v(0) - v(1),
+                Expr::Mul(_, _) => v(0) * v(1),
+                Expr::Div(_, _) => v(0) / v(1),
+                Expr::Pow(_, _) => v(0).pow(v(1)),
+                Expr::Log(_) => v(0).log(),
+                Expr::Exp(_) => v(0).exp(),
+            }
+        };
+        Ok(Node { value, operands })
+    }
+
+    /// Narrows the operands of `self` so that its value lies in `z`, given the values from the
+    /// forward evaluation in `node`, and narrows the domains of the variables accordingly.
+    fn backward(
+        &self,
+        node: &Node<BOUND>,
+        z: IntervalSet<BOUND>,
+        domains: &mut HashMap<String, IntervalSet<BOUND>>,
+    ) {
+        let z = intersect(&node.value, &z);
+        let v = |i: usize| &node.operands[i].value;
+        match *self {
+            Expr::Constant(_) => {}
+            Expr::Variable(ref name) => {
+                let x = intersect(&domains[name], &z);
+                domains.insert(name.clone(), x);
+            }
+            Expr::Neg(ref x) => x.backward(&node.operands[0], -z, domains),
+            Expr::Log(ref x) => x.backward(&node.operands[0], z.exp(), domains),
+            Expr::Exp(ref x) => x.backward(&node.operands[0], z.log(), domains),
+            Expr::Add(ref x, ref y) => {
+                let xz = intersect(v(0), &(z.clone() - v(1).clone()));
+                let yz = z - xz.clone();
+                x.backward(&node.operands[0], xz, domains);
+                y.backward(&node.operands[1], yz, domains);
+            }
+            Expr::Sub(ref x, ref y) => {
+                let xz = intersect(v(0), &(z.clone() + v(1).clone()));
+                let yz = xz.clone() - z;
+                x.backward(&node.operands[0], xz, domains);
+                y.backward(&node.operands[1], yz, domains);
+            }
+            Expr::Mul(ref x, ref y) => {
+                let xz = intersect(v(0), &rev(v(1), &z, Interval::mul_rev));
+                let yz = rev(&xz, &z, Interval::mul_rev);
+                x.backward(&node.operands[0], xz, domains);
+                y.backward(&node.operands[1], yz, domains);
+            }
+            Expr::Div(ref x, ref y) => {
+                let xz = intersect(v(0), &(z.clone() * v(1).clone()));
+                let yz = rev(&xz, &z, Interval::div_rev2);
+                x.backward(&node.operands[0], xz, domains);
+                y.backward(&node.operands[1], yz, domains);
+            }
+            Expr::Pow(ref x, ref y) => {
+                let xz = intersect(v(0), &rev(v(1), &z, Interval::pow_rev1));
+                let yz = rev(&xz, &z, Interval::pow_rev2);
+                x.backward(&node.operands[0], xz, domains);
+                y.backward(&node.operands[1], yz, domains);
+            }
+        }
+    }
+
+    /// Narrows the domains of the variables of `self` so that its value lies in `target`, with one
+    /// forward and one backward pass of the HC4Revise algorithm.
+    ///
+    /// The forward pass evaluates every node over interval sets. The backward pass intersects the
+    /// value of the root with `target`, and then the value of every operand with the reverse
+    /// operation of its node, such as `z - y` for `x` in `z = x + y`, down to the variables. No
+    /// solution of the constraint is removed, and the gaps of interval sets, such as those from
+    /// division by an interval that contains zero, are kept. If the constraint is proved to have no
+    /// solution, all domains of the variables of `self` are made empty.
+    ///
+    /// Returns `EvalError::UnboundVariable` if a variable of `self` is not in `domains`.
+    pub fn revise(
+        &self,
+        target: &IntervalSet<BOUND>,
+        domains: &mut HashMap<String, IntervalSet<BOUND>>,
+    ) -> Result<(), EvalError> {
+        let node = self.forward(domains)?;
+        self.backward(&node, target.clone(), domains);
+        let variables = self.variables();
+        if variables.iter().any(|name| domains[name].is_empty()) {
+            for name in variables {
+                domains.insert(name, IntervalSet::empty());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<BOUND: Float> Constraint<BOUND> {
+    /// Constructs the constraint that the value of `expr` lies in `target`.
+    #[inline]
+    pub fn new(expr: Expr<BOUND>, target: IntervalSet<BOUND>) -> Self {
+        Constraint { expr, target }
+    }
+
+    /// Narrows the domains of the variables of `self` with HC4Revise.
+    ///
+    /// See `Expr::revise` for details.
+    #[inline]
+    pub fn revise(
+        &self,
+        domains: &mut HashMap<String, IntervalSet<BOUND>>,
+    ) -> Result<(), EvalError> {
+        self.expr.revise(&self.target, domains)
+    }
+
+    /// Narrows the domains of the variables of `constraints` with HC4, that is HC4Revise of each
+    /// constraint in turn until a fixpoint.
+    ///
+    /// The loop stops when no bound of the hull of any domain moves by more than `tolerance` in a
+    /// round over all constraints, when a domain becomes empty, which proves that the constraints
+    /// have no common solution, or after `limit` rounds, such as `DEFAULT_PROPAGATE_LIMIT`.
+    /// Returns `EvalError::UnboundVariable` if a variable of `constraints` is not in `domains`.
+    pub fn propagate(
+        constraints: &[Self],
+        domains: &mut HashMap<String, IntervalSet<BOUND>>,
+        tolerance: BOUND,
+        limit: usize,
+    ) -> Result<(), EvalError> {
+        for _ in 0..limit {
+            let before = domains.clone();
+            for c in constraints {
+                c.revise(domains)?;
+            }
+            if domains.values().any(IntervalSet::is_empty) {
+                return Ok(());
+            }
+            let moved = |x: &IntervalSet<BOUND>, y: &IntervalSet<BOUND>| {
+                let precision = x.intervals[0].precision();
+                let x = Interval::minimal_cover(x.intervals.clone(), precision);
+                let y = Interval::minimal_cover(y.intervals.clone(), precision);
+                y.lo.sub_lo(x.lo) > tolerance || x.hi.sub_lo(y.hi) > tolerance
+            };
+            if !before.iter().any(|(name, x)| moved(x, &domains[name])) {
+                return Ok(());
+            }
+        }
+        Ok(())
+    }
+
+    /// Narrows the interval domains of the variables of `constraints` with HC4.
+    ///
+    /// This is `Constraint::propagate` with each domain replaced by the minimal cover of its
+    /// narrowed interval set. Empty domains become NaN.
+    pub fn propagate_intervals(
+        constraints: &[Self],
+        domains: &mut HashMap<String, Interval<BOUND>>,
+        tolerance: BOUND,
+        limit: usize,
+    ) -> Result<(), EvalError> {
+        let mut sets: HashMap<_, _> = domains
+            .iter()
+            .map(|(name, x)| (name.clone(), IntervalSet::from_interval(x.clone())))
+            .collect();
+        Self::propagate(constraints, &mut sets, tolerance, limit)?;
+        for (name, x) in domains.iter_mut() {
+            let precision = x.precision();
+            *x = Interval::minimal_cover(sets[name].intervals.clone(), precision);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,263 @@
+use super::def::{Expr, ParseExprError};
+
+use fp::Float;
+use intervalset::IntervalSet;
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Token of the expression grammar.
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    /// A number, or an interval or interval set literal in brackets.
+    Constant(&'a str),
+    /// A variable or function name.
+    Ident(&'a str),
+    /// An operator.
+    Op(char),
+    /// An opening parenthesis.
+    Open,
+    /// A closing parenthesis.
+    Close,
+    /// A comma between function arguments.
+    Comma,
+}
+
+/// Splits `s` into tokens.
+fn tokenize<'a>(s: &'a str) -> Result<Vec<Token<'a>>, ParseExprError> {
+    let mut tokens = vec![];
+    let mut chars = s.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '+' | '-' | '*' | '/' | '^' => Token::Op(c),
+            '(' => Token::Open,
+            ')' => Token::Close,
+            ',' => Token::Comma,
+            '<' | '{' => {
+                let close = if c == '<' { '>' } else { '}' };
+                match s[start..].find(close) {
+                    Some(len) => {
+                        while let Some(&(i, _)) = chars.peek() {
+                            if i > start + len {
+                                break;
+                            }
+                            chars.next();
+                        }
+                        Token::Constant(&s[start..start + len + 1])
+                    }
+                    None => return Err(ParseExprError::UnexpectedEnd),
+                }
+            }
+            c if c.is_ascii_digit() || c == '.' => {
+                let mut end = start + 1;
+                let mut prev = c;
+                while let Some(&(i, c)) = chars.peek() {
+                    let exponent_sign = (c == '+' || c == '-') && (prev == 'e' || prev == 'E');
+                    if !(c.is_ascii_alphanumeric() || c == '.' || exponent_sign) {
+                        break;
+                    }
+                    chars.next();
+                    end = i + c.len_utf8();
+                    prev = c;
+                }
+                Token::Constant(&s[start..end])
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut end = start + c.len_utf8();
+                while let Some(&(i, c)) = chars.peek() {
+                    if !(c.is_alphanumeric() || c == '_') {
+                        break;
+                    }
+                    chars.next();
+                    end = i + c.len_utf8();
+                }
+                Token::Ident(&s[start..end])
+            }
+            _ => return Err(ParseExprError::UnexpectedCharacter),
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+/// Recursive descent parser of the expression grammar.
+struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    pos: usize,
+    precision: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Token<'a>> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Result<&Token<'a>, ParseExprError> {
+        self.pos += 1;
+        self.tokens
+            .get(self.pos - 1)
+            .ok_or(ParseExprError::UnexpectedEnd)
+    }
+
+    fn expect(&mut self, token: Token<'a>) -> Result<(), ParseExprError> {
+        if *self.next()? == token {
+            Ok(())
+        } else {
+            Err(ParseExprError::UnexpectedToken)
+        }
+    }
+
+    /// EXPR = TERM ( ( '+' | '-' ) TERM )*
+    fn expr<BOUND: Float>(&mut self) -> Result<Expr<BOUND>, ParseExprError> {
+        let mut x = self.term()?;
+        loop {
+            x = match self.peek() {
+                Some(&Token::Op('+')) => {
+                    self.pos += 1;
+                    Expr::Add(Box::new(x), Box::new(self.term()?))
+                }
+                Some(&Token::Op('-')) => {
+                    self.pos += 1;
+                    Expr::Sub(Box::new(x), Box::new(self.term()?))
+                }
+                _ => return Ok(x),
+            }
+            .fold();
+        }
+    }
+
+    /// TERM = UNARY ( ( '*' | '/' ) UNARY )*
+    fn term<BOUND: Float>(&mut self) -> Result<Expr<BOUND>, ParseExprError> {
+        let mut x = self.unary()?;
+        loop {
+            x = match self.peek() {
+                Some(&Token::Op('*')) => {
+                    self.pos += 1;
+                    Expr::Mul(Box::new(x), Box::new(self.unary()?))
+                }
+                Some(&Token::Op('/')) => {
+                    self.pos += 1;
+                    Expr::Div(Box::new(x), Box::new(self.unary()?))
+                }
+                _ => return Ok(x),
+            }
+            .fold();
+        }
+    }
+
+    /// UNARY = '-' UNARY | ATOM ( '^' UNARY )?
+    fn unary<BOUND: Float>(&mut self) -> Result<Expr<BOUND>, ParseExprError> {
+        if self.peek() == Some(&Token::Op('-')) {
+            self.pos += 1;
+            return Ok(Expr::Neg(Box::new(self.unary()?)).fold());
+        }
+        let x = self.atom()?;
+        if self.peek() == Some(&Token::Op('^')) {
+            self.pos += 1;
+            Ok(Expr::Pow(Box::new(x), Box::new(self.unary()?)).fold())
+        } else {
+            Ok(x)
+        }
+    }
+
+    /// ATOM = CONSTANT | IDENT | IDENT '(' EXPR ( ',' EXPR )* ')' | '(' EXPR ')'
+    fn atom<BOUND: Float>(&mut self) -> Result<Expr<BOUND>, ParseExprError> {
+        let precision = self.precision;
+        match *self.next()? {
+            Token::Constant(s) => match IntervalSet::from_str_with_prec(s, precision) {
+                Ok(c) => Ok(Expr::Constant(c)),
+                Err(_) => Err(ParseExprError::ConstantParseError),
+            },
+            Token::Ident(name) => {
+                if self.peek() != Some(&Token::Open) {
+                    return Ok(Expr::Variable(name.to_string()));
+                }
+                self.pos += 1;
+                let mut args = vec![self.expr()?];
+                while self.peek() == Some(&Token::Comma) {
+                    self.pos += 1;
+                    args.push(self.expr()?);
+                }
+                self.expect(Token::Close)?;
+                let arity = match name {
+                    "log" | "exp" => 1,
+                    "pow" => 2,
+                    _ => return Err(ParseExprError::UnknownFunction),
+                };
+                if args.len() != arity {
+                    return Err(ParseExprError::InvalidNumberOfArguments);
+                }
+                let x = Box::new(args.remove(0));
+                Ok(match name {
+                    "log" => Expr::Log(x),
+                    "exp" => Expr::Exp(x),
+                    _ => Expr::Pow(x, Box::new(args.remove(0))),
+                }
+                .fold())
+            }
+            Token::Open => {
+                let x = self.expr()?;
+                self.expect(Token::Close)?;
+                Ok(x)
+            }
+            _ => Err(ParseExprError::UnexpectedToken),
+        }
+    }
+}
+
+impl<BOUND: Float> Expr<BOUND> {
+    /// Replaces `self` by a constant if all of its operands are constants.
+    ///
+    /// As the parser applies this to each node it builds, all constant subexpressions are folded.
+    fn fold(self) -> Self {
+        let constant = |x: &Self| matches!(*x, Expr::Constant(_));
+        let foldable = match self {
+            Expr::Constant(_) | Expr::Variable(_) => false,
+            Expr::Neg(ref x) | Expr::Log(ref x) | Expr::Exp(ref x) => constant(x),
+            Expr::Add(ref x, ref y)
+            | Expr::Sub(ref x, ref y)
+            | Expr::Mul(ref x, ref y)
+            | Expr::Div(ref x, ref y)
+            | Expr::Pow(ref x, ref y) => constant(x) && constant(y),
+        };
+        if foldable {
+            Expr::Constant(self.eval_set(&HashMap::new()).unwrap())
+        } else {
+            self
+        }
+    }
+
+    /// Constructs an expression by parsing a string, with constants of given precision.
+    ///
+    /// Accepts `EXPR` according to the rules below, where `IDENT` is a variable or function name
+    /// and `CONSTANT` is a number or an `INTERVAL_SET` as in `IntervalSet::from_str_with_prec`.
+    /// The functions are `log`, `exp` and `pow`, and `x^y` is the same as `pow(x, y)`. Constant
+    /// subexpressions are evaluated over interval sets at the given precision while parsing.
+    ///
+    ///   EXPR = TERM ( ( '+' | '-' ) TERM )*
+    ///   TERM = UNARY ( ( '*' | '/' ) UNARY )*
+    ///   UNARY = '-' UNARY | ATOM ( '^' UNARY )?
+    ///   ATOM = CONSTANT | IDENT | IDENT '(' EXPR ( ',' EXPR )* ')' | '(' EXPR ')'
+    pub fn from_str_with_prec(s: &str, precision: usize) -> Result<Self, ParseExprError> {
+        let mut parser = Parser {
+            tokens: tokenize(s)?,
+            pos: 0,
+            precision,
+        };
+        let x = parser.expr()?;
+        if parser.peek().is_some() {
+            return Err(ParseExprError::UnexpectedToken);
+        }
+        Ok(x)
+    }
+}
+
+impl<BOUND: Float> FromStr for Expr<BOUND> {
+    type Err = ParseExprError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_with_prec(s, 53)
+    }
+}
@@ -0,0 +1,12 @@
+mod def;
+mod impl_basic;
+mod impl_cmp;
+mod impl_eval;
+mod impl_hc4;
+mod impl_parse;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::def::{Constraint, EvalError, Expr, ParseExprError};
+pub use self::impl_hc4::DEFAULT_PROPAGATE_LIMIT;
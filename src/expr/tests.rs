@@ -0,0 +1,252 @@
+use super::{EvalError, Expr, ParseExprError};
+
+use dual::Dual;
+use interval::Interval;
+use intervalset::IntervalSet;
+use mpfr::Mpfr;
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+type B = Mpfr;
+type IV = Interval<B>;
+type IS = IntervalSet<B>;
+type E = Expr<B>;
+
+const PREC: usize = 53;
+
+macro_rules! assert_str_eq {
+    ($x:expr, $y:expr) => { assert_eq!($x, format!("{}", $y)) };
+    ($x:expr, $y:expr, $($arg:tt)+) => { assert_eq!($x, format!("{}", $y), $($arg)+) };
+}
+
+macro_rules! e {
+    ($s:expr) => {
+        E::from_str_with_prec($s, PREC).unwrap()
+    };
+}
+
+macro_rules! iv {
+    ($s:expr) => {
+        IV::from_str_with_prec($s, PREC).unwrap()
+    };
+}
+
+macro_rules! is {
+    ($s:expr) => {
+        IS::from_str_with_prec($s, PREC).unwrap()
+    };
+}
+
+macro_rules! vars {
+    ($($name:expr => $value:expr),*) => {{
+        let mut vars = HashMap::new();
+        $(vars.insert($name.to_string(), $value);)*
+        vars
+    }};
+}
+
+#[test]
+fn test_parse_display() {
+    assert_str_eq!("log(x) * y^2 / (1 - z)", e!("log(x) * y^2 / (1 - z)"));
+    assert_str_eq!("x - (y - z)", e!("x - (y - z)"));
+    assert_str_eq!("x - y - z", e!("(x - y) - z"));
+    assert_str_eq!("x / (y * z)", e!("x/(y*z)"));
+    assert_str_eq!("-x^2", e!("-x^2"));
+    assert_str_eq!("(-x)^2", e!("(-x)^2"));
+    assert_str_eq!("x^y^z", e!("x^(y^z)"));
+    assert_str_eq!("(x^y)^z", e!("(x^y)^z"));
+    assert_str_eq!("x^-1 * -y", e!("x^-1*-y"));
+    assert_str_eq!("x^y", e!("pow(x, y)"));
+    assert_str_eq!("exp(-x)", e!("exp(-x)"));
+    assert_str_eq!("<1, 2> * x + {0; <3, 4>}", e!("<1,2>*x + {0; <3, 4>}"));
+    assert_str_eq!("x * 0.5", e!("x * 5e-1"));
+    for s in &["x - -2", "(-2)^x", "x^-2", "-2 * x"] {
+        assert_str_eq!(*s, e!(s));
+    }
+}
+
+#[test]
+fn test_parse_errors() {
+    let err = |s| E::from_str(s).unwrap_err();
+    assert_eq!(ParseExprError::UnexpectedCharacter, err("x % 2"));
+    assert_eq!(ParseExprError::UnexpectedToken, err("x + * y"));
+    assert_eq!(ParseExprError::UnexpectedEnd, err("(x + y"));
+    assert_eq!(ParseExprError::UnexpectedToken, err("(x + y,"));
+    assert_eq!(ParseExprError::UnexpectedToken, err("x y"));
+    assert_eq!(ParseExprError::UnexpectedEnd, err("x +"));
+    assert_eq!(ParseExprError::UnexpectedEnd, err("<1, 2"));
+    assert_eq!(ParseExprError::UnknownFunction, err("sin(x)"));
+    assert_eq!(ParseExprError::InvalidNumberOfArguments, err("log(x, y)"));
+    assert_eq!(ParseExprError::InvalidNumberOfArguments, err("pow(x)"));
+    assert_eq!(ParseExprError::ConstantParseError, err("<2, 1>"));
+    assert_eq!(ParseExprError::ConstantParseError, err("2x"));
+}
+
+#[test]
+fn test_fold() {
+    assert_eq!(E::Constant(is!("3")), e!("1 + 2"));
+    assert_eq!(E::Constant(is!("-2")), e!("-2"));
+    assert_str_eq!("x + 3", e!("x + (1 + 2)"));
+    assert_str_eq!("1 + 2 * x + 3", e!("1 + 2 * x + 3"));
+    assert_str_eq!("{<-inf, -1>; <1, inf>}", e!("<1, 2> / <-1, 1>"));
+    assert_str_eq!("x * 8", e!("x * pow(2, 3)"));
+    let third = e!("1 / 3");
+    assert_eq!(E::Constant(IS::from_interval(iv!("1") / iv!("3"))), third);
+    let coarse = E::from_str_with_prec("1 / 3", 2).unwrap();
+    assert_str_eq!("<0.25, 0.375>", coarse);
+}
+
+#[test]
+fn test_eval() {
+    let f = e!("log(x) * y^2 / (1 - z)");
+    let vars = vars!("x" => iv!("1"), "y" => iv!("<1, 2>"), "z" => iv!("3"));
+    assert_str_eq!("0", f.eval(&vars).unwrap());
+    let f = e!("x^2 - 2 * x");
+    assert_str_eq!("<-2, 1>", f.eval(&vars!("x" => iv!("<0, 1>"))).unwrap());
+    let f = e!("x * {1; 2}");
+    assert_str_eq!("<1, 2>", f.eval(&vars!("x" => iv!("1"))).unwrap());
+    assert_eq!(
+        Err(EvalError::UnboundVariable("y".to_string())),
+        e!("x + y").eval(&vars!("x" => iv!("1")))
+    );
+    let names: Vec<_> = e!("x * (y + x) - log(z)").variables().into_iter().collect();
+    assert_eq!(vec!["x", "y", "z"], names);
+}
+
+#[test]
+fn test_eval_set() {
+    let f = e!("1 / x + {0; 10}");
+    let vars = vars!("x" => is!("<-1, 1>"));
+    assert_str_eq!("<-inf, inf>", f.eval_set(&vars).unwrap());
+    let f = e!("1 / x");
+    assert_str_eq!("{<-inf, -1>; <1, inf>}", f.eval_set(&vars).unwrap());
+    let f = e!("x * {1; 2}");
+    assert_str_eq!("{1; 2}", f.eval_set(&vars!("x" => is!("1"))).unwrap());
+}
+
+#[test]
+fn test_eval_with() {
+    let f = e!("x * exp(x)");
+    let vars = vars!("x" => Dual::variable(iv!("0")));
+    let constant = |c: &IS| Dual::constant(c.intervals[0].clone());
+    let y = f.eval_with(&vars, &constant).unwrap();
+    assert_str_eq!("0", y.value);
+    assert_str_eq!("1", y.derivative(0));
+}
+
+fn encloses(x: &IS, y: &IV) -> bool {
+    x.intervals.iter().any(|x| x.lo <= y.lo && y.hi <= x.hi)
+}
+
+#[test]
+fn test_revise() {
+    let mut domains = vars!("x" => is!("<0, 10>"), "y" => is!("<0, 1>"));
+    e!("x + y").revise(&is!("3"), &mut domains).unwrap();
+    assert_str_eq!("<2, 3>", domains["x"]);
+    assert_str_eq!("<0, 1>", domains["y"]);
+    let mut domains = vars!("x" => is!("<-10, 10>"));
+    e!("x^2").revise(&is!("4"), &mut domains).unwrap();
+    assert_eq!(2, domains["x"].intervals.len());
+    assert!(encloses(&domains["x"], &iv!("-2")), "{}", domains["x"]);
+    assert!(encloses(&domains["x"], &iv!("2")), "{}", domains["x"]);
+    assert!(domains["x"]
+        .intervals
+        .iter()
+        .all(|x| x.rad() < iv!("1e-14").lo));
+    let mut domains = vars!("x" => is!("<-5, 5>"));
+    e!("1 / x").revise(&is!("<1, 2>"), &mut domains).unwrap();
+    assert_str_eq!("<0.5, 1>", domains["x"]);
+    let mut domains = vars!("x" => is!("<-5, 5>"));
+    e!("1 / x")
+        .revise(&is!("{<-2, -1>; <1, 2>}"), &mut domains)
+        .unwrap();
+    assert_str_eq!("{<-1, -0.5>; <0.5, 1>}", domains["x"]);
+    let mut domains = vars!("x" => is!("<-5, 5>"));
+    e!("exp(x)").revise(&is!("<-1, 1>"), &mut domains).unwrap();
+    assert_str_eq!("<-5, 0>", domains["x"]);
+    let mut domains = vars!("x" => is!("<-5, 5>"));
+    e!("log(x) + 1").revise(&is!("1"), &mut domains).unwrap();
+    assert_str_eq!("1", domains["x"]);
+    let mut domains = vars!("x" => is!("<1, 2>"), "y" => is!("<-1, 1>"));
+    e!("x * y").revise(&is!("0"), &mut domains).unwrap();
+    assert_str_eq!("<1, 2>", domains["x"]);
+    assert_str_eq!("0", domains["y"]);
+    let mut domains = vars!("x" => is!("<0, 1>"), "y" => is!("<-1, 1>"));
+    e!("x * y").revise(&is!("0"), &mut domains).unwrap();
+    assert_str_eq!("<0, 1>", domains["x"]);
+    assert_str_eq!("<-1, 1>", domains["y"]);
+    let mut domains = vars!("x" => is!("<0.5, 8>"), "y" => is!("<-10, 10>"));
+    e!("x^y").revise(&is!("4"), &mut domains).unwrap();
+    assert_str_eq!(
+        "{<0.5, 0.8705505632961242>; <1.1486983549970349, 8>}",
+        domains["x"]
+    );
+    assert_str_eq!(
+        "{<-10, -1.9999999999999996>; <0.6666666666666665, 10>}",
+        domains["y"]
+    );
+}
+
+#[test]
+fn test_revise_infeasible() {
+    let mut domains = vars!("x" => is!("<-10, 10>"), "y" => is!("<0, 1>"));
+    e!("x^2 + y").revise(&is!("-1"), &mut domains).unwrap();
+    assert!(domains["x"].is_empty());
+    assert!(domains["y"].is_empty());
+    let mut domains = vars!("x" => is!("<-10, 10>"));
+    assert_eq!(
+        Err(EvalError::UnboundVariable("y".to_string())),
+        e!("x + y").revise(&is!("1"), &mut domains)
+    );
+}
+
+#[test]
+fn test_propagate() {
+    use super::{Constraint, DEFAULT_PROPAGATE_LIMIT};
+    let constraints = vec![
+        Constraint::new(e!("x - y / 2"), is!("1")),
+        Constraint::new(e!("y - x / 2"), is!("1")),
+    ];
+    let mut domains = vars!("x" => is!("<0, 100>"), "y" => is!("<0, 100>"));
+    let tolerance = B::from_str("1e-9").unwrap();
+    Constraint::propagate(
+        &constraints,
+        &mut domains,
+        tolerance,
+        DEFAULT_PROPAGATE_LIMIT,
+    )
+    .unwrap();
+    for name in &["x", "y"] {
+        let x = &domains[*name].intervals[0];
+        assert!(x.lo <= B::from(2.0) && B::from(2.0) <= x.hi, "{}", x);
+        assert!(x.rad() < iv!("1e-8").lo, "{}", x);
+    }
+    let mut domains = vars!("x" => iv!("<0, 100>"), "y" => iv!("<0, 100>"));
+    Constraint::propagate_intervals(&constraints, &mut domains, B::from(0.0), 1).unwrap();
+    assert_str_eq!("<1, 51>", domains["x"]);
+    assert_str_eq!("<1.5, 26.5>", domains["y"]);
+    Constraint::propagate_intervals(&constraints, &mut domains, B::from(0.0), 0).unwrap();
+    assert_str_eq!("<1, 51>", domains["x"]);
+    let limit = DEFAULT_PROPAGATE_LIMIT;
+    Constraint::propagate_intervals(&constraints, &mut domains, B::from(0.0), limit).unwrap();
+    assert!(domains["x"].rad() < iv!("1e-14").lo, "{}", domains["x"]);
+    let one = IS::from_str_with_prec("1", 24).unwrap();
+    let constraints = vec![
+        Constraint::new(E::from_str_with_prec("x - y / 2", 24).unwrap(), one.clone()),
+        Constraint::new(E::from_str_with_prec("y - x / 2", 24).unwrap(), one),
+    ];
+    let domain = IV::from_str_with_prec("<0, 100>", 24).unwrap();
+    let mut domains = vars!("x" => domain.clone(), "y" => domain);
+    Constraint::propagate_intervals(&constraints, &mut domains, B::from(0.0), limit).unwrap();
+    assert_eq!(24, domains["x"].precision());
+    assert!(domains["x"].rad() < iv!("1e-5").lo, "{}", domains["x"]);
+    let constraints = vec![
+        Constraint::new(e!("x + y"), is!("<0, 1>")),
+        Constraint::new(e!("x - y"), is!("<3, 4>")),
+        Constraint::new(e!("y"), is!("<0, 1>")),
+    ];
+    let mut domains = vars!("x" => iv!("<0, 100>"), "y" => iv!("<0, 100>"));
+    Constraint::propagate_intervals(&constraints, &mut domains, B::from(0.0), limit).unwrap();
+    assert!(domains["x"].is_nan());
+}
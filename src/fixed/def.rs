@@ -0,0 +1,79 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+/// Scale of a fixed-point number.
+///
+/// A fixed-point number with scale `S` represents `raw * S::RADIX^(-S::DIGITS)`. Only binary
+/// (`RADIX == 2`) and decimal (`RADIX == 10`) scales are supported, and `RADIX^DIGITS` must be
+/// less than `2^126`.
+pub trait Scale: Clone + Copy + Debug {
+    /// Radix of the scale.
+    const RADIX: u32;
+    /// Number of fractional digits in `RADIX`.
+    const DIGITS: u32;
+}
+
+/// Binary scale with 16 fractional bits.
+#[derive(Clone, Copy, Debug)]
+pub struct Binary16;
+
+impl Scale for Binary16 {
+    const RADIX: u32 = 2;
+    const DIGITS: u32 = 16;
+}
+
+/// Binary scale with 32 fractional bits.
+#[derive(Clone, Copy, Debug)]
+pub struct Binary32;
+
+impl Scale for Binary32 {
+    const RADIX: u32 = 2;
+    const DIGITS: u32 = 32;
+}
+
+/// Decimal scale with 2 fractional digits.
+#[derive(Clone, Copy, Debug)]
+pub struct Decimal2;
+
+impl Scale for Decimal2 {
+    const RADIX: u32 = 10;
+    const DIGITS: u32 = 2;
+}
+
+/// Decimal scale with 8 fractional digits.
+#[derive(Clone, Copy, Debug)]
+pub struct Decimal8;
+
+impl Scale for Decimal8 {
+    const RADIX: u32 = 10;
+    const DIGITS: u32 = 8;
+}
+
+/// Fixed-point struct.
+///
+/// Represents `raw * S::RADIX^(-S::DIGITS)`. The three largest magnitudes of `i128` are reserved:
+/// `i128::MAX` is positive infinity, `i128::MIN + 1` is negative infinity and `i128::MIN` is NaN.
+/// Results that do not fit saturate to the infinities, or to the largest finite values when they
+/// are rounded towards zero.
+#[derive(Clone, Copy, Debug)]
+pub struct Fixed<S: Scale> {
+    /// Scaled integer value.
+    pub raw: i128,
+    /// Scale marker.
+    pub scale: PhantomData<S>,
+}
+
+/// Raw value of NaN.
+pub const NAN_RAW: i128 = i128::MIN;
+
+/// Raw value of negative infinity.
+pub const NEG_INFINITY_RAW: i128 = i128::MIN + 1;
+
+/// Raw value of positive infinity.
+pub const INFINITY_RAW: i128 = i128::MAX;
+
+/// Largest finite raw value. The smallest one is its negation.
+pub const MAX_RAW: i128 = i128::MAX - 1;
+
+/// Precision of the rationals that transcendental functions are computed with.
+pub const RATIONAL_PREC: usize = 256;
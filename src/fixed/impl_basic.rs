@@ -0,0 +1,130 @@
+use super::def::{Fixed, Scale, INFINITY_RAW, MAX_RAW, NAN_RAW, NEG_INFINITY_RAW};
+
+use fp::{Float, Round, Sign};
+use rational::{BigInt, ParseRationalError, Rational};
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+impl<S: Scale> Fixed<S> {
+    /// Raw value of one, that is `S::RADIX^S::DIGITS`.
+    pub const ONE_RAW: i128 = (S::RADIX as i128).pow(S::DIGITS);
+
+    /// Constructs a fixed-point number from its raw value.
+    #[inline]
+    pub fn from_raw(raw: i128) -> Self {
+        Fixed {
+            raw,
+            scale: PhantomData,
+        }
+    }
+
+    /// Returns the result of a positive or negative overflow in given rounding direction.
+    #[inline]
+    pub fn overflow(positive: bool, rounding: Round) -> Self {
+        match (positive, rounding) {
+            (true, Round::Down) | (true, Round::TowardsZero) => Self::from_raw(MAX_RAW),
+            (true, _) => Self::from_raw(INFINITY_RAW),
+            (false, Round::Up) | (false, Round::TowardsZero) => Self::from_raw(-MAX_RAW),
+            (false, _) => Self::from_raw(NEG_INFINITY_RAW),
+        }
+    }
+
+    /// Constructs a finite fixed-point number from a raw value, or overflows if there is none or
+    /// it does not fit, and tells whether the raw value fits.
+    #[inline]
+    pub fn saturating(raw: Option<i128>, positive: bool, rounding: Round) -> (Self, bool) {
+        match raw {
+            Some(raw) if (-MAX_RAW..=MAX_RAW).contains(&raw) => (Self::from_raw(raw), true),
+            _ => (Self::overflow(positive, rounding), false),
+        }
+    }
+
+    /// Rounds `x` to a fixed-point number, and tells whether the result is exact.
+    pub fn from_rational(x: &Rational, rounding: Round) -> (Self, bool) {
+        if x.is_nan() {
+            return (Self::from_raw(NAN_RAW), true);
+        }
+        if x.is_infinite() {
+            return (Self::overflow(x.is_infinity(), Round::Nearest), true);
+        }
+        let scaled = &x.num * &BigInt::from(Self::ONE_RAW);
+        let (q, r) = scaled.div_rem_floor(&x.den);
+        let exact = r.is_zero();
+        let up = match rounding {
+            Round::Down => false,
+            Round::Up => !exact,
+            Round::TowardsZero => !exact && x.sign() == Sign::Negative,
+            Round::AwayFromZero => !exact && x.sign() == Sign::Positive,
+            Round::Nearest => match r.shl(1).cmp(&x.den) {
+                Ordering::Less => false,
+                Ordering::Equal => q.is_odd(),
+                Ordering::Greater => true,
+            },
+        };
+        let q = if up { &q + &BigInt::one() } else { q };
+        let (res, fits) = Self::saturating(q.to_i128(), x.sign() == Sign::Positive, rounding);
+        (res, exact && fits)
+    }
+
+    /// Converts `self` exactly to a rational with given precision.
+    pub fn to_rational(&self, precision: usize) -> Rational {
+        match self.raw {
+            NAN_RAW => Rational::nan(precision),
+            NEG_INFINITY_RAW => Rational::neg_infinity(precision),
+            INFINITY_RAW => Rational::infinity(precision),
+            raw => Rational::new(BigInt::from(raw), BigInt::from(Self::ONE_RAW), precision),
+        }
+    }
+}
+
+impl<S: Scale> Display for Fixed<S> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.raw {
+            NAN_RAW => return f.pad("NaN"),
+            NEG_INFINITY_RAW => return f.pad("-inf"),
+            INFINITY_RAW => return f.pad("inf"),
+            _ => {}
+        }
+        // The expansion is exact: `raw / RADIX^DIGITS == raw * (10 / RADIX)^DIGITS / 10^DIGITS`.
+        let digits = S::DIGITS as usize;
+        let factor = BigInt::from((10 / S::RADIX) as u64).pow(S::DIGITS);
+        let scaled = (&BigInt::from(self.raw.unsigned_abs()) * &factor).to_string();
+        let scaled = format!("{:0>width$}", scaled, width = digits + 1);
+        let (int, frac) = scaled.split_at(scaled.len() - digits);
+        let frac = frac.trim_end_matches('0');
+        let sign = if self.raw < 0 { "-" } else { "" };
+        if frac.is_empty() {
+            f.pad(&format!("{}{}", sign, int))
+        } else {
+            f.pad(&format!("{}{}.{}", sign, int, frac))
+        }
+    }
+}
+
+impl<S: Scale> FromStr for Fixed<S> {
+    type Err = ParseRationalError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let x = Rational::from_str(s)?;
+        Ok(Self::from_rational(&x, Round::Nearest).0)
+    }
+}
+
+impl<S: Scale> From<f64> for Fixed<S> {
+    #[inline]
+    fn from(val: f64) -> Self {
+        Self::from_rational(&Rational::from(val), Round::Nearest).0
+    }
+}
+
+impl<S: Scale> From<Fixed<S>> for f64 {
+    #[inline]
+    fn from(val: Fixed<S>) -> Self {
+        f64::from(val.to_rational(53))
+    }
+}
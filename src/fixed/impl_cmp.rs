@@ -0,0 +1,38 @@
+use super::def::{Fixed, Scale, NAN_RAW};
+
+use fp;
+
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+impl<S: Scale> PartialEq<Fixed<S>> for Fixed<S> {
+    #[inline]
+    fn eq(&self, other: &Fixed<S>) -> bool {
+        self.raw != NAN_RAW && self.raw == other.raw
+    }
+}
+
+impl<S: Scale> PartialOrd<Fixed<S>> for Fixed<S> {
+    #[inline]
+    fn partial_cmp(&self, other: &Fixed<S>) -> Option<Ordering> {
+        if self.raw == NAN_RAW || other.raw == NAN_RAW {
+            None // self or other is NaN and they cannot be compared
+        } else {
+            // The infinities are the extreme raw values, so they compare correctly.
+            Some(self.raw.cmp(&other.raw))
+        }
+    }
+}
+
+impl<S: Scale> fp::TotalOrd for Fixed<S> {
+    #[inline]
+    fn cmp_total(&self, rhs: &Fixed<S>) -> Ordering {
+        // NaN has the same raw value as the NaN of `i128` bounds.
+        self.raw.cmp_total(&rhs.raw)
+    }
+
+    #[inline]
+    fn hash_total<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state)
+    }
+}
@@ -0,0 +1,321 @@
+use super::def::{Fixed, Scale, INFINITY_RAW, MAX_RAW, NAN_RAW, NEG_INFINITY_RAW, RATIONAL_PREC};
+
+use fp;
+use fp::{Float, Round, Sign};
+use rational::{ParseRationalError, Rational};
+
+use std::cmp::Ordering;
+
+/// Number of value bits of the raw integer.
+const PRECISION: usize = 127;
+
+impl<S: Scale> Fixed<S> {
+    /// Rounds `self` to an integer with `round`, which maps the quotient and the remainder of
+    /// `raw / ONE_RAW` rounded towards negative infinity to the resulting integer.
+    fn to_integer<F>(self, round: F) -> Self
+    where
+        F: FnOnce(i128, i128) -> i128,
+    {
+        if !self.is_finite() {
+            return self;
+        }
+        let one = Self::ONE_RAW;
+        let n = round(self.raw.div_euclid(one), self.raw.rem_euclid(one));
+        Self::saturating(n.checked_mul(one), n > 0, Round::Nearest).0
+    }
+
+    /// Rounds `x` down or up to a fixed-point number.
+    #[inline]
+    fn from_rational_with(x: &Rational, up: bool) -> Self {
+        let rounding = if up { Round::Up } else { Round::Down };
+        Self::from_rational(x, rounding).0
+    }
+
+    /// Computes `self - n * rhs`, where `n` is `self / rhs` rounded towards zero, or to the
+    /// nearest integer with ties to even if `nearest` is set. The result is always exact.
+    fn rem_with(self, rhs: Self, nearest: bool) -> Self {
+        if self.is_finite() && rhs.is_infinite() {
+            return self;
+        }
+        if !self.is_finite() || !rhs.is_finite() || rhs.is_zero() {
+            return Self::nan(PRECISION);
+        }
+        let q = self.raw / rhs.raw;
+        let r = self.raw % rhs.raw;
+        let (r2, d) = (r.unsigned_abs() << 1, rhs.raw.unsigned_abs());
+        if nearest && (r2 > d || r2 == d && q % 2 != 0) {
+            Self::from_raw(r - r.signum() * d as i128)
+        } else {
+            Self::from_raw(r)
+        }
+    }
+}
+
+impl<S: Scale> fp::From<f64> for Fixed<S> {
+    #[inline]
+    fn from_lo(val: f64, _: usize) -> Self {
+        Self::from_rational_with(&Rational::from(val), false)
+    }
+
+    #[inline]
+    fn from_hi(val: f64, _: usize) -> Self {
+        Self::from_rational_with(&Rational::from(val), true)
+    }
+}
+
+impl<S: Scale> fp::FromStr for Fixed<S> {
+    #[inline]
+    fn from_str_lo(s: &str, _: usize) -> Result<Self, ParseRationalError> {
+        let x = Rational::from_str_with_prec(s, RATIONAL_PREC)?;
+        Ok(Self::from_rational_with(&x, false))
+    }
+
+    #[inline]
+    fn from_str_hi(s: &str, _: usize) -> Result<Self, ParseRationalError> {
+        let x = Rational::from_str_with_prec(s, RATIONAL_PREC)?;
+        Ok(Self::from_rational_with(&x, true))
+    }
+}
+
+impl<S: Scale> fp::Into<f64> for Fixed<S> {
+    #[inline]
+    fn into_lo(self) -> f64 {
+        fp::Into::into_lo(self.to_rational(RATIONAL_PREC))
+    }
+
+    #[inline]
+    fn into_hi(self) -> f64 {
+        fp::Into::into_hi(self.to_rational(RATIONAL_PREC))
+    }
+}
+
+impl<S: Scale> fp::MinMax for Fixed<S> {
+    #[inline]
+    fn min(self, rhs: Self) -> Self {
+        if rhs.is_nan() || self <= rhs {
+            self
+        } else {
+            rhs
+        }
+    }
+
+    #[inline]
+    fn max(self, rhs: Self) -> Self {
+        if rhs.is_nan() || self >= rhs {
+            self
+        } else {
+            rhs
+        }
+    }
+}
+
+impl<S: Scale> fp::Abs for Fixed<S> {
+    #[inline]
+    fn abs(self) -> Self {
+        if self.raw < 0 {
+            -self
+        } else {
+            self
+        }
+    }
+}
+
+impl<S: Scale> fp::Integral for Fixed<S> {
+    #[inline]
+    fn floor(self) -> Self {
+        self.to_integer(|q, _| q)
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        self.to_integer(|q, r| if r > 0 { q + 1 } else { q })
+    }
+
+    fn round_ties_even(self) -> Self {
+        let one = Self::ONE_RAW;
+        self.to_integer(|q, r| match (r << 1).cmp(&one) {
+            Ordering::Less => q,
+            Ordering::Equal => q + (q & 1),
+            Ordering::Greater => q + 1,
+        })
+    }
+
+    fn round_ties_away(self) -> Self {
+        let one = Self::ONE_RAW;
+        self.to_integer(|q, r| match (r << 1).cmp(&one) {
+            Ordering::Less => q,
+            Ordering::Equal if q < 0 => q,
+            _ => q + 1,
+        })
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        self.to_integer(|q, r| if q < 0 && r > 0 { q + 1 } else { q })
+    }
+
+    #[inline]
+    fn frac(self) -> Self {
+        if self.is_finite() {
+            Self::from_raw(self.raw % Self::ONE_RAW)
+        } else {
+            Self::nan(PRECISION)
+        }
+    }
+}
+
+impl<S: Scale> fp::Rem for Fixed<S> {
+    #[inline]
+    fn fmod_lo(self, rhs: Self) -> Self {
+        self.rem_with(rhs, false)
+    }
+
+    #[inline]
+    fn fmod_hi(self, rhs: Self) -> Self {
+        self.rem_with(rhs, false)
+    }
+
+    #[inline]
+    fn remainder_lo(self, rhs: Self) -> Self {
+        self.rem_with(rhs, true)
+    }
+
+    #[inline]
+    fn remainder_hi(self, rhs: Self) -> Self {
+        self.rem_with(rhs, true)
+    }
+}
+
+impl<S: Scale> fp::Hypot for Fixed<S> {
+    #[inline]
+    fn hypot_r(self, rhs: Self, round: Round) -> Self {
+        let z = fp::Hypot::hypot_r(
+            self.to_rational(RATIONAL_PREC),
+            rhs.to_rational(RATIONAL_PREC),
+            round,
+        );
+        Self::from_rational(&z, round).0
+    }
+}
+
+impl<S: Scale> Float for Fixed<S> {
+    #[inline]
+    fn zero(_: usize) -> Self {
+        Self::from_raw(0)
+    }
+
+    #[inline]
+    fn neg_zero(_: usize) -> Self {
+        Self::from_raw(0) // fixed-point numbers have a single zero
+    }
+
+    #[inline]
+    fn one(_: usize) -> Self {
+        Self::from_raw(Self::ONE_RAW)
+    }
+
+    #[inline]
+    fn infinity(_: usize) -> Self {
+        Self::from_raw(INFINITY_RAW)
+    }
+
+    #[inline]
+    fn neg_infinity(_: usize) -> Self {
+        Self::from_raw(NEG_INFINITY_RAW)
+    }
+
+    #[inline]
+    fn nan(_: usize) -> Self {
+        Self::from_raw(NAN_RAW)
+    }
+
+    #[inline]
+    fn sign(&self) -> Sign {
+        match self.raw {
+            NAN_RAW | 0 => Sign::Zero,
+            raw if raw < 0 => Sign::Negative,
+            _ => Sign::Positive,
+        }
+    }
+
+    #[inline]
+    fn precision(&self) -> usize {
+        PRECISION
+    }
+
+    #[inline]
+    fn is_finite(&self) -> bool {
+        -MAX_RAW <= self.raw && self.raw <= MAX_RAW
+    }
+
+    #[inline]
+    fn is_infinite(&self) -> bool {
+        self.raw == INFINITY_RAW || self.raw == NEG_INFINITY_RAW
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    #[inline]
+    fn is_infinity(&self) -> bool {
+        self.raw == INFINITY_RAW
+    }
+
+    #[inline]
+    fn is_neg_infinity(&self) -> bool {
+        self.raw == NEG_INFINITY_RAW
+    }
+
+    #[inline]
+    fn is_nan(&self) -> bool {
+        self.raw == NAN_RAW
+    }
+
+    #[inline]
+    fn has_odd_denominator(&self) -> bool {
+        // The denominator of `raw / ONE_RAW` is odd when `raw` has all the factors of two.
+        let twos = Self::ONE_RAW.trailing_zeros();
+        self.is_finite() && self.raw.trailing_zeros() >= twos
+    }
+
+    #[inline]
+    fn next_up(self) -> Self {
+        // The infinities are adjacent to the extreme finite values.
+        if self.is_nan() || self.is_infinity() {
+            self
+        } else {
+            Self::from_raw(self.raw + 1)
+        }
+    }
+
+    #[inline]
+    fn next_down(self) -> Self {
+        if self.is_nan() || self.is_neg_infinity() {
+            self
+        } else {
+            Self::from_raw(self.raw - 1)
+        }
+    }
+
+    /// Splits `self` into a mantissa and an exponent as in `frexp`.
+    ///
+    /// The mantissa is rounded to the nearest fixed-point number, so `self == m * 2^e` holds only
+    /// when the mantissa is representable.
+    fn frexp(self) -> (Self, isize) {
+        if !self.is_finite() || self.is_zero() {
+            return (self, 0);
+        }
+        let (m, e) = self.to_rational(RATIONAL_PREC).frexp();
+        (Self::from_rational(&m, Round::Nearest).0, e)
+    }
+
+    fn ldexp(self, exp: isize) -> Self {
+        if !self.is_finite() || self.is_zero() {
+            return self;
+        }
+        let x = self.to_rational(RATIONAL_PREC).ldexp(exp);
+        Self::from_rational(&x, Round::Nearest).0
+    }
+}
@@ -0,0 +1,253 @@
+use super::def::{Fixed, Scale, INFINITY_RAW, NAN_RAW, NEG_INFINITY_RAW, RATIONAL_PREC};
+
+use fp;
+use fp::{Float, Round};
+use rational::Rational;
+
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Mask of the low 64 bits of an `u128`.
+const LOW_MASK: u128 = (1 << 64) - 1;
+
+/// Computes the 256-bit product of `a` and `b` as a pair of its high and low halves.
+fn mul_wide(a: u128, b: u128) -> (u128, u128) {
+    let (a1, a0) = (a >> 64, a & LOW_MASK);
+    let (b1, b0) = (b >> 64, b & LOW_MASK);
+    let p00 = a0 * b0;
+    let p01 = a0 * b1;
+    let p10 = a1 * b0;
+    let p11 = a1 * b1;
+    let mid = (p00 >> 64) + (p01 & LOW_MASK) + (p10 & LOW_MASK);
+    let lo = (p00 & LOW_MASK) | (mid << 64);
+    let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+/// Divides the 256-bit `(hi, lo)` by nonzero `d`, and returns the quotient and the remainder.
+/// Returns `None` if the quotient does not fit in an `u128`.
+fn div_wide(hi: u128, lo: u128, d: u128) -> Option<(u128, u128)> {
+    if hi >= d {
+        return None;
+    }
+    let mut q = 0u128;
+    let mut r = hi;
+    for i in (0..128).rev() {
+        let carry = r >> 127;
+        r = (r << 1) | ((lo >> i) & 1);
+        q <<= 1;
+        if carry == 1 || r >= d {
+            r = r.wrapping_sub(d);
+            q |= 1;
+        }
+    }
+    Some((q, r))
+}
+
+impl<S: Scale> Fixed<S> {
+    /// Returns an `f64` that behaves like `self` under IEEE 754 arithmetic with zeros, infinities
+    /// and NaN: the special value itself, or the sign of a nonzero finite value.
+    #[inline]
+    fn class(&self) -> f64 {
+        match self.raw {
+            NAN_RAW => f64::NAN,
+            NEG_INFINITY_RAW => f64::NEG_INFINITY,
+            INFINITY_RAW => f64::INFINITY,
+            raw => raw.signum() as f64,
+        }
+    }
+
+    /// Computes `op` on the classes of `self` and `rhs`, for operations whose result is zero, an
+    /// infinity or NaN.
+    #[inline]
+    fn special<F>(self, rhs: Self, op: F) -> Self
+    where
+        F: FnOnce(f64, f64) -> f64,
+    {
+        Self::from(op(self.class(), rhs.class()))
+    }
+
+    /// Rounds the magnitude `n / d` of a result with given sign, where `n` is the 256-bit
+    /// `(hi, lo)`, and tells whether the result is exact.
+    fn round_quotient(
+        (hi, lo): (u128, u128),
+        d: u128,
+        positive: bool,
+        rounding: Round,
+    ) -> (Self, bool) {
+        let (q, r) = match div_wide(hi, lo, d) {
+            Some(qr) => qr,
+            None => return (Self::overflow(positive, rounding), false),
+        };
+        let away = match rounding {
+            _ if r == 0 => false,
+            Round::Down => !positive,
+            Round::Up => positive,
+            Round::TowardsZero => false,
+            Round::AwayFromZero => true,
+            Round::Nearest => match (r << 1).cmp(&d) {
+                Ordering::Less => false,
+                Ordering::Equal => q & 1 == 1,
+                Ordering::Greater => true,
+            },
+        };
+        let mag = if away { q.checked_add(1) } else { Some(q) };
+        let raw = mag
+            .and_then(|m| i128::try_from(m).ok())
+            .map(|m| if positive { m } else { -m });
+        let (res, fits) = Self::saturating(raw, positive, rounding);
+        (res, r == 0 && fits)
+    }
+
+    /// Adds `self` to `rhs`, rounds the result, and tells whether the result is exact.
+    fn add_with(self, rhs: Self, rounding: Round) -> (Self, bool) {
+        if !self.is_finite() || !rhs.is_finite() {
+            return (self.special(rhs, |x, y| x + y), true);
+        }
+        let raw = self.raw.checked_add(rhs.raw);
+        // Only operands of the same sign can overflow.
+        let positive = self.raw > 0;
+        Self::saturating(raw, positive, rounding)
+    }
+
+    /// Multiplies `self` by `rhs`, rounds the result, and tells whether the result is exact.
+    fn mul_with(self, rhs: Self, rounding: Round) -> (Self, bool) {
+        if !self.is_finite() || !rhs.is_finite() {
+            return (self.special(rhs, |x, y| x * y), true);
+        }
+        let positive = self.sign() == rhs.sign();
+        let n = mul_wide(self.raw.unsigned_abs(), rhs.raw.unsigned_abs());
+        Self::round_quotient(n, Self::ONE_RAW as u128, positive, rounding)
+    }
+
+    /// Divides `self` by `rhs`, rounds the result, and tells whether the result is exact.
+    fn div_with(self, rhs: Self, rounding: Round) -> (Self, bool) {
+        if !self.is_finite() || !rhs.is_finite() || rhs.is_zero() {
+            return (self.special(rhs, |x, y| x / y), true);
+        }
+        let positive = self.sign() == rhs.sign() || self.is_zero();
+        let n = mul_wide(self.raw.unsigned_abs(), Self::ONE_RAW as u128);
+        Self::round_quotient(n, rhs.raw.unsigned_abs(), positive, rounding)
+    }
+}
+
+impl<S: Scale> Neg for Fixed<S> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        if self.raw == NAN_RAW {
+            self
+        } else {
+            // The infinities are each other's negation.
+            Self::from_raw(-self.raw)
+        }
+    }
+}
+
+impl<S: Scale> Add for Fixed<S> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.add_with(rhs, Round::Nearest).0
+    }
+}
+
+impl<S: Scale> Sub for Fixed<S> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.add_with(-rhs, Round::Nearest).0
+    }
+}
+
+impl<S: Scale> Mul for Fixed<S> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_with(rhs, Round::Nearest).0
+    }
+}
+
+impl<S: Scale> Div for Fixed<S> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_with(rhs, Round::Nearest).0
+    }
+}
+
+impl<S: Scale> fp::Add for Fixed<S> {
+    #[inline]
+    fn add_r(self, rhs: Self, round: Round) -> Self {
+        self.add_with(rhs, round).0
+    }
+
+    #[inline]
+    fn add_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        self.add_with(rhs, round)
+    }
+}
+
+impl<S: Scale> fp::Sub for Fixed<S> {
+    #[inline]
+    fn sub_r(self, rhs: Self, round: Round) -> Self {
+        self.add_with(-rhs, round).0
+    }
+
+    #[inline]
+    fn sub_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        self.add_with(-rhs, round)
+    }
+}
+
+impl<S: Scale> fp::Mul for Fixed<S> {
+    #[inline]
+    fn mul_r(self, rhs: Self, round: Round) -> Self {
+        self.mul_with(rhs, round).0
+    }
+
+    #[inline]
+    fn mul_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        self.mul_with(rhs, round)
+    }
+}
+
+impl<S: Scale> fp::Div for Fixed<S> {
+    #[inline]
+    fn div_r(self, rhs: Self, round: Round) -> Self {
+        self.div_with(rhs, round).0
+    }
+
+    #[inline]
+    fn div_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        self.div_with(rhs, round)
+    }
+}
+
+impl<S: Scale> fp::Fma for Fixed<S> {
+    #[inline]
+    fn fma_r_exact(self, a: Self, b: Self, round: Round) -> (Self, bool) {
+        let x = self.to_rational(RATIONAL_PREC) * a.to_rational(RATIONAL_PREC)
+            + b.to_rational(RATIONAL_PREC);
+        Self::from_rational(&x, round)
+    }
+}
+
+impl<S: Scale> fp::Sum for Fixed<S> {
+    fn sum_r(xs: &[Self], _: usize, round: Round) -> Self {
+        let xs: Vec<_> = xs.iter().map(|x| x.to_rational(RATIONAL_PREC)).collect();
+        Self::from_rational(&Rational::sum_r(&xs, RATIONAL_PREC, round), round).0
+    }
+
+    fn dot_r(xs: &[Self], ys: &[Self], _: usize, round: Round) -> Self {
+        let xs: Vec<_> = xs.iter().map(|x| x.to_rational(RATIONAL_PREC)).collect();
+        let ys: Vec<_> = ys.iter().map(|y| y.to_rational(RATIONAL_PREC)).collect();
+        Self::from_rational(&Rational::dot_r(&xs, &ys, RATIONAL_PREC, round), round).0
+    }
+}
@@ -0,0 +1,76 @@
+use super::def::{Fixed, Scale, RATIONAL_PREC};
+
+use fp;
+use fp::Round;
+use rational::Rational;
+use transc::Transc;
+
+impl<S: Scale> Fixed<S> {
+    /// Applies `f` to the exact value of `self` and rounds the result in the direction of `round`.
+    /// The result is exact if `f` tells so and the rounding is exact.
+    fn with_rational<F>(self, round: Round, f: F) -> (Self, bool)
+    where
+        F: FnOnce(Rational) -> (Rational, bool),
+    {
+        let (x, exact) = f(self.to_rational(RATIONAL_PREC));
+        let (res, res_exact) = Self::from_rational(&x, round);
+        (res, exact && res_exact)
+    }
+}
+
+impl<S: Scale> Transc for Fixed<S> {
+    type Output = Self;
+
+    #[inline]
+    fn log(self) -> Self::Output {
+        let x = self.to_rational(RATIONAL_PREC).log();
+        Self::from_rational(&x, Round::Nearest).0
+    }
+
+    #[inline]
+    fn exp(self) -> Self::Output {
+        let x = self.to_rational(RATIONAL_PREC).exp();
+        Self::from_rational(&x, Round::Nearest).0
+    }
+
+    #[inline]
+    fn pow(self, rhs: Self) -> Self::Output {
+        let x = self
+            .to_rational(RATIONAL_PREC)
+            .pow(rhs.to_rational(RATIONAL_PREC));
+        Self::from_rational(&x, Round::Nearest).0
+    }
+}
+
+impl<S: Scale> fp::Transc for Fixed<S> {
+    #[inline]
+    fn log_r(self, round: Round) -> Self {
+        self.log_r_exact(round).0
+    }
+
+    #[inline]
+    fn exp_r(self, round: Round) -> Self {
+        self.exp_r_exact(round).0
+    }
+
+    #[inline]
+    fn pow_r(self, rhs: Self, round: Round) -> Self {
+        self.pow_r_exact(rhs, round).0
+    }
+
+    #[inline]
+    fn log_r_exact(self, round: Round) -> (Self, bool) {
+        self.with_rational(round, |x| fp::Transc::log_r_exact(x, round))
+    }
+
+    #[inline]
+    fn exp_r_exact(self, round: Round) -> (Self, bool) {
+        self.with_rational(round, |x| fp::Transc::exp_r_exact(x, round))
+    }
+
+    #[inline]
+    fn pow_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        let y = rhs.to_rational(RATIONAL_PREC);
+        self.with_rational(round, |x| fp::Transc::pow_r_exact(x, y, round))
+    }
+}
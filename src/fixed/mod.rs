@@ -0,0 +1,12 @@
+mod def;
+mod impl_basic;
+mod impl_cmp;
+mod impl_float;
+mod impl_ops;
+mod impl_transc;
+
+#[cfg(test)]
+#[allow(clippy::neg_cmp_op_on_partial_ord)]
+mod tests;
+
+pub use self::def::{Binary16, Binary32, Decimal2, Decimal8, Fixed, Scale};
@@ -0,0 +1,277 @@
+use super::{Binary16, Decimal2, Decimal8, Fixed};
+
+use fp;
+use fp::{Float, Integral, Sign};
+use transc::Transc;
+
+use std::str::FromStr;
+
+type D8 = Fixed<Decimal8>;
+type D2 = Fixed<Decimal2>;
+type B16 = Fixed<Binary16>;
+
+const PREC: usize = 53;
+
+macro_rules! assert_str_eq {
+    ($x:expr, $y:expr) => { assert_eq!($x, format!("{}", $y)) };
+    ($x:expr, $y:expr, $($arg:tt)+) => { assert_eq!($x, format!("{}", $y), $($arg)+) };
+}
+
+macro_rules! d8 {
+    ($v:expr) => {
+        D8::from_str($v).unwrap()
+    };
+}
+
+macro_rules! b16 {
+    ($v:expr) => {
+        B16::from_str($v).unwrap()
+    };
+}
+
+#[test]
+fn test_raw() {
+    assert_eq!(100_000_000, D8::ONE_RAW);
+    assert_eq!(65536, B16::ONE_RAW);
+    assert_eq!(150_000_000, d8!("1.5").raw);
+    assert_eq!(-32768, b16!("-0.5").raw);
+    assert_str_eq!("0.00000001", D8::from_raw(1));
+    assert_str_eq!("0.0000152587890625", B16::from_raw(1));
+}
+
+#[test]
+fn test_from_str() {
+    assert_str_eq!("0.1", d8!("0.1"));
+    assert_str_eq!("-123.45678901", d8!("-123.45678901"));
+    assert_str_eq!("0.33333333", d8!("1/3"));
+    assert_str_eq!("0.66666667", d8!("2/3"));
+    assert_str_eq!("0.100006103515625", b16!("0.1"));
+    assert_str_eq!("0", d8!("0.000000004"));
+    assert_str_eq!("0.00000002", d8!("0.000000015"));
+    assert_str_eq!("0.00000002", d8!("0.000000025"));
+    assert_str_eq!("inf", d8!("inf"));
+    assert_str_eq!("-inf", d8!("-inf"));
+    assert_str_eq!("NaN", d8!("NaN"));
+    assert_str_eq!("inf", d8!("1e40"));
+    assert!(D8::from_str("1.2.3").is_err());
+    assert_str_eq!(
+        "0.33333333",
+        fp::FromStr::from_str_lo("1/3", PREC)
+            .map(|x: D8| x)
+            .unwrap()
+    );
+    assert_str_eq!(
+        "0.33333334",
+        fp::FromStr::from_str_hi("1/3", PREC)
+            .map(|x: D8| x)
+            .unwrap()
+    );
+    assert_str_eq!(
+        "-0.33333334",
+        fp::FromStr::from_str_lo("-1/3", PREC)
+            .map(|x: D8| x)
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_f64() {
+    assert_str_eq!("0.1", D8::from(0.1));
+    assert_str_eq!("0.1", <D8 as fp::From<f64>>::from_lo(0.1, PREC));
+    assert_str_eq!("0.10000001", <D8 as fp::From<f64>>::from_hi(0.1, PREC));
+    assert_eq!(0.1, f64::from(d8!("0.1")));
+    assert_eq!(0.1, fp::Into::<f64>::into_hi(d8!("0.1")));
+    assert_eq!(Float::next_down(0.1), fp::Into::<f64>::into_lo(d8!("0.1")));
+    assert_eq!(f64::INFINITY, f64::from(d8!("inf")));
+    assert!(f64::from(d8!("NaN")).is_nan());
+}
+
+#[test]
+fn test_cmp() {
+    assert!(d8!("0.1") < d8!("0.2"));
+    assert!(d8!("-inf") < d8!("-1e20"));
+    assert!(d8!("inf") > d8!("1e20"));
+    assert!(d8!("inf") == d8!("inf"));
+    assert!(d8!("NaN") != d8!("NaN"));
+    assert!(!(d8!("NaN") < d8!("1")));
+    assert!(!(d8!("NaN") >= d8!("1")));
+    assert_eq!(Sign::Zero, d8!("NaN").sign());
+    assert_eq!(Sign::Negative, d8!("-inf").sign());
+}
+
+#[test]
+fn test_add_sub() {
+    use fp::{Add, Sub};
+    assert_str_eq!("0.3", d8!("0.1") + d8!("0.2"));
+    assert_str_eq!("-0.1", d8!("0.1") - d8!("0.2"));
+    let max = D8::infinity(PREC).next_down();
+    assert_str_eq!("inf", max + d8!("1"));
+    assert_eq!(max, max.add_lo(d8!("1")));
+    assert_str_eq!("inf", max.add_hi(d8!("1")));
+    assert_str_eq!("-inf", (-max).sub_lo(d8!("1")));
+    assert_eq!(-max, (-max).sub_hi(d8!("1")));
+    assert_eq!((d8!("0.3"), true), d8!("0.1").add_lo_exact(d8!("0.2")));
+    let tiny = D8::from_raw(1);
+    assert_eq!((max, true), D8::from_raw(i128::MAX - 2).add_lo_exact(tiny));
+    assert_eq!((max, false), max.add_lo_exact(tiny));
+    assert_eq!((-max, false), (-max).sub_hi_exact(tiny));
+    assert_str_eq!("NaN", d8!("inf") - d8!("inf"));
+    assert_str_eq!("-inf", d8!("1") - d8!("inf"));
+}
+
+#[test]
+fn test_mul_div() {
+    use fp::{Div, Mul};
+    assert_str_eq!("0.02", d8!("0.1") * d8!("0.2"));
+    assert_eq!((d8!("0.02"), true), d8!("0.1").mul_lo_exact(d8!("0.2")));
+    let max = D8::infinity(PREC).next_down();
+    assert_eq!((max, true), max.mul_lo_exact(d8!("1")));
+    assert_eq!((max, false), max.mul_lo_exact(d8!("2")));
+    assert_eq!((-max, false), max.mul_hi_exact(d8!("-2")));
+    assert_str_eq!("0.00000001", d8!("0.0001").mul_hi(d8!("0.00001")));
+    assert_str_eq!("0", d8!("0.0001").mul_lo(d8!("0.00001")));
+    assert_str_eq!("-0.00000001", d8!("-0.0001").mul_lo(d8!("0.00001")));
+    assert_str_eq!("0", d8!("-0.0001").mul_hi(d8!("0.00001")));
+    assert_str_eq!("0.33333333", d8!("1").div_lo(d8!("3")));
+    assert_str_eq!("0.33333334", d8!("1").div_hi(d8!("3")));
+    assert_str_eq!("-0.33333334", d8!("-1").div_lo(d8!("3")));
+    assert_str_eq!("-0.33333333", d8!("1").div_hi(d8!("-3")));
+    assert_str_eq!("0.66666667", d8!("2") / d8!("3"));
+    assert_eq!((d8!("0.25"), true), d8!("1").div_hi_exact(d8!("4")));
+    assert_str_eq!("inf", d8!("1") / d8!("0"));
+    assert_str_eq!("-inf", d8!("-1") / d8!("0"));
+    assert_str_eq!("NaN", d8!("0") / d8!("0"));
+    assert_str_eq!("0", d8!("1") / d8!("-inf"));
+    assert_str_eq!("NaN", d8!("0") * d8!("inf"));
+    let big = d8!("1e20");
+    assert_str_eq!("inf", big * big);
+    assert_eq!(D8::infinity(PREC).next_down(), big.mul_lo(big));
+    assert_str_eq!("-inf", big.mul_lo(-big));
+    assert_str_eq!("inf", d8!("1e25").div_hi(d8!("0.00000001")));
+    assert_str_eq!("0.02", D2::from(0.005) + D2::from(0.005));
+    assert_str_eq!("0.5", b16!("0.25") * b16!("2"));
+}
+
+#[test]
+fn test_fma_sum() {
+    use fp::{Fma, Round, Sum};
+    let x = d8!("0.00001");
+    assert_str_eq!("1", x.fma_lo(x, d8!("1")));
+    assert_str_eq!("1.00000001", x.fma_hi(x, d8!("1")));
+    assert_str_eq!("1", x.fma_r(x, d8!("1"), Round::Nearest));
+    assert_eq!(
+        (d8!("0.5"), true),
+        d8!("0.5").fma_hi_exact(d8!("2"), d8!("-0.5"))
+    );
+    let max = D8::infinity(PREC).next_down();
+    assert_eq!((max, false), max.fma_lo_exact(d8!("1"), d8!("1")));
+    assert_str_eq!(
+        "0",
+        D8::sum_lo(&[d8!("0.1"), d8!("0.2"), d8!("-0.3")], PREC)
+    );
+    let (xs, ys) = ([d8!("0.0001"), d8!("1")], [x, d8!("0")]);
+    assert_str_eq!("0", D8::dot_lo(&xs, &ys, PREC));
+    assert_str_eq!("0.00000001", D8::dot_hi(&xs, &ys, PREC));
+}
+
+#[test]
+fn test_round() {
+    use fp::{Div, Mul, Round};
+    assert_str_eq!("0.66666667", d8!("2").div_r(d8!("3"), Round::Nearest));
+    assert_str_eq!("0.66666666", d8!("2").div_r(d8!("3"), Round::TowardsZero));
+    assert_str_eq!("-0.66666666", d8!("-2").div_r(d8!("3"), Round::TowardsZero));
+    assert_str_eq!(
+        "-0.66666667",
+        d8!("-2").div_r(d8!("3"), Round::AwayFromZero)
+    );
+    assert_str_eq!(
+        "0.00000001",
+        d8!("0.0001").mul_r(d8!("0.00001"), Round::AwayFromZero)
+    );
+    let big = d8!("1e20");
+    assert_eq!(
+        D8::infinity(PREC).next_down(),
+        big.mul_r(big, Round::TowardsZero)
+    );
+    assert_str_eq!("-inf", big.mul_r(-big, Round::Nearest));
+}
+
+#[test]
+fn test_integral() {
+    let check = |x: &str, floor: &str, ceil: &str, even: &str, away: &str, trunc: &str| {
+        assert_str_eq!(floor, d8!(x).floor(), "floor({})", x);
+        assert_str_eq!(ceil, d8!(x).ceil(), "ceil({})", x);
+        assert_str_eq!(even, d8!(x).round_ties_even(), "round_ties_even({})", x);
+        assert_str_eq!(away, d8!(x).round_ties_away(), "round_ties_away({})", x);
+        assert_str_eq!(trunc, d8!(x).trunc(), "trunc({})", x);
+    };
+    check("2.3", "2", "3", "2", "2", "2");
+    check("-2.3", "-3", "-2", "-2", "-2", "-2");
+    check("2.5", "2", "3", "2", "3", "2");
+    check("-2.5", "-3", "-2", "-2", "-3", "-2");
+    check("3.5", "3", "4", "4", "4", "3");
+    check("-3.5", "-4", "-3", "-4", "-4", "-3");
+    check("7", "7", "7", "7", "7", "7");
+    check("-inf", "-inf", "-inf", "-inf", "-inf", "-inf");
+    assert_str_eq!("0.3", d8!("2.3").frac());
+    assert_str_eq!("-0.3", d8!("-2.3").frac());
+    assert_str_eq!("NaN", d8!("inf").frac());
+}
+
+#[test]
+fn test_rem_hypot() {
+    use fp::{Hypot, Rem};
+    assert_str_eq!("0.1", d8!("1.1").fmod_lo(d8!("0.5")));
+    assert_str_eq!("-0.1", d8!("-1.1").fmod_hi(d8!("0.5")));
+    assert_str_eq!("-0.1", d8!("1.9").remainder_lo(d8!("0.5")));
+    assert_str_eq!("-0.25", d8!("1.75").remainder_hi(d8!("0.5")));
+    assert_str_eq!("0.25", d8!("1.25").remainder_hi(d8!("0.5")));
+    assert_str_eq!("1.1", d8!("1.1").fmod_lo(d8!("-inf")));
+    assert_str_eq!("NaN", d8!("1.1").remainder_lo(d8!("0")));
+    assert_str_eq!("0.5", d8!("0.3").hypot_lo(d8!("-0.4")));
+    assert_str_eq!("1.41421356", d8!("1").hypot_lo(d8!("1")));
+    assert_str_eq!("1.41421357", d8!("1").hypot_hi(d8!("1")));
+}
+
+#[test]
+fn test_transc() {
+    use fp::Transc as FpTransc;
+    assert_str_eq!("0.69314718", d8!("2").log_lo());
+    assert_str_eq!("0.69314719", d8!("2").log_hi());
+    assert_str_eq!("0.69314718", d8!("2").log());
+    assert_str_eq!("2.71828182", d8!("1").exp_lo());
+    assert_str_eq!("2.71828183", d8!("1").exp_hi());
+    assert_eq!((d8!("0"), true), d8!("1").log_lo_exact());
+    assert_eq!((d8!("1.5"), true), d8!("2.25").pow_hi_exact(d8!("0.5")));
+    assert_str_eq!("1.41421356", d8!("2").pow_lo(d8!("0.5")));
+    assert_str_eq!("1.41421357", d8!("2").pow_hi(d8!("0.5")));
+    assert_str_eq!("NaN", d8!("-2").pow_lo(d8!("0.5")));
+    assert_str_eq!("-inf", d8!("0").log_lo());
+    assert_str_eq!("0", d8!("-inf").exp_hi());
+}
+
+#[test]
+fn test_float() {
+    assert_str_eq!("0.00000001", D8::zero(PREC).next_up());
+    assert_str_eq!("-0.00000001", D8::zero(PREC).next_down());
+    assert_str_eq!("inf", D8::infinity(PREC).next_down().next_up());
+    assert_str_eq!("-inf", D8::neg_infinity(PREC).next_up().next_down());
+    assert!(D8::infinity(PREC).next_down().is_finite());
+    assert_str_eq!("inf", D8::infinity(PREC).next_up());
+    assert_str_eq!("NaN", D8::nan(PREC).next_up());
+    assert!(D8::nan(PREC).is_nan());
+    assert!(!D8::nan(PREC).is_finite());
+    assert_str_eq!("-inf", -D8::infinity(PREC));
+    assert_str_eq!("NaN", -D8::nan(PREC));
+    assert_str_eq!("1.5", fp::Abs::abs(d8!("-1.5")));
+    assert!(d8!("3").has_odd_denominator());
+    assert!(d8!("0.2").has_odd_denominator());
+    assert!(!d8!("0.5").has_odd_denominator());
+    assert!(b16!("3").has_odd_denominator());
+    assert!(!b16!("1.5").has_odd_denominator());
+    let (m, e) = d8!("3").frexp();
+    assert_str_eq!("0.75", m);
+    assert_eq!(2, e);
+    assert_str_eq!("0.75", d8!("3").ldexp(-2));
+    assert_str_eq!("12", d8!("3").ldexp(2));
+}
@@ -1,7 +1,10 @@
 use transc;
 
+use std::cmp::Ordering;
 use std::convert;
-use std::fmt::Display;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
 use std::ops;
 use std::str;
 
@@ -16,6 +19,64 @@ pub enum Sign {
     Positive = 1,
 }
 
+/// Represents the rounding direction of an inexact result.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Round {
+    /// Round towards negative infinity.
+    Down,
+    /// Round towards positive infinity.
+    Up,
+    /// Round to the nearest representable number. Ties go to the even number.
+    Nearest,
+    /// Round towards zero.
+    TowardsZero,
+    /// Round away from zero.
+    AwayFromZero,
+}
+
+impl Round {
+    /// Computes `op(self)` with an operation that only rounds down, up or to the nearest, and
+    /// tells whether the result is exact.
+    ///
+    /// Rounding towards zero and away from zero is resolved with the result rounded down, which is
+    /// negative if and only if the exact result is negative.
+    pub fn resolve<T, F>(self, mut op: F) -> (T, bool)
+    where
+        T: Bound,
+        F: FnMut(Round) -> (T, bool),
+    {
+        match self {
+            Round::TowardsZero | Round::AwayFromZero => {
+                let (lo, exact) = op(Round::Down);
+                let negative = lo.sign() == Sign::Negative;
+                if exact || negative == (self == Round::AwayFromZero) {
+                    (lo, exact)
+                } else {
+                    op(Round::Up)
+                }
+            }
+            _ => op(self),
+        }
+    }
+
+    /// Computes `op(false)` or `op(true)` when rounding down or up respectively, and `nearest()`
+    /// when rounding to the nearest, and tells whether the result is exact.
+    ///
+    /// Results rounded to the nearest are never claimed to be exact.
+    pub fn resolve_with<T, F, N>(self, op: F, nearest: N) -> (T, bool)
+    where
+        T: Bound,
+        F: Fn(bool) -> (T, bool),
+        N: Fn() -> T,
+    {
+        self.resolve(|r| match r {
+            Round::Down => op(false),
+            Round::Up => op(true),
+            _ => (nearest(), false),
+        })
+    }
+}
+
 /// Finite precision version of the `std::convert::From` trait.
 pub trait From<T> {
     /// Converts `T` into `Self` and rounds down inexact representations.
@@ -64,79 +125,380 @@ pub trait Abs {
     fn abs(self) -> Self;
 }
 
+/// Trait for rounding to integral values.
+///
+/// Integral parts and fractional parts of a number are always exactly representable in the same
+/// precision, therefore these operations do not round.
+pub trait Integral {
+    /// Returns the greatest integer less than or equal to `self`.
+    fn floor(self) -> Self;
+    /// Returns the smallest integer greater than or equal to `self`.
+    fn ceil(self) -> Self;
+    /// Returns the nearest integer to `self`. Ties go to the even integer.
+    fn round_ties_even(self) -> Self;
+    /// Returns the nearest integer to `self`. Ties go away from zero.
+    fn round_ties_away(self) -> Self;
+    /// Returns the integer part of `self` by rounding towards zero.
+    fn trunc(self) -> Self;
+    /// Returns the fractional part of `self`, that is `self - self.trunc()`.
+    ///
+    /// The result has the same sign as `self`. Returns NaN for infinities.
+    fn frac(self) -> Self;
+}
+
+/// Trait for remainder operations.
+pub trait Rem: Sized {
+    /// Computes `self - n * rhs`, where `n` is `self / rhs` rounded towards zero, and rounds down
+    /// the result.
+    ///
+    /// The result has the same sign as `self`.
+    fn fmod_lo(self, rhs: Self) -> Self;
+    /// Computes `self - n * rhs`, where `n` is `self / rhs` rounded towards zero, and rounds up
+    /// the result.
+    ///
+    /// The result has the same sign as `self`.
+    fn fmod_hi(self, rhs: Self) -> Self;
+    /// Computes `self - n * rhs`, where `n` is `self / rhs` rounded to the nearest integer with
+    /// ties to even, and rounds down the result.
+    fn remainder_lo(self, rhs: Self) -> Self;
+    /// Computes `self - n * rhs`, where `n` is `self / rhs` rounded to the nearest integer with
+    /// ties to even, and rounds up the result.
+    fn remainder_hi(self, rhs: Self) -> Self;
+}
+
+/// Trait for the Euclidean norm `sqrt(x^2 + y^2)`.
+pub trait Hypot: Sized {
+    /// Computes `sqrt(self^2 + rhs^2)` without undue overflow and rounds the result in the
+    /// direction of `round`.
+    fn hypot_r(self, rhs: Self, round: Round) -> Self;
+    /// Computes `sqrt(self^2 + rhs^2)` without undue overflow and rounds down the result.
+    fn hypot_lo(self, rhs: Self) -> Self {
+        self.hypot_r(rhs, Round::Down)
+    }
+    /// Computes `sqrt(self^2 + rhs^2)` without undue overflow and rounds up the result.
+    fn hypot_hi(self, rhs: Self) -> Self {
+        self.hypot_r(rhs, Round::Up)
+    }
+}
+
+/// Trait for a total order and a hash consistent with it.
+///
+/// The order agrees with `PartialOrd` wherever that is defined. In addition, NaN equals NaN and is
+/// greater than all other values. Zeros of either sign are equal and have the same hash.
+pub trait TotalOrd {
+    /// Compares `self` and `rhs` in the total order.
+    fn cmp_total(&self, rhs: &Self) -> Ordering;
+    /// Feeds `self` into `state` so that values equal in the total order hash the same.
+    fn hash_total<H: Hasher>(&self, state: &mut H);
+}
+
+/// Wrapper that compares, orders and hashes its value through `fp::TotalOrd`.
+///
+/// It implements `Eq`, `Ord` and `Hash`, so it can be used as the key of a `HashMap` or a
+/// `BTreeMap`.
+#[derive(Clone, Copy, Debug)]
+pub struct Total<T>(pub T);
+
+impl<T: TotalOrd> PartialEq for Total<T> {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.0.cmp_total(&rhs.0) == Ordering::Equal
+    }
+}
+
+impl<T: TotalOrd> Eq for Total<T> {}
+
+impl<T: TotalOrd> PartialOrd for Total<T> {
+    #[inline]
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl<T: TotalOrd> Ord for Total<T> {
+    #[inline]
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.0.cmp_total(&rhs.0)
+    }
+}
+
+impl<T: TotalOrd> Hash for Total<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash_total(state)
+    }
+}
+
 /// Finite precision version of the `std::ops::Add` trait.
 pub trait Add: Sized + ops::Add<Output = Self> {
+    /// Adds `self` to `rhs` and rounds the result in the direction of `round`.
+    fn add_r(self, rhs: Self, round: Round) -> Self {
+        self.add_r_exact(rhs, round).0
+    }
+    /// Adds `self` to `rhs`, rounds the result in the direction of `round` and tells whether the
+    /// result is exact.
+    ///
+    /// The default implementation ignores `round` and never claims exactness.
+    fn add_r_exact(self, rhs: Self, _round: Round) -> (Self, bool) {
+        (self + rhs, false)
+    }
     /// Adds `self` to `rhs` and rounds down the result.
     fn add_lo(self, rhs: Self) -> Self {
-        self + rhs
+        self.add_r(rhs, Round::Down)
     }
     /// Adds `self` to `rhs` and rounds up the result.
     fn add_hi(self, rhs: Self) -> Self {
-        self + rhs
+        self.add_r(rhs, Round::Up)
+    }
+    /// Adds `self` to `rhs`, rounds down the result and tells whether the result is exact.
+    fn add_lo_exact(self, rhs: Self) -> (Self, bool) {
+        self.add_r_exact(rhs, Round::Down)
+    }
+    /// Adds `self` to `rhs`, rounds up the result and tells whether the result is exact.
+    fn add_hi_exact(self, rhs: Self) -> (Self, bool) {
+        self.add_r_exact(rhs, Round::Up)
     }
 }
 
 /// Finite precision version of the `std::ops::Sub` trait.
 pub trait Sub: Sized + ops::Sub<Output = Self> {
+    /// Subtracts `rhs` from `self` and rounds the result in the direction of `round`.
+    fn sub_r(self, rhs: Self, round: Round) -> Self {
+        self.sub_r_exact(rhs, round).0
+    }
+    /// Subtracts `rhs` from `self`, rounds the result in the direction of `round` and tells whether
+    /// the result is exact.
+    ///
+    /// The default implementation ignores `round` and never claims exactness.
+    fn sub_r_exact(self, rhs: Self, _round: Round) -> (Self, bool) {
+        (self - rhs, false)
+    }
     /// Subtracts `rhs` from `self` and rounds down the result.
     fn sub_lo(self, rhs: Self) -> Self {
-        self - rhs
+        self.sub_r(rhs, Round::Down)
     }
     /// Subtracts `rhs` from `self` and rounds up the result.
     fn sub_hi(self, rhs: Self) -> Self {
-        self - rhs
+        self.sub_r(rhs, Round::Up)
+    }
+    /// Subtracts `rhs` from `self`, rounds down the result and tells whether the result is exact.
+    fn sub_lo_exact(self, rhs: Self) -> (Self, bool) {
+        self.sub_r_exact(rhs, Round::Down)
+    }
+    /// Subtracts `rhs` from `self`, rounds up the result and tells whether the result is exact.
+    fn sub_hi_exact(self, rhs: Self) -> (Self, bool) {
+        self.sub_r_exact(rhs, Round::Up)
     }
 }
 
 /// Finite precision version of the `std::ops::Mul` trait.
 pub trait Mul: Sized + ops::Mul<Output = Self> {
+    /// Multiplies `self` by `rhs` and rounds the result in the direction of `round`.
+    fn mul_r(self, rhs: Self, round: Round) -> Self {
+        self.mul_r_exact(rhs, round).0
+    }
+    /// Multiplies `self` by `rhs`, rounds the result in the direction of `round` and tells whether
+    /// the result is exact.
+    ///
+    /// The default implementation ignores `round` and never claims exactness.
+    fn mul_r_exact(self, rhs: Self, _round: Round) -> (Self, bool) {
+        (self * rhs, false)
+    }
     /// Multiplies `self` by `rhs` and rounds down the result.
     fn mul_lo(self, rhs: Self) -> Self {
-        self * rhs
+        self.mul_r(rhs, Round::Down)
     }
     /// Multiplies `self` by `rhs` and rounds up the result.
     fn mul_hi(self, rhs: Self) -> Self {
-        self * rhs
+        self.mul_r(rhs, Round::Up)
+    }
+    /// Multiplies `self` by `rhs`, rounds down the result and tells whether the result is exact.
+    fn mul_lo_exact(self, rhs: Self) -> (Self, bool) {
+        self.mul_r_exact(rhs, Round::Down)
+    }
+    /// Multiplies `self` by `rhs`, rounds up the result and tells whether the result is exact.
+    fn mul_hi_exact(self, rhs: Self) -> (Self, bool) {
+        self.mul_r_exact(rhs, Round::Up)
     }
 }
 
 /// Finite precision version of the `std::ops::Div` trait.
 pub trait Div: Sized + ops::Div<Output = Self> {
+    /// Divides `self` by `rhs` and rounds the result in the direction of `round`.
+    fn div_r(self, rhs: Self, round: Round) -> Self {
+        self.div_r_exact(rhs, round).0
+    }
+    /// Divides `self` by `rhs`, rounds the result in the direction of `round` and tells whether the
+    /// result is exact.
+    ///
+    /// The default implementation ignores `round` and never claims exactness.
+    fn div_r_exact(self, rhs: Self, _round: Round) -> (Self, bool) {
+        (self / rhs, false)
+    }
     /// Divides `self` by `rhs` and rounds down the result.
     fn div_lo(self, rhs: Self) -> Self {
-        self / rhs
+        self.div_r(rhs, Round::Down)
     }
     /// Divides `self` by `rhs` and rounds up the result.
     fn div_hi(self, rhs: Self) -> Self {
-        self / rhs
+        self.div_r(rhs, Round::Up)
+    }
+    /// Divides `self` by `rhs`, rounds down the result and tells whether the result is exact.
+    fn div_lo_exact(self, rhs: Self) -> (Self, bool) {
+        self.div_r_exact(rhs, Round::Down)
+    }
+    /// Divides `self` by `rhs`, rounds up the result and tells whether the result is exact.
+    fn div_hi_exact(self, rhs: Self) -> (Self, bool) {
+        self.div_r_exact(rhs, Round::Up)
+    }
+}
+
+/// Trait for the fused multiply-add `self * a + b`.
+pub trait Fma: Sized {
+    /// Computes `self * a + b` and rounds the result once in the direction of `round`.
+    fn fma_r(self, a: Self, b: Self, round: Round) -> Self {
+        self.fma_r_exact(a, b, round).0
+    }
+    /// Computes `self * a + b`, rounds the result once in the direction of `round` and tells
+    /// whether the result is exact.
+    fn fma_r_exact(self, a: Self, b: Self, round: Round) -> (Self, bool);
+    /// Computes `self * a + b` and rounds down the result once.
+    fn fma_lo(self, a: Self, b: Self) -> Self {
+        self.fma_r(a, b, Round::Down)
+    }
+    /// Computes `self * a + b` and rounds up the result once.
+    fn fma_hi(self, a: Self, b: Self) -> Self {
+        self.fma_r(a, b, Round::Up)
+    }
+    /// Computes `self * a + b`, rounds down the result once and tells whether the result is exact.
+    fn fma_lo_exact(self, a: Self, b: Self) -> (Self, bool) {
+        self.fma_r_exact(a, b, Round::Down)
+    }
+    /// Computes `self * a + b`, rounds up the result once and tells whether the result is exact.
+    fn fma_hi_exact(self, a: Self, b: Self) -> (Self, bool) {
+        self.fma_r_exact(a, b, Round::Up)
+    }
+}
+
+/// Trait for sums and dot products of many numbers.
+///
+/// The sum of no numbers is zero with given precision. Infinities and NaN follow the rules of IEEE
+/// 754.
+pub trait Sum: Sized {
+    /// Computes the sum of `xs` and rounds the result once in the direction of `round`.
+    fn sum_r(xs: &[Self], precision: usize, round: Round) -> Self;
+    /// Computes the sum of `xs` and rounds down the result once.
+    fn sum_lo(xs: &[Self], precision: usize) -> Self {
+        Self::sum_r(xs, precision, Round::Down)
+    }
+    /// Computes the sum of `xs` and rounds up the result once.
+    fn sum_hi(xs: &[Self], precision: usize) -> Self {
+        Self::sum_r(xs, precision, Round::Up)
+    }
+    /// Computes the dot product of `xs` and `ys` and rounds the result once in the direction of
+    /// `round`.
+    ///
+    /// Panics if `xs` and `ys` have different lengths.
+    fn dot_r(xs: &[Self], ys: &[Self], precision: usize, round: Round) -> Self;
+    /// Computes the dot product of `xs` and `ys` and rounds down the result once.
+    fn dot_lo(xs: &[Self], ys: &[Self], precision: usize) -> Self {
+        Self::dot_r(xs, ys, precision, Round::Down)
+    }
+    /// Computes the dot product of `xs` and `ys` and rounds up the result once.
+    fn dot_hi(xs: &[Self], ys: &[Self], precision: usize) -> Self {
+        Self::dot_r(xs, ys, precision, Round::Up)
     }
 }
 
 /// Finite precision version of the `transc::Transc` trait.
 pub trait Transc: Sized + transc::Transc<Output = Self> {
+    /// Computes the natural logarithm of `self` and rounds the result in the direction of `round`.
+    fn log_r(self, round: Round) -> Self {
+        self.log_r_exact(round).0
+    }
+    /// Computes the natural logarithm of `self`, rounds the result in the direction of `round` and
+    /// tells whether the result is exact.
+    ///
+    /// The default implementation ignores `round` and never claims exactness.
+    fn log_r_exact(self, _round: Round) -> (Self, bool) {
+        (self.log(), false)
+    }
     /// Computes the natural logarithm of `self` and rounds down the result.
     fn log_lo(self) -> Self {
-        self.log()
+        self.log_r(Round::Down)
     }
     /// Computes the natural logarithm of `self` and rounds up the result.
     fn log_hi(self) -> Self {
-        self.log()
+        self.log_r(Round::Up)
+    }
+    /// Computes the natural logarithm of `self`, rounds down the result and tells whether the
+    /// result is exact.
+    fn log_lo_exact(self) -> (Self, bool) {
+        self.log_r_exact(Round::Down)
+    }
+    /// Computes the natural logarithm of `self`, rounds up the result and tells whether the result
+    /// is exact.
+    fn log_hi_exact(self) -> (Self, bool) {
+        self.log_r_exact(Round::Up)
+    }
+    /// Computes the natural exponential of `self` and rounds the result in the direction of
+    /// `round`.
+    fn exp_r(self, round: Round) -> Self {
+        self.exp_r_exact(round).0
+    }
+    /// Computes the natural exponential of `self`, rounds the result in the direction of `round`
+    /// and tells whether the result is exact.
+    ///
+    /// The default implementation ignores `round` and never claims exactness.
+    fn exp_r_exact(self, _round: Round) -> (Self, bool) {
+        (self.exp(), false)
     }
     /// Computes the natural exponential of `self` and rounds down the result.
     fn exp_lo(self) -> Self {
-        self.exp()
+        self.exp_r(Round::Down)
     }
     /// Computes the natural exponential of `self` and rounds up the result.
     fn exp_hi(self) -> Self {
-        self.exp()
+        self.exp_r(Round::Up)
+    }
+    /// Computes the natural exponential of `self`, rounds down the result and tells whether the
+    /// result is exact.
+    fn exp_lo_exact(self) -> (Self, bool) {
+        self.exp_r_exact(Round::Down)
+    }
+    /// Computes the natural exponential of `self`, rounds up the result and tells whether the
+    /// result is exact.
+    fn exp_hi_exact(self) -> (Self, bool) {
+        self.exp_r_exact(Round::Up)
+    }
+    /// Computes `self` raised to the power `rhs` and rounds the result in the direction of `round`.
+    fn pow_r(self, rhs: Self, round: Round) -> Self {
+        self.pow_r_exact(rhs, round).0
+    }
+    /// Computes `self` raised to the power `rhs`, rounds the result in the direction of `round` and
+    /// tells whether the result is exact.
+    ///
+    /// The default implementation ignores `round` and never claims exactness.
+    fn pow_r_exact(self, rhs: Self, _round: Round) -> (Self, bool) {
+        (self.pow(rhs), false)
     }
     /// Computes `self` raised to the power `rhs` and rounds down the result.
     fn pow_lo(self, rhs: Self) -> Self {
-        self.pow(rhs)
+        self.pow_r(rhs, Round::Down)
     }
     /// Computes `self` raised to the power `rhs` and rounds up the result.
     fn pow_hi(self, rhs: Self) -> Self {
-        self.pow(rhs)
+        self.pow_r(rhs, Round::Up)
+    }
+    /// Computes `self` raised to the power `rhs`, rounds down the result and tells whether the
+    /// result is exact.
+    fn pow_lo_exact(self, rhs: Self) -> (Self, bool) {
+        self.pow_r_exact(rhs, Round::Down)
+    }
+    /// Computes `self` raised to the power `rhs`, rounds up the result and tells whether the result
+    /// is exact.
+    fn pow_hi_exact(self, rhs: Self) -> (Self, bool) {
+        self.pow_r_exact(rhs, Round::Up)
     }
 }
 
@@ -156,7 +518,12 @@ pub trait Float:
     + Sub
     + Mul
     + Div
+    + Fma
+    + Sum
     + MinMax
+    + Integral
+    + Rem
+    + Hypot
     + Transc
 {
     /// Constructs a float representing zero.
@@ -192,4 +559,182 @@ pub trait Float:
 
     /// Whether the canonical rational representation of `self` has an odd denominator.
     fn has_odd_denominator(&self) -> bool;
+
+    /// Returns the smallest representable number that is greater than `self`.
+    ///
+    /// Positive infinity and NaN are returned as is.
+    fn next_up(self) -> Self;
+    /// Returns the greatest representable number that is less than `self`.
+    ///
+    /// Negative infinity and NaN are returned as is.
+    fn next_down(self) -> Self;
+    /// Returns the distance between `abs(self)` and the next representable number away from zero.
+    ///
+    /// Returns positive infinity for infinities and NaN for NaN.
+    fn ulp(&self) -> Self {
+        let a = self.clone().abs();
+        let up = a.clone().next_up();
+        if up.is_infinite() {
+            a.clone().sub_hi(a.next_down())
+        } else {
+            up.sub_hi(a)
+        }
+    }
+    /// Returns the exponent of `self` as in `frexp`.
+    fn exponent(&self) -> isize {
+        self.clone().frexp().1
+    }
+    /// Returns the mantissa of `self` as in `frexp`.
+    fn mantissa(&self) -> Self {
+        self.clone().frexp().0
+    }
+    /// Splits `self` into a mantissa `m` and an exponent `e` such that `self == m * 2^e` and
+    /// `0.5 <= abs(m) < 1`.
+    ///
+    /// Zero, infinities and NaN are returned as is with a zero exponent.
+    fn frexp(self) -> (Self, isize);
+    /// Computes `self * 2^exp`.
+    ///
+    /// The result is exact unless it overflows or underflows.
+    fn ldexp(self, exp: isize) -> Self;
+}
+
+/// Trait for the bounds of intervals.
+///
+/// Every `Float` is a bound. Integers are bounds too: their arithmetic is exact and saturates to
+/// infinities, but they have no real division, rounding or transcendental functions. NaN
+/// represents an empty interval.
+pub trait Bound:
+    Clone + PartialOrd + ops::Neg<Output = Self> + Abs + Add + Sub + Mul + MinMax
+{
+    /// Constructs a bound representing zero.
+    fn zero(precision: usize) -> Self;
+    /// Constructs a bound representing one.
+    fn one(precision: usize) -> Self;
+    /// Constructs a bound representing positive infinity.
+    fn infinity(precision: usize) -> Self;
+    /// Constructs a bound representing negative infinity.
+    fn neg_infinity(precision: usize) -> Self;
+    /// Constructs a bound representing NaN.
+    fn nan(precision: usize) -> Self;
+
+    /// Returns the sign of `self`.
+    fn sign(&self) -> Sign;
+    /// Returns the precision of `self`.
+    fn precision(&self) -> usize;
+
+    /// Whether `self` is a regular number (non-infinity and non-NaN).
+    fn is_finite(&self) -> bool;
+    /// Whether `self` is either positive infinity or negative infinity.
+    fn is_infinite(&self) -> bool;
+    /// Whether `self` is zero (or negative zero).
+    fn is_zero(&self) -> bool;
+    /// Whether `self` is positive infinity.
+    fn is_infinity(&self) -> bool;
+    /// Whether `self` is negative infinity.
+    fn is_neg_infinity(&self) -> bool;
+    /// Whether `self` is NaN.
+    fn is_nan(&self) -> bool;
+
+    /// Parses `Self` and rounds down inexact representations. Returns `None` on errors.
+    fn parse_lo(s: &str, precision: usize) -> Option<Self>;
+    /// Parses `Self` and rounds up inexact representations. Returns `None` on errors.
+    fn parse_hi(s: &str, precision: usize) -> Option<Self>;
+    /// Formats `self` as in `std::fmt::Display`.
+    fn fmt_bound(&self, f: &mut Formatter) -> fmt::Result;
+}
+
+impl<T: Float> Bound for T {
+    #[inline]
+    fn zero(precision: usize) -> Self {
+        Float::zero(precision)
+    }
+
+    #[inline]
+    fn one(precision: usize) -> Self {
+        Float::one(precision)
+    }
+
+    #[inline]
+    fn infinity(precision: usize) -> Self {
+        Float::infinity(precision)
+    }
+
+    #[inline]
+    fn neg_infinity(precision: usize) -> Self {
+        Float::neg_infinity(precision)
+    }
+
+    #[inline]
+    fn nan(precision: usize) -> Self {
+        Float::nan(precision)
+    }
+
+    #[inline]
+    fn sign(&self) -> Sign {
+        Float::sign(self)
+    }
+
+    #[inline]
+    fn precision(&self) -> usize {
+        Float::precision(self)
+    }
+
+    #[inline]
+    fn is_finite(&self) -> bool {
+        Float::is_finite(self)
+    }
+
+    #[inline]
+    fn is_infinite(&self) -> bool {
+        Float::is_infinite(self)
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        Float::is_zero(self)
+    }
+
+    #[inline]
+    fn is_infinity(&self) -> bool {
+        Float::is_infinity(self)
+    }
+
+    #[inline]
+    fn is_neg_infinity(&self) -> bool {
+        Float::is_neg_infinity(self)
+    }
+
+    #[inline]
+    fn is_nan(&self) -> bool {
+        Float::is_nan(self)
+    }
+
+    #[inline]
+    fn parse_lo(s: &str, precision: usize) -> Option<Self> {
+        T::from_str_lo(s, precision).ok()
+    }
+
+    #[inline]
+    fn parse_hi(s: &str, precision: usize) -> Option<Self> {
+        T::from_str_hi(s, precision).ok()
+    }
+
+    #[inline]
+    fn fmt_bound(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// Trait for integer bounds.
+///
+/// The largest representable magnitudes are reserved for the infinities and NaN. Results that do
+/// not fit saturate to the infinities when they are rounded away from zero, and to the largest
+/// finite values otherwise.
+pub trait Integer: Bound {
+    /// Computes `floor(self / rhs)` for nonzero `rhs`.
+    ///
+    /// A finite number divided by an infinity gives the floor of an infinitesimal quotient, which
+    /// is zero if the signs agree or `self` is zero, and minus one otherwise.
+    fn floor_div(self, rhs: Self) -> Self;
 }
@@ -0,0 +1,1618 @@
+use super::expansion::{headroom, Expansion, PRECISE_LEN};
+use super::rounded_f64::parse_decimal;
+use super::{two_sum, RoundedF64};
+
+use fp;
+use fp::{Float, Round, Sign};
+use rational::BigInt;
+use transc::Transc;
+
+use std::cmp::Ordering;
+use std::f64::consts::FRAC_1_SQRT_2;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::hash::Hasher;
+use std::num::ParseFloatError;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::str::FromStr;
+
+/// A double-double float, i.e. an unevaluated sum `hi + lo` of two `f64`s.
+///
+/// The pair is kept normalized: `hi` is the nearest float to `hi + lo`, and `lo` is zero whenever
+/// `hi` is zero, infinite or NaN. This gives about 106 bits of precision with the exponent range
+/// of `f64`, without any heap allocation.
+///
+/// Arithmetic computes the exact result as a nonoverlapping expansion of floats using error-free
+/// transformations, and then rounds the expansion in the requested direction. Logarithms and
+/// exponentials are computed as expansions of more than 200 bits from their series after argument
+/// reduction, with a bound on the remainder of the series. Remainders and decimal conversions are
+/// computed exactly with `rational::BigInt`. The resulting bounds are not always the tightest, but
+/// they are never wrong.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct DoubleDouble {
+    hi: f64,
+    lo: f64,
+}
+
+/// Greatest finite double-double.
+const MAX: DoubleDouble = DoubleDouble {
+    hi: f64::MAX,
+    lo: f64::from_bits(0x7c8f_ffff_ffff_ffff),
+};
+
+/// Lower bound on the magnitude of a result whose nearest-rounded approximation overflows.
+const OVERFLOW_MIN: f64 = f64::from_bits(0x7fef_ffff_ffff_fffb);
+
+/// Bound on the magnitude of the arguments of `exp` beyond which the result overflows or
+/// underflows.
+const EXP_ARG_MAX: f64 = 1000.0;
+
+/// Largest integer exponent for which powers are computed by repeated multiplication.
+const POW_INTEGER_MAX: f64 = 64.0;
+
+/// Number of terms kept by the products of integer powers, so that small powers are exact.
+const POW_LEN: usize = 8;
+
+/// Number of terms kept by the squares of `hypot`, which are thus exact.
+const SQUARE_LEN: usize = 8;
+
+/// Maximum number of significant decimal digits that are displayed.
+const DISPLAY_DIGITS: usize = 33;
+
+/// Constructs the normalized double-double representing exactly `hi + lo`.
+#[inline]
+fn normalize(hi: f64, lo: f64) -> DoubleDouble {
+    let (s, e) = two_sum(hi, lo);
+    if s == 0.0 || !s.is_finite() {
+        DoubleDouble { hi: s, lo: 0.0 }
+    } else {
+        DoubleDouble { hi: s, lo: e }
+    }
+}
+
+/// Returns a bound on a result that overflows, given its nearest-rounded approximation.
+#[inline]
+fn overflow(approx: f64, up: bool) -> DoubleDouble {
+    match (approx > 0.0, up) {
+        (true, true) => DoubleDouble::from(f64::INFINITY),
+        (true, false) => DoubleDouble::from(OVERFLOW_MIN),
+        (false, true) => DoubleDouble::from(-OVERFLOW_MIN),
+        (false, false) => DoubleDouble::from(f64::NEG_INFINITY),
+    }
+}
+
+impl Expansion {
+    /// Rounds the sum of `self` to the nearest double-double, approximately. `approx` is the
+    /// nearest-rounded approximation of the result, used for overflows and signed zeros.
+    fn round_nearest(&self, approx: f64) -> DoubleDouble {
+        if !self.is_finite() {
+            DoubleDouble::from(approx)
+        } else if self.len == 0 {
+            DoubleDouble::from(if approx == 0.0 { approx } else { 0.0 })
+        } else {
+            normalize(self.term(0), self.term(1))
+        }
+    }
+
+    /// Rounds the sum of `self` downwards or upwards to a double-double, and tells whether the
+    /// result is exact. `approx` is the nearest-rounded approximation of the result, used for
+    /// overflows and signed zeros.
+    fn round(&self, approx: f64, up: bool) -> (DoubleDouble, bool) {
+        if !self.is_finite() {
+            return (overflow(approx, up), false);
+        }
+        let rest = self.term(2);
+        if rest == 0.0 && self.err == 0.0 {
+            return (self.round_nearest(approx), true);
+        }
+        let (hi, lo) = two_sum(self.term(0), self.term(1));
+        // The remaining terms have the sign of the largest one, unless the error bound hides it.
+        let tail = self.tail_bound(3);
+        let known = self.err == 0.0 || RoundedF64(rest.abs()) > tail;
+        if rest != 0.0 && known && (rest > 0.0) != up {
+            return (normalize(hi, lo), false);
+        }
+        let d = fp::Add::add_hi(RoundedF64(rest.abs()), tail);
+        let lo = if up {
+            fp::Add::add_hi(RoundedF64(lo), d)
+        } else {
+            fp::Sub::sub_lo(RoundedF64(lo), d)
+        };
+        (normalize(hi, lo.0), false)
+    }
+
+    /// Rounds the sum of `self` downwards, upwards or to the nearest double-double, and tells
+    /// whether the result is exact. `approx` is the nearest-rounded approximation of the result,
+    /// used for overflows and signed zeros.
+    fn round_r(&self, approx: f64, round: Round) -> (DoubleDouble, bool) {
+        round.resolve(|r| match r {
+            Round::Down => self.round(approx, false),
+            Round::Up => self.round(approx, true),
+            _ => (self.round_nearest(approx), false),
+        })
+    }
+
+    /// Rounds the sum of `self` times `2^scale` downwards, upwards or to the nearest, and tells
+    /// whether the result is exact. The terms of `self` must be finite. `approx` is the
+    /// nearest-rounded approximation of the result, used for signed zeros.
+    fn round_scaled(&self, scale: isize, approx: f64, round: Round) -> (DoubleDouble, bool) {
+        let approx = Float::ldexp(approx, -scale);
+        round.resolve(|r| {
+            let (x, exact) = self.round_r(approx, r);
+            if scale == 0 {
+                return (x, exact);
+            }
+            // Scaling up is exact unless it overflows, in which case the exact result is beyond
+            // the greatest finite double-double.
+            let y = x.ldexp(scale);
+            match r {
+                Round::Down if y.hi == f64::INFINITY => (MAX, false),
+                Round::Up if y.hi == f64::NEG_INFINITY => (-MAX, false),
+                _ => (y, exact && y.hi.is_finite()),
+            }
+        })
+    }
+}
+
+impl DoubleDouble {
+    /// Constructs a double-double representing exactly `hi + lo`.
+    #[inline]
+    pub fn new(hi: f64, lo: f64) -> Self {
+        normalize(hi, lo)
+    }
+
+    /// Returns the high word of `self`, which is the nearest `f64` to `self`.
+    #[inline]
+    pub fn hi(&self) -> f64 {
+        self.hi
+    }
+
+    /// Returns the low word of `self`.
+    #[inline]
+    pub fn lo(&self) -> f64 {
+        self.lo
+    }
+
+    /// Returns the exact expansion of `self + rhs`. Both must be finite.
+    fn sum(self, rhs: Self) -> Expansion {
+        let mut e = Expansion::new();
+        e.push(self.hi);
+        e.push(self.lo);
+        e.push(rhs.hi);
+        e.push(rhs.lo);
+        e
+    }
+
+    /// Returns the expansion of `self * rhs`. Both must be finite.
+    fn product(self, rhs: Self) -> Expansion {
+        let mut e = Expansion::new();
+        e.push_product(self.hi, rhs.hi);
+        e.push_product(self.hi, rhs.lo);
+        e.push_product(self.lo, rhs.hi);
+        e.push_product(self.lo, rhs.lo);
+        e
+    }
+
+    /// Returns the expansion of `self - q * rhs`. All must be finite.
+    fn residual(self, rhs: Self, q: Self) -> Expansion {
+        let mut e = Expansion::new();
+        e.push(self.hi);
+        e.push(self.lo);
+        e.push_product(-q.hi, rhs.hi);
+        e.push_product(-q.hi, rhs.lo);
+        e.push_product(-q.lo, rhs.hi);
+        e.push_product(-q.lo, rhs.lo);
+        e
+    }
+
+    /// Approximates `self / rhs` by long division. Both must be finite and nonzero, and the
+    /// quotient of their high words must be finite.
+    fn quotient(self, rhs: Self) -> Self {
+        let mut q = Self::from(self.hi / rhs.hi);
+        for _ in 0..2 {
+            let r = self.residual(rhs, q);
+            if !r.is_finite() {
+                break;
+            }
+            q = normalize(q.hi, q.lo + r.estimate() / rhs.hi);
+        }
+        q
+    }
+
+    fn add_with(self, rhs: Self, up: bool) -> (Self, bool) {
+        let approx = self.hi + rhs.hi;
+        if !self.hi.is_finite() || !rhs.hi.is_finite() {
+            return (Self::from(approx), true);
+        }
+        self.sum(rhs).round(approx, up)
+    }
+
+    fn mul_with(self, rhs: Self, up: bool) -> (Self, bool) {
+        let approx = self.hi * rhs.hi;
+        if !self.hi.is_finite() || !rhs.hi.is_finite() {
+            return (Self::from(approx), true);
+        }
+        self.product(rhs).round(approx, up)
+    }
+
+    fn div_with(self, rhs: Self, up: bool) -> (Self, bool) {
+        let approx = self.hi / rhs.hi;
+        if !self.hi.is_finite() || !rhs.hi.is_finite() || self.hi == 0.0 || rhs.hi == 0.0 {
+            return (Self::from(approx), true);
+        }
+        if approx.is_infinite() {
+            return (overflow(approx, up), false);
+        }
+        let q = self.quotient(rhs);
+        let r = self.residual(rhs, q);
+        if !r.is_finite() {
+            // This only happens close to overflow, where `q` is far more accurate than `q.hi`.
+            let hi = if up {
+                Float::next_up(Float::next_up(q.hi))
+            } else {
+                Float::next_down(Float::next_down(q.hi))
+            };
+            return (Self::from(hi), false);
+        }
+        let r_top = r.term(0);
+        if r_top == 0.0 && r.err == 0.0 {
+            return (q, true);
+        }
+        // The exact quotient is `q + r / rhs`.
+        if r.err == 0.0 && ((r_top > 0.0) == (rhs.hi > 0.0)) != up {
+            return (q, false);
+        }
+        let rhs_min = fp::Sub::sub_lo(RoundedF64(rhs.hi.abs()), RoundedF64(rhs.lo.abs()));
+        let d = fp::Div::div_hi(r.tail_bound(0), rhs_min);
+        let lo = if up {
+            fp::Add::add_hi(RoundedF64(q.lo), d)
+        } else {
+            fp::Sub::sub_lo(RoundedF64(q.lo), d)
+        };
+        (normalize(q.hi, lo.0), false)
+    }
+}
+
+/// Splits a finite `x` into an integer `m` and an exponent `e` such that `x = m * 2^e`.
+fn integer_parts(x: f64) -> (BigInt, isize) {
+    let (m, e) = Float::frexp(x);
+    (BigInt::from(Float::ldexp(m, 53) as i64), e - 53)
+}
+
+/// Rounds `num / den * 2^exp` to an `f64` downwards, upwards or to the nearest with ties to even,
+/// and tells whether the result is exact. `den` must be positive.
+fn round_ratio(num: &BigInt, den: &BigInt, exp: isize, round: Round) -> (f64, bool) {
+    if num.is_zero() {
+        return (0.0, true);
+    }
+    let neg = num.sign() == Sign::Negative;
+    let num = num.clone().abs();
+    // The quotient has 55 or 56 bits, so that at least two bits are left for the rounding.
+    let shift = 55 + den.bits() as isize - num.bits() as isize;
+    let (q, r) = if shift >= 0 {
+        num.shl(shift as usize).div_rem(den)
+    } else {
+        num.div_rem(&den.shl(-shift as usize))
+    };
+    let q = q.to_u64().unwrap();
+    let q_exp = exp - shift;
+    let top = 63 - q.leading_zeros() as isize + q_exp;
+    if top > Float::exponent(&f64::MAX) - 1 {
+        let away = match round {
+            Round::Down => neg,
+            Round::Up => !neg,
+            _ => true,
+        };
+        let x = if away { f64::INFINITY } else { f64::MAX };
+        return (if neg { -x } else { x }, false);
+    }
+    let ulp_exp = (top - 52).max(-1074);
+    let t = ulp_exp - q_exp;
+    let (m, half, inexact) = if t >= 64 {
+        (0, Ordering::Less, true)
+    } else {
+        let rest = q & ((1 << t) - 1);
+        let half = rest.cmp(&(1 << (t - 1))).then(if r.is_zero() {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
+        });
+        (q >> t, half, rest != 0 || !r.is_zero())
+    };
+    let away = match round {
+        Round::Down => neg && inexact,
+        Round::Up => !neg && inexact,
+        _ => half == Ordering::Greater || (half == Ordering::Equal && m & 1 == 1),
+    };
+    let x = Float::ldexp((m + away as u64) as f64, ulp_exp);
+    (if neg { -x } else { x }, !inexact)
+}
+
+impl DoubleDouble {
+    /// Splits a finite `self` into an integer `m` and an exponent `e` such that
+    /// `self = m * 2^e`.
+    fn integer_parts(self) -> (BigInt, isize) {
+        let (hi, hi_exp) = integer_parts(self.hi);
+        if self.lo == 0.0 {
+            return (hi, hi_exp);
+        }
+        let (lo, lo_exp) = integer_parts(self.lo);
+        let exp = hi_exp.min(lo_exp);
+        let m = &hi.shl((hi_exp - exp) as usize) + &lo.shl((lo_exp - exp) as usize);
+        (m, exp)
+    }
+
+    /// Rounds `num / den * 2^exp` downwards, upwards or to the nearest double-double, and tells
+    /// whether the result is exact. `den` must be positive.
+    ///
+    /// The high word is the rounded value and the low word the rounded rest, so rounding to the
+    /// nearest is approximate.
+    fn from_ratio(num: &BigInt, den: &BigInt, exp: isize, round: Round) -> (Self, bool) {
+        let (hi, hi_exact) = round_ratio(num, den, exp, round);
+        if hi_exact || hi.is_infinite() {
+            return (Self::from(hi), hi_exact);
+        }
+        let (m, m_exp) = integer_parts(hi);
+        let rest_exp = exp.min(m_exp);
+        let rest =
+            &num.shl((exp - rest_exp) as usize) - &(&m * den).shl((m_exp - rest_exp) as usize);
+        let (lo, lo_exact) = round_ratio(&rest, den, rest_exp, round);
+        let res = normalize(hi, lo);
+        if res.hi.is_infinite() {
+            (if hi > 0.0 { MAX } else { -MAX }, false)
+        } else {
+            (res, lo_exact)
+        }
+    }
+
+    /// Parses `s` and rounds it downwards, upwards or to the nearest double-double.
+    fn parse_r(s: &str, round: Round) -> Result<Self, ParseFloatError> {
+        let x = s.trim().parse::<f64>()?;
+        if x.is_nan() || (x.is_infinite() && s.to_ascii_lowercase().contains("inf")) {
+            return Ok(Self::from(x));
+        }
+        // Decimal exponents are clamped to where every value is rounded like `10^400` or
+        // `10^-400`.
+        let (neg, digits, exp) = match parse_decimal(s) {
+            Some((neg, digits, exp)) => (neg, digits, exp.clamp(-400, 401)),
+            None => (x < 0.0, vec![1], if x == 0.0 { -400 } else { 401 }),
+        };
+        if digits.is_empty() {
+            return Ok(Self::from(if neg { -0.0 } else { 0.0 }));
+        }
+        let ten = BigInt::from(10u64);
+        let d = digits.iter().fold(BigInt::zero(), |acc, &d| {
+            &(&acc * &ten) + &BigInt::from(d as u64)
+        });
+        let d = if neg { -d } else { d };
+        let p = exp - digits.len() as i64;
+        let (num, den) = if p >= 0 {
+            (&d * &ten.pow(p as u32), BigInt::one())
+        } else {
+            (d, ten.pow(-p as u32))
+        };
+        Ok(Self::from_ratio(&num, &den, 0, round).0)
+    }
+
+    /// Returns the decimal digits of `self` rounded to `n` significant digits with ties to even,
+    /// with a leading `-` if `self` is negative, and the exponent `exp` such that `self` is about
+    /// `0.d1d2d3... * 10^exp`. `self` must be finite and nonzero.
+    fn to_decimal(self, n: usize) -> (String, isize) {
+        let (m, exp2) = self.integer_parts();
+        let m = m.abs();
+        let ten = BigInt::from(10u64);
+        let (min, max) = (ten.pow(n as u32 - 1), ten.pow(n as u32));
+        let mut exp = self.hi.abs().log10().floor() as isize + 1;
+        loop {
+            // The digits are `self * 10^(n - exp)` rounded to an integer.
+            let p = n as isize - exp;
+            let (mut num, mut den) = (m.clone(), BigInt::one());
+            if exp2 >= 0 {
+                num = num.shl(exp2 as usize);
+            } else {
+                den = den.shl(-exp2 as usize);
+            }
+            if p >= 0 {
+                num = &num * &ten.pow(p as u32);
+            } else {
+                den = &den * &ten.pow(-p as u32);
+            }
+            let (q, r) = num.div_rem(&den);
+            if q >= max {
+                exp += 1;
+                continue;
+            }
+            if q < min {
+                exp -= 1;
+                continue;
+            }
+            let r2 = r.shl(1);
+            let mut q = q;
+            if r2 > den || (r2 == den && q.is_odd()) {
+                q = &q + &BigInt::one();
+            }
+            if q == max {
+                q = min.clone();
+                exp += 1;
+            }
+            let sign = if self.hi < 0.0 { "-" } else { "" };
+            return (format!("{}{}", sign, q), exp);
+        }
+    }
+
+    /// Computes `self - n * rhs`, where `n` is `self / rhs` rounded towards zero or to the nearest
+    /// with ties to even, and rounds the result downwards or upwards.
+    fn rem_r(self, rhs: Self, nearest: bool, round: Round) -> Self {
+        if self.hi.is_finite() && rhs.hi.is_infinite() {
+            return self;
+        }
+        if !self.hi.is_finite() || !rhs.hi.is_finite() || self.hi == 0.0 || rhs.hi == 0.0 {
+            return Self::from(self.hi % rhs.hi);
+        }
+        let (x, x_exp) = self.integer_parts();
+        let (y, y_exp) = rhs.integer_parts();
+        let exp = x_exp.min(y_exp);
+        let x = x.shl((x_exp - exp) as usize);
+        let y = y.shl((y_exp - exp) as usize);
+        let (q, mut r) = x.div_rem(&y);
+        if nearest {
+            let (r2, y_abs) = (r.clone().abs().shl(1), y.clone().abs());
+            if r2 > y_abs || (r2 == y_abs && q.is_odd()) {
+                // The quotient is rounded away from zero instead.
+                r = if x.sign() == y.sign() {
+                    &r - &y
+                } else {
+                    &r + &y
+                };
+            }
+        }
+        if r.is_zero() {
+            return Self::from(if self.hi < 0.0 { -0.0 } else { 0.0 });
+        }
+        Self::from_ratio(&r, &BigInt::one(), exp, round).0
+    }
+}
+
+impl DoubleDouble {
+    /// Returns the exact expansion of `self`, which must be finite.
+    fn expansion(self) -> Expansion {
+        let mut e = Expansion::from(self.hi);
+        e.push(self.lo);
+        e
+    }
+
+    /// Computes `self * 2^exp` rounded downwards, upwards or to the nearest, and tells whether
+    /// the result is exact.
+    fn ldexp_r(self, exp: isize, round: Round) -> (Self, bool) {
+        let y = self.ldexp(exp);
+        if y.hi.is_infinite() {
+            return match round {
+                Round::Down if y.hi > 0.0 => (MAX, false),
+                Round::Up if y.hi < 0.0 => (-MAX, false),
+                _ => (y, false),
+            };
+        }
+        if y.ldexp(-exp) == self {
+            return (y, true);
+        }
+        // Both words are rounded to the nearest, so the error is at most the smallest subnormal.
+        let tiny = RoundedF64(f64::from_bits(1));
+        let lo = match round {
+            Round::Down => fp::Sub::sub_lo(RoundedF64(y.lo), tiny).0,
+            Round::Up => fp::Add::add_hi(RoundedF64(y.lo), tiny).0,
+            _ => y.lo,
+        };
+        (normalize(y.hi, lo), false)
+    }
+
+    /// Computes `log(self)` for a finite positive `self` as an expansion.
+    fn log_expansion(self) -> Expansion {
+        let (m, e) = self.frexp();
+        if m.hi < FRAC_1_SQRT_2 {
+            m.ldexp(1).expansion().log(e - 1)
+        } else {
+            m.expansion().log(e)
+        }
+    }
+
+    /// Computes `exp(z)` for an expansion `z`, and rounds the result downwards, upwards or to the
+    /// nearest. The result is never claimed to be exact.
+    fn exp_expansion_r(z: &Expansion, round: Round) -> Self {
+        let (top, rest) = (z.term(0), z.tail_bound(1));
+        if z.err.is_nan() || z.err > 1.0 || z.estimate().is_nan() || !rest.0.is_finite() {
+            return match round {
+                Round::Down => Self::from(0.0),
+                Round::Up => Self::from(f64::INFINITY),
+                _ => Self::from(f64::NAN),
+            };
+        }
+        let res = if fp::Sub::sub_lo(RoundedF64(top), rest).0 > EXP_ARG_MAX {
+            match round {
+                Round::Down => MAX,
+                _ => Self::from(f64::INFINITY),
+            }
+        } else if fp::Add::add_hi(RoundedF64(top), rest).0 < -EXP_ARG_MAX {
+            match round {
+                Round::Up => Self::from(f64::from_bits(1)),
+                _ => Self::from(0.0),
+            }
+        } else {
+            let (e, k) = z.exp();
+            let (y, _) = e.round_r(e.estimate(), round);
+            y.ldexp_r(k, round).0
+        };
+        // The result is positive, which is lost when the rounding goes through zero.
+        if res.hi < 0.0 {
+            Self::from(0.0)
+        } else {
+            res
+        }
+    }
+
+    fn log_with(self, round: Round) -> (Self, bool) {
+        if self.hi.is_nan() || self.hi < 0.0 {
+            return (Self::from(f64::NAN), true);
+        }
+        if self.hi == 0.0 {
+            return (Self::from(f64::NEG_INFINITY), true);
+        }
+        if self.hi == f64::INFINITY || self == Self::from(1.0) {
+            return (Self::from(self.hi.ln()), true);
+        }
+        let e = self.log_expansion();
+        let (y, _) = e.round_r(e.estimate(), round);
+        (y, false)
+    }
+
+    fn exp_with(self, round: Round) -> (Self, bool) {
+        if !self.hi.is_finite() || self.hi == 0.0 {
+            return (Self::from(self.hi.exp()), true);
+        }
+        (Self::exp_expansion_r(&self.expansion(), round), false)
+    }
+
+    /// Computes `self^rhs` exactly when the result is zero, one, an infinity or NaN, following the
+    /// special cases of C99 `pow`.
+    fn pow_special(self, rhs: Self) -> Option<Self> {
+        let one = Self::from(1.0);
+        if rhs.hi == 0.0 || self == one {
+            return Some(one);
+        }
+        if self.hi.is_nan() || rhs.hi.is_nan() {
+            return Some(Self::from(f64::NAN));
+        }
+        if rhs.hi.is_infinite() {
+            let grows = match fp::Abs::abs(self).partial_cmp(&one) {
+                Some(Ordering::Less) => rhs.hi < 0.0,
+                Some(Ordering::Equal) => return Some(one),
+                _ => rhs.hi > 0.0,
+            };
+            return Some(Self::from(if grows { f64::INFINITY } else { 0.0 }));
+        }
+        if self.hi.is_infinite() || self.hi == 0.0 {
+            let neg = self.hi.is_sign_negative() && rhs.is_odd_integer();
+            let grows = (rhs.hi > 0.0) == self.hi.is_infinite();
+            let res = if grows { f64::INFINITY } else { 0.0 };
+            return Some(Self::from(if neg { -res } else { res }));
+        }
+        if self.hi < 0.0 && !rhs.has_odd_denominator() {
+            return Some(Self::from(f64::NAN));
+        }
+        None
+    }
+
+    /// Whether `self` is an odd integer.
+    #[inline]
+    fn is_odd_integer(self) -> bool {
+        self.has_odd_denominator() && !self.ldexp(-1).has_odd_denominator()
+    }
+
+    /// Computes `self^n` for a finite positive `self` and an integer `n`, and tells whether the
+    /// result is exact. The power is computed exactly when it has few enough terms.
+    fn pow_integer(self, n: i64, round: Round) -> (Self, bool) {
+        let (m, e) = self.frexp();
+        let mut p = Expansion::from(1.0);
+        let mut base = m.expansion();
+        let mut k = n.unsigned_abs();
+        while k > 0 {
+            if k & 1 == 1 {
+                p = p.mul(&base, POW_LEN);
+            }
+            k >>= 1;
+            if k > 0 {
+                base = base.mul(&base, POW_LEN);
+            }
+        }
+        let exp = e * n as isize;
+        let approx = p.estimate();
+        if n > 0 {
+            let (p, p_exact) = p.round_r(approx, round);
+            let (res, exact) = p.ldexp_r(exp, round);
+            return (res, p_exact && exact);
+        }
+        // The inverse is rounded in the direction opposite to the power.
+        let (q, exact) = match round {
+            Round::Down => {
+                let (p, p_exact) = p.round(approx, true);
+                let (q, q_exact) = Self::from(1.0).div_with(p, false);
+                (q, p_exact && q_exact)
+            }
+            Round::Up => {
+                let (p, p_exact) = p.round(approx, false);
+                let (q, q_exact) = Self::from(1.0).div_with(p, true);
+                (q, p_exact && q_exact)
+            }
+            _ => (Self::from(1.0) / p.round_nearest(approx), false),
+        };
+        let (res, res_exact) = q.ldexp_r(exp, round);
+        (res, exact && res_exact)
+    }
+
+    fn pow_with(self, rhs: Self, round: Round) -> (Self, bool) {
+        if let Some(res) = self.pow_special(rhs) {
+            return (res, true);
+        }
+        if self.hi < 0.0 {
+            // A negative number has an integer power, which is negative if it is odd.
+            if !rhs.is_odd_integer() {
+                return (-self).pow_with(rhs, round);
+            }
+            let flipped = match round {
+                Round::Down => Round::Up,
+                Round::Up => Round::Down,
+                r => r,
+            };
+            let (res, exact) = (-self).pow_with(rhs, flipped);
+            return (-res, exact);
+        }
+        if rhs.has_odd_denominator() && rhs.hi.abs() <= POW_INTEGER_MAX {
+            return self.pow_integer(rhs.hi as i64, round);
+        }
+        // The power is `exp(rhs * log(self))`. When the exponent is surely too large, only its sign
+        // matters, and the product is not computed as it could overflow.
+        let log = self.log_expansion();
+        let log_min = fp::Sub::sub_lo(RoundedF64(log.term(0).abs()), log.tail_bound(1));
+        let z = if fp::Mul::mul_lo(log_min, RoundedF64(rhs.hi.abs() * 0.5)).0 > EXP_ARG_MAX {
+            let positive = (log.term(0) > 0.0) == (rhs.hi > 0.0);
+            Expansion::from(if positive {
+                f64::INFINITY
+            } else {
+                f64::NEG_INFINITY
+            })
+        } else {
+            log.mul(&rhs.expansion(), PRECISE_LEN)
+        };
+        (Self::exp_expansion_r(&z, round), false)
+    }
+
+    fn hypot_with(self, rhs: Self, round: Round) -> (Self, bool) {
+        let (x, y) = (fp::Abs::abs(self), fp::Abs::abs(rhs));
+        if x.hi.is_infinite() || y.hi.is_infinite() {
+            return (Self::from(f64::INFINITY), true);
+        }
+        if x.hi.is_nan() || y.hi.is_nan() {
+            return (Self::from(f64::NAN), true);
+        }
+        if x.hi == 0.0 || y.hi == 0.0 {
+            return (fp::MinMax::max(x, y), true);
+        }
+        // The operands are scaled so that the larger one is between 1/2 and 1.
+        let k = Float::exponent(&x.hi.max(y.hi));
+        let (x, y) = (x.expansion().ldexp(-k), y.expansion().ldexp(-k));
+        let mut s = x.mul(&x, SQUARE_LEN);
+        s.push_expansion(&y.mul(&y, SQUARE_LEN));
+        s.compress();
+        // The root is approximated by Newton's iteration, and the exact root is
+        // `c + r / (sqrt(s) + c)` where `r = s - c^2`.
+        let residual = |c: Self| {
+            let mut r = s;
+            r.push_product(-c.hi, c.hi);
+            r.push_product(-2.0 * c.hi, c.lo);
+            r.push_product(-c.lo, c.lo);
+            r.compress();
+            r
+        };
+        let mut c = Self::from(s.estimate().sqrt());
+        for _ in 0..2 {
+            c = normalize(c.hi, c.lo + residual(c).estimate() / (2.0 * c.hi));
+        }
+        let r = residual(c);
+        let sign = r.sign();
+        let c_min = fp::Sub::sub_lo(RoundedF64(c.hi), RoundedF64(c.lo.abs()));
+        // `|r| / c` bounds the correction, and `|r| / (2c)` does too when `r` is positive.
+        let d = fp::Div::div_hi(r.tail_bound(0), c_min);
+        let half = RoundedF64(d.0 * 0.5);
+        let (z, exact) = match (round, sign) {
+            (_, Some(Ordering::Equal)) => (c, true),
+            (Round::Down, Some(Ordering::Greater)) | (Round::Up, Some(Ordering::Less)) => {
+                (c, false)
+            }
+            (Round::Down, _) => (
+                normalize(c.hi, fp::Sub::sub_lo(RoundedF64(c.lo), d).0),
+                false,
+            ),
+            (Round::Up, Some(Ordering::Greater)) => (
+                normalize(c.hi, fp::Add::add_hi(RoundedF64(c.lo), half).0),
+                false,
+            ),
+            (Round::Up, _) => (
+                normalize(c.hi, fp::Add::add_hi(RoundedF64(c.lo), d).0),
+                false,
+            ),
+            _ => (c, false),
+        };
+        let (z, z_exact) = z.ldexp_r(k, round);
+        (z, exact && z_exact)
+    }
+}
+
+/// Formats the decimal `0.d1d2d3... * 10^exp` without an exponent, as `f64` does.
+fn format_decimal(digits: &str, exp: isize) -> String {
+    let (sign, digits) = match digits.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", digits),
+    };
+    let digits = digits.trim_end_matches('0');
+    let mut s = String::from(sign);
+    if exp <= 0 {
+        s.push_str("0.");
+        s.extend((0..-exp).map(|_| '0'));
+        s.push_str(digits);
+    } else if exp as usize >= digits.len() {
+        s.push_str(digits);
+        s.extend((digits.len()..exp as usize).map(|_| '0'));
+    } else {
+        s.push_str(&digits[..exp as usize]);
+        s.push('.');
+        s.push_str(&digits[exp as usize..]);
+    }
+    s
+}
+
+impl Display for DoubleDouble {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.lo == 0.0 {
+            return Display::fmt(&self.hi, f);
+        }
+        // The shortest decimal that is parsed back to `self` is displayed, as `f64` does.
+        let mut s = String::new();
+        for n in 2..=DISPLAY_DIGITS {
+            let (digits, exp) = self.to_decimal(n);
+            s = format_decimal(&digits, exp);
+            if s.parse::<Self>() == Ok(*self) {
+                break;
+            }
+        }
+        f.write_str(&s)
+    }
+}
+
+impl FromStr for DoubleDouble {
+    type Err = ParseFloatError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_r(s, Round::Nearest)
+    }
+}
+
+impl From<f64> for DoubleDouble {
+    #[inline]
+    fn from(val: f64) -> Self {
+        DoubleDouble { hi: val, lo: 0.0 }
+    }
+}
+
+impl From<DoubleDouble> for f64 {
+    #[inline]
+    fn from(val: DoubleDouble) -> Self {
+        val.hi
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        DoubleDouble {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl Add for DoubleDouble {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        let approx = self.hi + rhs.hi;
+        if !self.hi.is_finite() || !rhs.hi.is_finite() {
+            return Self::from(approx);
+        }
+        self.sum(rhs).round_nearest(approx)
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        let approx = self.hi * rhs.hi;
+        if !self.hi.is_finite() || !rhs.hi.is_finite() {
+            return Self::from(approx);
+        }
+        self.product(rhs).round_nearest(approx)
+    }
+}
+
+impl Div for DoubleDouble {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        let approx = self.hi / rhs.hi;
+        if !self.hi.is_finite() || !rhs.hi.is_finite() || self.hi == 0.0 || rhs.hi == 0.0 {
+            return Self::from(approx);
+        }
+        if approx.is_infinite() {
+            return Self::from(approx);
+        }
+        self.quotient(rhs)
+    }
+}
+
+impl Transc for DoubleDouble {
+    type Output = Self;
+
+    #[inline]
+    fn log(self) -> Self::Output {
+        self.log_with(Round::Nearest).0
+    }
+
+    #[inline]
+    fn exp(self) -> Self::Output {
+        self.exp_with(Round::Nearest).0
+    }
+
+    #[inline]
+    fn pow(self, rhs: Self) -> Self::Output {
+        self.pow_with(rhs, Round::Nearest).0
+    }
+}
+
+impl fp::From<f64> for DoubleDouble {
+    #[inline]
+    fn from_lo(val: f64, _: usize) -> Self {
+        Self::from(val)
+    }
+
+    #[inline]
+    fn from_hi(val: f64, _: usize) -> Self {
+        Self::from(val)
+    }
+}
+
+impl fp::FromStr for DoubleDouble {
+    #[inline]
+    fn from_str_lo(s: &str, _: usize) -> Result<Self, Self::Err> {
+        Self::parse_r(s, Round::Down)
+    }
+
+    #[inline]
+    fn from_str_hi(s: &str, _: usize) -> Result<Self, Self::Err> {
+        Self::parse_r(s, Round::Up)
+    }
+}
+
+impl fp::Into<f64> for DoubleDouble {
+    #[inline]
+    fn into_lo(self) -> f64 {
+        if self.lo < 0.0 {
+            Float::next_down(self.hi)
+        } else {
+            self.hi
+        }
+    }
+
+    #[inline]
+    fn into_hi(self) -> f64 {
+        if self.lo > 0.0 {
+            Float::next_up(self.hi)
+        } else {
+            self.hi
+        }
+    }
+}
+
+impl fp::MinMax for DoubleDouble {
+    #[inline]
+    fn min(self, rhs: Self) -> Self {
+        if self.is_nan() || rhs < self {
+            rhs
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn max(self, rhs: Self) -> Self {
+        if self.is_nan() || rhs > self {
+            rhs
+        } else {
+            self
+        }
+    }
+}
+
+impl fp::Abs for DoubleDouble {
+    #[inline]
+    fn abs(self) -> Self {
+        if self.hi.is_sign_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+}
+
+// When `hi` is not an integer, `lo` is too small to move `hi + lo` across an integer or a
+// half-integer other than `hi` itself. When `hi` is an integer, the rounding only depends on `lo`.
+impl fp::Integral for DoubleDouble {
+    #[inline]
+    fn floor(self) -> Self {
+        let hi = self.hi.floor();
+        if hi == self.hi {
+            normalize(hi, self.lo.floor())
+        } else {
+            Self::from(hi)
+        }
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        let hi = self.hi.ceil();
+        if hi == self.hi {
+            normalize(hi, self.lo.ceil())
+        } else {
+            Self::from(hi)
+        }
+    }
+
+    fn round_ties_even(self) -> Self {
+        let hi = self.hi.floor();
+        if hi == self.hi {
+            let lo = self.lo.floor();
+            let lo = match (self.lo - lo).partial_cmp(&0.5) {
+                Some(::std::cmp::Ordering::Less) => lo,
+                Some(::std::cmp::Ordering::Greater) => lo + 1.0,
+                _ if (hi % 2.0 + lo % 2.0) % 2.0 == 0.0 => lo,
+                _ => lo + 1.0,
+            };
+            normalize(hi, lo)
+        } else if self.hi - hi == 0.5 && self.lo != 0.0 {
+            Self::from(if self.lo > 0.0 { hi + 1.0 } else { hi })
+        } else {
+            Self::from(self.hi.round_ties_even())
+        }
+    }
+
+    fn round_ties_away(self) -> Self {
+        let hi = self.hi.floor();
+        if hi == self.hi {
+            let lo = self.lo.floor();
+            let lo = match (self.lo - lo).partial_cmp(&0.5) {
+                Some(::std::cmp::Ordering::Less) => lo,
+                Some(::std::cmp::Ordering::Greater) => lo + 1.0,
+                _ if hi < 0.0 => lo,
+                _ => lo + 1.0,
+            };
+            normalize(hi, lo)
+        } else if self.hi - hi == 0.5 && self.lo != 0.0 {
+            Self::from(if self.lo > 0.0 { hi + 1.0 } else { hi })
+        } else {
+            Self::from(self.hi.round())
+        }
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        if self.hi < 0.0 {
+            self.ceil()
+        } else {
+            self.floor()
+        }
+    }
+
+    #[inline]
+    fn frac(self) -> Self {
+        // The difference is exactly representable.
+        fp::Sub::sub_lo(self, self.trunc())
+    }
+}
+
+impl fp::Rem for DoubleDouble {
+    #[inline]
+    fn fmod_lo(self, rhs: Self) -> Self {
+        self.rem_r(rhs, false, Round::Down)
+    }
+
+    #[inline]
+    fn fmod_hi(self, rhs: Self) -> Self {
+        self.rem_r(rhs, false, Round::Up)
+    }
+
+    #[inline]
+    fn remainder_lo(self, rhs: Self) -> Self {
+        self.rem_r(rhs, true, Round::Down)
+    }
+
+    #[inline]
+    fn remainder_hi(self, rhs: Self) -> Self {
+        self.rem_r(rhs, true, Round::Up)
+    }
+}
+
+impl fp::Hypot for DoubleDouble {
+    #[inline]
+    fn hypot_r(self, rhs: Self, round: Round) -> Self {
+        round.resolve(|r| self.hypot_with(rhs, r)).0
+    }
+}
+
+impl fp::Add for DoubleDouble {
+    #[inline]
+    fn add_r(self, rhs: Self, round: Round) -> Self {
+        self.add_r_exact(rhs, round).0
+    }
+
+    #[inline]
+    fn add_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        round.resolve_with(|up| self.add_with(rhs, up), || self + rhs)
+    }
+}
+
+impl fp::Sub for DoubleDouble {
+    #[inline]
+    fn sub_r(self, rhs: Self, round: Round) -> Self {
+        self.sub_r_exact(rhs, round).0
+    }
+
+    #[inline]
+    fn sub_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        round.resolve_with(|up| self.add_with(-rhs, up), || self - rhs)
+    }
+}
+
+impl fp::Mul for DoubleDouble {
+    #[inline]
+    fn mul_r(self, rhs: Self, round: Round) -> Self {
+        self.mul_r_exact(rhs, round).0
+    }
+
+    #[inline]
+    fn mul_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        round.resolve_with(|up| self.mul_with(rhs, up), || self * rhs)
+    }
+}
+
+impl fp::Div for DoubleDouble {
+    #[inline]
+    fn div_r(self, rhs: Self, round: Round) -> Self {
+        self.div_r_exact(rhs, round).0
+    }
+
+    #[inline]
+    fn div_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        round.resolve_with(|up| self.div_with(rhs, up), || self / rhs)
+    }
+}
+
+impl fp::Fma for DoubleDouble {
+    fn fma_r_exact(self, a: Self, b: Self, round: Round) -> (Self, bool) {
+        let approx = self.hi.mul_add(a.hi, b.hi);
+        if !self.hi.is_finite() || !a.hi.is_finite() || !b.hi.is_finite() {
+            return (Self::from(approx), true);
+        }
+        let exp = Float::exponent(&self.hi) + Float::exponent(&a.hi);
+        let scale = headroom(exp.max(Float::exponent(&b.hi)), 2);
+        let mut e = Expansion::new();
+        for &x in &[self.hi, self.lo] {
+            for &y in &[a.hi, a.lo] {
+                e.push_product_scaled(x, y, scale);
+            }
+        }
+        e.push_scaled(b.hi, scale);
+        e.push_scaled(b.lo, scale);
+        e.round_scaled(scale, approx, round)
+    }
+}
+
+impl fp::Sum for DoubleDouble {
+    fn sum_r(xs: &[Self], _: usize, round: Round) -> Self {
+        let approx = xs.iter().fold(0.0, |s, x| s + x.hi);
+        if xs.iter().any(|x| !x.hi.is_finite()) {
+            return Self::from(approx);
+        }
+        let exp = xs.iter().map(|x| Float::exponent(&x.hi)).max();
+        let scale = headroom(exp.unwrap_or(0), xs.len());
+        let mut e = Expansion::new();
+        for x in xs {
+            e.push_scaled(x.hi, scale);
+            e.push_scaled(x.lo, scale);
+        }
+        e.round_scaled(scale, approx, round).0
+    }
+
+    fn dot_r(xs: &[Self], ys: &[Self], _: usize, round: Round) -> Self {
+        let approx = xs.iter().zip(ys).fold(0.0, |s, (x, y)| s + x.hi * y.hi);
+        if xs.iter().chain(ys).any(|x| !x.hi.is_finite()) {
+            // Finite products cannot change an infinite or NaN sum.
+            let approx = xs.iter().zip(ys).fold(0.0, |s, (x, y)| {
+                if x.hi.is_finite() && y.hi.is_finite() {
+                    s
+                } else {
+                    s + x.hi * y.hi
+                }
+            });
+            return Self::from(approx);
+        }
+        let exp = xs
+            .iter()
+            .zip(ys)
+            .map(|(x, y)| Float::exponent(&x.hi) + Float::exponent(&y.hi))
+            .max();
+        let scale = headroom(exp.unwrap_or(0), xs.len());
+        let mut e = Expansion::new();
+        for (x, y) in xs.iter().zip(ys) {
+            for &a in &[x.hi, x.lo] {
+                for &b in &[y.hi, y.lo] {
+                    e.push_product_scaled(a, b, scale);
+                }
+            }
+        }
+        e.round_scaled(scale, approx, round).0
+    }
+}
+
+impl fp::TotalOrd for DoubleDouble {
+    #[inline]
+    fn cmp_total(&self, rhs: &DoubleDouble) -> Ordering {
+        match (self.hi.is_nan(), rhs.hi.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => self.partial_cmp(rhs).unwrap(),
+        }
+    }
+
+    /// Hashes both parts, which are unique since the pair is normalized.
+    #[inline]
+    fn hash_total<H: Hasher>(&self, state: &mut H) {
+        self.hi.hash_total(state);
+        self.lo.hash_total(state);
+    }
+}
+
+impl fp::Transc for DoubleDouble {
+    #[inline]
+    fn log_r(self, round: Round) -> Self {
+        self.log_r_exact(round).0
+    }
+
+    #[inline]
+    fn exp_r(self, round: Round) -> Self {
+        self.exp_r_exact(round).0
+    }
+
+    #[inline]
+    fn pow_r(self, rhs: Self, round: Round) -> Self {
+        self.pow_r_exact(rhs, round).0
+    }
+
+    #[inline]
+    fn log_r_exact(self, round: Round) -> (Self, bool) {
+        round.resolve(|r| self.log_with(r))
+    }
+
+    #[inline]
+    fn exp_r_exact(self, round: Round) -> (Self, bool) {
+        round.resolve(|r| self.exp_with(r))
+    }
+
+    #[inline]
+    fn pow_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        round.resolve(|r| self.pow_with(rhs, r))
+    }
+}
+
+impl Float for DoubleDouble {
+    #[inline]
+    fn zero(_: usize) -> Self {
+        Self::from(0.0)
+    }
+
+    #[inline]
+    fn neg_zero(_: usize) -> Self {
+        Self::from(-0.0)
+    }
+
+    #[inline]
+    fn one(_: usize) -> Self {
+        Self::from(1.0)
+    }
+
+    #[inline]
+    fn infinity(_: usize) -> Self {
+        Self::from(f64::INFINITY)
+    }
+
+    #[inline]
+    fn neg_infinity(_: usize) -> Self {
+        Self::from(f64::NEG_INFINITY)
+    }
+
+    #[inline]
+    fn nan(_: usize) -> Self {
+        Self::from(f64::NAN)
+    }
+
+    #[inline]
+    fn sign(&self) -> Sign {
+        Float::sign(&self.hi)
+    }
+
+    #[inline]
+    fn precision(&self) -> usize {
+        106
+    }
+
+    #[inline]
+    fn is_finite(&self) -> bool {
+        self.hi.is_finite()
+    }
+
+    #[inline]
+    fn is_infinite(&self) -> bool {
+        self.hi.is_infinite()
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.hi == 0.0
+    }
+
+    #[inline]
+    fn is_infinity(&self) -> bool {
+        Float::is_infinity(&self.hi)
+    }
+
+    #[inline]
+    fn is_neg_infinity(&self) -> bool {
+        Float::is_neg_infinity(&self.hi)
+    }
+
+    #[inline]
+    fn is_nan(&self) -> bool {
+        self.hi.is_nan()
+    }
+
+    #[inline]
+    fn has_odd_denominator(&self) -> bool {
+        fp::Integral::floor(*self) == *self
+    }
+
+    #[inline]
+    fn next_up(self) -> Self {
+        if self.hi.is_nan() || self.hi == f64::INFINITY {
+            self
+        } else if self.hi == f64::NEG_INFINITY {
+            -MAX
+        } else {
+            normalize(self.hi, Float::next_up(self.lo))
+        }
+    }
+
+    #[inline]
+    fn next_down(self) -> Self {
+        -(-self).next_up()
+    }
+
+    fn frexp(self) -> (Self, isize) {
+        if self.hi == 0.0 || !self.hi.is_finite() {
+            return (self, 0);
+        }
+        let (m, mut e) = Float::frexp(self.hi);
+        // The mantissa of `hi` is rounded up in magnitude when `lo` has the opposite sign.
+        if m.abs() == 0.5 && self.lo != 0.0 && (self.lo > 0.0) != (m > 0.0) {
+            e -= 1;
+        }
+        (self.ldexp(-e), e)
+    }
+
+    #[inline]
+    fn ldexp(self, exp: isize) -> Self {
+        let hi = Float::ldexp(self.hi, exp);
+        // An overflowed `lo` of the opposite sign would turn the sum into NaN.
+        if !hi.is_finite() {
+            return Self::from(hi);
+        }
+        normalize(hi, Float::ldexp(self.lo, exp))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DoubleDouble as DD;
+    use fp::Float;
+    use mpfr::Mpfr;
+
+    const PREC: usize = 106;
+
+    /// Converts `x` exactly to MPFR at a precision large enough for exact arithmetic.
+    fn exact(x: DD) -> Mpfr {
+        let hi = Mpfr::from_custom(x.hi(), 1024, ::mpfr::MpfrRnd::HalfToEven);
+        hi + Mpfr::from_custom(x.lo(), 1024, ::mpfr::MpfrRnd::HalfToEven)
+    }
+
+    fn third() -> DD {
+        <DD as ::fp::Div>::div_lo(DD::from(1.0), DD::from(3.0))
+    }
+
+    #[test]
+    fn test_new() {
+        let x = DD::new(1.0, 1.0);
+        assert_eq!((2.0, 0.0), (x.hi(), x.lo()));
+        let x = DD::new(1.0, 1e-20);
+        assert_eq!((1.0, 1e-20), (x.hi(), x.lo()));
+        let x = DD::new(f64::MAX, f64::MAX);
+        assert!(x.is_infinity());
+    }
+
+    #[test]
+    fn test_from_str() {
+        use fp::FromStr;
+        assert_eq!(DD::from(0.5), DD::from_str_lo("0.5", PREC).unwrap());
+        assert_eq!(DD::from(0.5), DD::from_str_hi("+0.50", PREC).unwrap());
+        let lo = DD::from_str_lo("0.1", PREC).unwrap();
+        let hi = DD::from_str_hi("0.1", PREC).unwrap();
+        assert!(lo < hi);
+        assert_eq!(hi, lo.next_up());
+        assert_eq!(0.1, lo.hi());
+        assert_eq!(
+            DD::from(f64::INFINITY),
+            DD::from_str_hi("inf", PREC).unwrap()
+        );
+        assert!(DD::from_str_lo("NaN", PREC).unwrap().is_nan());
+        assert!(DD::from_str_lo("x", PREC).is_err());
+        assert_eq!(super::MAX, DD::from_str_lo("1e400", PREC).unwrap());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("1.5", format!("{}", DD::from(1.5)));
+        assert_eq!("-inf", format!("{}", DD::from(f64::NEG_INFINITY)));
+        assert_eq!(
+            "0.333333333333333333333333333333332",
+            format!("{}", DD::from(1.0) / DD::from(3.0))
+        );
+        assert_eq!("1.00000000000000000001", format!("{}", DD::new(1.0, 1e-20)));
+        assert_eq!(
+            "-12345678901234567890",
+            format!("{}", "-12345678901234567890".parse::<DD>().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_add() {
+        use fp::Add;
+        let x = DD::new(1.0, 1e-30);
+        assert_eq!((DD::new(2.0, 2e-30), true), x.add_lo_exact(x));
+        let (lo, is_exact) = third().add_lo_exact(DD::from(1e-40));
+        assert!(!is_exact);
+        let hi = third().add_hi(DD::from(1e-40));
+        assert_eq!(hi, lo.next_up());
+        assert!(exact(lo) <= exact(third()) + exact(DD::from(1e-40)));
+        assert_eq!(DD::from(0.0), DD::from(1.0).add_lo(DD::from(-1.0)));
+        assert_eq!(
+            DD::from(f64::INFINITY),
+            DD::from(f64::MAX).add_hi(DD::from(f64::MAX))
+        );
+        assert!(DD::from(f64::MAX).add_lo(DD::from(f64::MAX)).is_finite());
+    }
+
+    #[test]
+    fn test_mul() {
+        use fp::{Mul, Sub};
+        let x = third();
+        let (lo, lo_exact) = x.mul_lo_exact(x);
+        let (hi, hi_exact) = x.mul_hi_exact(x);
+        assert!(!lo_exact && !hi_exact);
+        let p = exact(x) * exact(x);
+        assert!(exact(lo) <= p && p <= exact(hi));
+        assert!(hi.sub_hi(lo) <= DD::from(1e-32));
+        assert_eq!(
+            (DD::from(6.0), true),
+            DD::from(2.0).mul_lo_exact(DD::from(3.0))
+        );
+        let tiny = DD::from(f64::from_bits(1));
+        assert!(tiny.mul_lo(DD::from(0.5)) <= DD::from(0.0));
+        assert!(tiny.mul_hi(DD::from(0.5)) >= DD::from(0.0));
+    }
+
+    #[test]
+    fn test_round() {
+        use fp::{Div, Round};
+        let (lo, hi) = (third(), third().next_up());
+        let one = DD::from(1.0);
+        assert_eq!(lo, one.div_r(DD::from(3.0), Round::TowardsZero));
+        assert_eq!(hi, one.div_r(DD::from(3.0), Round::AwayFromZero));
+        assert_eq!(-lo, (-one).div_r(DD::from(3.0), Round::TowardsZero));
+        assert_eq!(-hi, (-one).div_r(DD::from(3.0), Round::AwayFromZero));
+        let near = one.div_r(DD::from(3.0), Round::Nearest);
+        assert!(near == lo || near == hi);
+        assert_eq!(
+            (DD::from(0.5), true),
+            one.div_r_exact(DD::from(2.0), Round::TowardsZero)
+        );
+    }
+
+    #[test]
+    fn test_fma_sum() {
+        use fp::{Fma, Sum};
+        let tiny = DD::from(2.0f64.powi(-54));
+        let (one, ten) = (DD::from(1.0), DD::from(10.0));
+        assert_eq!((tiny, true), DD::from(0.1).fma_lo_exact(ten, -one));
+        let (lo, hi) = (third(), third().next_up());
+        assert_eq!(lo, lo.fma_lo(one, DD::from(0.0)));
+        assert_eq!(hi, third().fma_hi(one, DD::from(1e-300)));
+        let xs = [DD::from(1e100), one, DD::from(-1e100)];
+        assert_eq!(one, DD::sum_lo(&xs, PREC));
+        assert_eq!(one, DD::sum_hi(&xs, PREC));
+        let max = DD::from(f64::MAX);
+        assert_eq!(max, DD::sum_lo(&[max, max, -max], PREC));
+        assert_eq!(max, max.fma_hi(DD::from(2.0), -max));
+        let (lo, hi) = (
+            DD::sum_lo(&[third(), third()], PREC),
+            DD::sum_hi(&[third(), third()], PREC),
+        );
+        let sum = exact(third()) + exact(third());
+        assert!(exact(lo) <= sum && sum <= exact(hi));
+        let (xs, ys) = ([third(), DD::from(1e300)], [DD::from(3.0), DD::from(1e10)]);
+        let dot =
+            exact(third()) * exact(DD::from(3.0)) + exact(DD::from(1e300)) * exact(DD::from(1e10));
+        assert!(exact(DD::dot_lo(&xs, &ys, PREC)) <= dot);
+        assert!(dot <= exact(DD::dot_hi(&xs, &ys, PREC)));
+    }
+
+    #[test]
+    fn test_div() {
+        use fp::Div;
+        let (lo, lo_exact) = DD::from(1.0).div_lo_exact(DD::from(3.0));
+        let (hi, hi_exact) = DD::from(1.0).div_hi_exact(DD::from(3.0));
+        assert!(!lo_exact && !hi_exact);
+        assert_eq!(hi, lo.next_up());
+        let three = Mpfr::from_custom(3.0, 1024, ::mpfr::MpfrRnd::HalfToEven);
+        assert!(exact(lo) * three.clone() < exact(DD::from(1.0)));
+        assert!(exact(hi) * three > exact(DD::from(1.0)));
+        assert_eq!(
+            (DD::from(-0.5), true),
+            DD::from(1.0).div_hi_exact(DD::from(-2.0))
+        );
+        assert_eq!(DD::from(f64::INFINITY), DD::from(1.0).div_hi(DD::from(0.0)));
+    }
+
+    #[test]
+    fn test_transc() {
+        use fp::Transc;
+        assert_eq!((DD::from(0.0), true), DD::from(1.0).log_lo_exact());
+        assert_eq!((DD::from(1.0), true), DD::from(0.0).exp_hi_exact());
+        let e_lo = DD::from(1.0).exp_lo();
+        let e_hi = DD::from(1.0).exp_hi();
+        assert_eq!(e_hi, e_lo.next_up());
+        assert_eq!(::std::f64::consts::E, e_lo.hi());
+        assert_eq!(
+            (DD::from(9.0), true),
+            DD::from(3.0).pow_hi_exact(DD::from(2.0))
+        );
+        assert!(DD::from(2.0).pow_lo(DD::from(0.5)) < DD::from(2.0).pow_hi(DD::from(0.5)));
+        assert_eq!(
+            (DD::from(-0.125), true),
+            DD::from(-2.0).pow_lo_exact(DD::from(-3.0))
+        );
+        assert!(DD::from(-2.0).pow_lo(DD::from(0.5)).is_nan());
+        assert_eq!(super::MAX, DD::from(1e300).exp_lo());
+        assert_eq!(DD::from(0.0), DD::from(-1e300).exp_lo());
+        assert_eq!(super::MAX, DD::from(800.0).exp_lo());
+        assert_eq!(DD::from(f64::INFINITY), DD::from(800.0).exp_hi());
+        assert_eq!(super::MAX, DD::from(3.0).pow_lo(DD::from(700.5)));
+        assert_eq!(
+            DD::from(f64::INFINITY),
+            DD::from(3.0).pow_hi(DD::from(700.5))
+        );
+        // The bounds enclose the results of MPFR and are a few ulps apart at most.
+        let xs = [
+            third(),
+            DD::new(1.0, 1e-20),
+            DD::from(1e-300),
+            DD::from(700.0),
+        ];
+        for &x in &xs {
+            let log = exact(x).log_r(::fp::Round::Nearest);
+            assert!(exact(x.log_lo()) <= log && log <= exact(x.log_hi()));
+            assert!(x.log_hi() <= x.log_lo().next_up().next_up());
+            let exp = exact(x).exp_r(::fp::Round::Nearest);
+            assert!(exact(x.exp_lo()) <= exp && exp <= exact(x.exp_hi()));
+            assert!(x.exp_hi() <= x.exp_lo().next_up().next_up());
+            let pow = exact(x).pow_r(exact(third()), ::fp::Round::Nearest);
+            assert!(exact(x.pow_lo(third())) <= pow && pow <= exact(x.pow_hi(third())));
+        }
+    }
+
+    #[test]
+    fn test_rem_hypot() {
+        use fp::{Hypot, Rem};
+        let (three, four) = (DD::from(3.0), DD::from(4.0));
+        assert_eq!(DD::from(5.0), three.hypot_lo(four));
+        assert_eq!(DD::from(5.0), three.hypot_hi(-four));
+        let (lo, hi) = (third().hypot_lo(three), third().hypot_hi(three));
+        let h = exact(third()).hypot_r(exact(three), ::fp::Round::Nearest);
+        assert!(exact(lo) <= h && h <= exact(hi));
+        let (lo, hi) = (three.fmod_lo(third()), three.fmod_hi(third()));
+        let r = exact(three).fmod_lo(exact(third()));
+        assert!(exact(lo) <= r && r <= exact(hi));
+        assert_eq!(DD::from(-1.0), DD::from(7.0).remainder_lo(four));
+        assert_eq!(DD::from(-1.0), DD::from(-5.0).fmod_hi(DD::from(2.0)));
+        assert!(three.fmod_lo(DD::from(0.0)).is_nan());
+    }
+
+    #[test]
+    fn test_integral() {
+        use fp::Integral;
+        let x = DD::new(3.0, -1e-20);
+        assert_eq!(DD::from(2.0), x.floor());
+        assert_eq!(DD::from(3.0), x.ceil());
+        assert_eq!(DD::from(2.0), x.trunc());
+        assert_eq!(DD::from(3.0), x.round_ties_even());
+        assert_eq!(DD::new(1.0, -1e-20), x.frac());
+        let x = DD::new(2.5, 1e-20);
+        assert_eq!(DD::from(3.0), x.round_ties_even());
+        assert_eq!(DD::from(2.0), DD::new(2.5, -1e-20).round_ties_away());
+        let big = DD::new(2f64.powi(60), 0.5);
+        assert_eq!(DD::from(2f64.powi(60)), big.round_ties_even());
+        assert_eq!(DD::new(2f64.powi(60), 1.0), big.round_ties_away());
+        assert_eq!(
+            DD::new(2f64.powi(60), 2.0),
+            DD::new(2f64.powi(60), 1.5).round_ties_even()
+        );
+    }
+
+    #[test]
+    fn test_next() {
+        assert_eq!(DD::new(1.0, f64::from_bits(1)), DD::from(1.0).next_up());
+        assert_eq!(DD::new(1.0, -f64::from_bits(1)), DD::from(1.0).next_down());
+        assert_eq!(-super::MAX, DD::from(f64::NEG_INFINITY).next_up());
+        assert!(super::MAX.next_up().is_infinity());
+    }
+
+    #[test]
+    fn test_frexp() {
+        let (m, e) = DD::new(1.0, -1e-20).frexp();
+        assert_eq!(0, e);
+        assert_eq!(DD::new(1.0, -1e-20), m.ldexp(0));
+        let (m, e) = DD::new(3.0, 1e-20).frexp();
+        assert_eq!(2, e);
+        assert_eq!(DD::new(0.75, 0.25e-20), m);
+    }
+}
@@ -0,0 +1,401 @@
+use super::{two_sum, RoundedF64};
+
+use fp;
+use fp::Float;
+
+use std::cmp::Ordering;
+
+/// Maximum number of terms of an expansion.
+const EXPANSION_LEN: usize = 40;
+
+/// Smallest magnitude for which the error terms computed with a fused multiply-add are exact.
+///
+/// This is `2^-968`. Below this magnitude, the error terms may underflow.
+pub const EXACT_ERROR_MIN: f64 = f64::from_bits((1023 - 968) << 52);
+
+/// Smallest positive float, which bounds the error of a result that underflows.
+const TINY: f64 = f64::from_bits(1);
+
+/// Number of terms kept by products and quotients, which gives more than 200 bits of precision.
+pub const PRECISE_LEN: usize = 4;
+
+/// `ln(2)` split into three floats.
+const LN_2: [f64; 3] = [
+    f64::from_bits(0x3fe6_2e42_fefa_39ef),
+    f64::from_bits(0x3c7a_bc9e_3b39_803f),
+    f64::from_bits(0x3907_b57a_079a_1934),
+];
+
+/// Bound on the error of the sum of `LN_2`.
+const LN_2_ERR: f64 = 1e-49;
+
+/// Number of times the argument of the exponential series is halved before the series is summed,
+/// and the result squared after.
+const EXP_SQUARINGS: isize = 10;
+
+/// Bound on the remaining terms of a series, relative to its sum, below which summation stops.
+/// This is `2^-180`.
+const SERIES_EPSILON: f64 = f64::from_bits((1023 - 180) << 52);
+
+/// Maximum number of terms of a series.
+const SERIES_MAX_TERMS: usize = 100;
+
+/// Bound on the argument of the logarithm series, which converges quickly below it.
+const LOG_SERIES_MAX: f64 = 1.0 / 1024.0;
+
+/// Returns the exponent `k` such that the sum of `n` numbers of magnitude at most `2^exp` never
+/// overflows once they are scaled by `2^-k`. This is zero unless the numbers are huge.
+#[inline]
+pub fn headroom(exp: isize, n: usize) -> isize {
+    let bits = (usize::BITS - n.leading_zeros()) as isize;
+    (exp + bits + 2 - Float::exponent(&f64::MAX)).max(0)
+}
+
+/// Computes `x * 2^-scale` and a bound on the absolute error of the result.
+#[inline]
+fn scale_down(x: f64, scale: isize) -> (f64, f64) {
+    if scale == 0 {
+        return (x, 0.0);
+    }
+    let y = Float::ldexp(x, -scale);
+    let err = if Float::ldexp(y, scale) == x {
+        0.0
+    } else {
+        TINY
+    };
+    (y, err)
+}
+
+/// A nonoverlapping expansion, i.e. an exact unevaluated sum of floats sorted by increasing
+/// magnitude, together with a bound on the absolute error of the terms that could not be computed
+/// exactly.
+#[derive(Clone, Copy)]
+pub struct Expansion {
+    pub terms: [f64; EXPANSION_LEN],
+    pub len: usize,
+    pub err: f64,
+}
+
+impl Expansion {
+    #[inline]
+    pub fn new() -> Self {
+        Expansion {
+            terms: [0.0; EXPANSION_LEN],
+            len: 0,
+            err: 0.0,
+        }
+    }
+
+    /// Adds `x` to the error bound, rounding up.
+    #[inline]
+    pub fn add_err(&mut self, x: f64) {
+        self.err = fp::Add::add_hi(RoundedF64(self.err), RoundedF64(x)).0;
+    }
+
+    /// Adds `x` to `self` exactly. This is the GROW-EXPANSION algorithm of Shewchuk with zero
+    /// elimination. When there are too many terms, the smallest one is moved to the error bound.
+    pub fn push(&mut self, x: f64) {
+        let mut q = x;
+        let mut len = 0;
+        for i in 0..self.len {
+            let (s, e) = two_sum(q, self.terms[i]);
+            if e != 0.0 {
+                self.terms[len] = e;
+                len += 1;
+            }
+            q = s;
+        }
+        if q != 0.0 {
+            if len == EXPANSION_LEN {
+                self.add_err(self.terms[0].abs());
+                self.terms.copy_within(1.., 0);
+                len -= 1;
+            }
+            self.terms[len] = q;
+            len += 1;
+        }
+        self.len = len;
+    }
+
+    /// Adds `x * y` to `self`. The product is exact unless it underflows, in which case the error
+    /// bound is increased accordingly.
+    pub fn push_product(&mut self, x: f64, y: f64) {
+        let p = x * y;
+        if p == 0.0 && (x == 0.0 || y == 0.0) {
+            return;
+        }
+        self.push(p);
+        if p.is_finite() {
+            self.push(x.mul_add(y, -p));
+            if p.abs() < EXACT_ERROR_MIN {
+                self.add_err(TINY);
+            }
+        }
+    }
+
+    /// Adds `x * 2^-scale` to `self`. The scaling is exact unless it underflows, in which case the
+    /// error bound is increased accordingly.
+    #[inline]
+    pub fn push_scaled(&mut self, x: f64, scale: isize) {
+        let (x, err) = scale_down(x, scale);
+        self.add_err(err);
+        self.push(x);
+    }
+
+    /// Adds `x * y * 2^-scale` to `self`, scaling `x`. The result is exact unless the scaling or
+    /// the product underflows, in which case the error bound is increased accordingly.
+    pub fn push_product_scaled(&mut self, x: f64, y: f64, scale: isize) {
+        let (x, err) = scale_down(x, scale);
+        if err != 0.0 {
+            self.err = fp::Add::add_hi(
+                RoundedF64(self.err),
+                fp::Mul::mul_hi(RoundedF64(err), RoundedF64(y.abs())),
+            )
+            .0;
+        }
+        self.push_product(x, y);
+    }
+
+    /// Adds every term and the error bound of `rhs` to `self`.
+    pub fn push_expansion(&mut self, rhs: &Expansion) {
+        for &x in &rhs.terms[..rhs.len] {
+            self.push(x);
+        }
+        self.add_err(rhs.err);
+    }
+
+    /// Whether every term is finite, i.e. whether no intermediate result overflowed.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.terms[..self.len].iter().all(|x| x.is_finite())
+    }
+
+    /// Returns the `i`-th largest term, or zero if there is none.
+    #[inline]
+    pub fn term(&self, i: usize) -> f64 {
+        if i < self.len {
+            self.terms[self.len - 1 - i]
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the sum of the terms computed in floating point.
+    #[inline]
+    pub fn estimate(&self) -> f64 {
+        self.terms[..self.len].iter().fold(0.0, |s, x| s + x)
+    }
+
+    /// Returns an upper bound on the magnitude of the sum of the terms from the `i`-th largest
+    /// one, including the error bound.
+    ///
+    /// As the terms are nonoverlapping, the sum of the terms smaller than a given term is less
+    /// than that term in magnitude.
+    #[inline]
+    pub fn tail_bound(&self, i: usize) -> RoundedF64 {
+        fp::Add::add_hi(RoundedF64(self.term(i).abs() * 2.0), RoundedF64(self.err))
+    }
+
+    /// Returns the sign of the sum of `self`, or `None` if the error bound hides it.
+    #[inline]
+    pub fn sign(&self) -> Option<Ordering> {
+        let top = self.term(0);
+        if self.err == 0.0 || RoundedF64(top.abs()) > self.tail_bound(1) {
+            top.partial_cmp(&0.0)
+        } else {
+            None
+        }
+    }
+}
+
+impl Expansion {
+    /// Renormalizes `self` with the COMPRESS algorithm of Shewchuk, so that the largest term
+    /// approximates the sum to within an ulp and no term is much smaller than the next one.
+    pub fn compress(&mut self) {
+        let m = self.len;
+        if m < 2 {
+            return;
+        }
+        let mut g = [0.0; EXPANSION_LEN];
+        let mut bottom = m - 1;
+        let mut q = self.terms[m - 1];
+        for i in (0..m - 1).rev() {
+            let (s, e) = two_sum(q, self.terms[i]);
+            if e != 0.0 {
+                g[bottom] = s;
+                bottom -= 1;
+                q = e;
+            } else {
+                q = s;
+            }
+        }
+        g[bottom] = q;
+        let mut len = 0;
+        for &x in &g[bottom + 1..m] {
+            let (s, e) = two_sum(x, q);
+            if e != 0.0 {
+                self.terms[len] = e;
+                len += 1;
+            }
+            q = s;
+        }
+        self.terms[len] = q;
+        self.len = len + 1;
+    }
+
+    /// Keeps the `n` largest terms, and moves the others to the error bound.
+    pub fn truncate(&mut self, n: usize) {
+        if self.len > n {
+            let dropped = self.len - n;
+            self.add_err(2.0 * self.terms[dropped - 1].abs());
+            self.terms.copy_within(dropped..self.len, 0);
+            self.len = n;
+        }
+    }
+
+    /// Computes `self * 2^exp`. The scaling is exact unless it underflows, in which case the
+    /// error bound is increased accordingly.
+    pub fn ldexp(&self, exp: isize) -> Expansion {
+        let mut e = Expansion::new();
+        for &x in &self.terms[..self.len] {
+            e.push_scaled(x, -exp);
+        }
+        let (err, err_err) = scale_down(self.err, -exp);
+        e.add_err(err);
+        e.add_err(err_err);
+        e
+    }
+
+    /// Computes `self * rhs`, keeping at most `len` terms.
+    pub fn mul(&self, rhs: &Expansion, len: usize) -> Expansion {
+        let mut e = Expansion::new();
+        for &x in &self.terms[..self.len] {
+            for &y in &rhs.terms[..rhs.len] {
+                e.push_product(x, y);
+            }
+        }
+        // The error of the product is at most `|a| * err_b + |b| * err_a + err_a * err_b`.
+        e.add_err(fp::Mul::mul_hi(self.tail_bound(0), RoundedF64(rhs.err)).0);
+        e.add_err(fp::Mul::mul_hi(rhs.tail_bound(0), RoundedF64(self.err)).0);
+        e.compress();
+        e.truncate(len);
+        e
+    }
+
+    /// Computes `self / d` by long division, keeping at most `len` terms. `d` must be finite and
+    /// nonzero.
+    pub fn div_f64(&self, d: f64, len: usize) -> Expansion {
+        let mut q = Expansion::new();
+        let mut r = *self;
+        for _ in 0..len {
+            let x = r.estimate() / d;
+            if x == 0.0 || !x.is_finite() {
+                break;
+            }
+            q.push(x);
+            r.push_product(-x, d);
+            r.compress();
+        }
+        // The exact quotient is `q + r / d`.
+        q.add_err(fp::Div::div_hi(r.tail_bound(0), RoundedF64(d.abs())).0);
+        q.compress();
+        q
+    }
+
+    /// Computes `exp(self)` as `e * 2^k`, and returns `(e, k)`. The sum of `self` must be at
+    /// most about `10^4` in magnitude.
+    pub fn exp(&self) -> (Expansion, isize) {
+        // The argument is reduced to `r = self - k * ln(2)`, and `exp(r)` is computed as
+        // `exp(r / 2^n)^(2^n)`.
+        let k = (self.estimate() / LN_2[0]).round();
+        let mut r = *self;
+        for &c in &LN_2 {
+            r.push_product(-k, c);
+        }
+        r.add_err(fp::Mul::mul_hi(RoundedF64(k.abs()), RoundedF64(LN_2_ERR)).0);
+        r.compress();
+        r.truncate(PRECISE_LEN);
+        let r = r.ldexp(-EXP_SQUARINGS);
+        let r_bound = r.tail_bound(0);
+        let mut sum = Expansion::from(1.0);
+        let mut t = Expansion::from(1.0);
+        for n in 1..=SERIES_MAX_TERMS {
+            t = t.mul(&r, PRECISE_LEN).div_f64(n as f64, PRECISE_LEN);
+            sum.push_expansion(&t);
+            // The remaining terms decrease at least geometrically with ratio 1/2, so their sum
+            // is at most twice the next one.
+            let next = fp::Mul::mul_hi(t.tail_bound(0), r_bound);
+            let rest = fp::Div::div_hi(
+                fp::Mul::mul_hi(next, RoundedF64(2.0)),
+                RoundedF64(n as f64 + 1.0),
+            );
+            if rest.0 <= SERIES_EPSILON || n == SERIES_MAX_TERMS {
+                sum.add_err(rest.0);
+                break;
+            }
+        }
+        sum.compress();
+        sum.truncate(PRECISE_LEN);
+        for _ in 0..EXP_SQUARINGS {
+            sum = sum.mul(&sum, PRECISE_LEN);
+        }
+        (sum, k as isize)
+    }
+
+    /// Computes `log(self * 2^exp)`. The sum of `self` must be between `sqrt(1/2)` and `sqrt(2)`
+    /// approximately.
+    pub fn log(&self, exp: isize) -> Expansion {
+        // With `y` close to `log(self)`, `log(self) = y + log(1 + w)` where
+        // `w = self * exp(-y) - 1` is small. The approximation of the system math library is only
+        // trusted as a starting point, and refined if needed.
+        let mut y = self.estimate().ln();
+        let mut w = Expansion::new();
+        for _ in 0..3 {
+            let (p, k) = Expansion::from(-y).exp();
+            w = self.mul(&p, PRECISE_LEN).ldexp(k);
+            w.push(-1.0);
+            w.compress();
+            if w.tail_bound(0).0 <= LOG_SERIES_MAX {
+                break;
+            }
+            y += w.estimate();
+        }
+        let w_bound = w.tail_bound(0);
+        let mut sum = Expansion::new();
+        if w_bound.0 > LOG_SERIES_MAX {
+            sum.err = f64::INFINITY;
+            return sum;
+        }
+        let epsilon = fp::Mul::mul_lo(w_bound, RoundedF64(SERIES_EPSILON)).0;
+        let mut power = w;
+        for n in 1..=SERIES_MAX_TERMS {
+            let d = if n % 2 == 1 { n as f64 } else { -(n as f64) };
+            sum.push_expansion(&power.div_f64(d, PRECISE_LEN));
+            power = power.mul(&w, PRECISE_LEN);
+            // The remaining terms are at most `|w|^(n + 1) / ((n + 1) * (1 - |w|))` in magnitude.
+            let next = fp::Mul::mul_hi(power.tail_bound(0), RoundedF64(2.0));
+            let rest = fp::Div::div_hi(next, RoundedF64(n as f64 + 1.0)).0;
+            if rest <= epsilon || rest <= f64::MIN_POSITIVE || n == SERIES_MAX_TERMS {
+                sum.add_err(rest);
+                break;
+            }
+        }
+        sum.push(y);
+        let exp = exp as f64;
+        for &c in &LN_2 {
+            sum.push_product(exp, c);
+        }
+        sum.add_err(fp::Mul::mul_hi(RoundedF64(exp.abs()), RoundedF64(LN_2_ERR)).0);
+        sum.compress();
+        sum
+    }
+}
+
+impl From<f64> for Expansion {
+    #[inline]
+    fn from(x: f64) -> Self {
+        let mut e = Expansion::new();
+        e.push(x);
+        e
+    }
+}
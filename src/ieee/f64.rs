@@ -1,7 +1,12 @@
+use super::rounded_f64::{dot_rounded, sum_rounded};
+
 use fp;
-use fp::{Float, Sign};
+use fp::{Float, Round, Sign};
 use transc::Transc;
 
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
 impl Transc for f64 {
     type Output = Self;
 
@@ -66,10 +71,255 @@ impl fp::Abs for f64 {
     }
 }
 
-impl fp::Add for f64 {}
-impl fp::Sub for f64 {}
-impl fp::Mul for f64 {}
-impl fp::Div for f64 {}
+impl fp::Integral for f64 {
+    #[inline]
+    fn floor(self) -> Self {
+        self.floor()
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        self.ceil()
+    }
+
+    #[inline]
+    fn round_ties_even(self) -> Self {
+        self.round_ties_even()
+    }
+
+    #[inline]
+    fn round_ties_away(self) -> Self {
+        self.round()
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        self.trunc()
+    }
+
+    #[inline]
+    fn frac(self) -> Self {
+        self.fract()
+    }
+}
+
+impl fp::Hypot for f64 {
+    #[inline]
+    fn hypot_r(self, rhs: Self, _: Round) -> Self {
+        self.hypot(rhs)
+    }
+}
+
+/// Computes the IEEE remainder of `x` and `y`. Adapted from fdlibm's `__ieee754_remainder`.
+fn ieee_remainder(x: f64, y: f64) -> f64 {
+    if x.is_nan() || y.is_nan() || x.is_infinite() || y == 0.0 {
+        return f64::NAN;
+    }
+    let p = y.abs();
+    let mut r = if p <= f64::MAX / 2.0 {
+        // Now `abs(r) < 2 * p`.
+        (x % (p + p)).abs()
+    } else {
+        x.abs()
+    };
+    if p < 2.0 * f64::MIN_POSITIVE {
+        if r + r > p {
+            r -= p;
+            if r + r >= p {
+                r -= p;
+            }
+        }
+    } else {
+        let p_half = 0.5 * p;
+        if r > p_half {
+            r -= p;
+            if r >= p_half {
+                r -= p;
+            }
+        }
+    }
+    if x.is_sign_negative() {
+        -r
+    } else {
+        r
+    }
+}
+
+impl fp::Rem for f64 {
+    #[inline]
+    fn fmod_lo(self, rhs: Self) -> Self {
+        self % rhs
+    }
+
+    #[inline]
+    fn fmod_hi(self, rhs: Self) -> Self {
+        self % rhs
+    }
+
+    #[inline]
+    fn remainder_lo(self, rhs: Self) -> Self {
+        ieee_remainder(self, rhs)
+    }
+
+    #[inline]
+    fn remainder_hi(self, rhs: Self) -> Self {
+        ieee_remainder(self, rhs)
+    }
+}
+
+/// Whether `s == a + b` holds exactly. Uses the TwoSum error-free transformation.
+#[inline]
+fn is_sum_exact(a: f64, b: f64, s: f64) -> bool {
+    if s.is_nan() {
+        // `inf + (-inf)` has no exact value.
+        return a.is_nan() || b.is_nan();
+    }
+    if s.is_infinite() {
+        return !(a.is_finite() && b.is_finite());
+    }
+    let bb = s - a;
+    (a - (s - bb)) + (b - bb) == 0.0
+}
+
+/// Whether `p == a * b` holds exactly. Uses a fused multiply-add to compute the error term.
+#[inline]
+fn is_product_exact(a: f64, b: f64, p: f64) -> bool {
+    if p.is_nan() {
+        // `0 * inf` has no exact value.
+        return a.is_nan() || b.is_nan();
+    }
+    if p.is_infinite() {
+        return !(a.is_finite() && b.is_finite());
+    }
+    if a == 0.0 || b == 0.0 {
+        return true;
+    }
+    // The error term is not representable when the product underflows.
+    p.abs() >= f64::MIN_POSITIVE && a.mul_add(b, -p) == 0.0
+}
+
+/// Whether `q == a / b` holds exactly. Uses a fused multiply-add to compute the remainder.
+#[inline]
+fn is_quotient_exact(a: f64, b: f64, q: f64) -> bool {
+    if q.is_nan() {
+        // `0 / 0` and `inf / inf` have no exact value.
+        return a.is_nan() || b.is_nan();
+    }
+    if q.is_infinite() {
+        return !(a.is_finite() && b.is_finite()) || b == 0.0;
+    }
+    if a == 0.0 || b.is_infinite() {
+        return true;
+    }
+    // The remainder is not representable when the quotient underflows.
+    q.abs() >= f64::MIN_POSITIVE && q.mul_add(b, -a) == 0.0
+}
+
+impl fp::Add for f64 {
+    #[inline]
+    fn add_r(self, rhs: Self, _: Round) -> Self {
+        self + rhs
+    }
+
+    #[inline]
+    fn add_r_exact(self, rhs: Self, _: Round) -> (Self, bool) {
+        let z = self + rhs;
+        (z, is_sum_exact(self, rhs, z))
+    }
+}
+
+impl fp::Sub for f64 {
+    #[inline]
+    fn sub_r(self, rhs: Self, _: Round) -> Self {
+        self - rhs
+    }
+
+    #[inline]
+    fn sub_r_exact(self, rhs: Self, _: Round) -> (Self, bool) {
+        let z = self - rhs;
+        (z, is_sum_exact(self, -rhs, z))
+    }
+}
+
+impl fp::Mul for f64 {
+    #[inline]
+    fn mul_r(self, rhs: Self, _: Round) -> Self {
+        self * rhs
+    }
+
+    #[inline]
+    fn mul_r_exact(self, rhs: Self, _: Round) -> (Self, bool) {
+        let z = self * rhs;
+        (z, is_product_exact(self, rhs, z))
+    }
+}
+
+impl fp::Div for f64 {
+    #[inline]
+    fn div_r(self, rhs: Self, _: Round) -> Self {
+        self / rhs
+    }
+
+    #[inline]
+    fn div_r_exact(self, rhs: Self, _: Round) -> (Self, bool) {
+        let z = self / rhs;
+        (z, is_quotient_exact(self, rhs, z))
+    }
+}
+
+impl fp::Fma for f64 {
+    #[inline]
+    fn fma_r(self, a: Self, b: Self, _: Round) -> Self {
+        self.mul_add(a, b)
+    }
+
+    /// The result is only known to be exact when the product is exact.
+    #[inline]
+    fn fma_r_exact(self, a: Self, b: Self, _: Round) -> (Self, bool) {
+        let z = self.mul_add(a, b);
+        let p = self * a;
+        (z, is_product_exact(self, a, p) && is_sum_exact(p, b, z))
+    }
+}
+
+/// Sums and dot products are computed to the nearest from error-free expansions of the exact
+/// results, like those of `RoundedF64`, so that their signs are exact.
+impl fp::Sum for f64 {
+    fn sum_r(xs: &[Self], _: usize, _: Round) -> Self {
+        sum_rounded(xs.iter().cloned(), Round::Nearest)
+    }
+
+    fn dot_r(xs: &[Self], ys: &[Self], _: usize, _: Round) -> Self {
+        assert_eq!(xs.len(), ys.len());
+        dot_rounded(xs.iter().cloned().zip(ys.iter().cloned()), Round::Nearest)
+    }
+}
+
+impl fp::TotalOrd for f64 {
+    #[inline]
+    fn cmp_total(&self, rhs: &f64) -> Ordering {
+        match (self.is_nan(), rhs.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => self.partial_cmp(rhs).unwrap(),
+        }
+    }
+
+    /// Hashes the bits of `self`, with all NaNs and both zeros made canonical.
+    #[inline]
+    fn hash_total<H: Hasher>(&self, state: &mut H) {
+        let x = if self.is_nan() {
+            f64::NAN
+        } else if *self == 0.0 {
+            0.0
+        } else {
+            *self
+        };
+        x.to_bits().hash(state);
+    }
+}
+
 impl fp::Transc for f64 {}
 
 impl Float for f64 {
@@ -152,9 +402,69 @@ impl Float for f64 {
     fn has_odd_denominator(&self) -> bool {
         self.floor() == *self
     }
+
+    #[inline]
+    fn next_up(self) -> Self {
+        if self.is_nan() || self == f64::INFINITY {
+            self
+        } else if self == 0.0 {
+            f64::from_bits(1)
+        } else if self > 0.0 {
+            f64::from_bits(self.to_bits() + 1)
+        } else {
+            f64::from_bits(self.to_bits() - 1)
+        }
+    }
+
+    #[inline]
+    fn next_down(self) -> Self {
+        -(-self).next_up()
+    }
+
+    fn frexp(self) -> (Self, isize) {
+        if self == 0.0 || !f64::is_finite(self) {
+            return (self, 0);
+        }
+        let bits = self.to_bits();
+        let biased_exp = ((bits >> 52) & 0x7ff) as isize;
+        if biased_exp == 0 {
+            // Subnormal numbers are normalized first.
+            let (m, e) = (self * TWO_POW_54).frexp();
+            return (m, e - 54);
+        }
+        let m = f64::from_bits((bits & !(0x7ff << 52)) | (1022 << 52));
+        (m, biased_exp - 1022)
+    }
+
+    fn ldexp(self, exp: isize) -> Self {
+        // Scaling is split into steps so that intermediate results neither overflow nor get
+        // rounded twice.
+        let mut x = self;
+        let mut e = exp;
+        if e > 1023 {
+            x *= f64::from_bits(0x7fe << 52);
+            e -= 1023;
+            if e > 1023 {
+                x *= f64::from_bits(0x7fe << 52);
+                e = (e - 1023).min(1023);
+            }
+        } else if e < -1022 {
+            x *= f64::from_bits(1 << 52) * TWO_POW_53;
+            e += 1022 - 53;
+            if e < -1022 {
+                x *= f64::from_bits(1 << 52) * TWO_POW_53;
+                e = (e + 1022 - 53).max(-1022);
+            }
+        }
+        x * f64::from_bits(((e + 1023) as u64) << 52)
+    }
 }
 
+const TWO_POW_53: f64 = 9007199254740992.0;
+const TWO_POW_54: f64 = 18014398509481984.0;
+
 #[cfg(test)]
+#[allow(clippy::approx_constant, clippy::excessive_precision)]
 mod tests {
     const PREC: usize = 53;
 
@@ -168,6 +478,40 @@ mod tests {
         assert!(f64::from_lo(f64::NAN, PREC).is_nan());
     }
 
+    #[test]
+    fn test_fma_sum() {
+        use fp::{Fma, Round, Sum};
+        let tiny = 2.0f64.powi(-54);
+        assert_eq!((1.0, true), 0.5.fma_r_exact(4.0, -1.0, Round::Nearest));
+        assert_eq!(tiny, 0.1.fma_r(10.0, -1.0, Round::Nearest));
+        assert!(!0.1.fma_r_exact(0.1, 1.0, Round::Nearest).1);
+        let xs = [1e100, 1.0, -1e100];
+        assert_eq!(1.0, f64::sum_r(&xs, PREC, Round::Nearest));
+        let (a, b) = (2.0f64.powi(-60), 2.0f64.powi(-120));
+        let xs = [1.0, a, b, -1.0, -a];
+        assert_eq!(b, f64::sum_r(&xs, PREC, Round::Nearest));
+        assert_eq!(
+            tiny,
+            f64::dot_r(&[0.1, -1.0], &[10.0, 1.0], PREC, Round::Nearest)
+        );
+        assert_eq!(
+            f64::INFINITY,
+            f64::sum_r(&[1.0, f64::INFINITY], PREC, Round::Nearest)
+        );
+        assert!(f64::dot_r(&[0.0], &[f64::INFINITY], PREC, Round::Nearest).is_nan());
+    }
+
+    #[test]
+    fn test_total_ord() {
+        use fp::Total;
+        use std::collections::HashSet;
+        let xs = [0.0, -0.0, f64::NAN, -f64::NAN, 1.0, f64::INFINITY];
+        let set = xs.iter().map(|&x| Total(x)).collect::<HashSet<_>>();
+        assert_eq!(4, set.len());
+        assert!(Total(f64::INFINITY) < Total(f64::NAN));
+        assert!(Total(-0.0) == Total(0.0));
+    }
+
     #[test]
     fn test_from_hi() {
         use fp::From;
@@ -250,6 +594,38 @@ mod tests {
         assert_eq!(1.0, (-1.0).abs());
     }
 
+    #[test]
+    fn test_integral() {
+        use fp::Integral;
+        assert_eq!(1.0, Integral::floor(1.5));
+        assert_eq!(-1.0, Integral::ceil(-1.5));
+        assert_eq!(2.0, Integral::round_ties_even(2.5));
+        assert_eq!(3.0, Integral::round_ties_away(2.5));
+        assert_eq!(-2.0, Integral::trunc(-2.5));
+        assert_eq!(-0.5, Integral::frac(-2.5));
+        assert!(f64::is_nan(Integral::frac(f64::INFINITY)));
+    }
+
+    #[test]
+    fn test_rem() {
+        use fp::Rem;
+        assert_eq!(1.0, 3.0.fmod_lo(2.0));
+        assert_eq!(-1.0, (-3.0).fmod_hi(2.0));
+        assert_eq!(-1.0, 3.0.remainder_lo(2.0));
+        assert_eq!(1.0, 5.0.remainder_hi(2.0));
+        assert_eq!(0.5, 4.5.remainder_lo(2.0));
+        assert_eq!(-1.0, 7.0.remainder_hi(4.0));
+        assert!(f64::is_nan(f64::INFINITY.remainder_lo(2.0)));
+        assert!(f64::is_nan(1.0.remainder_hi(0.0)));
+    }
+
+    #[test]
+    fn test_hypot() {
+        use fp::Hypot;
+        assert_eq!(5.0, 3.0.hypot_lo(-4.0));
+        assert_eq!(1e300, 6e299.hypot_hi(8e299));
+    }
+
     #[test]
     fn test_add_lo() {
         use fp::Add;
@@ -306,6 +682,46 @@ mod tests {
         assert_eq!(-1.2222222222222223, (-1.1).div_hi(0.9));
     }
 
+    #[test]
+    fn test_add_exact() {
+        use fp::Add;
+        assert_eq!((1.5, true), 0.5.add_lo_exact(1.0));
+        assert_eq!((1.1, false), 0.1.add_hi_exact(1.0));
+        assert_eq!((f64::INFINITY, true), f64::INFINITY.add_lo_exact(1.0));
+        assert_eq!((f64::INFINITY, false), f64::MAX.add_hi_exact(f64::MAX));
+        assert!(!f64::INFINITY.add_lo_exact(f64::NEG_INFINITY).1);
+        assert!(f64::NAN.add_lo_exact(1.0).1);
+    }
+
+    #[test]
+    fn test_sub_exact() {
+        use fp::Sub;
+        assert_eq!((0.5, true), 1.5.sub_lo_exact(1.0));
+        assert_eq!((-0.9, false), 0.1.sub_hi_exact(1.0));
+        assert!(!f64::INFINITY.sub_hi_exact(f64::INFINITY).1);
+    }
+
+    #[test]
+    fn test_mul_exact() {
+        use fp::Mul;
+        assert_eq!((3.0, true), 1.5.mul_lo_exact(2.0));
+        assert_eq!((0.9900000000000001, false), 1.1.mul_hi_exact(0.9));
+        assert_eq!((0.0, false), 1e-200.mul_lo_exact(1e-200));
+        assert_eq!((0.0, true), 0.0.mul_lo_exact(1e-200));
+        assert!(!0.0.mul_lo_exact(f64::INFINITY).1);
+    }
+
+    #[test]
+    fn test_div_exact() {
+        use fp::Div;
+        assert_eq!((0.75, true), 1.5.div_lo_exact(2.0));
+        assert_eq!((1.2222222222222223, false), 1.1.div_hi_exact(0.9));
+        assert_eq!((f64::INFINITY, true), 1.0.div_lo_exact(0.0));
+        assert_eq!((0.0, false), 1e-200.div_lo_exact(1e200));
+        assert!(!0.0.div_lo_exact(0.0).1);
+        assert!(!f64::INFINITY.div_hi_exact(f64::INFINITY).1);
+    }
+
     #[test]
     fn test_log_lo() {
         use fp::Transc;
@@ -354,6 +770,63 @@ mod tests {
         assert_eq!(1.4142135623730951, 2.0.pow_hi(0.5));
     }
 
+    #[test]
+    fn test_next_up() {
+        use fp::Float;
+        assert_eq!(1.0000000000000002, 1.0.next_up());
+        assert_eq!(-0.9999999999999999, (-1.0).next_up());
+        assert_eq!(5e-324, 0.0.next_up());
+        assert_eq!(5e-324, (-0.0).next_up());
+        assert_eq!(-f64::MAX, f64::NEG_INFINITY.next_up());
+        assert_eq!(f64::INFINITY, f64::MAX.next_up());
+        assert_eq!(f64::INFINITY, f64::INFINITY.next_up());
+        assert!(f64::is_nan(f64::NAN.next_up()));
+    }
+
+    #[test]
+    fn test_next_down() {
+        use fp::Float;
+        assert_eq!(0.9999999999999999, 1.0.next_down());
+        assert_eq!(-1.0000000000000002, (-1.0).next_down());
+        assert_eq!(-5e-324, 0.0.next_down());
+        assert_eq!(f64::NEG_INFINITY, f64::NEG_INFINITY.next_down());
+        assert!(f64::is_nan(f64::NAN.next_down()));
+    }
+
+    #[test]
+    fn test_ulp() {
+        use fp::Float;
+        assert_eq!(2.220446049250313e-16, 1.0.ulp());
+        assert_eq!(4.440892098500626e-16, (-2.0).ulp());
+        assert_eq!(5e-324, 0.0.ulp());
+        assert_eq!(1.99584030953472e292, f64::MAX.ulp());
+        assert_eq!(f64::INFINITY, f64::INFINITY.ulp());
+        assert!(f64::is_nan(f64::NAN.ulp()));
+    }
+
+    #[test]
+    fn test_frexp() {
+        use fp::Float;
+        assert_eq!((0.75, 2), 3.0.frexp());
+        assert_eq!((-0.5, -1), (-0.25).frexp());
+        assert_eq!((0.5, -1073), 5e-324.frexp());
+        assert_eq!((0.0, 0), 0.0.frexp());
+        assert_eq!((f64::INFINITY, 0), f64::INFINITY.frexp());
+        assert_eq!(0.5, 1.0.mantissa());
+        assert_eq!(1, 1.0.exponent());
+    }
+
+    #[test]
+    fn test_ldexp() {
+        use fp::Float;
+        assert_eq!(3.0, 0.75.ldexp(2));
+        assert_eq!(5e-324, 0.5.ldexp(-1073));
+        assert_eq!(f64::MAX, 0.9999999999999999.ldexp(1024));
+        assert_eq!(f64::INFINITY, 1.0.ldexp(1024));
+        assert_eq!(1.0, 5e-324.ldexp(1074));
+        assert_eq!(0.0, 1.0.ldexp(-2000));
+    }
+
     #[test]
     fn test_constants() {
         use fp::Float;
@@ -1 +1,17 @@
+mod double_double;
+mod expansion;
 mod f64;
+mod rounded_f32;
+mod rounded_f64;
+
+pub use self::double_double::DoubleDouble;
+pub use self::rounded_f32::RoundedF32;
+pub use self::rounded_f64::RoundedF64;
+
+/// Computes `a + b` and its rounding error using the TwoSum error-free transformation.
+#[inline]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
@@ -0,0 +1,631 @@
+use super::rounded_f64::{dot_rounded, sum_rounded};
+use super::RoundedF64;
+
+use fp;
+use fp::{Float, Round, Sign};
+use transc::Transc;
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::hash::Hasher;
+use std::num::ParseFloatError;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::str::FromStr;
+
+/// A correctly rounding wrapper around `f32`.
+///
+/// Every operation is computed with `RoundedF64` in the requested direction and the result is then
+/// rounded to `f32` in the same direction. Since every `f32` is exactly representable as `f64`,
+/// the bounds are as tight as those of `RoundedF64`, at half the storage.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct RoundedF32(pub f32);
+
+/// Rounds down `x` to `f32` and tells whether the result is exact, given whether `x` itself is
+/// exact.
+#[inline]
+fn round_lo(x: RoundedF64, exact: bool) -> (RoundedF32, bool) {
+    let y = x.0 as f32;
+    if (y as f64) > x.0 {
+        (RoundedF32(next_down(y)), false)
+    } else {
+        (RoundedF32(y), exact && y as f64 == x.0)
+    }
+}
+
+/// Rounds up `x` to `f32` and tells whether the result is exact, given whether `x` itself is
+/// exact.
+#[inline]
+fn round_hi(x: RoundedF64, exact: bool) -> (RoundedF32, bool) {
+    let y = x.0 as f32;
+    if (y as f64) < x.0 {
+        (RoundedF32(next_up(y)), false)
+    } else {
+        (RoundedF32(y), exact && y as f64 == x.0)
+    }
+}
+
+/// Rounds `x` to `f32` down, up or to the nearest and tells whether the result is exact, given
+/// whether `x` itself is exact.
+#[inline]
+fn round_r(x: RoundedF64, exact: bool, round: Round) -> (RoundedF32, bool) {
+    match round {
+        Round::Down => round_lo(x, exact),
+        Round::Up => round_hi(x, exact),
+        _ => {
+            let y = x.0 as f32;
+            (RoundedF32(y), exact && y as f64 == x.0)
+        }
+    }
+}
+
+#[inline]
+fn next_up(x: f32) -> f32 {
+    if x.is_nan() || x == f32::INFINITY {
+        x
+    } else if x == 0.0 {
+        f32::from_bits(1)
+    } else if x > 0.0 {
+        f32::from_bits(x.to_bits() + 1)
+    } else {
+        f32::from_bits(x.to_bits() - 1)
+    }
+}
+
+#[inline]
+fn next_down(x: f32) -> f32 {
+    -next_up(-x)
+}
+
+impl RoundedF32 {
+    /// Converts `self` to `RoundedF64`, which is always exact.
+    #[inline]
+    fn widen(self) -> RoundedF64 {
+        RoundedF64(f64::from(self.0))
+    }
+
+    /// Computes `op(round)` with `RoundedF64` and rounds the result to `f32` in the same
+    /// direction. Since the precision of `f64` is more than twice that of `f32`, rounding twice to
+    /// the nearest is the same as rounding once for elementary arithmetic.
+    #[inline]
+    fn with_widened<F>(round: Round, mut op: F) -> (Self, bool)
+    where
+        F: FnMut(Round) -> (RoundedF64, bool),
+    {
+        round.resolve(|r| {
+            let (x, exact) = op(r);
+            round_r(x, exact, r)
+        })
+    }
+}
+
+impl Display for RoundedF32 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl FromStr for RoundedF32 {
+    type Err = ParseFloatError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim().parse::<f32>().map(RoundedF32)
+    }
+}
+
+impl From<f64> for RoundedF32 {
+    #[inline]
+    fn from(val: f64) -> Self {
+        RoundedF32(val as f32)
+    }
+}
+
+impl From<RoundedF32> for f64 {
+    #[inline]
+    fn from(val: RoundedF32) -> Self {
+        f64::from(val.0)
+    }
+}
+
+impl Neg for RoundedF32 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        RoundedF32(-self.0)
+    }
+}
+
+impl Add for RoundedF32 {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        RoundedF32(self.0 + rhs.0)
+    }
+}
+
+impl Sub for RoundedF32 {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        RoundedF32(self.0 - rhs.0)
+    }
+}
+
+impl Mul for RoundedF32 {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        RoundedF32(self.0 * rhs.0)
+    }
+}
+
+impl Div for RoundedF32 {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        RoundedF32(self.0 / rhs.0)
+    }
+}
+
+impl Transc for RoundedF32 {
+    type Output = Self;
+
+    #[inline]
+    fn log(self) -> Self::Output {
+        RoundedF32(self.0.ln())
+    }
+
+    #[inline]
+    fn exp(self) -> Self::Output {
+        RoundedF32(self.0.exp())
+    }
+
+    #[inline]
+    fn pow(self, rhs: Self) -> Self::Output {
+        RoundedF32(self.0.powf(rhs.0))
+    }
+}
+
+impl fp::From<f64> for RoundedF32 {
+    #[inline]
+    fn from_lo(val: f64, _: usize) -> Self {
+        round_lo(RoundedF64(val), true).0
+    }
+
+    #[inline]
+    fn from_hi(val: f64, _: usize) -> Self {
+        round_hi(RoundedF64(val), true).0
+    }
+}
+
+impl fp::FromStr for RoundedF32 {
+    fn from_str_lo(s: &str, precision: usize) -> Result<Self, Self::Err> {
+        let x = RoundedF64::from_str_lo(s, precision)?;
+        Ok(round_lo(x, true).0)
+    }
+
+    fn from_str_hi(s: &str, precision: usize) -> Result<Self, Self::Err> {
+        let x = RoundedF64::from_str_hi(s, precision)?;
+        Ok(round_hi(x, true).0)
+    }
+}
+
+impl fp::Into<f64> for RoundedF32 {
+    #[inline]
+    fn into_lo(self) -> f64 {
+        f64::from(self.0)
+    }
+
+    #[inline]
+    fn into_hi(self) -> f64 {
+        f64::from(self.0)
+    }
+}
+
+impl fp::MinMax for RoundedF32 {
+    #[inline]
+    fn min(self, rhs: Self) -> Self {
+        RoundedF32(self.0.min(rhs.0))
+    }
+
+    #[inline]
+    fn max(self, rhs: Self) -> Self {
+        RoundedF32(self.0.max(rhs.0))
+    }
+}
+
+impl fp::Abs for RoundedF32 {
+    #[inline]
+    fn abs(self) -> Self {
+        RoundedF32(self.0.abs())
+    }
+}
+
+impl fp::Integral for RoundedF32 {
+    #[inline]
+    fn floor(self) -> Self {
+        RoundedF32(self.0.floor())
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        RoundedF32(self.0.ceil())
+    }
+
+    #[inline]
+    fn round_ties_even(self) -> Self {
+        RoundedF32(self.0.round_ties_even())
+    }
+
+    #[inline]
+    fn round_ties_away(self) -> Self {
+        RoundedF32(self.0.round())
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        RoundedF32(self.0.trunc())
+    }
+
+    #[inline]
+    fn frac(self) -> Self {
+        RoundedF32(self.0.fract())
+    }
+}
+
+impl fp::Rem for RoundedF32 {
+    #[inline]
+    fn fmod_lo(self, rhs: Self) -> Self {
+        round_lo(self.widen().fmod_lo(rhs.widen()), true).0
+    }
+
+    #[inline]
+    fn fmod_hi(self, rhs: Self) -> Self {
+        round_hi(self.widen().fmod_hi(rhs.widen()), true).0
+    }
+
+    #[inline]
+    fn remainder_lo(self, rhs: Self) -> Self {
+        round_lo(self.widen().remainder_lo(rhs.widen()), true).0
+    }
+
+    #[inline]
+    fn remainder_hi(self, rhs: Self) -> Self {
+        round_hi(self.widen().remainder_hi(rhs.widen()), true).0
+    }
+}
+
+impl fp::Hypot for RoundedF32 {
+    #[inline]
+    fn hypot_r(self, rhs: Self, round: Round) -> Self {
+        Self::with_widened(round, |r| (self.widen().hypot_r(rhs.widen(), r), true)).0
+    }
+}
+
+impl fp::Add for RoundedF32 {
+    #[inline]
+    fn add_r(self, rhs: Self, round: Round) -> Self {
+        self.add_r_exact(rhs, round).0
+    }
+
+    #[inline]
+    fn add_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        Self::with_widened(round, |r| self.widen().add_r_exact(rhs.widen(), r))
+    }
+}
+
+impl fp::Sub for RoundedF32 {
+    #[inline]
+    fn sub_r(self, rhs: Self, round: Round) -> Self {
+        self.sub_r_exact(rhs, round).0
+    }
+
+    #[inline]
+    fn sub_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        Self::with_widened(round, |r| self.widen().sub_r_exact(rhs.widen(), r))
+    }
+}
+
+impl fp::Mul for RoundedF32 {
+    #[inline]
+    fn mul_r(self, rhs: Self, round: Round) -> Self {
+        self.mul_r_exact(rhs, round).0
+    }
+
+    #[inline]
+    fn mul_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        Self::with_widened(round, |r| self.widen().mul_r_exact(rhs.widen(), r))
+    }
+}
+
+impl fp::Div for RoundedF32 {
+    #[inline]
+    fn div_r(self, rhs: Self, round: Round) -> Self {
+        self.div_r_exact(rhs, round).0
+    }
+
+    #[inline]
+    fn div_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        Self::with_widened(round, |r| self.widen().div_r_exact(rhs.widen(), r))
+    }
+}
+
+impl fp::Fma for RoundedF32 {
+    /// Rounding to the nearest may be off by one ulp due to double rounding, as the product is
+    /// not added exactly in `f64`.
+    #[inline]
+    fn fma_r_exact(self, a: Self, b: Self, round: Round) -> (Self, bool) {
+        Self::with_widened(round, |r| {
+            fp::Fma::fma_r_exact(self.widen(), a.widen(), b.widen(), r)
+        })
+    }
+}
+
+impl fp::Sum for RoundedF32 {
+    fn sum_r(xs: &[Self], _: usize, round: Round) -> Self {
+        let xs = xs.iter().map(|x| f64::from(x.0));
+        Self::with_widened(round, |r| (RoundedF64(sum_rounded(xs.clone(), r)), false)).0
+    }
+
+    fn dot_r(xs: &[Self], ys: &[Self], _: usize, round: Round) -> Self {
+        let xys = xs
+            .iter()
+            .zip(ys)
+            .map(|(x, y)| (f64::from(x.0), f64::from(y.0)));
+        Self::with_widened(round, |r| (RoundedF64(dot_rounded(xys.clone(), r)), false)).0
+    }
+}
+
+impl fp::TotalOrd for RoundedF32 {
+    #[inline]
+    fn cmp_total(&self, rhs: &RoundedF32) -> Ordering {
+        (self.0 as f64).cmp_total(&(rhs.0 as f64))
+    }
+
+    #[inline]
+    fn hash_total<H: Hasher>(&self, state: &mut H) {
+        (self.0 as f64).hash_total(state)
+    }
+}
+
+impl fp::Transc for RoundedF32 {
+    #[inline]
+    fn log_r(self, round: Round) -> Self {
+        self.log_r_exact(round).0
+    }
+
+    #[inline]
+    fn exp_r(self, round: Round) -> Self {
+        self.exp_r_exact(round).0
+    }
+
+    #[inline]
+    fn pow_r(self, rhs: Self, round: Round) -> Self {
+        self.pow_r_exact(rhs, round).0
+    }
+
+    #[inline]
+    fn log_r_exact(self, round: Round) -> (Self, bool) {
+        Self::with_widened(round, |r| self.widen().log_r_exact(r))
+    }
+
+    #[inline]
+    fn exp_r_exact(self, round: Round) -> (Self, bool) {
+        Self::with_widened(round, |r| self.widen().exp_r_exact(r))
+    }
+
+    #[inline]
+    fn pow_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        Self::with_widened(round, |r| self.widen().pow_r_exact(rhs.widen(), r))
+    }
+}
+
+impl Float for RoundedF32 {
+    #[inline]
+    fn zero(_precision: usize) -> Self {
+        RoundedF32(0.0)
+    }
+
+    #[inline]
+    fn neg_zero(_precision: usize) -> Self {
+        RoundedF32(-0.0)
+    }
+
+    #[inline]
+    fn one(_precision: usize) -> Self {
+        RoundedF32(1.0)
+    }
+
+    #[inline]
+    fn infinity(_precision: usize) -> Self {
+        RoundedF32(f32::INFINITY)
+    }
+
+    #[inline]
+    fn neg_infinity(_precision: usize) -> Self {
+        RoundedF32(f32::NEG_INFINITY)
+    }
+
+    #[inline]
+    fn nan(_precision: usize) -> Self {
+        RoundedF32(f32::NAN)
+    }
+
+    #[inline]
+    fn sign(&self) -> Sign {
+        self.widen().sign()
+    }
+
+    #[inline]
+    fn precision(&self) -> usize {
+        24
+    }
+
+    #[inline]
+    fn is_finite(&self) -> bool {
+        self.0.is_finite()
+    }
+
+    #[inline]
+    fn is_infinite(&self) -> bool {
+        self.0.is_infinite()
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0 == 0.0
+    }
+
+    #[inline]
+    fn is_infinity(&self) -> bool {
+        self.0 == f32::INFINITY
+    }
+
+    #[inline]
+    fn is_neg_infinity(&self) -> bool {
+        self.0 == f32::NEG_INFINITY
+    }
+
+    #[inline]
+    fn is_nan(&self) -> bool {
+        self.0.is_nan()
+    }
+
+    #[inline]
+    fn has_odd_denominator(&self) -> bool {
+        self.widen().has_odd_denominator()
+    }
+
+    #[inline]
+    fn next_up(self) -> Self {
+        RoundedF32(next_up(self.0))
+    }
+
+    #[inline]
+    fn next_down(self) -> Self {
+        RoundedF32(next_down(self.0))
+    }
+
+    #[inline]
+    fn frexp(self) -> (Self, isize) {
+        let (m, e) = self.widen().frexp();
+        (RoundedF32(m.0 as f32), e)
+    }
+
+    #[inline]
+    fn ldexp(self, exp: isize) -> Self {
+        RoundedF32(self.widen().ldexp(exp).0 as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RoundedF32 as R;
+    use fp::Float;
+
+    const PREC: usize = 24;
+
+    #[test]
+    fn test_from_lo() {
+        use fp::From;
+        assert_eq!(R(0.099999994), R::from_lo(0.1, PREC));
+        assert_eq!(R(-0.1), R::from_lo(-0.1, PREC));
+        assert_eq!(R(0.5), R::from_lo(0.5, PREC));
+        assert_eq!(R(f32::MAX), R::from_lo(1e300, PREC));
+        assert_eq!(R(0.0), R::from_lo(1e-300, PREC));
+    }
+
+    #[test]
+    fn test_from_hi() {
+        use fp::From;
+        assert_eq!(R(0.1), R::from_hi(0.1, PREC));
+        assert_eq!(R(-0.099999994), R::from_hi(-0.1, PREC));
+        assert_eq!(R(f32::INFINITY), R::from_hi(1e300, PREC));
+        assert_eq!(R(f32::from_bits(1)), R::from_hi(1e-300, PREC));
+    }
+
+    #[test]
+    fn test_from_str() {
+        use fp::FromStr;
+        assert_eq!(R(0.099999994), R::from_str_lo("0.1", PREC).unwrap());
+        assert_eq!(R(0.1), R::from_str_hi("0.1", PREC).unwrap());
+        assert_eq!(R(0.25), R::from_str_lo("0.25", PREC).unwrap());
+        assert_eq!(R(0.25), R::from_str_hi("0.25", PREC).unwrap());
+    }
+
+    #[test]
+    fn test_add() {
+        use fp::Add;
+        assert_eq!(R(1.1).next_down(), R(0.1).add_lo(R(1.0)));
+        assert_eq!(R(1.1), R(0.1).add_hi(R(1.0)));
+        assert_eq!((R(3.0), true), R(1.0).add_hi_exact(R(2.0)));
+        assert_eq!(R(1.0), R(1.0).add_lo(R(1e-30)));
+        assert_eq!(R(1.0).next_up(), R(1.0).add_hi(R(1e-30)));
+        assert_eq!(R(f32::MAX), R(f32::MAX).add_lo(R(f32::MAX)));
+    }
+
+    #[test]
+    fn test_mul() {
+        use fp::Mul;
+        assert_eq!(R(0.3).next_down(), R(0.1).mul_lo(R(3.0)));
+        assert_eq!(R(0.3), R(0.1).mul_hi(R(3.0)));
+        assert_eq!((R(6.0), true), R(2.0).mul_lo_exact(R(3.0)));
+    }
+
+    #[test]
+    fn test_div() {
+        use fp::Div;
+        assert_eq!(R(0.3333333), R(1.0).div_lo(R(3.0)));
+        assert_eq!(R(0.33333334), R(1.0).div_hi(R(3.0)));
+        assert_eq!((R(0.5), true), R(1.0).div_hi_exact(R(2.0)));
+    }
+
+    #[test]
+    fn test_round() {
+        use fp::{Div, Round};
+        assert_eq!(R(0.33333334), R(1.0).div_r(R(3.0), Round::Nearest));
+        assert_eq!(R(0.3333333), R(1.0).div_r(R(3.0), Round::TowardsZero));
+        assert_eq!(R(-0.3333333), R(-1.0).div_r(R(3.0), Round::TowardsZero));
+        assert_eq!(R(-0.33333334), R(-1.0).div_r(R(3.0), Round::AwayFromZero));
+        assert_eq!((R(0.5), true), R(1.0).div_r_exact(R(2.0), Round::Nearest));
+    }
+
+    #[test]
+    fn test_fma_sum() {
+        use fp::{Fma, Sum};
+        assert_eq!((R(1.0), true), R(0.5).fma_lo_exact(R(4.0), R(-1.0)));
+        let lo = R(0.1).fma_lo(R(0.1), R(1.0));
+        assert_eq!(R(0.1).fma_hi(R(0.1), R(1.0)), lo.next_up());
+        let xs = [R(1e20), R(1.0), R(-1e20)];
+        assert_eq!(R(1.0), R::sum_lo(&xs, PREC));
+        assert_eq!(R(1.0), R::dot_hi(&xs, &[R(1.0); 3], PREC));
+    }
+
+    #[test]
+    fn test_transc() {
+        use fp::Transc;
+        assert!(R(1.0).exp_lo() <= R(::std::f32::consts::E));
+        assert!(R(::std::f32::consts::E) < R(1.0).exp_hi());
+        assert_eq!((R(0.0), true), R(1.0).log_hi_exact());
+        for x in &[R(89.0), R(800.0)] {
+            assert_eq!(R(f32::MAX), x.exp_lo());
+            assert_eq!(R(f32::INFINITY), x.exp_hi());
+        }
+        assert!(R(88.0).exp_hi() < R(f32::MAX));
+        assert_eq!(R(f32::MAX), R(3.0).pow_lo(R(700.5)));
+        assert_eq!(R(f32::INFINITY), R(3.0).pow_hi(R(700.5)));
+    }
+
+    #[test]
+    fn test_frexp() {
+        assert_eq!((R(0.75), 2), R(3.0).frexp());
+        assert_eq!(R(12.0), R(0.75).ldexp(4));
+    }
+}
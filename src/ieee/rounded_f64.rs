@@ -0,0 +1,941 @@
+use super::expansion::{headroom, Expansion, EXACT_ERROR_MIN};
+use super::DoubleDouble;
+
+use fp;
+use fp::{Float, Round, Sign};
+use transc::Transc;
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::hash::Hasher;
+use std::num::ParseFloatError;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::str::FromStr;
+
+/// A correctly rounding wrapper around `f64`.
+///
+/// Every operation is computed in the default rounding mode and the result is then stepped to the
+/// neighboring float whenever the rounding error has the wrong sign. The sign of the error is
+/// found with error-free transformations: TwoSum for addition and subtraction, and fused
+/// multiply-add for multiplication and division. Fused multiply-adds, sums and dot products are
+/// computed exactly as expansions of floats first. Logarithms, exponentials, powers and `hypot` are
+/// computed with `DoubleDouble` and rounded again in the same direction, so that they do not rely
+/// on the accuracy of the system math library. The resulting bounds are thus not always the
+/// tightest, but they are never wrong.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct RoundedF64(pub f64);
+
+/// Sign of `exact - rounded`, where `rounded` is the nearest float to `exact`. `None` means that
+/// the sign is unknown.
+type RoundingError = Option<Ordering>;
+
+/// Rounds down a nearest-rounded value given the sign of its rounding error.
+#[inline]
+fn round_lo(x: f64, err: RoundingError) -> (f64, bool) {
+    match err {
+        Some(Ordering::Equal) => (x, true),
+        Some(Ordering::Greater) => (x, false),
+        _ => (Float::next_down(x), false),
+    }
+}
+
+/// Rounds up a nearest-rounded value given the sign of its rounding error.
+#[inline]
+fn round_hi(x: f64, err: RoundingError) -> (f64, bool) {
+    match err {
+        Some(Ordering::Equal) => (x, true),
+        Some(Ordering::Less) => (x, false),
+        _ => (Float::next_up(x), false),
+    }
+}
+
+/// Rounds a nearest-rounded value in the direction of `round` given the sign of its rounding
+/// error.
+#[inline]
+fn round_r(x: f64, err: RoundingError, round: Round) -> (f64, bool) {
+    round.resolve(|r| match r {
+        Round::Down => round_lo(x, err),
+        Round::Up => round_hi(x, err),
+        _ => (x, err == Some(Ordering::Equal)),
+    })
+}
+
+/// Rounding error of an infinite result.
+#[inline]
+fn overflow_error(x: f64, is_overflow: bool) -> RoundingError {
+    if !is_overflow {
+        Some(Ordering::Equal)
+    } else if x > 0.0 {
+        Some(Ordering::Less)
+    } else {
+        Some(Ordering::Greater)
+    }
+}
+
+/// Computes `a + b` and its rounding error using the TwoSum error-free transformation.
+#[inline]
+fn add_with_error(a: f64, b: f64) -> (f64, RoundingError) {
+    let s = a + b;
+    if s.is_nan() {
+        return (s, Some(Ordering::Equal));
+    }
+    if s.is_infinite() {
+        return (s, overflow_error(s, a.is_finite() && b.is_finite()));
+    }
+    let bb = s - a;
+    let err = (a - (s - bb)) + (b - bb);
+    (s, err.partial_cmp(&0.0))
+}
+
+/// Computes `a * b` and its rounding error using a fused multiply-add.
+#[inline]
+fn mul_with_error(a: f64, b: f64) -> (f64, RoundingError) {
+    let p = a * b;
+    if p.is_nan() {
+        return (p, Some(Ordering::Equal));
+    }
+    if p.is_infinite() {
+        return (p, overflow_error(p, a.is_finite() && b.is_finite()));
+    }
+    if a == 0.0 || b == 0.0 {
+        return (p, Some(Ordering::Equal));
+    }
+    if p.abs() < EXACT_ERROR_MIN {
+        return (p, None);
+    }
+    (p, a.mul_add(b, -p).partial_cmp(&0.0))
+}
+
+/// Computes `a / b` and its rounding error using a fused multiply-add.
+#[inline]
+fn div_with_error(a: f64, b: f64) -> (f64, RoundingError) {
+    let q = a / b;
+    if q.is_nan() {
+        return (q, Some(Ordering::Equal));
+    }
+    if q.is_infinite() {
+        return (
+            q,
+            overflow_error(q, a.is_finite() && b.is_finite() && b != 0.0),
+        );
+    }
+    if a == 0.0 || b.is_infinite() {
+        return (q, Some(Ordering::Equal));
+    }
+    if a.abs() < EXACT_ERROR_MIN {
+        return (q, None);
+    }
+    // The exact quotient is `q + r / b`.
+    let r = (-q).mul_add(b, a);
+    let err = r.partial_cmp(&0.0);
+    if b < 0.0 {
+        (q, err.map(Ordering::reverse))
+    } else {
+        (q, err)
+    }
+}
+
+/// Computes `a * b + c` and its rounding error using an expansion of the exact result.
+fn fma_with_error(a: f64, b: f64, c: f64) -> (f64, RoundingError) {
+    let z = a.mul_add(b, c);
+    if z.is_nan() || !a.is_finite() || !b.is_finite() || !c.is_finite() {
+        return (z, Some(Ordering::Equal));
+    }
+    if z.is_infinite() {
+        return (z, overflow_error(z, true));
+    }
+    let exp = Float::exponent(&a) + Float::exponent(&b);
+    let scale = headroom(exp.max(Float::exponent(&c)), 3);
+    let mut e = Expansion::new();
+    e.push_product_scaled(a, b, scale);
+    e.push_scaled(c, scale);
+    e.push_scaled(-z, scale);
+    (z, e.sign())
+}
+
+/// Scales a result `z` rounded in the direction of `round` by `2^scale`. This is exact unless it
+/// overflows, in which case the exact result is beyond the greatest finite float.
+#[inline]
+fn unscale(z: f64, scale: isize, round: Round) -> f64 {
+    let y = Float::ldexp(z, scale);
+    match round {
+        Round::Down if y == f64::INFINITY => f64::MAX,
+        Round::Up if y == f64::NEG_INFINITY => f64::MIN,
+        _ => y,
+    }
+}
+
+/// Rounds the sum of `e` times `2^scale` in the direction of `round`. The terms of `e` must be
+/// finite.
+fn round_expansion(e: &Expansion, scale: isize, round: Round) -> f64 {
+    let s = e.estimate();
+    let mut r = *e;
+    r.push(-s);
+    // The exact result is `s + r`, and `d` bounds the magnitude of `r`. Stepping from `s` by `d`
+    // moves by one ulp whenever `s` is faithful.
+    let d = fp::Add::add_hi(RoundedF64(r.term(0).abs()), r.tail_bound(1));
+    let sign = r.sign();
+    round
+        .resolve(|rd| {
+            let z = match rd {
+                _ if sign == Some(Ordering::Equal) => s,
+                Round::Down if sign == Some(Ordering::Greater) => s,
+                Round::Down => fp::Sub::sub_lo(RoundedF64(s), d).0,
+                Round::Up if sign == Some(Ordering::Less) => s,
+                Round::Up => fp::Add::add_hi(RoundedF64(s), d).0,
+                _ => s,
+            };
+            (unscale(z, scale, rd), sign == Some(Ordering::Equal))
+        })
+        .0
+}
+
+/// Computes the sum of `xs` rounded in the direction of `round`, using an expansion of the exact
+/// sum.
+pub fn sum_rounded<I>(xs: I, round: Round) -> f64
+where
+    I: Iterator<Item = f64> + Clone,
+{
+    if xs.clone().any(|x| !x.is_finite()) {
+        return xs.filter(|x| !x.is_finite()).fold(0.0, |s, x| s + x);
+    }
+    let exp = xs.clone().map(|x| Float::exponent(&x)).max();
+    let scale = headroom(exp.unwrap_or(0), xs.clone().count());
+    let mut e = Expansion::new();
+    for x in xs.clone() {
+        e.push_scaled(x, scale);
+    }
+    if e.len == 0 && e.err == 0.0 {
+        // The exact sum is zero, which is negative only if all the terms are.
+        return if xs.clone().count() > 0 && xs.clone().all(|x| x.is_sign_negative()) {
+            -0.0
+        } else {
+            0.0
+        };
+    }
+    round_expansion(&e, scale, round)
+}
+
+/// Computes the sum of the products of the pairs of `xys` rounded in the direction of `round`,
+/// using an expansion of the exact sum.
+pub fn dot_rounded<I>(xys: I, round: Round) -> f64
+where
+    I: Iterator<Item = (f64, f64)> + Clone,
+{
+    let is_finite = |&(x, y): &(f64, f64)| x.is_finite() && y.is_finite();
+    if !xys.clone().all(|xy| is_finite(&xy)) {
+        // Finite products cannot change an infinite or NaN sum.
+        return xys
+            .filter(|xy| !is_finite(xy))
+            .fold(0.0, |s, (x, y)| s + x * y);
+    }
+    let exp = xys
+        .clone()
+        .map(|(x, y)| Float::exponent(&x) + Float::exponent(&y))
+        .max();
+    let scale = headroom(exp.unwrap_or(0), xys.clone().count());
+    let mut e = Expansion::new();
+    for (x, y) in xys.clone() {
+        e.push_product_scaled(x, y, scale);
+    }
+    if e.len == 0 && e.err == 0.0 {
+        // The exact sum is zero, which is negative only if all the products are.
+        let negative = xys.clone().all(|(x, y)| (x * y).is_sign_negative());
+        return if xys.count() > 0 && negative {
+            -0.0
+        } else {
+            0.0
+        };
+    }
+    round_expansion(&e, scale, round)
+}
+
+/// Computes `op` on double-doubles, rounding down, up or to the nearest, and rounds the result to
+/// an `f64` in the same direction.
+fn with_double_double<F>(round: Round, op: F) -> (RoundedF64, bool)
+where
+    F: Fn(Round) -> (DoubleDouble, bool),
+{
+    round.resolve(|r| {
+        let (z, exact) = op(r);
+        let x = match r {
+            Round::Down => fp::Into::into_lo(z),
+            Round::Up => fp::Into::into_hi(z),
+            _ => z.hi(),
+        };
+        // An inexact result that overflows is bounded by the largest finite value towards zero.
+        let x = match r {
+            Round::Down if !exact && x == f64::INFINITY => f64::MAX,
+            Round::Up if !exact && x == f64::NEG_INFINITY => f64::MIN,
+            _ => x,
+        };
+        (RoundedF64(x), exact && z.lo() == 0.0)
+    })
+}
+
+/// Splits a decimal string into its sign, its significant digits and its exponent such that the
+/// number is `0.d1d2d3... * 10^exp`. Leading and trailing zeros of the digits are removed.
+pub fn parse_decimal(s: &str) -> Option<(bool, Vec<u8>, i64)> {
+    let s = s.trim();
+    let (neg, s) = if let Some(rest) = s.strip_prefix('-') {
+        (true, rest)
+    } else {
+        (false, s.strip_prefix('+').unwrap_or(s))
+    };
+    let (mantissa, exp) = match s.find(['e', 'E']) {
+        Some(i) => (&s[..i], s[i + 1..].parse::<i64>().ok()?),
+        None => (s, 0),
+    };
+    let (int, frac) = match mantissa.find('.') {
+        Some(i) => (&mantissa[..i], &mantissa[i + 1..]),
+        None => (mantissa, ""),
+    };
+    if int.is_empty() && frac.is_empty() {
+        return None;
+    }
+    let mut digits = Vec::<u8>::new();
+    for c in int.bytes().chain(frac.bytes()) {
+        if !c.is_ascii_digit() {
+            return None;
+        }
+        digits.push(c - b'0');
+    }
+    let mut exp = exp.checked_add(int.len() as i64)?;
+    let leading = digits.iter().take_while(|&&d| d == 0).count();
+    digits.drain(..leading);
+    exp -= leading as i64;
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    if digits.is_empty() {
+        exp = 0;
+    }
+    Some((neg, digits, exp))
+}
+
+/// Parses `s` to the nearest float and computes the rounding error.
+fn parse_with_error(s: &str) -> Result<(f64, RoundingError), ParseFloatError> {
+    let x = s.trim().parse::<f64>()?;
+    if x.is_nan() {
+        return Ok((x, Some(Ordering::Equal)));
+    }
+    if x.is_infinite() {
+        let is_overflow = !s.to_ascii_lowercase().contains("inf");
+        return Ok((x, overflow_error(x, is_overflow)));
+    }
+    let exact = parse_decimal(s);
+    // Formatting with an explicit precision prints the exact decimal expansion of `x`.
+    let nearest = parse_decimal(&format!("{:.800e}", x.abs()));
+    let err = match (exact, nearest) {
+        (Some((neg, exact_digits, exact_exp)), Some((_, nearest_digits, nearest_exp))) => {
+            let ord = if exact_digits.is_empty() || nearest_digits.is_empty() {
+                exact_digits.len().cmp(&nearest_digits.len())
+            } else {
+                exact_exp
+                    .cmp(&nearest_exp)
+                    .then_with(|| exact_digits.cmp(&nearest_digits))
+            };
+            Some(if neg { ord.reverse() } else { ord })
+        }
+        _ => None,
+    };
+    Ok((x, err))
+}
+
+impl Display for RoundedF64 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl FromStr for RoundedF64 {
+    type Err = ParseFloatError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim().parse::<f64>().map(RoundedF64)
+    }
+}
+
+impl From<f64> for RoundedF64 {
+    #[inline]
+    fn from(val: f64) -> Self {
+        RoundedF64(val)
+    }
+}
+
+impl From<RoundedF64> for f64 {
+    #[inline]
+    fn from(val: RoundedF64) -> Self {
+        val.0
+    }
+}
+
+impl Neg for RoundedF64 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        RoundedF64(-self.0)
+    }
+}
+
+impl Add for RoundedF64 {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        RoundedF64(self.0 + rhs.0)
+    }
+}
+
+impl Sub for RoundedF64 {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        RoundedF64(self.0 - rhs.0)
+    }
+}
+
+impl Mul for RoundedF64 {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        RoundedF64(self.0 * rhs.0)
+    }
+}
+
+impl Div for RoundedF64 {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        RoundedF64(self.0 / rhs.0)
+    }
+}
+
+impl Transc for RoundedF64 {
+    type Output = Self;
+
+    #[inline]
+    fn log(self) -> Self::Output {
+        fp::Transc::log_r(self, Round::Nearest)
+    }
+
+    #[inline]
+    fn exp(self) -> Self::Output {
+        fp::Transc::exp_r(self, Round::Nearest)
+    }
+
+    #[inline]
+    fn pow(self, rhs: Self) -> Self::Output {
+        fp::Transc::pow_r(self, rhs, Round::Nearest)
+    }
+}
+
+impl fp::From<f64> for RoundedF64 {
+    #[inline]
+    fn from_lo(val: f64, _: usize) -> Self {
+        RoundedF64(val)
+    }
+
+    #[inline]
+    fn from_hi(val: f64, _: usize) -> Self {
+        RoundedF64(val)
+    }
+}
+
+impl fp::FromStr for RoundedF64 {
+    fn from_str_lo(s: &str, _: usize) -> Result<Self, Self::Err> {
+        let (x, err) = parse_with_error(s)?;
+        Ok(RoundedF64(round_lo(x, err).0))
+    }
+
+    fn from_str_hi(s: &str, _: usize) -> Result<Self, Self::Err> {
+        let (x, err) = parse_with_error(s)?;
+        Ok(RoundedF64(round_hi(x, err).0))
+    }
+}
+
+impl fp::Into<f64> for RoundedF64 {
+    #[inline]
+    fn into_lo(self) -> f64 {
+        self.0
+    }
+
+    #[inline]
+    fn into_hi(self) -> f64 {
+        self.0
+    }
+}
+
+impl fp::MinMax for RoundedF64 {
+    #[inline]
+    fn min(self, rhs: Self) -> Self {
+        RoundedF64(self.0.min(rhs.0))
+    }
+
+    #[inline]
+    fn max(self, rhs: Self) -> Self {
+        RoundedF64(self.0.max(rhs.0))
+    }
+}
+
+impl fp::Abs for RoundedF64 {
+    #[inline]
+    fn abs(self) -> Self {
+        RoundedF64(self.0.abs())
+    }
+}
+
+impl fp::Integral for RoundedF64 {
+    #[inline]
+    fn floor(self) -> Self {
+        RoundedF64(self.0.floor())
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        RoundedF64(self.0.ceil())
+    }
+
+    #[inline]
+    fn round_ties_even(self) -> Self {
+        RoundedF64(self.0.round_ties_even())
+    }
+
+    #[inline]
+    fn round_ties_away(self) -> Self {
+        RoundedF64(self.0.round())
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        RoundedF64(self.0.trunc())
+    }
+
+    #[inline]
+    fn frac(self) -> Self {
+        RoundedF64(self.0.fract())
+    }
+}
+
+// Remainders are always exactly representable.
+impl fp::Rem for RoundedF64 {
+    #[inline]
+    fn fmod_lo(self, rhs: Self) -> Self {
+        RoundedF64(fp::Rem::fmod_lo(self.0, rhs.0))
+    }
+
+    #[inline]
+    fn fmod_hi(self, rhs: Self) -> Self {
+        RoundedF64(fp::Rem::fmod_hi(self.0, rhs.0))
+    }
+
+    #[inline]
+    fn remainder_lo(self, rhs: Self) -> Self {
+        RoundedF64(fp::Rem::remainder_lo(self.0, rhs.0))
+    }
+
+    #[inline]
+    fn remainder_hi(self, rhs: Self) -> Self {
+        RoundedF64(fp::Rem::remainder_hi(self.0, rhs.0))
+    }
+}
+
+impl fp::Hypot for RoundedF64 {
+    fn hypot_r(self, rhs: Self, round: Round) -> Self {
+        let (x, y) = (DoubleDouble::from(self.0), DoubleDouble::from(rhs.0));
+        // An infinite result is exact only for an infinite operand, and overflows otherwise.
+        let infinite = self.0.is_infinite() || rhs.0.is_infinite();
+        with_double_double(round, |r| (fp::Hypot::hypot_r(x, y, r), infinite)).0
+    }
+}
+
+impl fp::Add for RoundedF64 {
+    #[inline]
+    fn add_r(self, rhs: Self, round: Round) -> Self {
+        self.add_r_exact(rhs, round).0
+    }
+
+    #[inline]
+    fn add_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        let (z, err) = add_with_error(self.0, rhs.0);
+        let (z, exact) = round_r(z, err, round);
+        (RoundedF64(z), exact)
+    }
+}
+
+impl fp::Sub for RoundedF64 {
+    #[inline]
+    fn sub_r(self, rhs: Self, round: Round) -> Self {
+        self.sub_r_exact(rhs, round).0
+    }
+
+    #[inline]
+    fn sub_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        fp::Add::add_r_exact(self, -rhs, round)
+    }
+}
+
+impl fp::Mul for RoundedF64 {
+    #[inline]
+    fn mul_r(self, rhs: Self, round: Round) -> Self {
+        self.mul_r_exact(rhs, round).0
+    }
+
+    #[inline]
+    fn mul_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        let (z, err) = mul_with_error(self.0, rhs.0);
+        let (z, exact) = round_r(z, err, round);
+        (RoundedF64(z), exact)
+    }
+}
+
+impl fp::Div for RoundedF64 {
+    #[inline]
+    fn div_r(self, rhs: Self, round: Round) -> Self {
+        self.div_r_exact(rhs, round).0
+    }
+
+    #[inline]
+    fn div_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        let (z, err) = div_with_error(self.0, rhs.0);
+        let (z, exact) = round_r(z, err, round);
+        (RoundedF64(z), exact)
+    }
+}
+
+impl fp::Fma for RoundedF64 {
+    fn fma_r_exact(self, a: Self, b: Self, round: Round) -> (Self, bool) {
+        let (z, err) = fma_with_error(self.0, a.0, b.0);
+        let (z, exact) = round_r(z, err, round);
+        (RoundedF64(z), exact)
+    }
+}
+
+impl fp::Sum for RoundedF64 {
+    fn sum_r(xs: &[Self], _: usize, round: Round) -> Self {
+        RoundedF64(sum_rounded(xs.iter().map(|x| x.0), round))
+    }
+
+    fn dot_r(xs: &[Self], ys: &[Self], _: usize, round: Round) -> Self {
+        RoundedF64(dot_rounded(
+            xs.iter().zip(ys).map(|(x, y)| (x.0, y.0)),
+            round,
+        ))
+    }
+}
+
+impl fp::TotalOrd for RoundedF64 {
+    #[inline]
+    fn cmp_total(&self, rhs: &RoundedF64) -> Ordering {
+        self.0.cmp_total(&rhs.0)
+    }
+
+    #[inline]
+    fn hash_total<H: Hasher>(&self, state: &mut H) {
+        self.0.hash_total(state)
+    }
+}
+
+impl fp::Transc for RoundedF64 {
+    #[inline]
+    fn log_r(self, round: Round) -> Self {
+        self.log_r_exact(round).0
+    }
+
+    #[inline]
+    fn exp_r(self, round: Round) -> Self {
+        self.exp_r_exact(round).0
+    }
+
+    #[inline]
+    fn pow_r(self, rhs: Self, round: Round) -> Self {
+        self.pow_r_exact(rhs, round).0
+    }
+
+    fn log_r_exact(self, round: Round) -> (Self, bool) {
+        let x = DoubleDouble::from(self.0);
+        with_double_double(round, |r| fp::Transc::log_r_exact(x, r))
+    }
+
+    fn exp_r_exact(self, round: Round) -> (Self, bool) {
+        let x = DoubleDouble::from(self.0);
+        with_double_double(round, |r| fp::Transc::exp_r_exact(x, r))
+    }
+
+    fn pow_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        let (x, y) = (DoubleDouble::from(self.0), DoubleDouble::from(rhs.0));
+        with_double_double(round, |r| fp::Transc::pow_r_exact(x, y, r))
+    }
+}
+
+impl Float for RoundedF64 {
+    #[inline]
+    fn zero(precision: usize) -> Self {
+        RoundedF64(f64::zero(precision))
+    }
+
+    #[inline]
+    fn neg_zero(precision: usize) -> Self {
+        RoundedF64(f64::neg_zero(precision))
+    }
+
+    #[inline]
+    fn one(precision: usize) -> Self {
+        RoundedF64(f64::one(precision))
+    }
+
+    #[inline]
+    fn infinity(precision: usize) -> Self {
+        RoundedF64(f64::infinity(precision))
+    }
+
+    #[inline]
+    fn neg_infinity(precision: usize) -> Self {
+        RoundedF64(f64::neg_infinity(precision))
+    }
+
+    #[inline]
+    fn nan(precision: usize) -> Self {
+        RoundedF64(f64::nan(precision))
+    }
+
+    #[inline]
+    fn sign(&self) -> Sign {
+        Float::sign(&self.0)
+    }
+
+    #[inline]
+    fn precision(&self) -> usize {
+        53
+    }
+
+    #[inline]
+    fn is_finite(&self) -> bool {
+        self.0.is_finite()
+    }
+
+    #[inline]
+    fn is_infinite(&self) -> bool {
+        self.0.is_infinite()
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0 == 0.0
+    }
+
+    #[inline]
+    fn is_infinity(&self) -> bool {
+        Float::is_infinity(&self.0)
+    }
+
+    #[inline]
+    fn is_neg_infinity(&self) -> bool {
+        Float::is_neg_infinity(&self.0)
+    }
+
+    #[inline]
+    fn is_nan(&self) -> bool {
+        self.0.is_nan()
+    }
+
+    #[inline]
+    fn has_odd_denominator(&self) -> bool {
+        Float::has_odd_denominator(&self.0)
+    }
+
+    #[inline]
+    fn next_up(self) -> Self {
+        RoundedF64(Float::next_up(self.0))
+    }
+
+    #[inline]
+    fn next_down(self) -> Self {
+        RoundedF64(Float::next_down(self.0))
+    }
+
+    #[inline]
+    fn frexp(self) -> (Self, isize) {
+        let (m, e) = Float::frexp(self.0);
+        (RoundedF64(m), e)
+    }
+
+    #[inline]
+    fn ldexp(self, exp: isize) -> Self {
+        RoundedF64(Float::ldexp(self.0, exp))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RoundedF64 as R;
+    use fp::Float;
+
+    const PREC: usize = 53;
+
+    #[test]
+    fn test_from_str_lo() {
+        use fp::FromStr;
+        assert_eq!(R(0.5), R::from_str_lo("0.5", PREC).unwrap());
+        assert_eq!(R(0.09999999999999999), R::from_str_lo("0.1", PREC).unwrap());
+        assert_eq!(R(-0.1), R::from_str_lo("-1e-1", PREC).unwrap());
+        assert_eq!(R(f64::MAX), R::from_str_lo("2e308", PREC).unwrap());
+        assert_eq!(R(f64::NEG_INFINITY), R::from_str_lo("-inf", PREC).unwrap());
+        assert!(R::from_str_lo("NaN", PREC).unwrap().is_nan());
+        assert!(R::from_str_lo("x", PREC).is_err());
+    }
+
+    #[test]
+    fn test_from_str_hi() {
+        use fp::FromStr;
+        assert_eq!(R(0.5), R::from_str_hi("+0.50", PREC).unwrap());
+        assert_eq!(R(0.1), R::from_str_hi("0.1", PREC).unwrap());
+        assert_eq!(
+            R(-0.09999999999999999),
+            R::from_str_hi("-0.1", PREC).unwrap()
+        );
+        assert_eq!(R(5e-324), R::from_str_hi("1e-400", PREC).unwrap());
+        assert_eq!(R(f64::INFINITY), R::from_str_hi("inf", PREC).unwrap());
+    }
+
+    #[test]
+    fn test_add() {
+        use fp::Add;
+        assert_eq!(R(1.1).next_down(), R(0.1).add_lo(R(1.0)));
+        assert_eq!(R(1.1), R(0.1).add_hi(R(1.0)));
+        assert_eq!((R(3.0), true), R(1.0).add_lo_exact(R(2.0)));
+        assert_eq!(R(f64::MAX), R(f64::MAX).add_lo(R(f64::MAX)));
+        assert_eq!(R(f64::INFINITY), R(f64::MAX).add_hi(R(f64::MAX)));
+    }
+
+    #[test]
+    fn test_round() {
+        use fp::{Add, Round, Transc};
+        assert_eq!(R(1.1), R(0.1).add_r(R(1.0), Round::Nearest));
+        assert_eq!(R(1.1).next_down(), R(0.1).add_r(R(1.0), Round::TowardsZero));
+        assert_eq!(R(1.1), R(0.1).add_r(R(1.0), Round::AwayFromZero));
+        assert_eq!(R(-1.1), R(-0.1).add_r(R(-1.0), Round::Down));
+        assert_eq!(
+            R(-1.1).next_up(),
+            R(-0.1).add_r(R(-1.0), Round::TowardsZero)
+        );
+        assert_eq!(R(-1.1), R(-0.1).add_r(R(-1.0), Round::AwayFromZero));
+        assert_eq!((R(3.0), true), R(1.0).add_r_exact(R(2.0), Round::Nearest));
+        assert_eq!(
+            R(f64::MAX),
+            R(f64::MAX).add_r(R(f64::MAX), Round::TowardsZero)
+        );
+        assert_eq!(R(1.0_f64.exp()), R(1.0).exp_r(Round::Nearest));
+        assert_eq!(R(1.0_f64.exp()), R(1.0).exp_r(Round::TowardsZero));
+        assert_eq!(
+            R(1.0_f64.exp().next_up()),
+            R(1.0).exp_r(Round::AwayFromZero)
+        );
+    }
+
+    #[test]
+    fn test_fma() {
+        use fp::{Fma, Round};
+        // The product of the double nearest to 0.1 and 10 is exactly 1 + 2^-54.
+        let tiny = R(2.0f64.powi(-54));
+        assert_eq!((tiny, true), R(0.1).fma_lo_exact(R(10.0), R(-1.0)));
+        assert_eq!((tiny, true), R(0.1).fma_hi_exact(R(10.0), R(-1.0)));
+        let (lo, lo_exact) = R(0.1).fma_lo_exact(R(0.1), R(1.0));
+        let (hi, hi_exact) = R(0.1).fma_hi_exact(R(0.1), R(1.0));
+        assert!(!lo_exact && !hi_exact);
+        assert_eq!(hi, lo.next_up());
+        let near = R(0.1).fma_r(R(0.1), R(1.0), Round::Nearest);
+        assert_eq!(R(0.1f64.mul_add(0.1, 1.0)), near);
+        assert_eq!(R(f64::MAX), R(f64::MAX).fma_lo(R(2.0), R(0.0)));
+        let max = R(f64::MAX);
+        assert_eq!((max, true), max.fma_lo_exact(R(2.0), -max));
+        assert!(R(f64::INFINITY).fma_lo(R(0.0), R(1.0)).is_nan());
+    }
+
+    #[test]
+    fn test_sum() {
+        use fp::Sum;
+        let xs = [R(1e100), R(1.0), R(-1e100)];
+        assert_eq!(R(1.0), R::sum_lo(&xs, PREC));
+        assert_eq!(R(1.0), R::sum_hi(&xs, PREC));
+        assert_eq!(R(0.3), R::sum_lo(&[R(0.1), R(0.2)], PREC));
+        assert_eq!(R(0.30000000000000004), R::sum_hi(&[R(0.1), R(0.2)], PREC));
+        assert_eq!(R(0.0), R::sum_lo(&[], PREC));
+        assert!(R::sum_hi(&[R(f64::INFINITY), R(f64::NEG_INFINITY)], PREC).is_nan());
+        let tiny = R(2.0f64.powi(-54));
+        let (xs, ys) = ([R(0.1), R(-1.0)], [R(10.0), R(1.0)]);
+        assert_eq!(tiny, R::dot_lo(&xs, &ys, PREC));
+        assert_eq!(tiny, R::dot_hi(&xs, &ys, PREC));
+        let max = R(f64::MAX);
+        assert_eq!(max, R::sum_lo(&[max, max, -max], PREC));
+        assert_eq!(max, R::sum_hi(&[max, max, -max], PREC));
+        assert_eq!(R(f64::INFINITY), R::sum_hi(&[max, max], PREC));
+        assert_eq!(max, R::sum_lo(&[max, max], PREC));
+        let (xs, ys) = ([R(1e300), R(1e300), R(1.0)], [R(1e10), R(-1e10), R(0.5)]);
+        assert_eq!(R(0.5), R::dot_lo(&xs, &ys, PREC));
+        assert_eq!(R(0.5), R::dot_hi(&xs, &ys, PREC));
+        let (xs, ys) = ([R(f64::INFINITY), max], [R(1.0), R(-2.0)]);
+        assert_eq!(R(f64::INFINITY), R::dot_lo(&xs, &ys, PREC));
+    }
+
+    #[test]
+    fn test_sub() {
+        use fp::Sub;
+        assert_eq!(R(-0.9), R(0.1).sub_lo(R(1.0)));
+        assert_eq!(R(-0.9).next_up(), R(0.1).sub_hi(R(1.0)));
+        assert_eq!((R(0.0), true), R(1.0).sub_hi_exact(R(1.0)));
+    }
+
+    #[test]
+    fn test_mul() {
+        use fp::Mul;
+        assert_eq!(R(0.3), R(0.1).mul_lo(R(3.0)));
+        assert_eq!(R(0.30000000000000004), R(0.1).mul_hi(R(3.0)));
+        assert_eq!((R(6.0), true), R(2.0).mul_hi_exact(R(3.0)));
+        assert!(R(5e-324).mul_lo(R(0.5)) <= R(0.0));
+        assert_eq!(R(5e-324), R(5e-324).mul_hi(R(0.5)));
+    }
+
+    #[test]
+    fn test_div() {
+        use fp::Div;
+        assert_eq!(R(0.3333333333333333), R(1.0).div_lo(R(3.0)));
+        assert_eq!(R(0.33333333333333337), R(1.0).div_hi(R(3.0)));
+        assert_eq!(R(-0.33333333333333337), R(1.0).div_lo(R(-3.0)));
+        assert_eq!((R(0.5), true), R(1.0).div_lo_exact(R(2.0)));
+        assert_eq!(R(f64::INFINITY), R(1.0).div_hi(R(0.0)));
+    }
+
+    #[test]
+    fn test_transc() {
+        use fp::Transc;
+        assert_eq!((R(0.0), true), R(1.0).log_lo_exact());
+        assert_eq!((R(1.0), true), R(0.0).exp_hi_exact());
+        assert_eq!(R(::std::f64::consts::E), R(1.0).exp_lo());
+        assert_eq!(R(::std::f64::consts::E.next_up()), R(1.0).exp_hi());
+        assert_eq!(R(::std::f64::consts::LN_2), R(2.0).log_lo());
+        assert_eq!(R(0.0), R(-1e10).exp_lo());
+        assert!(R(0.0) <= R(1.0 + 1e-15).log_lo());
+        assert_eq!((R(9.0), true), R(3.0).pow_hi_exact(R(2.0)));
+        assert!(R(2.0).pow_lo(R(0.5)) < R(2.0).pow_hi(R(0.5)));
+    }
+
+    #[test]
+    fn test_hypot() {
+        use fp::Hypot;
+        assert_eq!(R(3.0), R(3.0).hypot_lo(R(0.0)));
+        assert_eq!(R(5.0), R(3.0).hypot_lo(R(4.0)));
+        assert_eq!(R(5.0), R(3.0).hypot_hi(R(4.0)));
+        assert!(R(1.0).hypot_lo(R(1.0)) < R(1.0).hypot_hi(R(1.0)));
+        assert_eq!(R(f64::INFINITY), R(f64::NAN).hypot_lo(R(f64::NEG_INFINITY)));
+        assert_eq!(R(f64::MAX), R(f64::MAX).hypot_lo(R(f64::MAX)));
+        assert_eq!(R(f64::INFINITY), R(f64::MAX).hypot_hi(R(f64::MAX)));
+    }
+}
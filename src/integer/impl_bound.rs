@@ -0,0 +1,330 @@
+use fp;
+use fp::{Bound, Integer, Round, Sign};
+use rational::Rational;
+
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+/// Precision of the rationals that integer bounds are parsed with.
+const PARSE_PREC: usize = 53;
+
+/// Implements `fp::Bound` and `fp::Integer` for a primitive signed integer type.
+///
+/// `MIN` is NaN, `MIN + 1` is negative infinity and `MAX` is positive infinity, so that the
+/// finite values are symmetric around zero and negation never overflows.
+macro_rules! impl_integer {
+    ($t:ident) => {
+        impl fp::MinMax for $t {
+            #[inline]
+            fn min(self, rhs: Self) -> Self {
+                if self.is_nan() {
+                    rhs
+                } else if rhs.is_nan() {
+                    self
+                } else {
+                    Ord::min(self, rhs)
+                }
+            }
+
+            #[inline]
+            fn max(self, rhs: Self) -> Self {
+                if self.is_nan() {
+                    rhs
+                } else if rhs.is_nan() {
+                    self
+                } else {
+                    Ord::max(self, rhs)
+                }
+            }
+        }
+
+        impl fp::Abs for $t {
+            #[inline]
+            fn abs(self) -> Self {
+                if self.is_nan() {
+                    self
+                } else {
+                    $t::abs(self)
+                }
+            }
+        }
+
+        impl fp::Add for $t {
+            #[inline]
+            fn add_r(self, rhs: Self, round: Round) -> Self {
+                $t::add_with(self, rhs, round).0
+            }
+
+            #[inline]
+            fn add_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+                $t::add_with(self, rhs, round)
+            }
+        }
+
+        impl fp::Sub for $t {
+            #[inline]
+            fn sub_r(self, rhs: Self, round: Round) -> Self {
+                $t::add_with(self, $t::negate(rhs), round).0
+            }
+
+            #[inline]
+            fn sub_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+                $t::add_with(self, $t::negate(rhs), round)
+            }
+        }
+
+        impl fp::Mul for $t {
+            #[inline]
+            fn mul_r(self, rhs: Self, round: Round) -> Self {
+                $t::mul_with(self, rhs, round).0
+            }
+
+            #[inline]
+            fn mul_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+                $t::mul_with(self, rhs, round)
+            }
+        }
+
+        impl Bound for $t {
+            #[inline]
+            fn zero(_: usize) -> Self {
+                0
+            }
+
+            #[inline]
+            fn one(_: usize) -> Self {
+                1
+            }
+
+            #[inline]
+            fn infinity(_: usize) -> Self {
+                $t::MAX
+            }
+
+            #[inline]
+            fn neg_infinity(_: usize) -> Self {
+                $t::MIN + 1
+            }
+
+            #[inline]
+            fn nan(_: usize) -> Self {
+                $t::MIN
+            }
+
+            #[inline]
+            fn sign(&self) -> Sign {
+                if *self == 0 || self.is_nan() {
+                    Sign::Zero
+                } else if *self < 0 {
+                    Sign::Negative
+                } else {
+                    Sign::Positive
+                }
+            }
+
+            #[inline]
+            fn precision(&self) -> usize {
+                $t::BITS as usize - 1
+            }
+
+            #[inline]
+            fn is_finite(&self) -> bool {
+                $t::MIN + 1 < *self && *self < $t::MAX
+            }
+
+            #[inline]
+            fn is_infinite(&self) -> bool {
+                *self == $t::MAX || *self == $t::MIN + 1
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                *self == 0
+            }
+
+            #[inline]
+            fn is_infinity(&self) -> bool {
+                *self == $t::MAX
+            }
+
+            #[inline]
+            fn is_neg_infinity(&self) -> bool {
+                *self == $t::MIN + 1
+            }
+
+            #[inline]
+            fn is_nan(&self) -> bool {
+                *self == $t::MIN
+            }
+
+            #[inline]
+            fn parse_lo(s: &str, _: usize) -> Option<Self> {
+                let x = Rational::from_str_with_prec(s, PARSE_PREC).ok()?;
+                Some($t::from_rational(fp::Integral::floor(x), Round::Down))
+            }
+
+            #[inline]
+            fn parse_hi(s: &str, _: usize) -> Option<Self> {
+                let x = Rational::from_str_with_prec(s, PARSE_PREC).ok()?;
+                Some($t::from_rational(fp::Integral::ceil(x), Round::Up))
+            }
+
+            fn fmt_bound(&self, f: &mut Formatter) -> fmt::Result {
+                if self.is_nan() {
+                    f.pad("NaN")
+                } else if self.is_infinity() {
+                    f.pad("inf")
+                } else if self.is_neg_infinity() {
+                    f.pad("-inf")
+                } else {
+                    Display::fmt(self, f)
+                }
+            }
+        }
+
+        impl Integer for $t {
+            fn floor_div(self, rhs: Self) -> Self {
+                if self.is_nan() || rhs.is_nan() || self.is_infinite() && rhs.is_infinite() {
+                    return $t::MIN;
+                }
+                let negative = (self.sign() as i8) * (rhs.sign() as i8) < 0;
+                if self.is_infinite() {
+                    if negative {
+                        $t::MIN + 1
+                    } else {
+                        $t::MAX
+                    }
+                } else if rhs.is_infinite() {
+                    -(negative as $t)
+                } else {
+                    // The finite values are symmetric, so the quotient cannot overflow.
+                    self.div_euclid(rhs) - (rhs < 0 && self.rem_euclid(rhs) != 0) as $t
+                }
+            }
+        }
+
+        impl fp::TotalOrd for $t {
+            #[inline]
+            fn cmp_total(&self, rhs: &Self) -> Ordering {
+                // NaN is the minimum, so it must be moved above the positive infinity.
+                match (self.is_nan(), rhs.is_nan()) {
+                    (true, true) => Ordering::Equal,
+                    (true, false) => Ordering::Greater,
+                    (false, true) => Ordering::Less,
+                    (false, false) => Ord::cmp(self, rhs),
+                }
+            }
+
+            #[inline]
+            fn hash_total<H: Hasher>(&self, state: &mut H) {
+                self.hash(state)
+            }
+        }
+
+        impl IntegerBound for $t {
+            fn overflow(positive: bool, round: Round) -> Self {
+                match (positive, round) {
+                    (true, Round::Down) | (true, Round::TowardsZero) => $t::MAX - 1,
+                    (true, _) => $t::MAX,
+                    (false, Round::Up) | (false, Round::TowardsZero) => $t::MIN + 2,
+                    (false, _) => $t::MIN + 1,
+                }
+            }
+
+            #[inline]
+            fn negate(self) -> Self {
+                if self.is_nan() {
+                    self
+                } else {
+                    -self
+                }
+            }
+
+            fn from_rational(x: Rational, round: Round) -> Self {
+                if x.is_nan() {
+                    return $t::MIN;
+                }
+                if !x.is_finite() {
+                    return if x.is_infinity() {
+                        $t::MAX
+                    } else {
+                        $t::MIN + 1
+                    };
+                }
+                let positive = x.sign() == Sign::Positive;
+                match x.num.to_i128().and_then(|n| $t::try_from(n).ok()) {
+                    Some(n) if n.is_finite() => n,
+                    _ => Self::overflow(positive, round),
+                }
+            }
+
+            fn class(self) -> f64 {
+                if self.is_nan() {
+                    f64::NAN
+                } else {
+                    self.sign() as i8 as f64 * if self.is_finite() { 1.0 } else { f64::INFINITY }
+                }
+            }
+
+            fn from_class(x: f64) -> Self {
+                if x.is_nan() {
+                    $t::MIN
+                } else if x == f64::INFINITY {
+                    $t::MAX
+                } else if x == f64::NEG_INFINITY {
+                    $t::MIN + 1
+                } else {
+                    0
+                }
+            }
+
+            fn add_with(self, rhs: Self, round: Round) -> (Self, bool) {
+                if !self.is_finite() || !rhs.is_finite() {
+                    return ($t::from_class(self.class() + rhs.class()), true);
+                }
+                match self.checked_add(rhs) {
+                    Some(n) if n.is_finite() => (n, true),
+                    _ => ($t::overflow(self > 0, round), false),
+                }
+            }
+
+            fn mul_with(self, rhs: Self, round: Round) -> (Self, bool) {
+                if !self.is_finite() || !rhs.is_finite() {
+                    return ($t::from_class(self.class() * rhs.class()), true);
+                }
+                match self.checked_mul(rhs) {
+                    Some(n) if n.is_finite() => (n, true),
+                    _ => ($t::overflow((self < 0) == (rhs < 0), round), false),
+                }
+            }
+        }
+    };
+}
+
+/// Helpers that the implementations of `fp::Bound` for integers share.
+trait IntegerBound: Sized {
+    /// Returns the result of a positive or negative overflow rounded in the direction of `round`.
+    /// Rounding to the nearest overflows to the infinities as in IEEE 754.
+    fn overflow(positive: bool, round: Round) -> Self;
+    /// Negates `self`, keeping NaN as is.
+    fn negate(self) -> Self;
+    /// Rounds an integral rational to an integer bound, which only rounds if it overflows.
+    fn from_rational(x: Rational, round: Round) -> Self;
+    /// Returns an `f64` that behaves like `self` under IEEE 754 arithmetic with zeros,
+    /// infinities and NaN: the special value itself, or the sign of a nonzero finite value.
+    fn class(self) -> f64;
+    /// Converts the zero, infinity or NaN result of an operation on classes back to a bound.
+    fn from_class(x: f64) -> Self;
+    /// Computes `self + rhs`, rounds an overflowing result in the direction of `round`, and tells
+    /// whether the result is exact.
+    fn add_with(self, rhs: Self, round: Round) -> (Self, bool);
+    /// Computes `self * rhs`, rounds an overflowing result in the direction of `round`, and tells
+    /// whether the result is exact.
+    fn mul_with(self, rhs: Self, round: Round) -> (Self, bool);
+}
+
+impl_integer!(i64);
+impl_integer!(i128);
@@ -0,0 +1,4 @@
+mod impl_bound;
+
+#[cfg(test)]
+mod tests;
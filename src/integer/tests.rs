@@ -0,0 +1,125 @@
+use fp::{Abs, Add, Bound, Integer, MinMax, Mul, Sub};
+
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+struct Show<T>(T);
+
+impl<T: Bound> Display for Show<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.0.fmt_bound(f)
+    }
+}
+
+macro_rules! assert_str_eq {
+    ($x:expr, $y:expr) => { assert_eq!($x, format!("{}", Show($y))) };
+    ($x:expr, $y:expr, $($arg:tt)+) => { assert_eq!($x, format!("{}", Show($y)), $($arg)+) };
+}
+
+const INF: i64 = i64::MAX;
+const NEG_INF: i64 = i64::MIN + 1;
+const NAN: i64 = i64::MIN;
+
+#[test]
+fn test_specials() {
+    assert_eq!(INF, i64::infinity(0));
+    assert_eq!(NEG_INF, i64::neg_infinity(0));
+    assert_eq!(NAN, i64::nan(0));
+    assert_eq!(63, 0i64.precision());
+    assert_eq!(127, 0i128.precision());
+    assert!(i64::is_finite(&(i64::MAX - 1)));
+    assert!(i64::is_finite(&(i64::MIN + 2)));
+    assert!(!i64::is_finite(&INF));
+    assert!(!i64::is_finite(&NAN));
+    assert!(i64::is_infinite(&NEG_INF));
+    assert!(!i64::is_infinite(&NAN));
+    assert_str_eq!("inf", INF);
+    assert_str_eq!("-inf", NEG_INF);
+    assert_str_eq!("NaN", NAN);
+    assert_str_eq!("-42", -42i64);
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(Some(3), i64::parse_lo("3", 0));
+    assert_eq!(Some(0), i64::parse_lo("1/3", 0));
+    assert_eq!(Some(1), i64::parse_hi("1/3", 0));
+    assert_eq!(Some(-1), i64::parse_lo("-0.5", 0));
+    assert_eq!(Some(0), i64::parse_hi("-0.5", 0));
+    assert_eq!(Some(INF), i64::parse_hi("inf", 0));
+    assert_eq!(Some(NEG_INF), i64::parse_lo("-inf", 0));
+    assert_eq!(Some(i64::MAX - 1), i64::parse_lo("1e30", 0));
+    assert_eq!(Some(INF), i64::parse_hi("1e30", 0));
+    assert_eq!(Some(NEG_INF), i64::parse_lo("-1e30", 0));
+    assert_eq!(Some(i64::MIN + 2), i64::parse_hi("-1e30", 0));
+    assert_eq!(
+        Some(1_000_000_000_000_000_000_000_000_000_000),
+        i128::parse_lo("1e30", 0)
+    );
+    assert_eq!(None, i64::parse_lo("x", 0));
+}
+
+#[test]
+fn test_add_sub() {
+    assert_eq!((5, true), 2i64.add_lo_exact(3));
+    assert_eq!((i64::MAX - 1, false), (i64::MAX - 1).add_lo_exact(1));
+    assert_eq!((INF, false), (i64::MAX - 1).add_hi_exact(1));
+    assert_eq!((NEG_INF, false), (i64::MIN + 2).sub_lo_exact(1));
+    assert_eq!((i64::MIN + 2, false), (i64::MIN + 2).sub_hi_exact(1));
+    assert_eq!(INF, INF.add_lo(-5));
+    assert_eq!(NEG_INF, 5i64.sub_hi(INF));
+    assert_eq!(NAN, INF.add_lo(NEG_INF));
+    assert_eq!(NAN, INF.sub_hi(INF));
+    assert_eq!(NAN, NAN.add_lo(1));
+}
+
+#[test]
+fn test_mul() {
+    assert_eq!((-6, true), 2i64.mul_lo_exact(-3));
+    assert_eq!((INF, false), (1i64 << 40).mul_hi_exact(1 << 40));
+    assert_eq!((i64::MAX - 1, false), (1i64 << 40).mul_lo_exact(1 << 40));
+    assert_eq!((NEG_INF, false), (1i64 << 40).mul_lo_exact(-(1 << 40)));
+    assert_eq!(NEG_INF, INF.mul_lo(-2));
+    assert_eq!(0, 0i64.mul_hi(7));
+    assert_eq!(NAN, INF.mul_hi(0));
+    assert_eq!((1i128 << 80, true), (1i128 << 40).mul_lo_exact(1 << 40));
+}
+
+#[test]
+fn test_round() {
+    use fp::Round;
+    let big = 1i64 << 40;
+    assert_eq!(i64::MAX - 1, big.mul_r(big, Round::TowardsZero));
+    assert_eq!(INF, big.mul_r(big, Round::AwayFromZero));
+    assert_eq!(INF, big.mul_r(big, Round::Nearest));
+    assert_eq!(i64::MIN + 2, big.mul_r(-big, Round::TowardsZero));
+    assert_eq!(NEG_INF, big.mul_r(-big, Round::Nearest));
+    assert_eq!((5, true), 2i64.add_r_exact(3, Round::Nearest));
+}
+
+#[test]
+fn test_floor_div() {
+    assert_eq!(2, 7i64.floor_div(3));
+    assert_eq!(-3, (-7i64).floor_div(3));
+    assert_eq!(-3, 7i64.floor_div(-3));
+    assert_eq!(2, (-7i64).floor_div(-3));
+    assert_eq!(-2, 6i64.floor_div(-3));
+    assert_eq!(0, 7i64.floor_div(INF));
+    assert_eq!(-1, 7i64.floor_div(NEG_INF));
+    assert_eq!(-1, (-7i64).floor_div(INF));
+    assert_eq!(NEG_INF, INF.floor_div(-3));
+    assert_eq!(INF, NEG_INF.floor_div(-3));
+    assert_eq!(NAN, INF.floor_div(INF));
+    assert_eq!(NAN, NAN.floor_div(1));
+}
+
+#[test]
+fn test_minmax_abs() {
+    assert_eq!(-3, MinMax::min(-3i64, 2));
+    assert_eq!(2, MinMax::max(-3i64, 2));
+    assert_eq!(2, MinMax::min(NAN, 2));
+    assert_eq!(2, MinMax::max(2, NAN));
+    assert_eq!(3, Abs::abs(-3i64));
+    assert_eq!(INF, Abs::abs(NEG_INF));
+    assert_eq!(NAN, Abs::abs(NAN));
+}
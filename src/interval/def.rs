@@ -13,6 +13,17 @@ pub enum ParseIntervalError {
     InvalidBounds,
 }
 
+/// Cancellative subtraction error enum.
+///
+/// See `Interval::checked_cancel_minus` for details.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CancelError {
+    /// An operand is unbounded.
+    Unbounded,
+    /// The subtrahend is wider than the minuend, or it is empty and the minuend is not.
+    WiderSubtrahend,
+}
+
 /// Represents the sign class of an interval.
 ///
 /// See http://fab.cba.mit.edu/classes/S62.12/docs/Hickey_interval.pdf for details.
@@ -38,3 +49,38 @@ pub struct Interval<BOUND: PartialOrd> {
     /// Inclusive upper bound.
     pub hi: BOUND,
 }
+
+/// Represents what is known about the roots in a root enclosure.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RootKind {
+    /// The enclosure is proved to contain exactly one root.
+    Unique,
+    /// The enclosure may contain any number of roots, including none.
+    Possible,
+}
+
+/// Root enclosure struct.
+///
+/// See `Interval::newton` for details.
+#[derive(Clone, Debug)]
+pub struct Root<BOUND: PartialOrd> {
+    /// Interval that encloses the roots.
+    pub interval: Interval<BOUND>,
+    /// What is known about the roots in `interval`.
+    pub kind: RootKind,
+}
+
+/// Represents a strategy for enclosing the range of a function over an interval.
+///
+/// See `Interval::range` for details.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RangeForm {
+    /// Evaluates the function over the interval with interval arithmetic.
+    Natural,
+    /// Expands the function around the midpoint with a derivative enclosure.
+    MeanValue,
+    /// Expands the function around the midpoint with a slope enclosure.
+    Slope,
+    /// Bounds the function from below and above with mean value forms around two optimal centers.
+    Bicentered,
+}
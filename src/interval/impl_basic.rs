@@ -1,6 +1,6 @@
 use super::def::{Interval, ParseIntervalError, SignClass};
 
-use fp::{Float, Sign};
+use fp::{Bound, Float, Round, Sign};
 
 use std::fmt;
 use std::fmt::{Display, Formatter, Write};
@@ -9,18 +9,12 @@ use std::str::FromStr;
 impl SignClass {
     /// Whether `self` is `SignClass::Positive(_)`.
     pub fn is_non_negative(&self) -> bool {
-        match *self {
-            SignClass::Positive(_) => true,
-            _ => false,
-        }
+        matches!(*self, SignClass::Positive(_))
     }
 
     /// Whether `self` is `SignClass::Negative(_)`.
     pub fn is_non_positive(&self) -> bool {
-        match *self {
-            SignClass::Negative(_) => true,
-            _ => false,
-        }
+        matches!(*self, SignClass::Negative(_))
     }
 }
 
@@ -47,7 +41,17 @@ impl Display for SignClass {
     }
 }
 
-impl<BOUND: Float> Interval<BOUND> {
+/// Displays a bound with `fp::Bound::fmt_bound`.
+struct Show<'a, BOUND: 'a>(&'a BOUND);
+
+impl<'a, BOUND: Bound> Display for Show<'a, BOUND> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.0.fmt_bound(f)
+    }
+}
+
+impl<BOUND: Bound> Interval<BOUND> {
     /// Constructs a new interval from given bounds.
     ///
     /// Lower bound must be less than or equal to upper bound. Only exception is when they are both
@@ -67,15 +71,14 @@ impl<BOUND: Float> Interval<BOUND> {
         assert!(
             !lo.is_nan() && !hi.is_nan() && lo <= hi || lo.is_nan() && hi.is_nan(),
             "invalid bounds: <{}, {}>",
-            lo,
-            hi
+            Show(&lo),
+            Show(&hi)
         );
         assert!(
-            !(lo.is_infinity() && hi.is_infinity())
-                && !(lo.is_neg_infinity() && hi.is_neg_infinity()),
+            !(lo.is_infinity() && hi.is_infinity() || lo.is_neg_infinity() && hi.is_neg_infinity()),
             "invalid bounds: <{}, {}>",
-            lo,
-            hi
+            Show(&lo),
+            Show(&hi)
         );
         Interval { lo, hi }
     }
@@ -127,24 +130,15 @@ impl<BOUND: Float> Interval<BOUND> {
         Self::new(BOUND::neg_infinity(precision), BOUND::infinity(precision))
     }
 
-    /// Constructs an interval from a float with given precision.
-    #[inline]
-    pub fn from_with_prec(val: f64, precision: usize) -> Self {
-        Self::new(
-            BOUND::from_lo(val, precision),
-            BOUND::from_hi(val, precision),
-        )
-    }
-
     /// Constructs an interval by parsing a string.
     ///
     /// Accepts `INTERVAL` according to the rule below.
     ///
     ///   INTERVAL = FLOAT | '<' FLOAT ',' FLOAT '>'
     pub fn from_str_with_prec(s: &str, precision: usize) -> Result<Self, ParseIntervalError> {
-        let lo = BOUND::from_str_lo(s, precision);
-        let hi = BOUND::from_str_hi(s, precision);
-        if let (Ok(lo), Ok(hi)) = (lo, hi) {
+        let lo = BOUND::parse_lo(s, precision);
+        let hi = BOUND::parse_hi(s, precision);
+        if let (Some(lo), Some(hi)) = (lo, hi) {
             Ok(Self::new(lo, hi))
         } else {
             if !s.starts_with('<') {
@@ -157,9 +151,9 @@ impl<BOUND: Float> Interval<BOUND> {
             let s = s.trim_end_matches('>').trim_end();
             let p: Vec<&str> = s.split(',').collect();
             if p.len() == 2 {
-                let lo = BOUND::from_str_lo(p[0].trim(), precision);
-                let hi = BOUND::from_str_hi(p[1].trim(), precision);
-                if let (Ok(lo), Ok(hi)) = (lo, hi) {
+                let lo = BOUND::parse_lo(p[0].trim(), precision);
+                let hi = BOUND::parse_hi(p[1].trim(), precision);
+                if let (Some(lo), Some(hi)) = (lo, hi) {
                     if !lo.is_nan() && !hi.is_nan() && lo <= hi || lo.is_nan() && hi.is_nan() {
                         Ok(Self::new(lo, hi))
                     } else {
@@ -247,6 +241,56 @@ impl<BOUND: Float> Interval<BOUND> {
         self.lo.sign() <= Sign::Zero && self.hi.sign() >= Sign::Zero
     }
 
+    /// Returns the intersection of `self` and `rhs`.
+    ///
+    /// The result is a NaN interval if `self` and `rhs` do not intersect.
+    #[inline]
+    pub fn intersection(self, rhs: Self) -> Self {
+        let precision = self.precision();
+        if self.is_nan() || rhs.is_nan() {
+            return Self::nan(precision);
+        }
+        let lo = self.lo.max(rhs.lo);
+        let hi = self.hi.min(rhs.hi);
+        if lo <= hi {
+            Self::new(lo, hi)
+        } else {
+            Self::nan(precision)
+        }
+    }
+
+    /// Returns the smallest interval that contains both `self` and `rhs`.
+    #[inline]
+    pub fn hull(self, rhs: Self) -> Self {
+        if self.is_nan() {
+            rhs
+        } else if rhs.is_nan() {
+            self
+        } else {
+            Self::new(self.lo.min(rhs.lo), self.hi.max(rhs.hi))
+        }
+    }
+
+    /// Returns the magnitude of `self`, that is the greatest absolute value of its elements.
+    ///
+    /// Returns NaN for NaN intervals.
+    #[inline]
+    pub fn mag(&self) -> BOUND {
+        self.lo.clone().abs().max(self.hi.clone().abs())
+    }
+
+    /// Returns the mignitude of `self`, that is the least absolute value of its elements.
+    ///
+    /// Returns NaN for NaN intervals.
+    #[inline]
+    pub fn mig(&self) -> BOUND {
+        if self.has_zero() && !self.is_nan() {
+            BOUND::zero(self.precision())
+        } else {
+            self.lo.clone().abs().min(self.hi.clone().abs())
+        }
+    }
+
     /// Cuts `self` into two at `val` and returns the left and right pieces as a pair.
     ///
     /// If `self` lies on only one side of `val`, the non-existent side will be a NaN interval.
@@ -264,6 +308,110 @@ impl<BOUND: Float> Interval<BOUND> {
             (Self::new(self.lo, val.clone()), Self::new(val, self.hi))
         }
     }
+
+    /// Widens `self` by `rel` times the magnitude of each bound plus `abs` on each side.
+    ///
+    /// For intervals that contain zero, this is the epsilon-inflation `self * <1 - rel, 1 + rel> +
+    /// <-abs, abs>`. Infinite bounds are left as is.
+    pub fn inflate(self, rel: BOUND, abs: BOUND) -> Self {
+        assert!(rel.sign() >= Sign::Zero && abs.sign() >= Sign::Zero);
+        if self.is_nan() {
+            return self;
+        }
+        let lo = if self.lo.is_finite() {
+            let d = self
+                .lo
+                .clone()
+                .abs()
+                .mul_hi(rel.clone())
+                .add_hi(abs.clone());
+            self.lo.sub_lo(d)
+        } else {
+            self.lo
+        };
+        let hi = if self.hi.is_finite() {
+            let d = self.hi.clone().abs().mul_hi(rel).add_hi(abs);
+            self.hi.add_hi(d)
+        } else {
+            self.hi
+        };
+        Self::new(lo, hi)
+    }
+}
+
+impl<BOUND: Float> Interval<BOUND> {
+    /// Constructs an interval from a float with given precision.
+    #[inline]
+    pub fn from_with_prec(val: f64, precision: usize) -> Self {
+        Self::new(
+            BOUND::from_lo(val, precision),
+            BOUND::from_hi(val, precision),
+        )
+    }
+
+    /// Returns a number in `self` that is as close to its midpoint as possible.
+    ///
+    /// The midpoint of the whole interval is zero, and the midpoint of other unbounded intervals is
+    /// the finite number of greatest magnitude on their side. Returns NaN for NaN intervals.
+    pub fn mid(&self) -> BOUND {
+        let precision = self.precision();
+        if self.is_nan() || self.is_singleton() {
+            return self.hi.clone();
+        }
+        match (self.lo.is_finite(), self.hi.is_finite()) {
+            (false, false) => BOUND::zero(precision),
+            (false, true) => self.lo.clone().next_up(),
+            (true, false) => self.hi.clone().next_down(),
+            (true, true) => {
+                let s = self.lo.clone().add_r(self.hi.clone(), Round::Nearest);
+                let m = if s.is_finite() {
+                    s.ldexp(-1)
+                } else {
+                    // Halving first avoids the overflow, and cannot underflow for such bounds.
+                    let lo = self.lo.clone().ldexp(-1);
+                    lo.add_r(self.hi.clone().ldexp(-1), Round::Nearest)
+                };
+                m.max(self.lo.clone()).min(self.hi.clone())
+            }
+        }
+    }
+
+    /// Returns the smallest number `r`, rounded up, such that `<mid - r, mid + r>` contains `self`
+    /// where `mid` is `self.mid()`.
+    ///
+    /// Returns positive infinity for unbounded intervals and NaN for NaN intervals.
+    pub fn rad(&self) -> BOUND {
+        let mid = self.mid();
+        let lo = mid.clone().sub_hi(self.lo.clone());
+        lo.max(self.hi.clone().sub_hi(mid))
+    }
+
+    /// Widens `self` by `eps` times its width on each side, and then moves each finite bound
+    /// outwards by at least one ulp.
+    ///
+    /// This is the `blow` operation of C-XSC and guarantees that the result contains `self` in its
+    /// interior unless `self` is unbounded.
+    pub fn blow(self, eps: BOUND) -> Self {
+        assert!(eps.sign() >= Sign::Zero);
+        if self.is_nan() {
+            return self;
+        }
+        let mut d = self.hi.clone().sub_hi(self.lo.clone()).mul_hi(eps);
+        if d.is_nan() {
+            d = BOUND::zero(self.precision());
+        }
+        let lo = if self.lo.is_finite() {
+            self.lo.sub_lo(d.clone()).next_down()
+        } else {
+            self.lo
+        };
+        let hi = if self.hi.is_finite() {
+            self.hi.add_hi(d).next_up()
+        } else {
+            self.hi
+        };
+        Self::new(lo, hi)
+    }
 }
 
 impl<BOUND: Float> From<f64> for Interval<BOUND> {
@@ -273,7 +421,7 @@ impl<BOUND: Float> From<f64> for Interval<BOUND> {
     }
 }
 
-impl<BOUND: Float> FromStr for Interval<BOUND> {
+impl<BOUND: Bound> FromStr for Interval<BOUND> {
     type Err = ParseIntervalError;
 
     #[inline]
@@ -282,31 +430,23 @@ impl<BOUND: Float> FromStr for Interval<BOUND> {
     }
 }
 
-impl<BOUND: Float> Display for Interval<BOUND> {
+impl<BOUND: Bound> Display for Interval<BOUND> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         if self.is_singleton() || self.is_nan() {
             // `self.lo` may be printed as -0, so we prefer `self.hi`.
-            Display::fmt(&self.hi, f)
+            self.hi.fmt_bound(f)
         } else {
-            if let Err(e) = f.write_char('<') {
-                return Err(e);
-            }
-            if let Err(e) = Display::fmt(&self.lo, f) {
-                return Err(e);
-            }
-            if let Err(e) = f.write_str(", ") {
-                return Err(e);
-            }
-            if let Err(e) = Display::fmt(&self.hi, f) {
-                return Err(e);
-            }
+            f.write_char('<')?;
+            self.lo.fmt_bound(f)?;
+            f.write_str(", ")?;
+            self.hi.fmt_bound(f)?;
             f.write_char('>')
         }
     }
 }
 
-impl<BOUND: Float> Into<(BOUND, BOUND)> for Interval<BOUND> {
-    fn into(self) -> (BOUND, BOUND) {
-        (self.lo, self.hi)
+impl<BOUND: Bound> From<Interval<BOUND>> for (BOUND, BOUND) {
+    fn from(i: Interval<BOUND>) -> Self {
+        (i.lo, i.hi)
     }
 }
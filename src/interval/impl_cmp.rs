@@ -1,10 +1,32 @@
 use super::def::Interval;
 
-use fp::Float;
+use fp;
+use fp::{Bound, TotalOrd};
 
-impl<BOUND: Float> PartialEq for Interval<BOUND> {
+use std::cmp::Ordering;
+use std::hash::Hasher;
+
+impl<BOUND: Bound> PartialEq for Interval<BOUND> {
     #[inline]
     fn eq(&self, rhs: &Self) -> bool {
         self.is_nan() && rhs.is_nan() || self.lo == rhs.lo && self.hi == rhs.hi
     }
 }
+
+/// Orders intervals lexicographically by their lower and then upper bounds.
+///
+/// Since both bounds of NaN are NaN, it is greater than all other intervals.
+impl<BOUND: Bound + TotalOrd> fp::TotalOrd for Interval<BOUND> {
+    #[inline]
+    fn cmp_total(&self, rhs: &Self) -> Ordering {
+        self.lo
+            .cmp_total(&rhs.lo)
+            .then_with(|| self.hi.cmp_total(&rhs.hi))
+    }
+
+    #[inline]
+    fn hash_total<H: Hasher>(&self, state: &mut H) {
+        self.lo.hash_total(state);
+        self.hi.hash_total(state);
+    }
+}
@@ -0,0 +1,114 @@
+use super::def::Interval;
+
+use fp::{Float, Sign};
+
+impl<BOUND: Float> Interval<BOUND> {
+    /// Applies a non-decreasing function to both bounds of `self`.
+    #[inline]
+    fn monotone<F>(self, f: F) -> Self
+    where
+        F: Fn(BOUND) -> BOUND,
+    {
+        if self.is_nan() {
+            self
+        } else {
+            Self::new(f(self.lo), f(self.hi))
+        }
+    }
+
+    /// Computes the greatest integer less than or equal to each element of `self`.
+    #[inline]
+    pub fn floor(self) -> Self {
+        self.monotone(BOUND::floor)
+    }
+
+    /// Computes the smallest integer greater than or equal to each element of `self`.
+    #[inline]
+    pub fn ceil(self) -> Self {
+        self.monotone(BOUND::ceil)
+    }
+
+    /// Rounds each element of `self` to the nearest integer. Ties go to the even integer.
+    #[inline]
+    pub fn round_ties_even(self) -> Self {
+        self.monotone(BOUND::round_ties_even)
+    }
+
+    /// Rounds each element of `self` to the nearest integer. Ties go away from zero.
+    #[inline]
+    pub fn round_ties_away(self) -> Self {
+        self.monotone(BOUND::round_ties_away)
+    }
+
+    /// Rounds each element of `self` towards zero.
+    #[inline]
+    pub fn trunc(self) -> Self {
+        self.monotone(BOUND::trunc)
+    }
+
+    /// Computes the sign (`-1`, `0` or `1`) of each element of `self`.
+    #[inline]
+    pub fn sign(self) -> Self {
+        let precision = self.precision();
+        self.monotone(|x| match x.sign() {
+            Sign::Negative => -BOUND::one(precision),
+            Sign::Zero => BOUND::zero(precision),
+            Sign::Positive => BOUND::one(precision),
+        })
+    }
+
+    fn frac_p_multi(self) -> Vec<Self> {
+        assert!(self.sign_class().is_non_negative());
+
+        let precision = self.precision();
+        if !self.hi.is_finite() {
+            return vec![Self::new(BOUND::zero(precision), BOUND::one(precision))];
+        }
+        let lo_int = self.lo.clone().trunc();
+        let hi_int = self.hi.clone().trunc();
+        if lo_int == hi_int {
+            return vec![Self::new(
+                self.lo.sub_lo(lo_int.clone()),
+                self.hi.sub_hi(lo_int),
+            )];
+        }
+        let mut intervals = Vec::<Self>::new();
+        intervals.push(Self::new(
+            self.lo.sub_lo(lo_int.clone()),
+            BOUND::one(precision),
+        ));
+        if hi_int.clone().sub_lo(lo_int) > BOUND::one(precision) {
+            intervals.push(Self::new(BOUND::zero(precision), BOUND::one(precision)));
+        }
+        intervals.push(Self::new(BOUND::zero(precision), self.hi.sub_hi(hi_int)));
+        intervals
+    }
+
+    /// Computes the fractional part of each element of `self` and returns a vector of intervals
+    /// minimally covering the result.
+    ///
+    /// The fractional part has the same sign as the element. See `fp::Integral::frac`.
+    pub fn frac_multi(self) -> Vec<Self> {
+        if self.is_nan() {
+            return vec![];
+        }
+        let precision = self.precision();
+        let mut intervals = Vec::<Self>::new();
+        let (self_n, self_p) = self.split(BOUND::zero(precision));
+        if !self_p.is_nan() {
+            intervals.append(&mut self_p.frac_p_multi());
+        }
+        if !self_n.is_nan() {
+            intervals.extend((-self_n).frac_p_multi().drain(..).map(|i| -i));
+        }
+        intervals
+    }
+
+    /// Computes the fractional part of each element of `self`.
+    ///
+    /// The fractional part has the same sign as the element. See `fp::Integral::frac`.
+    pub fn frac(self) -> Self {
+        let precision = self.precision();
+        Self::minimal_cover(self.frac_multi(), precision)
+    }
+}
@@ -0,0 +1,59 @@
+use super::def::{Interval, SignClass};
+
+use fp::{Bound, Float};
+
+impl<BOUND: Bound> Interval<BOUND> {
+    /// Computes the absolute value of each element of `self`.
+    pub fn abs(self) -> Self {
+        if self.is_nan() {
+            return self;
+        }
+        match self.sign_class() {
+            SignClass::Mixed => {
+                let precision = self.precision();
+                Self::new(BOUND::zero(precision), (-self.lo).max(self.hi))
+            }
+            SignClass::Zero | SignClass::Positive(_) => Self::new(self.lo.abs(), self.hi.abs()),
+            SignClass::Negative(_) => Self::new(self.hi.abs(), -self.lo),
+        }
+    }
+
+    /// Computes the minimum of each pair of elements of `self` and `rhs`.
+    pub fn min(self, rhs: Self) -> Self {
+        if self.is_nan() || rhs.is_nan() {
+            let precision = self.precision();
+            return Self::nan(precision);
+        }
+        Self::new(self.lo.min(rhs.lo), self.hi.min(rhs.hi))
+    }
+
+    /// Computes the maximum of each pair of elements of `self` and `rhs`.
+    pub fn max(self, rhs: Self) -> Self {
+        if self.is_nan() || rhs.is_nan() {
+            let precision = self.precision();
+            return Self::nan(precision);
+        }
+        Self::new(self.lo.max(rhs.lo), self.hi.max(rhs.hi))
+    }
+
+    /// Restricts each element of `self` to the range given by the elements of `min` and `max`.
+    ///
+    /// This is equivalent to `self.max(min).min(max)`.
+    #[inline]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        self.max(min).min(max)
+    }
+}
+
+impl<BOUND: Float> Interval<BOUND> {
+    /// Computes `sqrt(x^2 + y^2)` for each pair of elements `x` of `self` and `y` of `rhs`.
+    pub fn hypot(self, rhs: Self) -> Self {
+        if self.is_nan() || rhs.is_nan() {
+            let precision = self.precision();
+            return Self::nan(precision);
+        }
+        let x = self.abs();
+        let y = rhs.abs();
+        Self::new(x.lo.hypot_lo(y.lo), x.hi.hypot_hi(y.hi))
+    }
+}
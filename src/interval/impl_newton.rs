@@ -0,0 +1,164 @@
+use super::def::{Interval, Root, RootKind};
+
+use fp::Float;
+
+use std::cmp::Ordering;
+
+/// Default maximum number of Newton steps taken by `Interval::newton`.
+pub const DEFAULT_NEWTON_LIMIT: usize = 1000;
+
+impl<BOUND: Float> Interval<BOUND> {
+    /// Returns the width of `self` rounded up.
+    #[inline]
+    fn width_hi(&self) -> BOUND {
+        self.hi.clone().sub_hi(self.lo.clone())
+    }
+
+    /// Takes an interval Newton step `m - f(m) / df(self)` from the midpoint `m` of `self`, and
+    /// returns its intersections with `self` and whether `self` is proved to contain a unique root.
+    ///
+    /// The quotient is computed with `Interval::div_multi`, which gives two pieces when `df(self)`
+    /// contains zero. When both `f(m)` and `df(self)` contain zero, any quotient is possible, so
+    /// `self` is returned as is.
+    fn newton_step<F, DF>(self, f: &F, df: &DF) -> (Vec<Self>, bool)
+    where
+        F: Fn(Self) -> Self,
+        DF: Fn(Self) -> Self,
+    {
+        let m = Self::singleton(self.mid());
+        let fm = f(m.clone());
+        let dfx = df(self.clone());
+        if fm.is_nan() || dfx.is_nan() || fm.has_zero() && dfx.has_zero() {
+            return (vec![self], false);
+        }
+        let ns: Vec<_> = fm
+            .div_multi(dfx.clone())
+            .into_iter()
+            .map(|q| m.clone() - q)
+            .collect();
+        // A Newton step that maps `self` into itself proves that there is a root, and a derivative
+        // without zeros proves that there is at most one.
+        let unique = !dfx.has_zero() && ns.len() == 1 && self.lo <= ns[0].lo && ns[0].hi <= self.hi;
+        let pieces = ns
+            .into_iter()
+            .map(|n| n.intersection(self.clone()))
+            .filter(|n| !n.is_nan())
+            .collect();
+        (pieces, unique)
+    }
+
+    /// Finds all roots of `f` in `self` with the interval Newton method, given the derivative
+    /// `df` of `f`.
+    ///
+    /// Both `f` and `df` must compute enclosures of the ranges of the function and its derivative
+    /// over their arguments. The result is the same as `Interval::newton_with_limits` with zero
+    /// tolerance and `DEFAULT_NEWTON_LIMIT` steps.
+    #[inline]
+    pub fn newton<F, DF>(self, f: F, df: DF) -> Vec<Root<BOUND>>
+    where
+        F: Fn(Self) -> Self,
+        DF: Fn(Self) -> Self,
+    {
+        let tolerance = BOUND::zero(self.precision());
+        self.newton_with_limits(f, df, tolerance, DEFAULT_NEWTON_LIMIT)
+    }
+
+    /// Finds all roots of `f` in `self` with the interval Newton method, given the derivative
+    /// `df` of `f`.
+    ///
+    /// Returns disjoint enclosures in ascending order that together contain all roots of `f` in
+    /// `self`. An enclosure of `RootKind::Unique` is proved to contain exactly one root. It is
+    /// narrowed with Newton steps until it is no wider than `tolerance` or stops shrinking. An
+    /// enclosure of `RootKind::Possible` could not be proved to contain roots, nor to be free of
+    /// them, either because it is no wider than `tolerance` or because `limit` Newton steps have
+    /// been taken in total. Multiple roots, such as the root of `x^2`, are always possible roots.
+    ///
+    /// Parts of `self` that the Newton step does not shrink to at most half of their width are
+    /// bisected.
+    pub fn newton_with_limits<F, DF>(
+        self,
+        f: F,
+        df: DF,
+        tolerance: BOUND,
+        limit: usize,
+    ) -> Vec<Root<BOUND>>
+    where
+        F: Fn(Self) -> Self,
+        DF: Fn(Self) -> Self,
+    {
+        let mut stack = vec![self];
+        let mut roots = vec![];
+        let mut steps = 0;
+        while let Some(x) = stack.pop() {
+            let fx = f(x.clone());
+            if x.is_nan() || fx.is_nan() || !fx.has_zero() {
+                continue;
+            }
+            if steps >= limit || x.width_hi() <= tolerance {
+                roots.push(Root {
+                    interval: x,
+                    kind: RootKind::Possible,
+                });
+                continue;
+            }
+            steps += 1;
+            let (mut pieces, unique) = x.clone().newton_step(&f, &df);
+            if unique {
+                let mut y = pieces.pop().unwrap();
+                while steps < limit && y.width_hi() > tolerance {
+                    steps += 1;
+                    match y.clone().newton_step(&f, &df).0.pop() {
+                        Some(z) if z != y => y = z,
+                        _ => break,
+                    }
+                }
+                roots.push(Root {
+                    interval: y,
+                    kind: RootKind::Unique,
+                });
+            } else if pieces.len() == 1 {
+                let y = pieces.pop().unwrap();
+                if y != x && y.width_hi() <= x.width_hi().ldexp(-1) {
+                    stack.push(y);
+                    continue;
+                }
+                let m = y.mid();
+                if y.lo < m && m < y.hi {
+                    stack.push(Self::new(m.clone(), y.hi));
+                    stack.push(Self::new(y.lo, m));
+                } else {
+                    roots.push(Root {
+                        interval: y,
+                        kind: RootKind::Possible,
+                    });
+                }
+            } else {
+                stack.extend(pieces.into_iter().rev());
+            }
+        }
+        roots.sort_by(|r, s| {
+            r.interval
+                .lo
+                .partial_cmp(&s.interval.lo)
+                .unwrap_or(Ordering::Equal)
+        });
+        // Bisection may put a root on the boundary of two enclosures. Touching enclosures of unique
+        // roots hold the same root if the function is monotonic on their hull.
+        let mut merged: Vec<Root<BOUND>> = vec![];
+        for r in roots {
+            if let Some(last) = merged.last_mut() {
+                if last.interval.hi >= r.interval.lo {
+                    let hull = last.interval.clone().hull(r.interval);
+                    let monotonic = !df(hull.clone()).has_zero();
+                    if last.kind != RootKind::Unique || r.kind != RootKind::Unique || !monotonic {
+                        last.kind = RootKind::Possible;
+                    }
+                    last.interval = hull;
+                    continue;
+                }
+            }
+            merged.push(r);
+        }
+        merged
+    }
+}
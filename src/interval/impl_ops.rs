@@ -1,19 +1,24 @@
-use super::def::{Interval, SignClass};
+use super::def::{CancelError, Interval, SignClass};
 
-use fp::Float;
+use fp::{Bound, Float, Integer, Sign};
 
+use std::cell::{Cell, RefCell};
+use std::iter;
 use std::ops::{Add, Div, Mul, Neg, Sub};
 
-impl<BOUND: Float> Neg for Interval<BOUND> {
+impl<BOUND: Bound> Neg for Interval<BOUND> {
     type Output = Self;
 
     #[inline]
     fn neg(self) -> Self::Output {
+        if self.is_nan() {
+            return self; // the NaN of integer bounds cannot be negated
+        }
         Self::new(-self.hi, -self.lo)
     }
 }
 
-impl<BOUND: Float> Add<Self> for Interval<BOUND> {
+impl<BOUND: Bound> Add<Self> for Interval<BOUND> {
     type Output = Self;
 
     #[inline]
@@ -22,7 +27,7 @@ impl<BOUND: Float> Add<Self> for Interval<BOUND> {
     }
 }
 
-impl<BOUND: Float> Sub<Self> for Interval<BOUND> {
+impl<BOUND: Bound> Sub<Self> for Interval<BOUND> {
     type Output = Self;
 
     #[inline]
@@ -31,26 +36,89 @@ impl<BOUND: Float> Sub<Self> for Interval<BOUND> {
     }
 }
 
-impl<BOUND: Float> Mul<Self> for Interval<BOUND> {
-    type Output = Self;
+impl<BOUND: Float> Interval<BOUND> {
+    /// Computes the interval `z` such that `z + rhs` is `self`, which exists when the width of
+    /// `self` is not less than that of `rhs`.
+    ///
+    /// This is `cancelMinus` of IEEE 1788. The result is a NaN interval if `self` is empty and
+    /// `rhs` is bounded or empty. Returns `CancelError::Unbounded` if `self` or `rhs` is unbounded,
+    /// and `CancelError::WiderSubtrahend` if `rhs` is wider than `self` or empty while `self` is
+    /// not. Widths are compared exactly.
+    pub fn checked_cancel_minus(self, rhs: Self) -> Result<Self, CancelError> {
+        let precision = self.precision();
+        let unbounded = |x: &Self| x.lo.is_infinite() || x.hi.is_infinite();
+        if unbounded(&self) || unbounded(&rhs) {
+            return Err(CancelError::Unbounded);
+        }
+        if self.is_nan() {
+            return Ok(self);
+        }
+        if rhs.is_nan() {
+            return Err(CancelError::WiderSubtrahend);
+        }
+        // The sum is rounded only once, so its sign is that of the exact difference of widths.
+        let terms = [
+            self.hi.clone(),
+            -self.lo.clone(),
+            -rhs.hi.clone(),
+            rhs.lo.clone(),
+        ];
+        if BOUND::sum_lo(&terms, precision).sign() == Sign::Negative {
+            return Err(CancelError::WiderSubtrahend);
+        }
+        Ok(Self::new(self.lo.sub_lo(rhs.lo), self.hi.sub_hi(rhs.hi)))
+    }
 
+    /// Computes the interval `z` such that `z + rhs` is `self`, or the whole interval if there is
+    /// none.
+    ///
+    /// See `Interval::checked_cancel_minus` for details.
     #[inline]
-    fn mul(self, rhs: Self) -> Self::Output {
+    pub fn cancel_minus(self, rhs: Self) -> Self {
+        let precision = self.precision();
+        self.checked_cancel_minus(rhs)
+            .unwrap_or_else(|_| Self::whole(precision))
+    }
+
+    /// Computes the interval `z` such that `z - rhs` is `self`, which is `self.cancel_minus(-rhs)`.
+    ///
+    /// This is `cancelPlus` of IEEE 1788. See `Interval::checked_cancel_minus` for details.
+    #[inline]
+    pub fn checked_cancel_plus(self, rhs: Self) -> Result<Self, CancelError> {
+        self.checked_cancel_minus(-rhs)
+    }
+
+    /// Computes the interval `z` such that `z - rhs` is `self`, or the whole interval if there is
+    /// none.
+    ///
+    /// See `Interval::checked_cancel_minus` for details.
+    #[inline]
+    pub fn cancel_plus(self, rhs: Self) -> Self {
+        self.cancel_minus(-rhs)
+    }
+}
+
+impl<BOUND: Bound> Interval<BOUND> {
+    /// Multiplies `self` by `rhs` using `mul_lo` and `mul_hi` to compute the lower and upper
+    /// bounds respectively.
+    fn mul_with<LO, HI>(self, rhs: Self, mul_lo: LO, mul_hi: HI) -> Self
+    where
+        LO: Fn(BOUND, BOUND) -> BOUND,
+        HI: Fn(BOUND, BOUND) -> BOUND,
+    {
         match self.sign_class() {
             SignClass::Mixed => match rhs.sign_class() {
                 SignClass::Mixed => Self::new(
-                    self.lo
-                        .clone()
-                        .mul_lo(rhs.hi.clone())
-                        .min(self.hi.clone().mul_lo(rhs.lo.clone())),
-                    self.lo.mul_hi(rhs.lo).max(self.hi.mul_hi(rhs.hi)),
+                    mul_lo(self.lo.clone(), rhs.hi.clone())
+                        .min(mul_lo(self.hi.clone(), rhs.lo.clone())),
+                    mul_hi(self.lo, rhs.lo).max(mul_hi(self.hi, rhs.hi)),
                 ),
                 SignClass::Zero => rhs,
                 SignClass::Positive(_) => {
-                    Self::new(self.lo.mul_lo(rhs.hi.clone()), self.hi.mul_hi(rhs.hi))
+                    Self::new(mul_lo(self.lo, rhs.hi.clone()), mul_hi(self.hi, rhs.hi))
                 }
                 SignClass::Negative(_) => {
-                    Self::new(self.hi.mul_lo(rhs.lo.clone()), self.lo.mul_hi(rhs.lo))
+                    Self::new(mul_lo(self.hi, rhs.lo.clone()), mul_hi(self.lo, rhs.lo))
                 }
             },
             SignClass::Zero => {
@@ -62,27 +130,49 @@ impl<BOUND: Float> Mul<Self> for Interval<BOUND> {
             }
             SignClass::Positive(_) => match rhs.sign_class() {
                 SignClass::Mixed => {
-                    Self::new(self.hi.clone().mul_lo(rhs.lo), self.hi.mul_hi(rhs.hi))
+                    Self::new(mul_lo(self.hi.clone(), rhs.lo), mul_hi(self.hi, rhs.hi))
                 }
                 SignClass::Zero => rhs,
-                SignClass::Positive(_) => Self::new(self.lo.mul_lo(rhs.lo), self.hi.mul_hi(rhs.hi)),
-                SignClass::Negative(_) => Self::new(self.hi.mul_lo(rhs.lo), self.lo.mul_hi(rhs.hi)),
+                SignClass::Positive(_) => {
+                    Self::new(mul_lo(self.lo, rhs.lo), mul_hi(self.hi, rhs.hi))
+                }
+                SignClass::Negative(_) => {
+                    Self::new(mul_lo(self.hi, rhs.lo), mul_hi(self.lo, rhs.hi))
+                }
             },
             SignClass::Negative(_) => match rhs.sign_class() {
                 SignClass::Mixed => {
-                    Self::new(self.lo.clone().mul_lo(rhs.hi), self.lo.mul_hi(rhs.lo))
+                    Self::new(mul_lo(self.lo.clone(), rhs.hi), mul_hi(self.lo, rhs.lo))
                 }
                 SignClass::Zero => rhs,
-                SignClass::Positive(_) => Self::new(self.lo.mul_lo(rhs.hi), self.hi.mul_hi(rhs.lo)),
-                SignClass::Negative(_) => Self::new(self.hi.mul_lo(rhs.hi), self.lo.mul_hi(rhs.lo)),
+                SignClass::Positive(_) => {
+                    Self::new(mul_lo(self.lo, rhs.hi), mul_hi(self.hi, rhs.lo))
+                }
+                SignClass::Negative(_) => {
+                    Self::new(mul_lo(self.hi, rhs.hi), mul_hi(self.lo, rhs.lo))
+                }
             },
         }
     }
 }
 
-impl<BOUND: Float> Interval<BOUND> {
-    /// Divides `self` by `rhs` and returns a vector of intervals minimally covering the result.
-    pub fn div_multi(self, rhs: Self) -> Vec<Self> {
+impl<BOUND: Bound> Mul<Self> for Interval<BOUND> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_with(rhs, BOUND::mul_lo, BOUND::mul_hi)
+    }
+}
+
+impl<BOUND: Bound> Interval<BOUND> {
+    /// Divides `self` by `rhs` using `div_lo` and `div_hi` to compute the lower and upper bounds
+    /// respectively.
+    fn div_multi_with<LO, HI>(self, rhs: Self, div_lo: LO, div_hi: HI) -> Vec<Self>
+    where
+        LO: Fn(BOUND, BOUND) -> BOUND,
+        HI: Fn(BOUND, BOUND) -> BOUND,
+    {
         let precision = self.precision();
         match self.sign_class() {
             SignClass::Mixed => match rhs.sign_class() {
@@ -93,8 +183,8 @@ impl<BOUND: Float> Interval<BOUND> {
                         vec![Self::whole(precision)]
                     } else {
                         vec![Self::new(
-                            self.lo.div_lo(rhs.lo.clone()),
-                            self.hi.div_hi(rhs.lo),
+                            div_lo(self.lo, rhs.lo.clone()),
+                            div_hi(self.hi, rhs.lo),
                         )]
                     }
                 }
@@ -103,8 +193,8 @@ impl<BOUND: Float> Interval<BOUND> {
                         vec![Self::whole(precision)]
                     } else {
                         vec![Self::new(
-                            self.hi.div_lo(rhs.hi.clone()),
-                            self.lo.div_hi(rhs.hi),
+                            div_lo(self.hi, rhs.hi.clone()),
+                            div_hi(self.lo, rhs.hi),
                         )]
                     }
                 }
@@ -124,9 +214,9 @@ impl<BOUND: Float> Interval<BOUND> {
                         vec![
                             Interval::new(
                                 BOUND::neg_infinity(precision),
-                                self.lo.clone().div_hi(rhs.lo),
+                                div_hi(self.lo.clone(), rhs.lo),
                             ),
-                            Interval::new(self.lo.div_lo(rhs.hi), BOUND::infinity(precision)),
+                            Interval::new(div_lo(self.lo, rhs.hi), BOUND::infinity(precision)),
                         ]
                     }
                 }
@@ -134,21 +224,21 @@ impl<BOUND: Float> Interval<BOUND> {
                 SignClass::Positive(other_has_zero) => {
                     if other_has_zero {
                         vec![Self::new(
-                            self.lo.clone().div_lo(rhs.hi),
+                            div_lo(self.lo.clone(), rhs.hi),
                             BOUND::infinity(precision),
                         )]
                     } else {
-                        vec![Self::new(self.lo.div_lo(rhs.hi), self.hi.div_hi(rhs.lo))]
+                        vec![Self::new(div_lo(self.lo, rhs.hi), div_hi(self.hi, rhs.lo))]
                     }
                 }
                 SignClass::Negative(other_has_zero) => {
                     if other_has_zero {
                         vec![Self::new(
                             BOUND::neg_infinity(precision),
-                            self.lo.div_hi(rhs.lo),
+                            div_hi(self.lo, rhs.lo),
                         )]
                     } else {
-                        vec![Self::new(self.hi.div_lo(rhs.hi), self.lo.div_hi(rhs.lo))]
+                        vec![Self::new(div_lo(self.hi, rhs.hi), div_hi(self.lo, rhs.lo))]
                     }
                 }
             },
@@ -160,9 +250,9 @@ impl<BOUND: Float> Interval<BOUND> {
                         vec![
                             Interval::new(
                                 BOUND::neg_infinity(precision),
-                                self.hi.clone().div_hi(rhs.hi),
+                                div_hi(self.hi.clone(), rhs.hi),
                             ),
-                            Interval::new(self.hi.div_lo(rhs.lo), BOUND::infinity(precision)),
+                            Interval::new(div_lo(self.hi, rhs.lo), BOUND::infinity(precision)),
                         ]
                     }
                 }
@@ -171,20 +261,20 @@ impl<BOUND: Float> Interval<BOUND> {
                     if other_has_zero {
                         vec![Self::new(
                             BOUND::neg_infinity(precision),
-                            self.hi.div_hi(rhs.hi),
+                            div_hi(self.hi, rhs.hi),
                         )]
                     } else {
-                        vec![Self::new(self.lo.div_lo(rhs.lo), self.hi.div_hi(rhs.hi))]
+                        vec![Self::new(div_lo(self.lo, rhs.lo), div_hi(self.hi, rhs.hi))]
                     }
                 }
                 SignClass::Negative(other_has_zero) => {
                     if other_has_zero {
                         vec![Self::new(
-                            self.hi.div_lo(rhs.lo),
+                            div_lo(self.hi, rhs.lo),
                             BOUND::infinity(precision),
                         )]
                     } else {
-                        vec![Self::new(self.hi.div_lo(rhs.lo), self.lo.div_hi(rhs.hi))]
+                        vec![Self::new(div_lo(self.hi, rhs.lo), div_hi(self.lo, rhs.hi))]
                     }
                 }
             },
@@ -192,6 +282,13 @@ impl<BOUND: Float> Interval<BOUND> {
     }
 }
 
+impl<BOUND: Float> Interval<BOUND> {
+    /// Divides `self` by `rhs` and returns a vector of intervals minimally covering the result.
+    pub fn div_multi(self, rhs: Self) -> Vec<Self> {
+        self.div_multi_with(rhs, BOUND::div_lo, BOUND::div_hi)
+    }
+}
+
 impl<BOUND: Float> Div<Self> for Interval<BOUND> {
     type Output = Self;
 
@@ -201,3 +298,243 @@ impl<BOUND: Float> Div<Self> for Interval<BOUND> {
         Self::minimal_cover(self.div_multi(rhs), precision)
     }
 }
+
+impl<BOUND: Bound> Interval<BOUND> {
+    /// Adds `self` to `rhs` and tells whether both bounds of the result are exact.
+    #[inline]
+    pub fn add_exact(self, rhs: Self) -> (Self, bool) {
+        let (lo, lo_exact) = self.lo.add_lo_exact(rhs.lo);
+        let (hi, hi_exact) = self.hi.add_hi_exact(rhs.hi);
+        (Self::new(lo, hi), lo_exact && hi_exact)
+    }
+
+    /// Subtracts `rhs` from `self` and tells whether both bounds of the result are exact.
+    #[inline]
+    pub fn sub_exact(self, rhs: Self) -> (Self, bool) {
+        let (lo, lo_exact) = self.lo.sub_lo_exact(rhs.hi);
+        let (hi, hi_exact) = self.hi.sub_hi_exact(rhs.lo);
+        (Self::new(lo, hi), lo_exact && hi_exact)
+    }
+
+    /// Multiplies `self` by `rhs` and tells whether both bounds of the result are exact.
+    ///
+    /// When both operands contain zero in their interiors, all candidate bounds must be exact for
+    /// the result to be reported as exact.
+    pub fn mul_exact(self, rhs: Self) -> (Self, bool) {
+        let exact = Cell::new(true);
+        let res = self.mul_with(
+            rhs,
+            |x, y| {
+                let (z, e) = x.mul_lo_exact(y);
+                exact.set(exact.get() && e);
+                z
+            },
+            |x, y| {
+                let (z, e) = x.mul_hi_exact(y);
+                exact.set(exact.get() && e);
+                z
+            },
+        );
+        (res, exact.get())
+    }
+}
+
+impl<BOUND: Float> Interval<BOUND> {
+    /// Divides `self` by `rhs`, returns a vector of intervals minimally covering the result and
+    /// tells whether all bounds of the result are exact.
+    pub fn div_multi_exact(self, rhs: Self) -> (Vec<Self>, bool) {
+        let exact = Cell::new(true);
+        let intervals = self.div_multi_with(
+            rhs,
+            |x, y| {
+                let (z, e) = x.div_lo_exact(y);
+                exact.set(exact.get() && e);
+                z
+            },
+            |x, y| {
+                let (z, e) = x.div_hi_exact(y);
+                exact.set(exact.get() && e);
+                z
+            },
+        );
+        (intervals, exact.get())
+    }
+
+    /// Divides `self` by `rhs` and tells whether all bounds of the result are exact.
+    pub fn div_exact(self, rhs: Self) -> (Self, bool) {
+        let precision = self.precision();
+        let (intervals, exact) = self.div_multi_exact(rhs);
+        (Self::minimal_cover(intervals, precision), exact)
+    }
+}
+
+impl<BOUND: Float> Interval<BOUND> {
+    /// Returns pairs of bounds whose exact products are the lower and the upper bound of
+    /// `self * rhs` respectively.
+    fn mul_pairs(self, rhs: Self) -> ((BOUND, BOUND), (BOUND, BOUND)) {
+        let precision = self.precision();
+        let (los, his) = (RefCell::new(vec![]), RefCell::new(vec![]));
+        let res = self.mul_with(
+            rhs,
+            |x, y| {
+                los.borrow_mut().push((x.clone(), y.clone()));
+                x.mul_lo(y)
+            },
+            |x, y| {
+                his.borrow_mut().push((x.clone(), y.clone()));
+                x.mul_hi(y)
+            },
+        );
+        let pick = |mut pairs: Vec<(BOUND, BOUND)>, bound: BOUND, lower: bool| match pairs.len() {
+            0 => (bound, BOUND::one(precision)),
+            1 => pairs.remove(0),
+            _ => {
+                // Both operands contain zero in their interiors, so the candidates are compared
+                // through the sign of the exact difference of their products.
+                let (q, p) = (pairs.remove(1), pairs.remove(0));
+                let diff = BOUND::dot_hi(
+                    &[p.0.clone(), -q.0.clone()],
+                    &[p.1.clone(), q.1.clone()],
+                    precision,
+                );
+                if (diff.sign() == Sign::Positive) == lower {
+                    q
+                } else {
+                    p
+                }
+            }
+        };
+        (
+            pick(los.into_inner(), res.lo, true),
+            pick(his.into_inner(), res.hi, false),
+        )
+    }
+
+    /// Computes `self * a + b` and rounds each bound of the result only once.
+    pub fn fma(self, a: Self, b: Self) -> Self {
+        if self.is_nan() || a.is_nan() || b.is_nan() {
+            return Self::nan(self.precision());
+        }
+        if self.is_zero() || a.is_zero() {
+            return b;
+        }
+        let (lo, hi) = (b.lo, b.hi);
+        self.mul_with(
+            a,
+            |x, y| x.fma_lo(y, lo.clone()),
+            |x, y| x.fma_hi(y, hi.clone()),
+        )
+    }
+
+    /// Computes the sum of `xs` and rounds each bound of the result only once.
+    ///
+    /// The sum of no intervals is zero with given precision.
+    pub fn sum(xs: &[Self], precision: usize) -> Self {
+        if xs.iter().any(Self::is_nan) {
+            return Self::nan(precision);
+        }
+        let los: Vec<_> = xs.iter().map(|x| x.lo.clone()).collect();
+        let his: Vec<_> = xs.iter().map(|x| x.hi.clone()).collect();
+        Self::new(
+            BOUND::sum_lo(&los, precision),
+            BOUND::sum_hi(&his, precision),
+        )
+    }
+
+    /// Computes the dot product of `xs` and `ys` and rounds each bound of the result only once.
+    ///
+    /// The dot product of no intervals is zero with given precision. Panics if `xs` and `ys` have
+    /// different lengths.
+    pub fn dot(xs: &[Self], ys: &[Self], precision: usize) -> Self {
+        assert_eq!(xs.len(), ys.len());
+        if xs.iter().chain(ys).any(Self::is_nan) {
+            return Self::nan(precision);
+        }
+        let (mut lo_xs, mut lo_ys) = (vec![], vec![]);
+        let (mut hi_xs, mut hi_ys) = (vec![], vec![]);
+        for (x, y) in xs.iter().zip(ys) {
+            let ((a, b), (c, d)) = x.clone().mul_pairs(y.clone());
+            lo_xs.push(a);
+            lo_ys.push(b);
+            hi_xs.push(c);
+            hi_ys.push(d);
+        }
+        Self::new(
+            BOUND::dot_lo(&lo_xs, &lo_ys, precision),
+            BOUND::dot_hi(&hi_xs, &hi_ys, precision),
+        )
+    }
+}
+
+impl<BOUND: Float> iter::Sum for Interval<BOUND> {
+    /// Rounds each bound of the result only once, unlike repeated addition.
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let xs: Vec<_> = iter.collect();
+        let precision = xs.first().map_or(53, Self::precision);
+        Interval::sum(&xs, precision)
+    }
+}
+
+impl<BOUND: Bound> iter::Product for Interval<BOUND> {
+    fn product<I: Iterator<Item = Self>>(mut iter: I) -> Self {
+        match iter.next() {
+            Some(first) => iter.fold(first, |p, x| p * x),
+            None => Self::one(53),
+        }
+    }
+}
+
+impl<BOUND: Integer> Interval<BOUND> {
+    /// Divides `self` by `rhs` with each quotient rounded towards negative infinity, and returns
+    /// a vector of intervals minimally covering the result.
+    ///
+    /// Integer division by zero is undefined, so zero is excluded from `rhs`.
+    pub fn div_floor_multi(self, rhs: Self) -> Vec<Self> {
+        if self.is_nan() || rhs.is_nan() {
+            return vec![];
+        }
+        let precision = self.precision();
+        let one = BOUND::one(precision);
+        let mut intervals = vec![];
+        if rhs.lo.sign() == Sign::Negative {
+            let hi = rhs.hi.clone().min(-one.clone());
+            let rhs_n = Self::new(rhs.lo.clone(), hi);
+            intervals.append(&mut self.clone().div_multi_with(
+                rhs_n,
+                BOUND::floor_div,
+                BOUND::floor_div,
+            ));
+        }
+        if rhs.hi.sign() == Sign::Positive {
+            let rhs_p = Self::new(rhs.lo.max(one), rhs.hi);
+            intervals.append(&mut self.div_multi_with(rhs_p, BOUND::floor_div, BOUND::floor_div));
+        }
+        intervals
+    }
+
+    /// Divides `self` by `rhs` with each quotient rounded towards negative infinity, and returns
+    /// the minimal interval covering the result.
+    #[inline]
+    pub fn div_floor(self, rhs: Self) -> Self {
+        let precision = self.precision();
+        Self::minimal_cover(self.div_floor_multi(rhs), precision)
+    }
+}
+
+impl Div<Self> for Interval<i64> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_floor(rhs)
+    }
+}
+
+impl Div<Self> for Interval<i128> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_floor(rhs)
+    }
+}
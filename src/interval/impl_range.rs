@@ -0,0 +1,136 @@
+use super::def::{Interval, RangeForm};
+
+use fp::{Float, Round};
+use intervalset::IntervalSet;
+use slope::Slope;
+
+impl<BOUND: Float> Interval<BOUND> {
+    /// Returns the centers of the bicentered form of Baumann for the derivative enclosure `d`,
+    /// which maximize the lower bound and minimize the upper bound of the mean value form.
+    ///
+    /// The centers need not be exact, so they are computed rounded to the nearest and moved into
+    /// `self`. Falls back to the midpoint of `self` if `self` or `d` is unbounded.
+    fn bicenters(&self, d: &Self) -> (BOUND, BOUND) {
+        let zero = BOUND::zero(self.precision());
+        if d.lo >= zero {
+            (self.lo.clone(), self.hi.clone())
+        } else if d.hi <= zero {
+            (self.hi.clone(), self.lo.clone())
+        } else if [&self.lo, &self.hi, &d.lo, &d.hi]
+            .iter()
+            .all(|x| x.is_finite())
+        {
+            let center = |x: &BOUND, y: &BOUND| {
+                let a = d.hi.clone().mul_r(x.clone(), Round::Nearest);
+                let b = d.lo.clone().mul_r(y.clone(), Round::Nearest);
+                let c = a.sub_r(b, Round::Nearest);
+                let c = c.div_r(
+                    d.hi.clone().sub_r(d.lo.clone(), Round::Nearest),
+                    Round::Nearest,
+                );
+                if c.is_finite() {
+                    c.max(self.lo.clone()).min(self.hi.clone())
+                } else {
+                    self.mid()
+                }
+            };
+            (center(&self.lo, &self.hi), center(&self.hi, &self.lo))
+        } else {
+            (self.mid(), self.mid())
+        }
+    }
+
+    /// Encloses the range of `f` over `self` with the mean value form `f(c) + d * (self - c)`
+    /// around the center `c`, given the derivative enclosure `d`.
+    fn mean_value_at<F>(self, f: &F, c: BOUND, d: Self) -> Self
+    where
+        F: Fn(Slope<Self>) -> Slope<Self>,
+    {
+        let c = Self::singleton(c);
+        f(Slope::constant(c.clone())).range + d * (self - c)
+    }
+
+    /// Encloses the range of `f` over `self` with the given form.
+    ///
+    /// `f` is evaluated with slope arithmetic, so that one closure serves all forms.
+    /// `RangeForm::Natural` evaluates `f` once over `self`, and is the best form for wide inputs.
+    /// The other forms are centered forms, whose overestimation shrinks quadratically with the
+    /// width of `self`, so that they are much tighter for narrow inputs where a variable occurs
+    /// more than once, as in `x - x * x`. `RangeForm::MeanValue` expands `f` around the midpoint
+    /// with an enclosure of the derivative, and `RangeForm::Slope` with an enclosure of the slope,
+    /// which is never wider. `RangeForm::Bicentered` intersects the mean value forms around the
+    /// centers of Baumann, which give the greatest lower bound and the least upper bound, and is
+    /// the exact range of monotonic functions up to rounding.
+    ///
+    /// Returns NaN if `self` is NaN.
+    pub fn range<F>(self, f: F, form: RangeForm) -> Self
+    where
+        F: Fn(Slope<Self>) -> Slope<Self>,
+    {
+        if self.is_nan() {
+            return self;
+        }
+        match form {
+            RangeForm::Natural => f(Slope::constant(self)).range,
+            RangeForm::MeanValue => {
+                let d = f(Slope::variable(self.clone(), self.clone())).slope;
+                let c = self.mid();
+                self.mean_value_at(&f, c, d)
+            }
+            RangeForm::Slope => {
+                let c = Self::singleton(self.mid());
+                let s = f(Slope::variable(self.clone(), c.clone()));
+                s.center + s.slope * (self - c)
+            }
+            RangeForm::Bicentered => {
+                let d = f(Slope::variable(self.clone(), self.clone())).slope;
+                let (lo, hi) = self.bicenters(&d);
+                let lower = self.clone().mean_value_at(&f, lo, d.clone());
+                let upper = self.mean_value_at(&f, hi, d);
+                lower.intersection(upper)
+            }
+        }
+    }
+
+    /// Encloses the range of `f` over `self` with the given form over a uniform subdivision of
+    /// `self`.
+    ///
+    /// `self` is split at the midpoints of its pieces with `Interval::split` until the range of
+    /// every piece is no wider than `width`, or until splitting again would give more than `limit`
+    /// pieces. The ranges of the pieces are merged into an interval set, whose gaps are proved to
+    /// be outside the range of `f`. See `Interval::range` for the forms.
+    pub fn range_subdivided<F>(
+        self,
+        f: F,
+        form: RangeForm,
+        width: BOUND,
+        limit: usize,
+    ) -> IntervalSet<BOUND>
+    where
+        F: Fn(Slope<Self>) -> Slope<Self>,
+    {
+        let mut pieces = vec![self];
+        loop {
+            let ranges: Vec<_> = pieces.iter().map(|x| x.clone().range(&f, form)).collect();
+            let narrow = ranges
+                .iter()
+                .all(|r| r.hi.clone().sub_hi(r.lo.clone()) <= width);
+            if narrow || pieces.len() * 2 > limit {
+                return IntervalSet::from_intervals(ranges);
+            }
+            let n = pieces.len();
+            pieces = pieces
+                .into_iter()
+                .flat_map(|x| {
+                    let m = x.mid();
+                    let (l, r) = x.split(m);
+                    vec![l, r]
+                })
+                .filter(|x| !x.is_nan())
+                .collect();
+            if pieces.len() == n {
+                return IntervalSet::from_intervals(ranges);
+            }
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use super::def::{Interval, SignClass};
+
+use fp::{Float, Sign};
+
+impl<BOUND: Float> Interval<BOUND> {
+    /// Computes `self - n * rhs`, where `n` is `self / rhs` rounded to an integer using `int_op`,
+    /// and returns a vector of intervals minimally covering the result.
+    ///
+    /// `rhs` must be strictly positive and every possible result must lie in `range`. If the
+    /// quotient range covers more than three integers, `range` itself is returned.
+    fn rem_multi_with<F>(self, rhs: Self, int_op: F, range: Self) -> Vec<Self>
+    where
+        F: Fn(BOUND) -> BOUND,
+    {
+        assert_eq!(rhs.sign_class(), SignClass::Positive(false));
+
+        if self.is_nan() || range.is_nan() {
+            return vec![];
+        }
+        let precision = self.precision();
+        let q = self.clone() / rhs.clone();
+        let n_lo = int_op(q.lo);
+        let n_hi = int_op(q.hi);
+        let span = n_hi.clone().sub_hi(n_lo.clone());
+        if span.is_nan() || span > BOUND::from_hi(2.0, precision) {
+            return vec![range];
+        }
+        let mut intervals = Vec::<Self>::new();
+        let one = BOUND::one(precision);
+        let mut n = n_lo;
+        while n <= n_hi {
+            let shift = Self::singleton(n.clone()) * rhs.clone();
+            intervals.push((self.clone() - shift).intersection(range.clone()));
+            let (next, exact) = n.add_hi_exact(one.clone());
+            if !exact {
+                return vec![range];
+            }
+            n = next;
+        }
+        intervals.retain(|i| !i.is_nan());
+        intervals
+    }
+
+    /// Returns the greatest magnitude in `self`.
+    #[inline]
+    fn max_abs(&self) -> BOUND {
+        self.lo.clone().abs().max(self.hi.clone().abs())
+    }
+
+    /// Returns `self` if it is positive and `-self` if it is negative.
+    #[inline]
+    fn into_positive(self) -> Self {
+        if self.lo.sign() == Sign::Positive {
+            self
+        } else {
+            -self
+        }
+    }
+
+    fn fmod_p_multi(self, rhs: Self) -> Vec<Self> {
+        assert!(self.sign_class().is_non_negative());
+
+        let precision = self.precision();
+        let range = Self::new(BOUND::zero(precision), self.hi.clone().min(rhs.max_abs()));
+        if rhs.has_zero() {
+            vec![range]
+        } else {
+            self.rem_multi_with(rhs.into_positive(), BOUND::trunc, range)
+        }
+    }
+
+    /// Computes the remainder of `self / rhs` with the quotient rounded towards zero, and returns
+    /// a vector of intervals minimally covering the result.
+    ///
+    /// This is the `fmod` function of C, hence the result has the same sign as the dividend. If
+    /// `self / rhs` covers at most three integers, the result is made of the corresponding shifted
+    /// pieces of `self`; otherwise, the result is covered by a single interval.
+    pub fn fmod_multi(self, rhs: Self) -> Vec<Self> {
+        if self.is_nan() || rhs.is_nan() || rhs.is_zero() {
+            return vec![];
+        }
+        if self.is_singleton() && rhs.is_singleton() {
+            let res = Self::new(self.lo.fmod_lo(rhs.lo), self.hi.fmod_hi(rhs.hi));
+            return if res.is_nan() { vec![] } else { vec![res] };
+        }
+        let precision = self.precision();
+        let mut intervals = Vec::<Self>::new();
+        let (self_n, self_p) = self.split(BOUND::zero(precision));
+        if !self_p.is_nan() {
+            intervals.append(&mut self_p.fmod_p_multi(rhs.clone()));
+        }
+        if !self_n.is_nan() {
+            intervals.extend((-self_n).fmod_p_multi(rhs).drain(..).map(|i| -i));
+        }
+        intervals
+    }
+
+    /// Computes the remainder of `self / rhs` with the quotient rounded towards zero.
+    ///
+    /// See `Interval::fmod_multi` for details.
+    pub fn fmod(self, rhs: Self) -> Self {
+        let precision = self.precision();
+        Self::minimal_cover(self.fmod_multi(rhs), precision)
+    }
+
+    fn remainder_p_multi(self, rhs: Self) -> Vec<Self> {
+        assert!(self.sign_class().is_non_negative());
+
+        let precision = self.precision();
+        let half = self
+            .hi
+            .clone()
+            .min(rhs.max_abs().div_hi(BOUND::from_lo(2.0, precision)));
+        let range = Self::new(-half.clone(), half);
+        if rhs.has_zero() {
+            vec![range]
+        } else {
+            self.rem_multi_with(rhs.into_positive(), BOUND::round_ties_even, range)
+        }
+    }
+
+    /// Computes the remainder of `self / rhs` with the quotient rounded to the nearest integer,
+    /// and returns a vector of intervals minimally covering the result.
+    ///
+    /// This is the `remainder` function of IEEE 754, hence ties go to the even quotient. See
+    /// `Interval::fmod_multi` for the form of the result.
+    pub fn remainder_multi(self, rhs: Self) -> Vec<Self> {
+        if self.is_nan() || rhs.is_nan() || rhs.is_zero() {
+            return vec![];
+        }
+        if self.is_singleton() && rhs.is_singleton() {
+            let res = Self::new(self.lo.remainder_lo(rhs.lo), self.hi.remainder_hi(rhs.hi));
+            return if res.is_nan() { vec![] } else { vec![res] };
+        }
+        let precision = self.precision();
+        let mut intervals = Vec::<Self>::new();
+        let (self_n, self_p) = self.split(BOUND::zero(precision));
+        if !self_p.is_nan() {
+            intervals.append(&mut self_p.remainder_p_multi(rhs.clone()));
+        }
+        if !self_n.is_nan() {
+            intervals.extend((-self_n).remainder_p_multi(rhs).drain(..).map(|i| -i));
+        }
+        intervals
+    }
+
+    /// Computes the remainder of `self / rhs` with the quotient rounded to the nearest integer.
+    ///
+    /// See `Interval::remainder_multi` for details.
+    pub fn remainder(self, rhs: Self) -> Self {
+        let precision = self.precision();
+        Self::minimal_cover(self.remainder_multi(rhs), precision)
+    }
+
+    /// Computes the least non-negative remainder of `self / rhs`, and returns a vector of
+    /// intervals minimally covering the result.
+    ///
+    /// The result always lies in `<0, abs(rhs)>`. See `Interval::fmod_multi` for the form of the
+    /// result.
+    pub fn rem_euclid_multi(self, rhs: Self) -> Vec<Self> {
+        if self.is_nan() || rhs.is_nan() || rhs.is_zero() {
+            return vec![];
+        }
+        let precision = self.precision();
+        let rhs_max = rhs.max_abs();
+        if self.is_singleton() && rhs.is_singleton() {
+            let r_lo = self.lo.clone().fmod_lo(rhs_max.clone());
+            let r_hi = self.hi.clone().fmod_hi(rhs_max.clone());
+            if r_lo.is_nan() || r_hi.is_nan() {
+                return vec![];
+            } else if r_lo.sign() >= Sign::Zero {
+                return vec![Self::new(r_lo, r_hi)];
+            } else if r_hi.sign() < Sign::Zero {
+                return vec![Self::new(
+                    r_lo.add_lo(rhs_max.clone()),
+                    r_hi.add_hi(rhs_max),
+                )];
+            }
+        }
+        let range = if self.lo.sign() >= Sign::Zero {
+            Self::new(BOUND::zero(precision), self.hi.clone().min(rhs_max))
+        } else {
+            Self::new(BOUND::zero(precision), rhs_max)
+        };
+        if rhs.has_zero() {
+            vec![range]
+        } else {
+            self.rem_multi_with(rhs.into_positive(), BOUND::floor, range)
+        }
+    }
+
+    /// Computes the least non-negative remainder of `self / rhs`.
+    ///
+    /// See `Interval::rem_euclid_multi` for details.
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        let precision = self.precision();
+        Self::minimal_cover(self.rem_euclid_multi(rhs), precision)
+    }
+}
@@ -0,0 +1,178 @@
+use super::def::Interval;
+
+use fp::Float;
+use intervalset::IntervalSet;
+use transc::Transc;
+
+impl<BOUND: Float> Interval<BOUND> {
+    /// Returns the non-negative part of `self`, or a NaN interval if there is none.
+    fn non_negative_part(self) -> Self {
+        let precision = self.precision();
+        self.intersection(Self::new(
+            BOUND::zero(precision),
+            BOUND::infinity(precision),
+        ))
+    }
+
+    /// Returns the intersections of `self` with each of `intervals` as an interval set.
+    fn intersect_all(self, intervals: Vec<Self>) -> IntervalSet<BOUND> {
+        IntervalSet::from_intervals(
+            intervals
+                .into_iter()
+                .map(|i| i.intersection(self.clone()))
+                .collect(),
+        )
+    }
+
+    /// Returns the elements of `self` whose absolute value is in `mag`, which is non-negative.
+    fn signed_rev(self, mag: Self) -> IntervalSet<BOUND> {
+        if mag.is_nan() {
+            return IntervalSet::empty();
+        }
+        self.intersect_all(vec![-mag.clone(), mag])
+    }
+
+    /// Encloses all elements `x` of `self` such that `x * y` is in `result` for some `y` in `rhs`.
+    ///
+    /// This is `mulRev` of IEEE 1788. Unlike `result / rhs`, this keeps all of `self` when both
+    /// `result` and `rhs` contain zero, because `x * 0` is zero for any `x`.
+    pub fn mul_rev(self, rhs: Self, result: Self) -> IntervalSet<BOUND> {
+        if self.is_nan() || rhs.is_nan() || result.is_nan() {
+            IntervalSet::empty()
+        } else if rhs.has_zero() && result.has_zero() {
+            IntervalSet::from_interval(self)
+        } else {
+            self.intersect_all(result.div_multi(rhs))
+        }
+    }
+
+    /// Encloses all `x` such that `x * y` is in `result` for some `y` in `rhs` with two intervals.
+    ///
+    /// This is `mulRevToPair` of IEEE 1788. The first interval is NaN if there is no such `x`, and
+    /// the second interval is NaN unless the enclosure has a gap, in which case it is the greater
+    /// one.
+    pub fn mul_rev_to_pair(rhs: Self, result: Self) -> (Self, Self) {
+        let precision = rhs.precision();
+        let mut intervals = Self::whole(precision)
+            .mul_rev(rhs, result)
+            .intervals
+            .into_iter();
+        let first = intervals.next().unwrap_or_else(|| Self::nan(precision));
+        let second = intervals.next().unwrap_or_else(|| Self::nan(precision));
+        (first, second)
+    }
+
+    /// Encloses all elements `x` of `self` such that `x / y` is in `result` for some `y` in `rhs`.
+    ///
+    /// This is `divRev1` of IEEE 1788. The result is a NaN interval if there is no such `x`.
+    pub fn div_rev1(self, rhs: Self, result: Self) -> Self {
+        if rhs.is_zero() {
+            return Self::nan(self.precision());
+        }
+        self.intersection(result * rhs)
+    }
+
+    /// Encloses all elements `y` of `self` such that `x / y` is in `result` for some `x` in `lhs`.
+    ///
+    /// This is `divRev2` of IEEE 1788, which is the same as `self.mul_rev(result, lhs)`.
+    #[inline]
+    pub fn div_rev2(self, lhs: Self, result: Self) -> IntervalSet<BOUND> {
+        self.mul_rev(result, lhs)
+    }
+
+    /// Encloses all elements `x` of `self` such that `x^2` is in `result`.
+    ///
+    /// This is `sqrRev` of IEEE 1788, with the negative and positive square roots of `result` as
+    /// separate intervals.
+    pub fn sqr_rev(self, result: Self) -> IntervalSet<BOUND> {
+        let precision = self.precision();
+        let result = result.non_negative_part();
+        if result.is_nan() {
+            return IntervalSet::empty();
+        }
+        let half = BOUND::from_lo(0.5, precision);
+        let root = Self::new(result.lo.pow_lo(half.clone()), result.hi.pow_hi(half));
+        self.signed_rev(root)
+    }
+
+    /// Encloses all elements `x` of `self` such that `|x|` is in `result`.
+    ///
+    /// This is `absRev` of IEEE 1788, with the negative and positive elements as separate
+    /// intervals.
+    #[inline]
+    pub fn abs_rev(self, result: Self) -> IntervalSet<BOUND> {
+        self.signed_rev(result.non_negative_part())
+    }
+
+    /// Encloses all elements `x` of `self` such that `x^y` is in `result` for some `y` in `rhs`.
+    ///
+    /// This is `powRev1` of IEEE 1788, extended to negative `x` in the same way as `pow`, which
+    /// gives both `-|x|^y` and `|x|^y`. Positive `x` satisfy `y * log(x)` in `log(result)`, and
+    /// negative `x` satisfy `y * log(|x|)` in `log(|result|)`.
+    pub fn pow_rev1(self, rhs: Self, result: Self) -> IntervalSet<BOUND> {
+        if self.is_nan() || rhs.is_nan() || result.is_nan() {
+            return IntervalSet::empty();
+        }
+        let precision = self.precision();
+        let base = |result: Self| {
+            let log = result.non_negative_part().log();
+            let mut intervals = vec![];
+            if !log.is_nan() {
+                for i in Self::whole(precision).mul_rev(rhs.clone(), log).intervals {
+                    intervals.push(i.exp());
+                }
+            }
+            intervals
+        };
+        let mut intervals = base(result.clone());
+        intervals.extend(base(result.clone().abs()).into_iter().map(|i| -i));
+        if result.has_zero() {
+            intervals.push(Self::zero(precision));
+        }
+        self.intersect_all(intervals)
+    }
+
+    /// Encloses all elements `y` of `self` such that `x^y` is in `result` for some `x` in `lhs`.
+    ///
+    /// This is `powRev2` of IEEE 1788, extended to negative `x` as in `pow_rev1`. All of `self` is
+    /// kept if `lhs` contains zero and `result` contains zero or one.
+    pub fn pow_rev2(self, lhs: Self, result: Self) -> IntervalSet<BOUND> {
+        if self.is_nan() || lhs.is_nan() || result.is_nan() {
+            return IntervalSet::empty();
+        }
+        let precision = self.precision();
+        let one = BOUND::one(precision);
+        if lhs.has_zero() && (result.has_zero() || (result.lo <= one && one <= result.hi)) {
+            return IntervalSet::from_interval(self);
+        }
+        let exponent = |lhs: Self, result: Self| {
+            let log_lhs = lhs.non_negative_part().log();
+            let log_result = result.non_negative_part().log();
+            if log_lhs.is_nan() || log_result.is_nan() {
+                vec![]
+            } else {
+                self.clone().mul_rev(log_lhs, log_result).intervals
+            }
+        };
+        let mut intervals = exponent(lhs.clone(), result.clone());
+        intervals.append(&mut exponent(-lhs, result.abs()));
+        IntervalSet::from_intervals(intervals)
+    }
+
+    /// Encloses all elements `x` of `self` such that `exp(x)` is in `result`.
+    ///
+    /// This is `expRev` of IEEE 1788. The result is a NaN interval if there is no such `x`.
+    #[inline]
+    pub fn exp_rev(self, result: Self) -> Self {
+        let log = result.non_negative_part().log();
+        self.intersection(log)
+    }
+
+    /// Encloses all elements `x` of `self` such that `log(x)` is in `result`.
+    ///
+    /// This is `logRev` of IEEE 1788. The result is a NaN interval if there is no such `x`.
+    #[inline]
+    pub fn log_rev(self, result: Self) -> Self {
+        self.intersection(result.exp())
+    }
+}
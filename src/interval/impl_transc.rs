@@ -1,10 +1,15 @@
 use super::def::{Interval, SignClass};
 
-use fp::{Float, Sign};
+use fp::{Float, Round, Sign};
 use transc::Transc;
 
+use std::cell::Cell;
+
 impl<BOUND: Float> Interval<BOUND> {
-    fn pow_a_sp_multi(self, rhs: BOUND) -> Vec<Self> {
+    fn pow_a_sp_multi<P>(self, rhs: BOUND, pow: &P) -> Vec<Self>
+    where
+        P: Fn(BOUND, BOUND, Round) -> BOUND,
+    {
         assert_eq!(rhs.sign(), Sign::Positive);
 
         let precision = self.precision();
@@ -12,41 +17,49 @@ impl<BOUND: Float> Interval<BOUND> {
         let (self_n, self_p) = self.split(BOUND::zero(precision));
         if !self_n.is_nan() {
             intervals.push(Self::new(
-                BOUND::min(self_n.lo.clone().pow_lo(rhs.clone()),
-                           self_n.hi.clone().pow_lo(rhs.clone())),
-                BOUND::max(self_n.lo.pow_hi(rhs.clone()),
-                           self_n.hi.pow_hi(rhs.clone())),
+                BOUND::min(pow(self_n.lo.clone(), rhs.clone(), Round::Down),
+                           pow(self_n.hi.clone(), rhs.clone(), Round::Down)),
+                BOUND::max(pow(self_n.lo, rhs.clone(), Round::Up),
+                           pow(self_n.hi, rhs.clone(), Round::Up)),
             ));
         }
         if !self_p.is_nan() {
             intervals.push(Self::new(
-                self_p.lo.pow_lo(rhs.clone()),
-                self_p.hi.pow_hi(rhs),
+                pow(self_p.lo, rhs.clone(), Round::Down),
+                pow(self_p.hi, rhs, Round::Up),
             ));
         }
         intervals
     }
 
-    fn pow_a_sn_multi(self, rhs: BOUND) -> Vec<Self> {
+    fn pow_a_sn_multi<P, R>(self, rhs: BOUND, pow: &P, recip: &R) -> Vec<Self>
+    where
+        P: Fn(BOUND, BOUND, Round) -> BOUND,
+        R: Fn(Self) -> Vec<Self>,
+    {
         assert_eq!(rhs.sign(), Sign::Negative);
 
-        let mut pos_intervals = self.pow_a_sp_multi(-rhs);
-        let res = pos_intervals
-            .drain(..)
-            .flat_map(|i| Interval::one(i.precision()).div_multi(i))
-            .collect();
+        let mut pos_intervals = self.pow_a_sp_multi(-rhs, pow);
+        let res = pos_intervals.drain(..).flat_map(recip).collect();
         res
     }
 
-    fn pow_a_s_multi(self, rhs: BOUND) -> Vec<Self> {
+    fn pow_a_s_multi<P, R>(self, rhs: BOUND, pow: &P, recip: &R) -> Vec<Self>
+    where
+        P: Fn(BOUND, BOUND, Round) -> BOUND,
+        R: Fn(Self) -> Vec<Self>,
+    {
         match rhs.sign() {
-            Sign::Negative => self.pow_a_sn_multi(rhs),
+            Sign::Negative => self.pow_a_sn_multi(rhs, pow, recip),
             Sign::Zero => vec![Self::one(self.precision())],
-            Sign::Positive => self.pow_a_sp_multi(rhs),
+            Sign::Positive => self.pow_a_sp_multi(rhs, pow),
         }
     }
 
-    fn pow_p_p_multi(self, rhs: Self) -> Vec<Self> {
+    fn pow_p_p_multi<P>(self, rhs: Self, pow: &P) -> Vec<Self>
+    where
+        P: Fn(BOUND, BOUND, Round) -> BOUND,
+    {
         assert!(self.sign_class().is_non_negative());
         assert!(rhs.sign_class().is_non_negative());
 
@@ -58,63 +71,77 @@ impl<BOUND: Float> Interval<BOUND> {
         let (self_01, self_1i) = self.split(BOUND::one(precision));
         if !self_1i.is_nan() {
             intervals.push(Self::new(
-                self_1i.lo.pow_lo(rhs.lo.clone()),
-                self_1i.hi.pow_hi(rhs.hi.clone()),
+                pow(self_1i.lo, rhs.lo.clone(), Round::Down),
+                pow(self_1i.hi, rhs.hi.clone(), Round::Up),
             ));
         }
         if !self_01.is_nan() {
             intervals.push(Self::new(
-                self_01.lo.pow_lo(rhs.hi),
-                self_01.hi.pow_hi(rhs.lo),
+                pow(self_01.lo, rhs.hi, Round::Down),
+                pow(self_01.hi, rhs.lo, Round::Up),
             ));
         }
         intervals
     }
 
-    fn pow_p_n_multi(self, rhs: Self) -> Vec<Self> {
+    fn pow_p_n_multi<P, R>(self, rhs: Self, pow: &P, recip: &R) -> Vec<Self>
+    where
+        P: Fn(BOUND, BOUND, Round) -> BOUND,
+        R: Fn(Self) -> Vec<Self>,
+    {
         assert!(self.sign_class().is_non_negative());
         assert!(rhs.sign_class().is_non_positive());
 
-        let mut pos_intervals = self.pow_p_p_multi(-rhs);
-        let res = pos_intervals
-            .drain(..)
-            .flat_map(|i| Interval::one(i.precision()).div_multi(i))
-            .collect();
+        let mut pos_intervals = self.pow_p_p_multi(-rhs, pow);
+        let res = pos_intervals.drain(..).flat_map(recip).collect();
         res
     }
 
-    fn pow_p_a_multi(self, rhs: Self) -> Vec<Self> {
+    fn pow_p_a_multi<P, R>(self, rhs: Self, pow: &P, recip: &R) -> Vec<Self>
+    where
+        P: Fn(BOUND, BOUND, Round) -> BOUND,
+        R: Fn(Self) -> Vec<Self>,
+    {
         assert!(self.sign_class().is_non_negative());
 
         let mut intervals = Vec::<Self>::new();
         let precision = rhs.precision();
         let (rhs_n, rhs_p) = rhs.split(BOUND::zero(precision));
         if !rhs_p.is_nan() {
-            intervals.append(&mut self.clone().pow_p_p_multi(rhs_p));
+            intervals.append(&mut self.clone().pow_p_p_multi(rhs_p, pow));
         }
         if !rhs_n.is_nan() {
-            intervals.append(&mut self.pow_p_n_multi(rhs_n));
+            intervals.append(&mut self.pow_p_n_multi(rhs_n, pow, recip));
         }
         intervals
     }
 
-    fn pow_n_a_multi(self, rhs: Self) -> Vec<Self> {
+    fn pow_n_a_multi<P, R>(self, rhs: Self, pow: &P, recip: &R) -> Vec<Self>
+    where
+        P: Fn(BOUND, BOUND, Round) -> BOUND,
+        R: Fn(Self) -> Vec<Self>,
+    {
         assert!(self.sign_class().is_non_positive());
 
         let mut intervals = Vec::<Self>::new();
-        let mut neg_intervals = (-self).pow_p_a_multi(rhs);
+        let mut neg_intervals = (-self).pow_p_a_multi(rhs, pow, recip);
         intervals.append(&mut neg_intervals.iter().map(|i| -i.clone()).collect());
         intervals.append(&mut neg_intervals);
         intervals
     }
 
-    /// Computes `self` raised to the power `rhs` and returns a vector of intervals minimally
-    /// covering the result.
-    pub fn pow_multi(self, rhs: Self) -> Vec<Self> {
+    /// Computes `self` raised to the power `rhs` using `pow` to compute the bounds of powers in
+    /// the given rounding direction and `recip` to compute the reciprocals of intervals, and
+    /// returns a vector of intervals minimally covering the result.
+    fn pow_multi_with<P, R>(self, rhs: Self, pow: &P, recip: &R) -> Vec<Self>
+    where
+        P: Fn(BOUND, BOUND, Round) -> BOUND,
+        R: Fn(Self) -> Vec<Self>,
+    {
         if self.is_nan() || rhs.is_nan() {
             vec![]
         } else if rhs.is_singleton() {
-            self.pow_a_s_multi(rhs.hi)
+            self.pow_a_s_multi(rhs.hi, pow, recip)
         } else if self.is_zero() {
             if rhs.has_zero() {
                 vec![Self::zero(self.precision()), Self::one(self.precision())]
@@ -126,34 +153,110 @@ impl<BOUND: Float> Interval<BOUND> {
             let mut intervals = Vec::<Self>::new();
             let (self_n, self_p) = self.split(BOUND::zero(precision));
             if !self_p.is_nan() {
-                intervals.append(&mut self_p.pow_p_a_multi(rhs.clone()));
+                intervals.append(&mut self_p.pow_p_a_multi(rhs.clone(), pow, recip));
             }
             if !self_n.is_nan() {
-                intervals.append(&mut self_n.pow_n_a_multi(rhs));
+                intervals.append(&mut self_n.pow_n_a_multi(rhs, pow, recip));
             }
             intervals
         }
     }
-}
 
-impl<BOUND: Float> Transc for Interval<BOUND> {
-    type Output = Self;
+    /// Computes `self` raised to the power `rhs` and returns a vector of intervals minimally
+    /// covering the result.
+    pub fn pow_multi(self, rhs: Self) -> Vec<Self> {
+        self.pow_multi_with(rhs, &BOUND::pow_r, &|i: Self| {
+            Interval::one(i.precision()).div_multi(i)
+        })
+    }
 
-    fn log(self) -> Self::Output {
+    /// Computes `self` raised to the power `rhs`, returns a vector of intervals minimally covering
+    /// the result and tells whether all bounds of the result are exact.
+    pub fn pow_multi_exact(self, rhs: Self) -> (Vec<Self>, bool) {
+        let exact = Cell::new(true);
+        let intervals = self.pow_multi_with(
+            rhs,
+            &|x: BOUND, y, r| {
+                let (z, e) = x.pow_r_exact(y, r);
+                exact.set(exact.get() && e);
+                z
+            },
+            &|i: Self| {
+                let (intervals, e) = Interval::one(i.precision()).div_multi_exact(i);
+                exact.set(exact.get() && e);
+                intervals
+            },
+        );
+        (intervals, exact.get())
+    }
+
+    /// Computes `self` raised to the power `rhs` and tells whether all bounds of the result are
+    /// exact.
+    pub fn pow_exact(self, rhs: Self) -> (Self, bool) {
+        let precision = self.precision();
+        let (intervals, exact) = self.pow_multi_exact(rhs);
+        (Self::minimal_cover(intervals, precision), exact)
+    }
+}
+
+impl<BOUND: Float> Interval<BOUND> {
+    /// Computes the natural logarithm of `self` using `log_lo` and `log_hi` to compute the lower
+    /// and upper bounds respectively.
+    fn log_with<LO, HI>(self, log_lo: LO, log_hi: HI) -> Self
+    where
+        LO: Fn(BOUND) -> BOUND,
+        HI: Fn(BOUND) -> BOUND,
+    {
         match self.sign_class() {
-            SignClass::Mixed => Self::new(BOUND::neg_infinity(self.precision()), self.hi.log_hi()),
+            SignClass::Mixed => Self::new(BOUND::neg_infinity(self.precision()), log_hi(self.hi)),
             SignClass::Zero => Self::nan(self.precision()),
             SignClass::Positive(has_zero) => {
                 if has_zero {
-                    Self::new(BOUND::neg_infinity(self.precision()), self.hi.log_hi())
+                    Self::new(BOUND::neg_infinity(self.precision()), log_hi(self.hi))
                 } else {
-                    Self::new(self.lo.log_lo(), self.hi.log_hi())
+                    Self::new(log_lo(self.lo), log_hi(self.hi))
                 }
             }
             SignClass::Negative(_) => Self::nan(self.precision()),
         }
     }
 
+    /// Computes the natural logarithm of `self` and tells whether all bounds of the result are
+    /// exact.
+    pub fn log_exact(self) -> (Self, bool) {
+        let exact = Cell::new(true);
+        let res = self.log_with(
+            |x| {
+                let (z, e) = x.log_lo_exact();
+                exact.set(exact.get() && e);
+                z
+            },
+            |x| {
+                let (z, e) = x.log_hi_exact();
+                exact.set(exact.get() && e);
+                z
+            },
+        );
+        (res, exact.get())
+    }
+
+    /// Computes the natural exponential of `self` and tells whether both bounds of the result are
+    /// exact.
+    #[inline]
+    pub fn exp_exact(self) -> (Self, bool) {
+        let (lo, lo_exact) = self.lo.exp_lo_exact();
+        let (hi, hi_exact) = self.hi.exp_hi_exact();
+        (Self::new(lo, hi), lo_exact && hi_exact)
+    }
+}
+
+impl<BOUND: Float> Transc for Interval<BOUND> {
+    type Output = Self;
+
+    fn log(self) -> Self::Output {
+        self.log_with(BOUND::log_lo, BOUND::log_hi)
+    }
+
     fn exp(self) -> Self::Output {
         Interval::new(self.lo.exp_lo(), self.hi.exp_hi())
     }
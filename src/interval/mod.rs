@@ -1,13 +1,46 @@
 mod def;
 mod impl_basic;
 mod impl_cmp;
+mod impl_integral;
+mod impl_minmax;
+mod impl_newton;
 mod impl_ops;
+mod impl_range;
+mod impl_rem;
+mod impl_rev;
 mod impl_transc;
 
 #[cfg(test)]
 mod tests;
 
+#[cfg(test)]
+mod tests_common;
+
 #[cfg(test)]
 mod tests_f64;
 
-pub use self::def::{Interval, ParseIntervalError, SignClass};
+#[cfg(test)]
+mod tests_double_double;
+
+#[cfg(test)]
+mod tests_rounded_f32;
+
+#[cfg(test)]
+mod tests_rounded_f64;
+
+#[cfg(test)]
+mod tests_rational;
+
+#[cfg(test)]
+mod tests_fixed;
+
+#[cfg(test)]
+mod tests_decimal;
+
+#[cfg(test)]
+mod tests_integer;
+
+pub use self::def::{
+    CancelError, Interval, ParseIntervalError, RangeForm, Root, RootKind, SignClass,
+};
+pub use self::impl_newton::DEFAULT_NEWTON_LIMIT;
@@ -247,6 +247,63 @@ fn test_split() {
     );
 }
 
+#[test]
+fn test_intersection() {
+    assert_str_eq!("<1.5, 2>", iv!("<1, 2>").intersection(iv!("<1.5, 3>")));
+    assert_str_eq!("2", iv!("<1, 2>").intersection(iv!("<2, 3>")));
+    assert_str_eq!("<1, 2>", iv!("<1, 2>").intersection(iv!("<-inf, inf>")));
+    assert_str_eq!("NaN", iv!("<1, 2>").intersection(iv!("<3, 4>")));
+    assert_str_eq!("NaN", iv!("<1, 2>").intersection(iv!("NaN")));
+}
+
+#[test]
+fn test_hull() {
+    assert_str_eq!("<1, 3>", iv!("<1, 2>").hull(iv!("<1.5, 3>")));
+    assert_str_eq!("<-1, 2>", iv!("<1, 2>").hull(iv!("-1")));
+    assert_str_eq!("<1, 2>", iv!("<1, 2>").hull(iv!("NaN")));
+    assert_str_eq!("<1, 2>", iv!("NaN").hull(iv!("<1, 2>")));
+    assert_str_eq!("NaN", iv!("NaN").hull(iv!("NaN")));
+}
+
+#[test]
+fn test_mag_mid_rad() {
+    use fp::Float;
+    assert_str_eq!("3", iv!("<-3, 2>").mag());
+    assert_str_eq!("inf", iv!("<-inf, 2>").mag());
+    assert_str_eq!("NaN", iv!("NaN").mag());
+    assert_str_eq!("1.5", iv!("<1, 2>").mid());
+    assert_str_eq!("0.5", iv!("<1, 2>").rad());
+    assert_str_eq!("2", iv!("<1, 4>").mid());
+    assert_str_eq!("2", iv!("<1, 4>").rad());
+    assert_str_eq!("-3", iv!("-3").mid());
+    assert_str_eq!("0", iv!("-3").rad());
+    assert_str_eq!("0", iv!("<-inf, inf>").mid());
+    assert_str_eq!("inf", iv!("<-inf, inf>").rad());
+    assert!(iv!("<1, inf>").mid().is_finite());
+    assert_str_eq!("inf", iv!("<1, inf>").rad());
+    assert_str_eq!("NaN", iv!("NaN").mid());
+    assert_str_eq!("NaN", iv!("NaN").rad());
+}
+
+#[test]
+fn test_inflate() {
+    assert_str_eq!("<-1.5, 3>", iv!("<-1, 2>").inflate(b!("0.5"), b!("0")));
+    assert_str_eq!("<-1.5, 1.5>", iv!("<-1, 1>").inflate(b!("0"), b!("0.5")));
+    assert_str_eq!("<-0.25, 3>", iv!("<0, 2>").inflate(b!("0.25"), b!("0.25")));
+    assert_str_eq!("<-inf, 3>", iv!("<-inf, 2>").inflate(b!("0.5"), b!("0")));
+    assert_str_eq!("NaN", iv!("NaN").inflate(b!("0.5"), b!("0.5")));
+}
+
+#[test]
+fn test_blow() {
+    assert_str_eq!("<0.75, 1.5>", iv!("1").blow(b!("0.5")));
+    assert_str_eq!("<0.75, 3>", iv!("<1, 2>").blow(b!("0")));
+    assert_str_eq!("<-4, 6>", iv!("<-1, 2>").blow(b!("0.5")));
+    assert_str_eq!("<-inf, inf>", iv!("<0, inf>").blow(b!("0.5")));
+    assert_str_eq!("<-inf, 3>", iv!("<-inf, 2>").blow(b!("0")));
+    assert_str_eq!("NaN", iv!("NaN").blow(b!("0.5")));
+}
+
 #[test]
 fn test_from_f64() {
     use std::f64;
@@ -305,6 +362,20 @@ fn test_partial_eq() {
     assert_ne!(iv!("<0, 1>"), iv!("<0, 2>"));
 }
 
+#[test]
+fn test_total_ord() {
+    use fp::Total;
+    use std::collections::BTreeSet;
+    let mut set = BTreeSet::new();
+    for s in &["NaN", "<0, 2>", "<-inf, 0>", "<0, 1>", "<-0, 1>", "NaN"] {
+        set.insert(Total(iv!(s)));
+    }
+    assert_eq!(
+        vec!["<-inf, 0>", "<0, 1>", "<0, 2>", "NaN"],
+        set.iter().map(|x| format!("{}", x.0)).collect::<Vec<_>>()
+    );
+}
+
 #[test]
 fn test_neg() {
     use std::ops::Neg;
@@ -456,6 +527,275 @@ fn test_div() {
     );
 }
 
+#[test]
+fn test_add_exact() {
+    let (z, exact) = iv!("<1, 2>").add_exact(iv!("<0.5, 1>"));
+    assert_str_eq!("<1.5, 3>", z);
+    assert!(exact);
+    let (z, exact) = iv!("<1, 2>").add_exact(iv!("0.75"));
+    assert_str_eq!("<1.5, 3>", z);
+    assert!(!exact);
+    let (z, exact) = iv!("<-inf, 2>").add_exact(iv!("1"));
+    assert_str_eq!("<-inf, 3>", z);
+    assert!(exact);
+}
+
+#[test]
+fn test_sub_exact() {
+    let (z, exact) = iv!("<1, 2>").sub_exact(iv!("<0.5, 1>"));
+    assert_str_eq!("<-0, 1.5>", z);
+    assert!(exact);
+    let (z, exact) = iv!("3").sub_exact(iv!("0.75"));
+    assert_str_eq!("<2, 3>", z);
+    assert!(!exact);
+}
+
+#[test]
+fn test_cancel_minus() {
+    use super::CancelError;
+    assert_str_eq!("<1, 2>", iv!("<1.5, 3>").cancel_minus(iv!("<0.5, 1>")));
+    assert_str_eq!("<0.5, 1>", iv!("<1.5, 3>").cancel_minus(iv!("<1, 2>")));
+    assert_str_eq!("<2, 3>", iv!("3").cancel_minus(iv!("0.75")));
+    assert_str_eq!("<-inf, inf>", iv!("<1, 2>").cancel_minus(iv!("<0, 2>")));
+    assert_str_eq!("NaN", IV::nan(PREC).cancel_minus(iv!("<0, 2>")));
+    let checked = |x: IV, y: IV| x.checked_cancel_minus(y);
+    assert_eq!(
+        Err(CancelError::WiderSubtrahend),
+        checked(iv!("1"), iv!("<0, 1>"))
+    );
+    assert_eq!(
+        Err(CancelError::WiderSubtrahend),
+        checked(iv!("1"), IV::nan(PREC))
+    );
+    assert_eq!(
+        Err(CancelError::Unbounded),
+        checked(iv!("<1, inf>"), iv!("1"))
+    );
+    assert_eq!(
+        Err(CancelError::Unbounded),
+        checked(IV::nan(PREC), IV::whole(PREC))
+    );
+    // The widths are equal, although the computed widths are not.
+    let x = iv!("<0.75, 3>");
+    assert_str_eq!("<2, 3>", x.size());
+    assert_str_eq!("1.5", checked(x, iv!("<-0.75, 1.5>")).unwrap());
+}
+
+#[test]
+fn test_cancel_plus() {
+    use super::CancelError;
+    assert_str_eq!("<3, 4>", iv!("<1, 2>").cancel_plus(iv!("<2, 2>")));
+    assert_str_eq!("<-inf, inf>", iv!("<1, 2>").cancel_plus(iv!("<-3, 0>")));
+    assert_eq!(
+        Err(CancelError::WiderSubtrahend),
+        iv!("<1, 2>").checked_cancel_plus(iv!("<-3, 0>"))
+    );
+    let z = iv!("<-1, 2>") - iv!("<0.5, 1>");
+    assert_str_eq!("<-1, 2>", z.cancel_plus(iv!("<0.5, 1>")));
+}
+
+#[test]
+fn test_mul_exact() {
+    let (z, exact) = iv!("<-2, 1>").mul_exact(iv!("<1, 1.5>"));
+    assert_str_eq!("<-3, 1.5>", z);
+    assert!(exact);
+    let (z, exact) = iv!("<1, 1.5>").mul_exact(iv!("1.5"));
+    assert_str_eq!("<1.5, 3>", z);
+    assert!(!exact);
+    let (z, exact) = iv!("0").mul_exact(iv!("0.75"));
+    assert_str_eq!("0", z);
+    assert!(exact);
+}
+
+#[test]
+fn test_div_exact() {
+    let (z, exact) = iv!("<1, 3>").div_exact(iv!("2"));
+    assert_str_eq!("<0.5, 1.5>", z);
+    assert!(exact);
+    let (z, exact) = iv!("1").div_exact(iv!("3"));
+    assert_str_eq!("<0.25, 0.375>", z);
+    assert!(!exact);
+    let (z, exact) = iv!("<1, 2>").div_exact(iv!("<-1, 1>"));
+    assert_str_eq!("<-inf, inf>", z);
+    assert!(exact);
+}
+
+#[test]
+fn test_log_exact() {
+    let (z, exact) = iv!("<0, 1>").log_exact();
+    assert_str_eq!("<-inf, 0>", z);
+    assert!(exact);
+    let (z, exact) = iv!("<1, 2>").log_exact();
+    assert_str_eq!("<0, 0.75>", z);
+    assert!(!exact);
+}
+
+#[test]
+fn test_exp_exact() {
+    let (z, exact) = iv!("<-inf, 0>").exp_exact();
+    assert_str_eq!("<0, 1>", z);
+    assert!(exact);
+    let (z, exact) = iv!("<0, 1>").exp_exact();
+    assert_str_eq!("<1, 3>", z);
+    assert!(!exact);
+}
+
+#[test]
+fn test_pow_exact() {
+    let (z, exact) = iv!("2").pow_exact(iv!("8"));
+    assert_str_eq!("256", z);
+    assert!(exact);
+    let (z, exact) = iv!("<1, 2>").pow_exact(iv!("-1"));
+    assert_str_eq!("<0.5, 1>", z);
+    assert!(exact);
+    let (z, exact) = iv!("<2, 3>").pow_exact(iv!("2"));
+    assert_str_eq!("<4, 12>", z);
+    assert!(!exact);
+    let (z, exact) = iv!("2").pow_exact(iv!("0.5"));
+    assert_str_eq!("<1, 1.5>", z);
+    assert!(!exact);
+}
+
+#[test]
+fn test_floor() {
+    assert_str_eq!("<0, 3>", iv!("<0.75, 3>").floor());
+    assert_str_eq!("<-2, 1>", iv!("<-1.5, 1.5>").floor());
+    assert_str_eq!("<-inf, 1>", iv!("<-inf, 1.5>").floor());
+    assert_str_eq!("NaN", iv!("NaN").floor());
+}
+
+#[test]
+fn test_ceil() {
+    assert_str_eq!("<1, 3>", iv!("<0.75, 3>").ceil());
+    assert_str_eq!("<-1, 2>", iv!("<-1.5, 1.5>").ceil());
+}
+
+#[test]
+fn test_round() {
+    assert_str_eq!("<-2, 2>", iv!("<-1.5, 1.5>").round_ties_even());
+    assert_str_eq!("<-0, 1>", iv!("<-0.375, 0.75>").round_ties_even());
+    assert_str_eq!("<-1, 1>", iv!("<-0.5, 0.5>").round_ties_away());
+    assert_str_eq!("0", iv!("<-0.5, 0.5>").round_ties_even());
+}
+
+#[test]
+fn test_trunc() {
+    assert_str_eq!("<-1, 1>", iv!("<-1.5, 1.5>").trunc());
+    assert_str_eq!("<0, 2>", iv!("<0.75, 2>").trunc());
+}
+
+#[test]
+fn test_sign() {
+    test_unary_op(
+        |iv| iv.sign(),
+        all_sign_classes(),
+        vec![
+            ("nan", "NaN"),
+            ("whl", "<-1, 1>"),
+            ("m", "<-1, 1>"),
+            ("z", "0"),
+            ("p0", "<0, 1>"),
+            ("p1", "1"),
+            ("n0", "<-1, 0>"),
+            ("n1", "-1"),
+        ],
+        false,
+    );
+}
+
+#[test]
+fn test_frac() {
+    assert_str_eq!("<-0, 0.5>", iv!("<1, 1.5>").frac());
+    assert_str_eq!("<0, 1>", iv!("<0.75, 1.5>").frac());
+    assert_str_eq!("<-0.75, 1>", iv!("<-0.75, 1.5>").frac());
+    assert_str_eq!("<-0.75, 0.75>", iv!("<-0.75, 0.75>").frac());
+    assert_str_eq!("<0, 1>", iv!("<1, inf>").frac());
+    assert_str_eq!("NaN", iv!("NaN").frac());
+    assert_eq!(2, iv!("<0.75, 1.5>").frac_multi().len());
+}
+
+#[test]
+fn test_abs() {
+    test_unary_op(
+        |iv| iv.abs(),
+        all_sign_classes(),
+        vec![
+            ("nan", "NaN"),
+            ("whl", "<0, inf>"),
+            ("m", "<0, 1>"),
+            ("z", "0"),
+            ("p0", "<0, 1>"),
+            ("p1", "<1, 2>"),
+            ("n0", "<0, 1>"),
+            ("n1", "<1, 2>"),
+        ],
+        false,
+    );
+    assert_str_eq!("<0, 3>", iv!("<-3, 2>").abs());
+    assert_str_eq!("<0, 1>", iv!("<-0, 1>").abs());
+}
+
+#[test]
+fn test_min_max() {
+    assert_str_eq!("<-1, 2>", iv!("<-1, 3>").min(iv!("<1, 2>")));
+    assert_str_eq!("<1, 3>", iv!("<-1, 3>").max(iv!("<1, 2>")));
+    assert_str_eq!("<-inf, 1>", iv!("<-inf, 1>").min(iv!("<0, inf>")));
+    assert_str_eq!("NaN", iv!("<-1, 3>").min(iv!("NaN")));
+    assert_str_eq!("NaN", iv!("NaN").max(iv!("<1, 2>")));
+}
+
+#[test]
+fn test_clamp() {
+    assert_str_eq!("<0, 1>", iv!("<-3, 2>").clamp(iv!("0"), iv!("1")));
+    assert_str_eq!("<1, 1.5>", iv!("<1, 1.5>").clamp(iv!("0"), iv!("<1, 2>")));
+    assert_str_eq!("<-1, 2>", iv!("<-4, 4>").clamp(iv!("-1"), iv!("2")));
+    assert_str_eq!("NaN", iv!("<-3, 2>").clamp(iv!("NaN"), iv!("1")));
+}
+
+#[test]
+fn test_hypot() {
+    assert_str_eq!("<1, 3>", iv!("<-2, 1>").hypot(iv!("1")));
+    assert_str_eq!("<3, 6>", iv!("<-4, -3>").hypot(iv!("<0, 3>")));
+    assert_str_eq!("<0, inf>", iv!("<-inf, 1>").hypot(iv!("<-1, 1>")));
+    assert_str_eq!("NaN", iv!("NaN").hypot(iv!("1")));
+}
+
+#[test]
+fn test_fmod() {
+    assert_str_eq!("1", iv!("3").fmod(iv!("2")));
+    assert_str_eq!("-1", iv!("-3").fmod(iv!("2")));
+    assert_str_eq!("<0, 0.5>", iv!("<1, 1.5>").fmod(iv!("1")));
+    assert_str_eq!("<0, 1>", iv!("<3, 4>").fmod(iv!("1.5")));
+    assert_str_eq!("<0, 1>", iv!("<0.75, 1.5>").fmod(iv!("-1")));
+    assert_str_eq!("<-1, 1>", iv!("<-1.5, 1.5>").fmod(iv!("1")));
+    assert_str_eq!("<0, 1>", iv!("<1, 8>").fmod(iv!("1")));
+    assert_str_eq!("<0, 1>", iv!("<1, 2>").fmod(iv!("<-1, 1>")));
+    assert_str_eq!("<1, 2>", iv!("<1, 2>").fmod(iv!("<3, inf>")));
+    assert_str_eq!("NaN", iv!("<1, 2>").fmod(iv!("0")));
+    assert_str_eq!("NaN", iv!("NaN").fmod(iv!("1")));
+    assert_eq!(2, iv!("<0.75, 1.5>").fmod_multi(iv!("1")).len());
+}
+
+#[test]
+fn test_remainder() {
+    assert_str_eq!("-1", iv!("3").remainder(iv!("2")));
+    assert_str_eq!("<-0.5, 0.5>", iv!("<0.75, 1.5>").remainder(iv!("1")));
+    assert_str_eq!("<-1, 1>", iv!("<-1, 1>").remainder(iv!("4")));
+    assert_str_eq!("<-0.5, 0.5>", iv!("<1, 8>").remainder(iv!("-1")));
+    assert_str_eq!("NaN", iv!("<1, 2>").remainder(iv!("0")));
+}
+
+#[test]
+fn test_rem_euclid() {
+    assert_str_eq!("1", iv!("-3").rem_euclid(iv!("2")));
+    assert_str_eq!("1", iv!("-3").rem_euclid(iv!("-2")));
+    assert_str_eq!("<1, 1.5>", iv!("<-1, -0.75>").rem_euclid(iv!("2")));
+    assert_str_eq!("<0, 2>", iv!("<-1, 1>").rem_euclid(iv!("2")));
+    assert_str_eq!("<0, 1>", iv!("<1, 2>").rem_euclid(iv!("<-1, 1>")));
+    assert_str_eq!("NaN", iv!("<1, 2>").rem_euclid(iv!("0")));
+    assert_eq!(2, iv!("<-1, 1>").rem_euclid_multi(iv!("2")).len());
+}
+
 #[test]
 fn test_log() {
     use transc::Transc;
@@ -633,6 +973,284 @@ fn test_pow() {
     );
 }
 
+const F64_PREC: usize = 53;
+
+macro_rules! b53 {
+    ($s:expr) => {
+        B::from_str_with_prec($s, F64_PREC).unwrap()
+    };
+}
+
+macro_rules! iv53 {
+    ($s:expr) => {
+        IV::from_str_with_prec($s, F64_PREC).unwrap()
+    };
+}
+
+/// Returns the lower bound of `x`, which must have `F64_PREC` bits of precision, as `f64`.
+fn lo(x: &IV) -> f64 {
+    ::fp::Into::<f64>::into_lo(x.lo.clone())
+}
+
+/// Returns the upper bound of `x`, which must have `F64_PREC` bits of precision, as `f64`.
+fn hi(x: &IV) -> f64 {
+    ::fp::Into::<f64>::into_hi(x.hi.clone())
+}
+
+#[test]
+fn test_newton() {
+    use super::RootKind;
+    let two = iv53!("2");
+    let roots = iv53!("<-3, 3>").newton(|x| x.clone() * x - two.clone(), |x| iv53!("2") * x);
+    assert_eq!(2, roots.len());
+    let sqrt2 = 2f64.sqrt();
+    for (r, s) in roots.iter().zip(&[-sqrt2, sqrt2]) {
+        assert_eq!(RootKind::Unique, r.kind);
+        assert!(
+            lo(&r.interval) <= *s && *s <= hi(&r.interval),
+            "{}",
+            r.interval
+        );
+        assert!(hi(&r.interval) - lo(&r.interval) <= 4.0 * f64::EPSILON);
+    }
+    // None of the roots `2 cos(2 pi k / 9)` is a bisection point. Newton steps approach a root on
+    // the boundary of an enclosure only linearly, until the bounds underflow.
+    let cubic = |x: IV| x.clone() * x.clone() * x.clone() - iv53!("3") * x + iv53!("1");
+    let roots = iv53!("<-2, 2>").newton(cubic, |x| iv53!("3") * x.clone() * x - iv53!("3"));
+    assert_eq!(3, roots.len());
+    for (r, k) in roots.iter().zip(&[4.0, 2.0, 1.0]) {
+        let s = 2.0 * (2.0 * ::std::f64::consts::PI * k / 9.0).cos();
+        assert_eq!(RootKind::Unique, r.kind);
+        assert!(
+            lo(&r.interval) - 1e-9 <= s && s <= hi(&r.interval) + 1e-9,
+            "{}",
+            r.interval
+        );
+        assert!(hi(&r.interval) - lo(&r.interval) <= 4.0 * s.abs() * f64::EPSILON);
+    }
+    let roots = iv53!("<0, 3>").newton(
+        |x| (x.clone() - iv53!("1")) * (x - iv53!("1")),
+        |x| iv53!("2") * (x - iv53!("1")),
+    );
+    assert!(!roots.is_empty());
+    assert!(roots.iter().all(|r| r.kind == RootKind::Possible));
+    assert!(roots
+        .iter()
+        .any(|r| lo(&r.interval) <= 1.0 && 1.0 <= hi(&r.interval)));
+    let roots = iv53!("<-3, 3>").newton(|x| x.clone() * x + iv53!("1"), |x| iv53!("2") * x);
+    assert!(roots.is_empty());
+    assert!(IV::nan(F64_PREC).newton(|x| x, |_| iv53!("1")).is_empty());
+}
+
+#[test]
+fn test_newton_with_limits() {
+    use super::RootKind;
+    let f = |x: IV| x.clone() * x - iv53!("2");
+    let roots = iv53!("<1, 3>").newton_with_limits(f, |x| iv53!("2") * x, b53!("0.5"), 1000);
+    assert_eq!(1, roots.len());
+    assert_eq!(RootKind::Unique, roots[0].kind);
+    assert!(hi(&roots[0].interval) - lo(&roots[0].interval) <= 0.5);
+    let roots = iv53!("<-3, 3>").newton_with_limits(f, |x| iv53!("2") * x, b53!("0"), 0);
+    assert_eq!(1, roots.len());
+    assert_eq!(RootKind::Possible, roots[0].kind);
+    assert_str_eq!("<-3, 3>", roots[0].interval);
+}
+
+#[test]
+fn test_range() {
+    use super::RangeForm;
+    use slope::Slope;
+    use transc::Transc;
+    type S = Slope<IV>;
+    let f = |x: S| x.clone() - x.clone() * x;
+    let x = iv53!("<0, 1>");
+    assert_str_eq!("<-1, 1>", x.clone().range(f, RangeForm::Natural));
+    assert_str_eq!("<-0.25, 0.75>", x.clone().range(f, RangeForm::MeanValue));
+    // The lower bound is `-0` with Mpfr bounds.
+    let r = x.clone().range(f, RangeForm::Slope);
+    assert_eq!((0.0, 0.5), (lo(&r), hi(&r)), "{}", r);
+    assert_str_eq!("<-0.25, 0.75>", x.range(f, RangeForm::Bicentered));
+    let x = iv53!("<0.25, 0.375>");
+    assert_str_eq!("<0.109375, 0.3125>", x.clone().range(f, RangeForm::Natural));
+    assert_str_eq!(
+        "<0.1875, 0.234375>",
+        x.clone().range(f, RangeForm::Bicentered)
+    );
+    for form in &[RangeForm::MeanValue, RangeForm::Slope] {
+        let r = x.clone().range(f, *form);
+        assert!(lo(&r) <= 0.1875 && 0.234375 <= hi(&r), "{}", r);
+        assert!(hi(&r) - lo(&r) < 0.2, "{}", r);
+    }
+    assert!(IV::nan(F64_PREC).range(f, RangeForm::Slope).is_nan());
+    let g = |x: S| (x.clone() * x).exp();
+    let r = iv53!("<-1, 1>").range(g, RangeForm::Bicentered);
+    assert!(lo(&r) <= 1.0 && 1f64.exp() <= hi(&r), "{}", r);
+}
+
+#[test]
+fn test_range_subdivided() {
+    use super::RangeForm;
+    use slope::Slope;
+    type S = Slope<IV>;
+    let f = |x: S| x.clone() - x.clone() * x;
+    let x = iv53!("<0, 1>");
+    for form in &[
+        RangeForm::Natural,
+        RangeForm::MeanValue,
+        RangeForm::Slope,
+        RangeForm::Bicentered,
+    ] {
+        let r = x.clone().range_subdivided(f, *form, b53!("0.01"), 1024);
+        assert_eq!(1, r.intervals.len());
+        let r = &r.intervals[0];
+        assert!(lo(r) <= 0.0 && 0.25 <= hi(r), "{}", r);
+        assert!(-0.01 <= lo(r) && hi(r) <= 0.26, "{}", r);
+    }
+    let g = |x: S| S::constant(iv53!("1")) / x;
+    let r = iv53!("<-1, 1>").range_subdivided(g, RangeForm::Natural, b53!("0"), 2);
+    assert_str_eq!("{<-inf, -1>; <1, inf>}", r);
+    let r = iv53!("1").range_subdivided(f, RangeForm::Natural, b53!("-1"), 1024);
+    assert_str_eq!("0", r);
+}
+
+/// Tells whether `x` contains `<l, h>` and its bounds are within `1e-9` of `l` and `h`.
+fn tight(x: &IV, l: f64, h: f64) -> bool {
+    lo(x) <= l && h <= hi(x) && l - lo(x) < 1e-9 && hi(x) - h < 1e-9
+}
+
+#[test]
+fn test_mul_div_rev() {
+    let x = iv53!("<-10, 10>");
+    assert_str_eq!("<1, 2>", x.clone().mul_rev(iv53!("2"), iv53!("<2, 4>")));
+    assert_str_eq!(
+        "{<-10, -1>; <1, 10>}",
+        x.clone().mul_rev(iv53!("<-1, 1>"), iv53!("<1, 2>"))
+    );
+    assert_str_eq!(
+        "<-10, 10>",
+        x.clone().mul_rev(iv53!("<0, 1>"), iv53!("<-1, 0>"))
+    );
+    assert_str_eq!("{}", x.clone().mul_rev(iv53!("0"), iv53!("1")));
+    assert_str_eq!("{}", iv53!("<3, 4>").mul_rev(iv53!("1"), iv53!("<1, 2>")));
+    let (x1, x2) = IV::mul_rev_to_pair(iv53!("<-1, 2>"), iv53!("<1, 2>"));
+    assert_str_eq!("<-inf, -1>", x1);
+    assert_str_eq!("<0.5, inf>", x2);
+    let (x1, x2) = IV::mul_rev_to_pair(iv53!("0"), iv53!("1"));
+    assert!(x1.is_nan() && x2.is_nan());
+    assert_str_eq!(
+        "<2, 8>",
+        x.clone().div_rev1(iv53!("<1, 2>"), iv53!("<2, 4>"))
+    );
+    assert!(x.clone().div_rev1(iv53!("0"), iv53!("1")).is_nan());
+    assert_str_eq!(
+        "<0.5, 2>",
+        x.clone().div_rev2(iv53!("<1, 2>"), iv53!("<1, 2>"))
+    );
+    assert_str_eq!(
+        "{<-10, -1>; <1, 10>}",
+        x.div_rev2(iv53!("1"), iv53!("<-1, 1>"))
+    );
+}
+
+#[test]
+fn test_sqr_abs_rev() {
+    let x = iv53!("<-10, 10>");
+    let r = x.clone().sqr_rev(iv53!("<4, 9>"));
+    assert_eq!(2, r.intervals.len());
+    assert!(tight(&r.intervals[0], -3.0, -2.0) && tight(&r.intervals[1], 2.0, 3.0));
+    let r = x.clone().sqr_rev(iv53!("<-1, 4>"));
+    assert!(
+        r.intervals.len() == 1 && tight(&r.intervals[0], -2.0, 2.0),
+        "{}",
+        r
+    );
+    assert_str_eq!("{}", x.clone().sqr_rev(iv53!("<-4, -1>")));
+    let r = iv53!("<0, 10>").sqr_rev(iv53!("<4, 9>"));
+    assert!(
+        r.intervals.len() == 1 && tight(&r.intervals[0], 2.0, 3.0),
+        "{}",
+        r
+    );
+    let r = x.clone().sqr_rev(iv53!("2"));
+    assert!(r.intervals.len() == 2 && tight(&r.intervals[1], 2.0f64.sqrt(), 2.0f64.sqrt()));
+    assert_str_eq!("{<-3, -2>; <2, 3>}", x.clone().abs_rev(iv53!("<2, 3>")));
+    assert_str_eq!("<-3, 3>", x.clone().abs_rev(iv53!("<-1, 3>")));
+    assert_str_eq!("{}", x.abs_rev(iv53!("<-3, -1>")));
+}
+
+#[test]
+fn test_pow_rev() {
+    let x = iv53!("<0.5, 8>");
+    let r = x.clone().pow_rev1(iv53!("2"), iv53!("<4, 16>"));
+    assert!(
+        r.intervals.len() == 1 && tight(&r.intervals[0], 2.0, 4.0),
+        "{}",
+        r
+    );
+    let r = iv53!("<-10, 10>").pow_rev1(iv53!("2"), iv53!("<4, 16>"));
+    assert_eq!(2, r.intervals.len());
+    assert!(tight(&r.intervals[0], -4.0, -2.0) && tight(&r.intervals[1], 2.0, 4.0));
+    let r = x.clone().pow_rev1(iv53!("-1"), iv53!("<1, 2>"));
+    assert!(
+        r.intervals.len() == 1 && tight(&r.intervals[0], 0.5, 1.0),
+        "{}",
+        r
+    );
+    let r = iv53!("<-1, 0>").pow_rev1(iv53!("2"), iv53!("<-1, 0>"));
+    assert!(
+        r.intervals.len() == 1 && tight(&r.intervals[0], -1.0, 0.0),
+        "{}",
+        r
+    );
+    assert_str_eq!("{}", x.clone().pow_rev1(iv53!("<1, 2>"), iv53!("<-2, -1>")));
+    let r = x.pow_rev1(iv53!("<1, 2>"), iv53!("4"));
+    assert_eq!(1, r.intervals.len());
+    assert!(
+        lo(&r.intervals[0]) <= 2.0 && 4.0 <= hi(&r.intervals[0]),
+        "{}",
+        r
+    );
+    let y = iv53!("<-10, 10>");
+    let r = y.clone().pow_rev2(iv53!("2"), iv53!("<4, 8>"));
+    assert_eq!(1, r.intervals.len());
+    assert!(
+        lo(&r.intervals[0]) <= 2.0 && 3.0 <= hi(&r.intervals[0]),
+        "{}",
+        r
+    );
+    assert!(
+        1.9 <= lo(&r.intervals[0]) && hi(&r.intervals[0]) <= 3.1,
+        "{}",
+        r
+    );
+    let r = y.clone().pow_rev2(iv53!("-2"), iv53!("4"));
+    assert!(
+        r.intervals.iter().any(|y| lo(y) <= 2.0 && 2.0 <= hi(y)),
+        "{}",
+        r
+    );
+    assert!(
+        r.intervals.iter().all(|y| 1.9 <= lo(y) && hi(y) <= 2.1),
+        "{}",
+        r
+    );
+    assert_str_eq!("<-10, 10>", y.clone().pow_rev2(iv53!("<0, 2>"), iv53!("1")));
+    assert_str_eq!("{}", y.pow_rev2(iv53!("1"), iv53!("2")));
+}
+
+#[test]
+fn test_exp_log_rev() {
+    let x = iv53!("<-10, 10>");
+    assert_str_eq!("<-10, 0>", x.clone().exp_rev(iv53!("<-1, 1>")));
+    assert!(x.clone().exp_rev(iv53!("<-2, -1>")).is_nan());
+    let r = x.clone().exp_rev(iv53!("<1, 2>"));
+    assert!(lo(&r) <= 0.0 && 2.0f64.ln() <= hi(&r), "{}", r);
+    assert_str_eq!("<1, 10>", x.clone().log_rev(iv53!("<0, 10>")));
+    assert!(iv53!("<-2, -1>").log_rev(iv53!("0")).is_nan());
+    let r = x.log_rev(iv53!("<-1, 0>"));
+    assert!(lo(&r) <= (-1.0f64).exp() && hi(&r) == 1.0, "{}", r);
+}
+
 fn simple<'a>() -> Vec<(&'a str, IV)> {
     vec![
         ("nan", iv!("NaN")),
@@ -694,9 +1312,9 @@ fn all_sign_classes_small_and_big<'a>() -> Vec<(&'a str, IV)> {
     ]
 }
 
-fn find_unary_case<'x, 'z>(cases: &'z Vec<(&str, &str)>, cx: &'x str) -> &'z str {
-    for &(ref cz, ref z) in cases {
-        let is_match = &format!("{}", cx).as_str() == cz || &format!("*").as_str() == cz;
+fn find_unary_case<'z>(cases: &'z Vec<(&str, &str)>, cx: &str) -> &'z str {
+    for (cz, z) in cases {
+        let is_match = cx == *cz || "*" == *cz;
         if is_match {
             return z;
         }
@@ -704,18 +1322,18 @@ fn find_unary_case<'x, 'z>(cases: &'z Vec<(&str, &str)>, cx: &'x str) -> &'z str
     panic!("unmatched case: {}", cx);
 }
 
-fn find_binary_case<'x, 'y, 'z>(
+fn find_binary_case<'z>(
     cases: &'z Vec<(&str, &str)>,
-    cx: &'x str,
-    cy: &'y str,
+    cx: &str,
+    cy: &str,
     commutative: bool,
 ) -> Option<&'z str> {
-    for &(ref cz, ref z) in cases {
-        let cz = String::from(cz.clone());
+    for (cz, z) in cases {
+        let cz = String::from(*cz);
         let mut is_match = format!("{}.{}", cx, cy) == cz
             || format!("{}.*", cx).as_str() == cz
             || format!("*.{}", cy).as_str() == cz
-            || format!("*.*").as_str() == cz;
+            || "*.*" == cz;
         if commutative {
             is_match = is_match
                 || format!("{}.{}", cy, cx) == cz
@@ -729,9 +1347,9 @@ fn find_binary_case<'x, 'y, 'z>(
     None
 }
 
-pub fn test_unary_op<'a, OP, R>(
+pub fn test_unary_op<OP, R>(
     op: OP,
-    cases: Vec<(&'a str, IV)>,
+    cases: Vec<(&str, IV)>,
     expected: Vec<(&str, &str)>,
     print: bool,
 ) where
@@ -754,10 +1372,10 @@ pub fn test_unary_op<'a, OP, R>(
     }
 }
 
-pub fn test_binary_op<'a, OP, R>(
+pub fn test_binary_op<OP, R>(
     op: OP,
-    left_cases: Vec<(&'a str, IV)>,
-    right_cases: Vec<(&'a str, IV)>,
+    left_cases: Vec<(&str, IV)>,
+    right_cases: Vec<(&str, IV)>,
     mut expected: Vec<(&str, &str)>,
     commutative: bool,
 ) where
@@ -781,7 +1399,7 @@ pub fn test_binary_op<'a, OP, R>(
         for (cy, y) in right_cases.clone() {
             let z = op(x.clone(), y.clone());
             if let Some(cz) = find_binary_case(&expected, cx, cy, commutative) {
-                if String::from(cz) != format!("{}", z) {
+                if cz != format!("{}", z) {
                     failed_cases.push(format!("    (\"{}.{}\", \"{}\"),", cx, cy, z));
                 }
             } else {
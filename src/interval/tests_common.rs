@@ -0,0 +1,133 @@
+use super::Interval;
+
+use fp::Bound;
+
+macro_rules! assert_str_eq {
+    ($x:expr, $y:expr) => { assert_eq!($x, format!("{}", $y)) };
+    ($x:expr, $y:expr, $($arg:tt)+) => { assert_eq!($x, format!("{}", $y), $($arg)+) };
+}
+
+fn iv<B: Bound>(precision: usize, s: &str) -> Interval<B> {
+    Interval::from_str_with_prec(s, precision).unwrap()
+}
+
+pub fn simple<'a, B: Bound>(precision: usize) -> Vec<(&'a str, Interval<B>)> {
+    vec![
+        ("nan", iv(precision, "NaN")),
+        ("whl", iv(precision, "<-inf, inf>")),
+        ("m", iv(precision, "<-1, 1>")),
+        ("z", iv(precision, "0")),
+        ("p", iv(precision, "<1, 2>")),
+        ("n", iv(precision, "<-2, -1>")),
+    ]
+}
+
+pub fn all_sign_classes<'a, B: Bound>(precision: usize) -> Vec<(&'a str, Interval<B>)> {
+    vec![
+        ("nan", iv(precision, "NaN")),
+        ("whl", iv(precision, "<-inf, inf>")),
+        ("m", iv(precision, "<-1, 1>")),
+        ("z", iv(precision, "0")),
+        ("p0", iv(precision, "<0, 1>")),
+        ("p1", iv(precision, "<1, 2>")),
+        ("n0", iv(precision, "<-1, 0>")),
+        ("n1", iv(precision, "<-2, -1>")),
+    ]
+}
+
+fn find_unary_case<'z>(cases: &'z Vec<(&str, &str)>, cx: &str) -> &'z str {
+    for (cz, z) in cases {
+        let is_match = cx == *cz || "*" == *cz;
+        if is_match {
+            return z;
+        }
+    }
+    panic!("unmatched case: {}", cx);
+}
+
+fn find_binary_case<'z>(
+    cases: &'z Vec<(&str, &str)>,
+    cx: &str,
+    cy: &str,
+    commutative: bool,
+) -> Option<&'z str> {
+    for (cz, z) in cases {
+        let cz = String::from(*cz);
+        let mut is_match = format!("{}.{}", cx, cy) == cz
+            || format!("{}.*", cx).as_str() == cz
+            || format!("*.{}", cy).as_str() == cz
+            || "*.*" == cz;
+        if commutative {
+            is_match = is_match
+                || format!("{}.{}", cy, cx) == cz
+                || format!("{}.*", cy).as_str() == cz
+                || format!("*.{}", cx).as_str() == cz;
+        }
+        if is_match {
+            return Some(z);
+        }
+    }
+    None
+}
+
+pub fn test_unary_op<B: Bound, OP, R>(
+    op: OP,
+    cases: Vec<(&str, Interval<B>)>,
+    expected: Vec<(&str, &str)>,
+    print: bool,
+) where
+    OP: Fn(Interval<B>) -> R,
+    R: std::fmt::Display + Sized,
+{
+    for (cx, x) in cases {
+        let z = op(x.clone());
+        if print {
+            println!("    (\"{}\", \"{}\"),", cx, z);
+        } else {
+            assert_str_eq!(
+                String::from(find_unary_case(&expected, cx)),
+                z,
+                "{} ({})",
+                cx,
+                x
+            );
+        }
+    }
+}
+
+pub fn test_binary_op<B: Bound, OP, R>(
+    op: OP,
+    left_cases: Vec<(&str, Interval<B>)>,
+    right_cases: Vec<(&str, Interval<B>)>,
+    mut expected: Vec<(&str, &str)>,
+    commutative: bool,
+) where
+    OP: Fn(Interval<B>, Interval<B>) -> R,
+    R: std::fmt::Display + Sized,
+{
+    let mut expected_up_to_now = Vec::<(&str, &str)>::new();
+    for (cz, z) in expected.drain(..) {
+        let parts = cz.split(".").collect::<Vec<_>>();
+        let (cx, cy) = (parts[0], parts[1]);
+        if find_binary_case(&expected_up_to_now, cx, cy, commutative).is_some() {
+            panic!("redundant case: {}.{}", cx, cy);
+        }
+        expected_up_to_now.push((cz, z));
+    }
+    expected = expected_up_to_now;
+
+    let mut unmatched_cases = Vec::<String>::new();
+    for (cx, x) in left_cases.clone() {
+        for (cy, y) in right_cases.clone() {
+            let z = op(x.clone(), y.clone());
+            if let Some(cz) = find_binary_case(&expected, cx, cy, commutative) {
+                assert_str_eq!(String::from(cz), z, "{}.{} ({} . {})", cx, cy, x, y);
+            } else {
+                unmatched_cases.push(format!("    (\"{}.{}\", \"{}\"),", cx, cy, z));
+            }
+        }
+    }
+    if !unmatched_cases.is_empty() {
+        panic!("unmatched case(s):\n{}", unmatched_cases.join("\n"));
+    }
+}
@@ -470,9 +470,9 @@ fn all_sign_classes<'a>() -> Vec<(&'a str, IV)> {
     ]
 }
 
-fn find_unary_case<'x, 'z>(cases: &'z Vec<(&str, &str)>, cx: &'x str) -> &'z str {
-    for &(ref cz, ref z) in cases {
-        let is_match = &format!("{}", cx).as_str() == cz || &format!("*").as_str() == cz;
+fn find_unary_case<'z>(cases: &'z Vec<(&str, &str)>, cx: &str) -> &'z str {
+    for (cz, z) in cases {
+        let is_match = cx == *cz || "*" == *cz;
         if is_match {
             return z;
         }
@@ -480,18 +480,18 @@ fn find_unary_case<'x, 'z>(cases: &'z Vec<(&str, &str)>, cx: &'x str) -> &'z str
     panic!("unmatched case: {}", cx);
 }
 
-fn find_binary_case<'x, 'y, 'z>(
+fn find_binary_case<'z>(
     cases: &'z Vec<(&str, &str)>,
-    cx: &'x str,
-    cy: &'y str,
+    cx: &str,
+    cy: &str,
     commutative: bool,
 ) -> Option<&'z str> {
-    for &(ref cz, ref z) in cases {
-        let cz = String::from(cz.clone());
+    for (cz, z) in cases {
+        let cz = String::from(*cz);
         let mut is_match = format!("{}.{}", cx, cy) == cz
             || format!("{}.*", cx).as_str() == cz
             || format!("*.{}", cy).as_str() == cz
-            || format!("*.*").as_str() == cz;
+            || "*.*" == cz;
         if commutative {
             is_match = is_match
                 || format!("{}.{}", cy, cx) == cz
@@ -505,9 +505,9 @@ fn find_binary_case<'x, 'y, 'z>(
     None
 }
 
-pub fn test_unary_op<'a, OP, R>(
+pub fn test_unary_op<OP, R>(
     op: OP,
-    cases: Vec<(&'a str, IV)>,
+    cases: Vec<(&str, IV)>,
     expected: Vec<(&str, &str)>,
     print: bool,
 ) where
@@ -530,10 +530,10 @@ pub fn test_unary_op<'a, OP, R>(
     }
 }
 
-pub fn test_binary_op<'a, OP, R>(
+pub fn test_binary_op<OP, R>(
     op: OP,
-    left_cases: Vec<(&'a str, IV)>,
-    right_cases: Vec<(&'a str, IV)>,
+    left_cases: Vec<(&str, IV)>,
+    right_cases: Vec<(&str, IV)>,
     mut expected: Vec<(&str, &str)>,
     commutative: bool,
 ) where
@@ -0,0 +1,133 @@
+use super::Interval;
+use fp::Bound;
+use intervalset::IntervalSet;
+
+type IV = Interval<i64>;
+#[allow(clippy::upper_case_acronyms)]
+type IVS = IntervalSet<i64>;
+
+const PREC: usize = 63;
+
+macro_rules! assert_str_eq {
+    ($x:expr, $y:expr) => { assert_eq!($x, format!("{}", $y)) };
+    ($x:expr, $y:expr, $($arg:tt)+) => { assert_eq!($x, format!("{}", $y), $($arg)+) };
+}
+
+macro_rules! iv {
+    ($s:expr) => {
+        IV::from_str_with_prec($s, PREC).unwrap()
+    };
+}
+
+macro_rules! ivs {
+    ($s:expr) => {
+        IVS::from_str_with_prec($s, PREC).unwrap()
+    };
+}
+
+#[test]
+fn test_new() {
+    assert_str_eq!("<0, 1>", IV::new(0, 1));
+    assert_str_eq!("-1", IV::new(-1, -1));
+    assert_str_eq!("<-inf, inf>", IV::whole(PREC));
+    assert_str_eq!("<-inf, 3>", IV::new(i64::neg_infinity(PREC), 3));
+    assert_str_eq!("NaN", IV::nan(PREC));
+    assert_eq!(PREC, IV::zero(PREC).precision());
+}
+
+#[test]
+fn test_from_str() {
+    assert_str_eq!("<1, 5>", iv!("<1, 5>"));
+    assert_str_eq!("<0, 1>", iv!("1/3"));
+    assert_str_eq!("<-1, 0>", iv!("-0.5"));
+    assert_str_eq!("<0, 2>", iv!("<0.5, 1.5>"));
+    assert_str_eq!("<9223372036854775806, inf>", iv!("1e30"));
+    assert_str_eq!("<-inf, 0>", iv!("<-inf, 0>"));
+    assert!("<1, x>".parse::<IV>().is_err());
+}
+
+#[test]
+fn test_neg() {
+    assert_str_eq!("<-5, -1>", -iv!("<1, 5>"));
+    assert_str_eq!("<-inf, 1>", -iv!("<-1, inf>"));
+    assert_str_eq!("NaN", -IV::nan(PREC));
+}
+
+#[test]
+fn test_add_sub() {
+    assert_str_eq!("<4, 8>", iv!("<1, 2>") + iv!("<3, 6>"));
+    assert_str_eq!("<-5, -1>", iv!("<1, 2>") - iv!("<3, 6>"));
+    assert_str_eq!("<1, inf>", iv!("<1, 2>") + iv!("<0, inf>"));
+    let max = IV::singleton(i64::MAX - 1);
+    assert_str_eq!("<9223372036854775806, inf>", max.clone() + iv!("1"));
+    assert_str_eq!("<-inf, -9223372036854775806>", -max - iv!("1"));
+    assert_eq!((iv!("5"), true), iv!("2").add_exact(iv!("3")));
+    assert!(!IV::singleton(i64::MAX - 1).add_exact(iv!("1")).1);
+}
+
+#[test]
+fn test_mul() {
+    assert_str_eq!("<-10, 15>", iv!("<-2, 3>") * iv!("<1, 5>"));
+    assert_str_eq!("<-inf, inf>", iv!("<-2, 3>") * iv!("<1, inf>"));
+    assert_str_eq!("0", iv!("0") * IV::whole(PREC));
+    let big = iv!("4294967296");
+    assert_str_eq!("<9223372036854775806, inf>", big.clone() * big.clone());
+    assert!(!big.clone().mul_exact(big).1);
+}
+
+#[test]
+fn test_div() {
+    assert_str_eq!("<0, 2>", iv!("<1, 5>") / iv!("<2, 3>"));
+    assert_str_eq!("<-3, -1>", iv!("<-5, -1>") / iv!("<2, 3>"));
+    assert_str_eq!("<-5, 5>", iv!("<1, 5>") / iv!("<-1, 1>"));
+    assert_str_eq!("<-5, -1>", iv!("<1, 5>") / iv!("<-inf, -1>"));
+    assert_str_eq!("<0, 5>", iv!("<1, 5>") / iv!("<1, inf>"));
+    assert_str_eq!("NaN", iv!("<1, 5>") / iv!("0"));
+    assert_eq!(
+        vec![iv!("<-5, -1>"), iv!("<1, 5>")],
+        iv!("<1, 5>").div_floor_multi(iv!("<-1, 1>"))
+    );
+}
+
+#[test]
+fn test_minmax() {
+    assert_str_eq!("<0, 3>", iv!("<-2, 3>").abs());
+    assert_str_eq!("<-2, 3>", iv!("<-2, 3>").min(iv!("<1, 5>")));
+    assert_str_eq!("<1, 5>", iv!("<-2, 3>").max(iv!("<1, 5>")));
+    assert_str_eq!("<-2, 1>", iv!("<-2, 3>").min(iv!("1")));
+}
+
+#[test]
+fn test_i128() {
+    let a = Interval::<i128>::from_str_with_prec("<1e20, 1e21>", 127).unwrap();
+    assert_str_eq!(
+        "<200000000000000000000, 2000000000000000000000>",
+        a.clone() + a.clone()
+    );
+    assert_str_eq!(
+        "<170141183460469231731687303715884105726, inf>",
+        a.clone() * a.clone()
+    );
+    assert_str_eq!(
+        "<33333333333333333334, 333333333333333333334>",
+        -a.div_floor(Interval::<i128>::new(-3, -3))
+    );
+}
+
+#[test]
+fn test_set() {
+    let s = IVS::from_intervals(vec![
+        iv!("<5, 7>"),
+        iv!("<1, 3>"),
+        iv!("<2, 6>"),
+        iv!("<10, 11>"),
+    ]);
+    assert_str_eq!("{<1, 7>; <10, 11>}", s);
+    assert_str_eq!("{<-5, -1>; <1, 5>}", ivs!("<1, 5>") / ivs!("<-1, 1>"));
+    assert_str_eq!(
+        "{<-7, -1>; <1, 7>}",
+        ivs!("{<-7, -1>; <1, 7>}").abs() * ivs!("{-1; 1}")
+    );
+    assert_str_eq!("{<-11, -10>; <-7, -1>}", -s.clone());
+    assert_str_eq!("{<2, 8>; <11, 12>}", s + ivs!("1"));
+}
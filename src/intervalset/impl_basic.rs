@@ -1,6 +1,6 @@
 use super::def::{IntervalSet, ParseIntervalSetError};
 
-use fp::Float;
+use fp::{Bound, Float};
 use interval::Interval;
 
 use std::cmp::Ordering;
@@ -8,7 +8,7 @@ use std::fmt;
 use std::fmt::{Display, Formatter, Write};
 use std::str::FromStr;
 
-impl<BOUND: Float> IntervalSet<BOUND> {
+impl<BOUND: Bound> IntervalSet<BOUND> {
     /// Constructs an interval set of one interval from given bounds.
     ///
     /// The result may be empty if both bounds are NaN.
@@ -98,15 +98,6 @@ impl<BOUND: Float> IntervalSet<BOUND> {
         Self::from_interval(Interval::whole(precision))
     }
 
-    /// Constructs an interval set from a float with given precision.
-    #[inline]
-    pub fn from_with_prec(val: f64, precision: usize) -> Self {
-        Self::new(
-            BOUND::from_lo(val, precision),
-            BOUND::from_hi(val, precision),
-        )
-    }
-
     /// Constructs an interval set by parsing a string.
     ///
     /// Accepts `INTERVAL_SET` according to the rule below.
@@ -181,6 +172,36 @@ impl<BOUND: Float> IntervalSet<BOUND> {
         }
         Self::from_intervals(intervals)
     }
+
+    /// Performs a binary operation that tells whether its result is exact by performing it on all
+    /// pairs of intervals of `self` and `rhs`, and tells whether all bounds of the result are
+    /// exact.
+    pub fn binary_op_exact<OP>(self, rhs: Self, op: OP) -> (Self, bool)
+    where
+        OP: Fn(Interval<BOUND>, Interval<BOUND>) -> (Vec<Interval<BOUND>>, bool),
+    {
+        let mut intervals = Vec::<Interval<BOUND>>::new();
+        let mut exact = true;
+        for i in &self.intervals {
+            for j in &rhs.intervals {
+                let (mut res, res_exact) = op(i.clone(), j.clone());
+                intervals.append(&mut res);
+                exact &= res_exact;
+            }
+        }
+        (Self::from_intervals(intervals), exact)
+    }
+}
+
+impl<BOUND: Float> IntervalSet<BOUND> {
+    /// Constructs an interval set from a float with given precision.
+    #[inline]
+    pub fn from_with_prec(val: f64, precision: usize) -> Self {
+        Self::new(
+            BOUND::from_lo(val, precision),
+            BOUND::from_hi(val, precision),
+        )
+    }
 }
 
 impl<BOUND: Float> From<f64> for IntervalSet<BOUND> {
@@ -190,7 +211,7 @@ impl<BOUND: Float> From<f64> for IntervalSet<BOUND> {
     }
 }
 
-impl<BOUND: Float> FromStr for IntervalSet<BOUND> {
+impl<BOUND: Bound> FromStr for IntervalSet<BOUND> {
     type Err = ParseIntervalSetError;
 
     #[inline]
@@ -199,35 +220,27 @@ impl<BOUND: Float> FromStr for IntervalSet<BOUND> {
     }
 }
 
-impl<BOUND: Float> Display for IntervalSet<BOUND> {
+impl<BOUND: Bound> Display for IntervalSet<BOUND> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         if self.intervals.is_empty() {
             f.write_str("{}")
         } else if self.intervals.len() == 1 {
             Display::fmt(&self.intervals[0], f)
         } else {
-            if let Err(e) = f.write_char('{') {
-                return Err(e);
-            }
+            f.write_char('{')?;
             let mut iter = self.intervals.iter();
-            if let Err(e) = Display::fmt(&iter.next().unwrap(), f) {
-                return Err(e);
-            }
+            Display::fmt(&iter.next().unwrap(), f)?;
             for i in iter {
-                if let Err(e) = f.write_str("; ") {
-                    return Err(e);
-                }
-                if let Err(e) = Display::fmt(&i, f) {
-                    return Err(e);
-                }
+                f.write_str("; ")?;
+                Display::fmt(&i, f)?;
             }
             f.write_char('}')
         }
     }
 }
 
-impl<BOUND: Float> Into<Vec<(BOUND, BOUND)>> for IntervalSet<BOUND> {
-    fn into(mut self) -> Vec<(BOUND, BOUND)> {
-        self.intervals.drain(..).map(|i| (i.lo, i.hi)).collect()
+impl<BOUND: Bound> From<IntervalSet<BOUND>> for Vec<(BOUND, BOUND)> {
+    fn from(mut s: IntervalSet<BOUND>) -> Self {
+        s.intervals.drain(..).map(|i| (i.lo, i.hi)).collect()
     }
 }
@@ -1,15 +1,35 @@
 use super::def::IntervalSet;
 
-use fp::Float;
+use fp;
+use fp::{Bound, TotalOrd};
 
-impl<BOUND: Float> PartialEq for IntervalSet<BOUND> {
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+impl<BOUND: Bound> PartialEq for IntervalSet<BOUND> {
     #[inline]
     fn eq(&self, rhs: &Self) -> bool {
+        self.intervals == rhs.intervals
+    }
+}
+
+/// Orders interval sets lexicographically by their intervals. A set that is a prefix of another
+/// one is less than it, so that the empty set is the least.
+impl<BOUND: Bound + TotalOrd> fp::TotalOrd for IntervalSet<BOUND> {
+    fn cmp_total(&self, rhs: &Self) -> Ordering {
         for (i, j) in self.intervals.iter().zip(&rhs.intervals) {
-            if !(i.lo == j.lo && i.hi == j.hi) {
-                return false;
+            match i.cmp_total(j) {
+                Ordering::Equal => {}
+                ord => return ord,
             }
         }
-        true
+        self.intervals.len().cmp(&rhs.intervals.len())
+    }
+
+    fn hash_total<H: Hasher>(&self, state: &mut H) {
+        self.intervals.len().hash(state);
+        for i in &self.intervals {
+            i.hash_total(state);
+        }
     }
 }
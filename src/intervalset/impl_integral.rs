@@ -0,0 +1,144 @@
+use super::def::IntervalSet;
+
+use fp::Float;
+use interval::Interval;
+
+/// Default maximum number of integers enumerated by the rounding functions of `IntervalSet`.
+pub const DEFAULT_CARDINALITY_LIMIT: usize = 64;
+
+impl<BOUND: Float> IntervalSet<BOUND> {
+    /// Maps each interval of `self` to a range of integers using `op` and enumerates the integers.
+    ///
+    /// If the total number of integers exceeds `limit`, or if any of them is not exactly
+    /// representable, the integer ranges are returned as intervals instead.
+    fn integral_op<OP>(mut self, op: OP, limit: usize) -> Self
+    where
+        OP: Fn(Interval<BOUND>) -> Interval<BOUND>,
+    {
+        let ranges = Self::from_intervals(self.intervals.drain(..).map(op).collect());
+        let mut count = 0f64;
+        for i in &ranges.intervals {
+            count += i.hi.clone().sub_hi(i.lo.clone()).into_hi() + 1.0;
+        }
+        if count.is_nan() || count > limit as f64 {
+            return ranges;
+        }
+        let mut intervals = Vec::<Interval<BOUND>>::new();
+        for i in &ranges.intervals {
+            let one = BOUND::one(i.precision());
+            let mut val = i.lo.clone();
+            while val <= i.hi {
+                intervals.push(Interval::singleton(val.clone()));
+                // Rounding up avoids a negative zero after `-1`.
+                let (next, exact) = val.add_hi_exact(one.clone());
+                if !exact {
+                    return ranges;
+                }
+                val = next;
+            }
+        }
+        Self { intervals }
+    }
+
+    /// Computes the greatest integer less than or equal to each element of `self`.
+    ///
+    /// The result is the exact set of integers if there are at most `DEFAULT_CARDINALITY_LIMIT`
+    /// of them. See `IntervalSet::floor_with_limit` for details.
+    #[inline]
+    pub fn floor(self) -> Self {
+        self.floor_with_limit(DEFAULT_CARDINALITY_LIMIT)
+    }
+
+    /// Computes the greatest integer less than or equal to each element of `self`.
+    ///
+    /// The result is the exact set of integers if there are at most `limit` of them. Otherwise,
+    /// the integers are covered by intervals.
+    #[inline]
+    pub fn floor_with_limit(self, limit: usize) -> Self {
+        self.integral_op(|i| i.floor(), limit)
+    }
+
+    /// Computes the smallest integer greater than or equal to each element of `self`.
+    ///
+    /// See `IntervalSet::floor` for the form of the result.
+    #[inline]
+    pub fn ceil(self) -> Self {
+        self.ceil_with_limit(DEFAULT_CARDINALITY_LIMIT)
+    }
+
+    /// Computes the smallest integer greater than or equal to each element of `self`.
+    ///
+    /// See `IntervalSet::floor_with_limit` for the form of the result.
+    #[inline]
+    pub fn ceil_with_limit(self, limit: usize) -> Self {
+        self.integral_op(|i| i.ceil(), limit)
+    }
+
+    /// Rounds each element of `self` to the nearest integer. Ties go to the even integer.
+    ///
+    /// See `IntervalSet::floor` for the form of the result.
+    #[inline]
+    pub fn round_ties_even(self) -> Self {
+        self.round_ties_even_with_limit(DEFAULT_CARDINALITY_LIMIT)
+    }
+
+    /// Rounds each element of `self` to the nearest integer. Ties go to the even integer.
+    ///
+    /// See `IntervalSet::floor_with_limit` for the form of the result.
+    #[inline]
+    pub fn round_ties_even_with_limit(self, limit: usize) -> Self {
+        self.integral_op(|i| i.round_ties_even(), limit)
+    }
+
+    /// Rounds each element of `self` to the nearest integer. Ties go away from zero.
+    ///
+    /// See `IntervalSet::floor` for the form of the result.
+    #[inline]
+    pub fn round_ties_away(self) -> Self {
+        self.round_ties_away_with_limit(DEFAULT_CARDINALITY_LIMIT)
+    }
+
+    /// Rounds each element of `self` to the nearest integer. Ties go away from zero.
+    ///
+    /// See `IntervalSet::floor_with_limit` for the form of the result.
+    #[inline]
+    pub fn round_ties_away_with_limit(self, limit: usize) -> Self {
+        self.integral_op(|i| i.round_ties_away(), limit)
+    }
+
+    /// Rounds each element of `self` towards zero.
+    ///
+    /// See `IntervalSet::floor` for the form of the result.
+    #[inline]
+    pub fn trunc(self) -> Self {
+        self.trunc_with_limit(DEFAULT_CARDINALITY_LIMIT)
+    }
+
+    /// Rounds each element of `self` towards zero.
+    ///
+    /// See `IntervalSet::floor_with_limit` for the form of the result.
+    #[inline]
+    pub fn trunc_with_limit(self, limit: usize) -> Self {
+        self.integral_op(|i| i.trunc(), limit)
+    }
+
+    /// Computes the sign (`-1`, `0` or `1`) of each element of `self`.
+    ///
+    /// The result is always the exact set of signs.
+    #[inline]
+    pub fn sign(self) -> Self {
+        self.integral_op(|i| i.sign(), 3)
+    }
+
+    /// Computes the fractional part of each element of `self`.
+    ///
+    /// The fractional part has the same sign as the element. See `fp::Integral::frac`.
+    pub fn frac(mut self) -> Self {
+        Self::from_intervals(
+            self.intervals
+                .drain(..)
+                .flat_map(|i| i.frac_multi())
+                .collect(),
+        )
+    }
+}
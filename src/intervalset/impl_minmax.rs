@@ -0,0 +1,38 @@
+use super::def::IntervalSet;
+
+use fp::{Bound, Float};
+
+impl<BOUND: Bound> IntervalSet<BOUND> {
+    /// Computes the absolute value of each element of `self`.
+    pub fn abs(mut self) -> Self {
+        Self::from_intervals(self.intervals.drain(..).map(|i| i.abs()).collect())
+    }
+
+    /// Computes the minimum of each pair of elements of `self` and `rhs`.
+    #[inline]
+    pub fn min(self, rhs: Self) -> Self {
+        self.binary_op(rhs, |i, j| vec![i.min(j)])
+    }
+
+    /// Computes the maximum of each pair of elements of `self` and `rhs`.
+    #[inline]
+    pub fn max(self, rhs: Self) -> Self {
+        self.binary_op(rhs, |i, j| vec![i.max(j)])
+    }
+
+    /// Restricts each element of `self` to the range given by the elements of `min` and `max`.
+    ///
+    /// This is equivalent to `self.max(min).min(max)`.
+    #[inline]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        self.max(min).min(max)
+    }
+}
+
+impl<BOUND: Float> IntervalSet<BOUND> {
+    /// Computes `sqrt(x^2 + y^2)` for each pair of elements `x` of `self` and `y` of `rhs`.
+    #[inline]
+    pub fn hypot(self, rhs: Self) -> Self {
+        self.binary_op(rhs, |i, j| vec![i.hypot(j)])
+    }
+}
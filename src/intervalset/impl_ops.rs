@@ -1,10 +1,11 @@
 use super::def::IntervalSet;
 
-use fp::Float;
+use fp::{Bound, Float, Integer};
 
+use std::iter;
 use std::ops::{Add, Div, Mul, Neg, Sub};
 
-impl<BOUND: Float> Neg for IntervalSet<BOUND> {
+impl<BOUND: Bound> Neg for IntervalSet<BOUND> {
     type Output = Self;
 
     #[inline]
@@ -15,7 +16,7 @@ impl<BOUND: Float> Neg for IntervalSet<BOUND> {
     }
 }
 
-impl<BOUND: Float> Add<Self> for IntervalSet<BOUND> {
+impl<BOUND: Bound> Add<Self> for IntervalSet<BOUND> {
     type Output = Self;
 
     #[inline]
@@ -24,7 +25,7 @@ impl<BOUND: Float> Add<Self> for IntervalSet<BOUND> {
     }
 }
 
-impl<BOUND: Float> Sub<Self> for IntervalSet<BOUND> {
+impl<BOUND: Bound> Sub<Self> for IntervalSet<BOUND> {
     type Output = Self;
 
     #[inline]
@@ -33,7 +34,7 @@ impl<BOUND: Float> Sub<Self> for IntervalSet<BOUND> {
     }
 }
 
-impl<BOUND: Float> Mul<Self> for IntervalSet<BOUND> {
+impl<BOUND: Bound> Mul<Self> for IntervalSet<BOUND> {
     type Output = Self;
 
     #[inline]
@@ -50,3 +51,110 @@ impl<BOUND: Float> Div<Self> for IntervalSet<BOUND> {
         self.binary_op(other, |i, j| i.div_multi(j))
     }
 }
+
+impl<BOUND: Bound> iter::Sum for IntervalSet<BOUND> {
+    fn sum<I: Iterator<Item = Self>>(mut iter: I) -> Self {
+        match iter.next() {
+            Some(first) => iter.fold(first, |s, x| s + x),
+            None => Self::zero(53),
+        }
+    }
+}
+
+impl<BOUND: Bound> iter::Product for IntervalSet<BOUND> {
+    fn product<I: Iterator<Item = Self>>(mut iter: I) -> Self {
+        match iter.next() {
+            Some(first) => iter.fold(first, |p, x| p * x),
+            None => Self::one(53),
+        }
+    }
+}
+
+impl<BOUND: Bound> IntervalSet<BOUND> {
+    /// Adds `self` to `rhs` and tells whether all bounds of the result are exact.
+    #[inline]
+    pub fn add_exact(self, rhs: Self) -> (Self, bool) {
+        self.binary_op_exact(rhs, |i, j| {
+            let (k, exact) = i.add_exact(j);
+            (vec![k], exact)
+        })
+    }
+
+    /// Subtracts `rhs` from `self` and tells whether all bounds of the result are exact.
+    #[inline]
+    pub fn sub_exact(self, rhs: Self) -> (Self, bool) {
+        self.binary_op_exact(rhs, |i, j| {
+            let (k, exact) = i.sub_exact(j);
+            (vec![k], exact)
+        })
+    }
+
+    /// Multiplies `self` by `rhs` and tells whether all bounds of the result are exact.
+    #[inline]
+    pub fn mul_exact(self, rhs: Self) -> (Self, bool) {
+        self.binary_op_exact(rhs, |i, j| {
+            let (k, exact) = i.mul_exact(j);
+            (vec![k], exact)
+        })
+    }
+}
+
+impl<BOUND: Float> IntervalSet<BOUND> {
+    /// Divides `self` by `rhs` and tells whether all bounds of the result are exact.
+    #[inline]
+    pub fn div_exact(self, rhs: Self) -> (Self, bool) {
+        self.binary_op_exact(rhs, |i, j| i.div_multi_exact(j))
+    }
+
+    /// Computes the remainder of `self / rhs` with the quotient rounded towards zero.
+    ///
+    /// See `Interval::fmod_multi` for details.
+    #[inline]
+    pub fn fmod(self, rhs: Self) -> Self {
+        self.binary_op(rhs, |i, j| i.fmod_multi(j))
+    }
+
+    /// Computes the remainder of `self / rhs` with the quotient rounded to the nearest integer.
+    ///
+    /// See `Interval::remainder_multi` for details.
+    #[inline]
+    pub fn remainder(self, rhs: Self) -> Self {
+        self.binary_op(rhs, |i, j| i.remainder_multi(j))
+    }
+
+    /// Computes the least non-negative remainder of `self / rhs`.
+    ///
+    /// See `Interval::rem_euclid_multi` for details.
+    #[inline]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        self.binary_op(rhs, |i, j| i.rem_euclid_multi(j))
+    }
+}
+
+impl<BOUND: Integer> IntervalSet<BOUND> {
+    /// Divides `self` by `rhs` with each quotient rounded towards negative infinity.
+    ///
+    /// See `Interval::div_floor_multi` for details.
+    #[inline]
+    pub fn div_floor(self, rhs: Self) -> Self {
+        self.binary_op(rhs, |i, j| i.div_floor_multi(j))
+    }
+}
+
+impl Div<Self> for IntervalSet<i64> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: Self) -> Self::Output {
+        self.div_floor(other)
+    }
+}
+
+impl Div<Self> for IntervalSet<i128> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: Self) -> Self::Output {
+        self.div_floor(other)
+    }
+}
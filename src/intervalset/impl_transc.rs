@@ -3,6 +3,47 @@ use super::def::IntervalSet;
 use fp::Float;
 use transc::Transc;
 
+impl<BOUND: Float> IntervalSet<BOUND> {
+    /// Computes the natural logarithm of `self` and tells whether all bounds of the result are
+    /// exact.
+    pub fn log_exact(mut self) -> (Self, bool) {
+        let mut exact = true;
+        let intervals = self
+            .intervals
+            .drain(..)
+            .map(|i| {
+                let (res, res_exact) = i.log_exact();
+                exact &= res_exact;
+                res
+            })
+            .collect();
+        (Self::from_intervals(intervals), exact)
+    }
+
+    /// Computes the natural exponential of `self` and tells whether all bounds of the result are
+    /// exact.
+    pub fn exp_exact(mut self) -> (Self, bool) {
+        let mut exact = true;
+        let intervals = self
+            .intervals
+            .drain(..)
+            .map(|i| {
+                let (res, res_exact) = i.exp_exact();
+                exact &= res_exact;
+                res
+            })
+            .collect();
+        (Self::from_intervals(intervals), exact)
+    }
+
+    /// Computes `self` raised to the power `rhs` and tells whether all bounds of the result are
+    /// exact.
+    #[inline]
+    pub fn pow_exact(self, rhs: Self) -> (Self, bool) {
+        self.binary_op_exact(rhs, |i, j| i.pow_multi_exact(j))
+    }
+}
+
 impl<BOUND: Float> Transc for IntervalSet<BOUND> {
     type Output = Self;
 
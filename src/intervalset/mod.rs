@@ -1,6 +1,8 @@
 mod def;
 mod impl_basic;
 mod impl_cmp;
+mod impl_integral;
+mod impl_minmax;
 mod impl_ops;
 mod impl_transc;
 
@@ -8,3 +10,4 @@ mod impl_transc;
 mod tests;
 
 pub use self::def::{IntervalSet, ParseIntervalSetError};
+pub use self::impl_integral::DEFAULT_CARDINALITY_LIMIT;
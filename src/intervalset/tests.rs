@@ -3,6 +3,7 @@ use super::IntervalSet;
 use mpfr::Mpfr;
 
 type B = Mpfr;
+#[allow(clippy::upper_case_acronyms)]
 type IVS = IntervalSet<B>;
 
 const PREC: usize = 2;
@@ -188,6 +189,48 @@ fn test_partial_eq() {
     assert_eq!(ivs!("{<0, 1>}"), ivs!("{<0, 1>}"));
     assert_eq!(ivs!("{<0, 1>; 2}"), ivs!("{<0, 1>; 2}"));
     assert_ne!(ivs!("{<0, 1>; 2}"), ivs!("{<0, 1>; 3}"));
+    assert_ne!(ivs!("{1}"), ivs!("{1; 2}"));
+    assert_ne!(ivs!("{1; 2}"), ivs!("{1}"));
+    assert_ne!(ivs!("{}"), ivs!("{1}"));
+}
+
+#[test]
+fn test_total() {
+    use fp::Total;
+    use std::collections::{BTreeMap, HashMap};
+    let mut cache = HashMap::new();
+    cache.insert(Total(ivs!("{<0, 1>; 2}")), 1);
+    cache.insert(Total(ivs!("{1; 2}")), 2);
+    cache.insert(Total(ivs!("{1}")), 3);
+    assert_eq!(
+        Some(&1),
+        cache.get(&Total(IVS::from_str_with_prec("{<-0, 1>; 2}", 53).unwrap()))
+    );
+    assert_eq!(Some(&2), cache.get(&Total(ivs!("{1; 2}"))));
+    assert_eq!(Some(&3), cache.get(&Total(ivs!("{1}"))));
+    assert_eq!(None, cache.get(&Total(ivs!("{2}"))));
+    let mut sorted = BTreeMap::new();
+    for s in &[
+        "{1; 2}",
+        "{}",
+        "{<0, 1>; 2}",
+        "{1}",
+        "{<-inf, 0>}",
+        "{<0, 1>}",
+    ] {
+        sorted.insert(Total(ivs!(s)), *s);
+    }
+    assert_eq!(
+        vec![
+            "{}",
+            "{<-inf, 0>}",
+            "{<0, 1>}",
+            "{<0, 1>; 2}",
+            "{1}",
+            "{1; 2}"
+        ],
+        sorted.values().cloned().collect::<Vec<_>>()
+    );
 }
 
 #[test]
@@ -372,6 +415,109 @@ fn test_div() {
     );
 }
 
+#[test]
+fn test_iter_sum_product() {
+    let xs = vec![ivs!("{<0, 1>; <3, 4>}"), ivs!("1"), ivs!("<-1, 0>")];
+    assert_str_eq!("{<-0, 2>; <3, 6>}", xs.into_iter().sum::<IVS>());
+    assert_str_eq!("0", Vec::<IVS>::new().into_iter().sum::<IVS>());
+    let xs = vec![ivs!("{<-2, -1>; <1, 2>}"), ivs!("-1")];
+    assert_str_eq!("{<-2, -1>; <1, 2>}", xs.into_iter().product::<IVS>());
+    assert_str_eq!("1", Vec::<IVS>::new().into_iter().product::<IVS>());
+    assert_str_eq!("{}", vec![ivs!("1"), ivs!("{}")].into_iter().sum::<IVS>());
+}
+
+#[test]
+fn test_floor() {
+    assert_str_eq!("{0; 1; 2; 3}", ivs!("<0.75, 3>").floor());
+    assert_str_eq!("{-2; -1; 0; 1}", ivs!("{<-1.5, -1>; <0, 1.5>}").floor());
+    assert_str_eq!("{<0, 1>; 3}", ivs!("{<0.75, 1.5>; 3}").floor_with_limit(2));
+    assert_str_eq!("<1, 6>", ivs!("<1, 6>").floor());
+    assert_str_eq!("<-inf, 1>", ivs!("<-inf, 1.5>").floor());
+    assert_str_eq!("{}", ivs!("{}").floor());
+}
+
+#[test]
+fn test_ceil() {
+    assert_str_eq!("{1; 2; 3}", ivs!("<0.75, 3>").ceil());
+    assert_str_eq!("<1, 3>", ivs!("<0.75, 3>").ceil_with_limit(2));
+}
+
+#[test]
+fn test_round() {
+    assert_str_eq!("{-2; -1; 0; 1; 2}", ivs!("<-1.5, 1.5>").round_ties_even());
+    assert_str_eq!("{-1; 0; 1}", ivs!("<-0.5, 0.5>").round_ties_away());
+}
+
+#[test]
+fn test_trunc() {
+    assert_str_eq!("{-1; 0; 1}", ivs!("<-1.5, 1.5>").trunc());
+}
+
+#[test]
+fn test_sign() {
+    assert_str_eq!("{-1; 0; 1}", ivs!("<-inf, inf>").sign());
+    assert_str_eq!("{-1; 1}", ivs!("{<-2, -1>; <1, 2>}").sign());
+    assert_str_eq!("0", ivs!("0").sign());
+    assert_str_eq!("{}", ivs!("{}").sign());
+}
+
+#[test]
+fn test_frac() {
+    assert_str_eq!("{<0, 0.5>; <0.75, 1>}", ivs!("<0.75, 1.5>").frac());
+    assert_str_eq!("<-1, 1>", ivs!("<-1.5, 1.5>").frac());
+    assert_str_eq!("{-0.5; <0, 1>}", ivs!("{-1.5; <0.75, 2>}").frac());
+}
+
+#[test]
+fn test_abs() {
+    assert_str_eq!("<0, 3>", ivs!("<-3, 2>").abs());
+    assert_str_eq!("<1, 2>", ivs!("{<-2, -1>; <1, 1.5>}").abs());
+    assert_str_eq!("{<0, 0.75>; 2; 3}", ivs!("{-3; <-0.75, 0.5>; 2}").abs());
+    assert_str_eq!("{}", ivs!("{}").abs());
+}
+
+#[test]
+fn test_min_max() {
+    assert_str_eq!("{<-2, -1>; 1}", ivs!("{<-2, -1>; 3}").min(ivs!("1")));
+    assert_str_eq!("{1; 3}", ivs!("{<-2, -1>; 3}").max(ivs!("1")));
+}
+
+#[test]
+fn test_clamp() {
+    assert_str_eq!("{0; 1}", ivs!("{<-2, -1>; 3}").clamp(ivs!("0"), ivs!("1")));
+}
+
+#[test]
+fn test_hypot() {
+    assert_str_eq!("{<1, 1.5>; <3, 4>}", ivs!("{<-1, 0>; 3}").hypot(ivs!("1")));
+}
+
+#[test]
+fn test_fmod() {
+    assert_str_eq!(
+        "{<0, 0.5>; <0.75, 1>}",
+        ivs!("{<0.75, 1.5>; 3}").fmod(ivs!("1"))
+    );
+    assert_str_eq!("<-1, -0.75>", ivs!("{-3; <-1, -0.75>}").fmod(ivs!("2")));
+    assert_str_eq!("{}", ivs!("<1, 2>").fmod(ivs!("0")));
+}
+
+#[test]
+fn test_remainder() {
+    assert_str_eq!(
+        "{<-1, -0.5>; <-0.25, 0.5>; <0.75, 1>}",
+        ivs!("{<0.75, 1.5>; 3}").remainder(ivs!("{1; 2}"))
+    );
+}
+
+#[test]
+fn test_rem_euclid() {
+    assert_str_eq!(
+        "{<0, 0.75>; <1, 2>}",
+        ivs!("<-0.75, 0.75>").rem_euclid(ivs!("2"))
+    );
+}
+
 #[test]
 fn test_log() {
     use transc::Transc;
@@ -594,6 +740,37 @@ fn test_pow() {
     );
 }
 
+#[test]
+fn test_exact() {
+    let (z, exact) = ivs!("{<1, 2>; <4, 6>}").add_exact(ivs!("0.5"));
+    assert_str_eq!("{<1.5, 3>; <4, 8>}", z);
+    assert!(!exact);
+    let (z, exact) = ivs!("{<1, 2>; <4, 6>}").sub_exact(ivs!("2"));
+    assert_str_eq!("{<-1, 0>; <2, 4>}", z);
+    assert!(exact);
+    let (z, exact) = ivs!("{<1, 2>; <4, 6>}").mul_exact(ivs!("-0.5"));
+    assert_str_eq!("{<-3, -2>; <-1, -0.5>}", z);
+    assert!(exact);
+    let (z, exact) = ivs!("1").div_exact(ivs!("<-2, 1>"));
+    assert_str_eq!("{<-inf, -0.5>; <1, inf>}", z);
+    assert!(exact);
+    let (z, exact) = ivs!("{<-1, 0>; <1, 2>}").log_exact();
+    assert_str_eq!("<0, 0.75>", z);
+    assert!(!exact);
+    let (z, exact) = ivs!("{<-inf, -1>; 0}").exp_exact();
+    assert_str_eq!("{<0, 0.375>; 1}", z);
+    assert!(!exact);
+    let (z, exact) = ivs!("{-2; 2}").pow_exact(ivs!("8"));
+    assert_str_eq!("256", z);
+    assert!(exact);
+    let (z, exact) = ivs!("{-2; 2}").pow_exact(ivs!("-1"));
+    assert_str_eq!("{-0.5; 0.5}", z);
+    assert!(exact);
+    let (z, exact) = ivs!("2").pow_exact(ivs!("{0.5; 3}"));
+    assert_str_eq!("{<1, 1.5>; 8}", z);
+    assert!(!exact);
+}
+
 fn simple<'a>() -> Vec<(&'a str, IVS)> {
     vec![
         ("nan", ivs!("{}")),
@@ -659,9 +836,9 @@ fn all_sign_classes_small_and_big<'a>() -> Vec<(&'a str, IVS)> {
     ]
 }
 
-fn find_unary_case<'x, 'z>(cases: &'z Vec<(&str, &str)>, cx: &'x str) -> Option<&'z str> {
-    for &(ref cz, ref z) in cases {
-        let is_match = &format!("{}", cx).as_str() == cz || &format!("*").as_str() == cz;
+fn find_unary_case<'z>(cases: &'z Vec<(&str, &str)>, cx: &str) -> Option<&'z str> {
+    for (cz, z) in cases {
+        let is_match = cx == *cz || "*" == *cz;
         if is_match {
             return Some(z);
         }
@@ -669,18 +846,18 @@ fn find_unary_case<'x, 'z>(cases: &'z Vec<(&str, &str)>, cx: &'x str) -> Option<
     None
 }
 
-fn find_binary_case<'x, 'y, 'z>(
+fn find_binary_case<'z>(
     cases: &'z Vec<(&str, &str)>,
-    cx: &'x str,
-    cy: &'y str,
+    cx: &str,
+    cy: &str,
     commutative: bool,
 ) -> Option<&'z str> {
-    for &(ref cz, ref z) in cases {
-        let cz = String::from(cz.clone());
+    for (cz, z) in cases {
+        let cz = String::from(*cz);
         let mut is_match = format!("{}.{}", cx, cy) == cz
             || format!("{}.*", cx).as_str() == cz
             || format!("*.{}", cy).as_str() == cz
-            || format!("*.*").as_str() == cz;
+            || "*.*" == cz;
         if commutative {
             is_match = is_match
                 || format!("{}.{}", cy, cx) == cz
@@ -694,7 +871,7 @@ fn find_binary_case<'x, 'y, 'z>(
     None
 }
 
-pub fn test_unary_op<'a, OP, R>(op: OP, cases: Vec<(&'a str, IVS)>, expected: Vec<(&str, &str)>)
+pub fn test_unary_op<OP, R>(op: OP, cases: Vec<(&str, IVS)>, expected: Vec<(&str, &str)>)
 where
     OP: Fn(IVS) -> R,
     R: std::fmt::Display + Sized,
@@ -713,10 +890,10 @@ where
     }
 }
 
-pub fn test_binary_op<'a, OP, R>(
+pub fn test_binary_op<OP, R>(
     op: OP,
-    left_cases: Vec<(&'a str, IVS)>,
-    right_cases: Vec<(&'a str, IVS)>,
+    left_cases: Vec<(&str, IVS)>,
+    right_cases: Vec<(&str, IVS)>,
     mut expected: Vec<(&str, &str)>,
     commutative: bool,
 ) where
@@ -740,7 +917,7 @@ pub fn test_binary_op<'a, OP, R>(
         for (cy, y) in right_cases.clone() {
             let z = op(x.clone(), y.clone());
             if let Some(cz) = find_binary_case(&expected, cx, cy, commutative) {
-                if String::from(cz) != format!("{}", z) {
+                if cz != format!("{}", z) {
                     failed_cases.push(format!("    (\"{}.{}\", \"{}\"),", cx, cy, z));
                 }
             } else {
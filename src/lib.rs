@@ -8,20 +8,29 @@
 //! possible results are contained.
 //!
 //! In addition to the `Interval` and `IntervalSet` structs, the library also provides the `Mpfr`
-//! struct that wraps the GNU MPFR library. The `Mpfr` struct is an ideal (and currently only)
-//! bound type for intervals.
+//! struct that wraps the GNU MPFR library. The `Mpfr` struct is an ideal bound type for
+//! intervals. When 53 bits are enough, the `ieee::RoundedF64` struct is a faster, allocation-free
+//! alternative that also rounds correctly, and the `ieee::DoubleDouble` struct provides about 106
+//! bits in the same way. The `rational::Rational` struct never rounds elementary arithmetic at
+//! all, which makes interval linear algebra exact, and the `fixed::Fixed` struct rounds
+//! fixed-point bounds such as cents outward to their resolution. When results are shown in
+//! decimal, the `decimal::Decimal` struct keeps `0.1` exactly `0.1`. Integer ranges such as array
+//! indices can be bounded by `i64` and `i128` directly.
 //!
 //! Honest Intervals tries to be a pragmatic implementation of interval arithmetic rather than an
 //! abstract basis for all possible implementations. Users do not have to implement any traits; they
 //! can create a correctly rounding interval right away by calling `IntervalSet::<Mpfr>::new()`.
 
-extern crate libc;
 extern crate core;
+extern crate libc;
 
 /// Finite precision module.
 ///
 /// This module defines `fp::Float` trait and related traits where the floating point operations can
-/// round up or down depending on which version of the operation is used.
+/// round up or down depending on which version of the operation is used. Each operation also takes
+/// any `fp::Round` direction, such as to the nearest or towards zero, through its `_r` version.
+/// The `fp::Total` wrapper gives bounds, intervals and interval sets a total order and a hash, so
+/// that they can be used as map keys.
 pub mod fp;
 
 /// Transcendental trait module.
@@ -35,15 +44,50 @@ pub mod transc;
 /// module defines `mpfr::Mpfr` struct which implements `fp::Float` using GNU MPFR.
 pub mod mpfr;
 
-/// A naive implementation of `fp::Float` for f64.
+/// IEEE 754 floating point module.
 ///
-/// Default rounding mode is used for all operations. Therefore, the actual results may be outside
-/// of the lower and upper bounds. Use only when accuracy is not critical.
+/// This module implements `fp::Float` for f64 naively: default rounding mode is used for all
+/// operations, therefore the actual results may be outside of the lower and upper bounds. Use it
+/// only when accuracy is not critical. The `ieee::RoundedF64` and `ieee::RoundedF32` wrappers round
+/// correctly instead, and so does the `ieee::DoubleDouble` pair of f64s. Their transcendental
+/// functions are computed in the crate with bounded errors, and never trust the accuracy of the
+/// system math library.
 pub mod ieee;
 
+/// Rational module.
+///
+/// This module defines `rational::Rational` struct which implements `fp::Float` with exact rational
+/// arithmetic on top of its own arbitrary-precision integer, `rational::BigInt`. Transcendental
+/// functions are rounded to a chosen precision.
+pub mod rational;
+
+/// Fixed-point module.
+///
+/// This module defines `fixed::Fixed` struct which implements `fp::Float` for binary and decimal
+/// fixed-point numbers stored in an `i128`, such as `Fixed<fixed::Decimal8>` with a resolution of
+/// `10^-8`.
+pub mod fixed;
+
+/// Decimal module.
+///
+/// This module defines `decimal::Decimal` struct which implements `fp::Float` with an
+/// arbitrary-precision decimal coefficient and exponent, rounding every operation in the direction
+/// that is asked for.
+pub mod decimal;
+
+/// Integer module.
+///
+/// This module implements `fp::Bound` and `fp::Integer` for `i64` and `i128`, so that integer
+/// ranges such as array indices can be bounded by `Interval<i64>` and `IntervalSet<i64>`. The
+/// extreme values of each type are reserved for the infinities and NaN.
+pub mod integer;
+
 /// Interval module.
 ///
 /// This module defines `Interval` struct that represents an interval bounded by two `fp::Float`s.
+/// `Interval::newton` isolates the roots of a function in an interval and proves which of them are
+/// unique, and `Interval::range` encloses the range of a function with centered forms. Reverse
+/// operations such as `Interval::mul_rev` enclose the operands that give a result, as in IEEE 1788.
 pub mod interval;
 
 /// Interval module.
@@ -51,8 +95,43 @@ pub mod interval;
 /// This module defines `IntervalSet` struct that represents a non-intersecting set of `Interval`s.
 pub mod intervalset;
 
-pub use interval::{Interval, ParseIntervalError, SignClass};
+/// Interval linear algebra module.
+///
+/// This module defines `linalg::IntervalVector` and `linalg::IntervalMatrix` structs of
+/// `Interval`s. Their products use dot products that round each bound only once.
+/// `IntervalVector::minimize` encloses the global minimum of a function over a box with branch
+/// and bound.
+pub mod linalg;
+
+/// Automatic differentiation module.
+///
+/// This module defines `dual::Dual` struct of a value and its partial derivatives, so that
+/// `Dual<Interval>` computes enclosures of derivatives in forward mode, as needed by
+/// `Interval::newton`.
+pub mod dual;
+
+/// Expression module.
+///
+/// This module defines `expr::Expr` enum of arithmetic expressions over named variables, which can
+/// be parsed from strings such as `log(x) * y^2 / (1 - z)` and evaluated over intervals and
+/// interval sets. It also defines `expr::Constraint` struct, whose domains of variables can be
+/// narrowed with the HC4 constraint propagation algorithm.
+pub mod expr;
+
+/// Slope arithmetic module.
+///
+/// This module defines `slope::Slope` struct of the range, the center value and the slope of a
+/// function, which `Interval::range` uses to enclose ranges with centered forms.
+pub mod slope;
+
+pub use dual::Dual;
+pub use expr::{Constraint, Expr};
+pub use interval::{
+    CancelError, Interval, ParseIntervalError, RangeForm, Root, RootKind, SignClass,
+};
 pub use intervalset::{IntervalSet, ParseIntervalSetError};
+pub use linalg::{IntervalMatrix, IntervalVector, Minimum};
+pub use slope::Slope;
 
 #[cfg(test)]
 mod tests {
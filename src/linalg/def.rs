@@ -0,0 +1,71 @@
+use interval::Interval;
+use intervalset::IntervalSet;
+
+/// Interval vector parsing error enum.
+#[derive(Debug)]
+pub enum ParseIntervalVectorError {
+    /// The first character of the string is anything other than `'['`.
+    MissingOpeningBracket,
+    /// The last character of the string is anything other than `']'`.
+    MissingClosingBracket,
+    /// There was an error while parsing an interval.
+    IntervalsParseError,
+}
+
+/// Interval matrix parsing error enum.
+#[derive(Debug)]
+pub enum ParseIntervalMatrixError {
+    /// The first character of the string is anything other than `'['`.
+    MissingOpeningBracket,
+    /// The last character of the string is anything other than `']'`.
+    MissingClosingBracket,
+    /// There was an error while parsing an interval.
+    IntervalsParseError,
+    /// The rows do not all have the same number of elements.
+    RaggedRows,
+}
+
+/// Interval linear system solving error enum.
+#[derive(Debug, PartialEq)]
+pub enum SolveError {
+    /// A pivot of Gaussian elimination contains zero.
+    ZeroPivot,
+    /// The midpoint matrix is singular in working precision, so that it has no approximate inverse.
+    SingularMidpoint,
+    /// The matrix could not be proved to be regular.
+    RegularityNotProved,
+}
+
+/// Interval vector struct.
+///
+/// Represents a box, that is the set of all vectors whose elements lie in the corresponding
+/// intervals.
+#[derive(Clone, Debug)]
+pub struct IntervalVector<BOUND: PartialOrd> {
+    /// Elements of the vector.
+    pub elements: Vec<Interval<BOUND>>,
+}
+
+/// Interval matrix struct.
+///
+/// Represents the set of all matrices whose elements lie in the corresponding intervals.
+#[derive(Clone, Debug)]
+pub struct IntervalMatrix<BOUND: PartialOrd> {
+    /// Number of rows.
+    pub rows: usize,
+    /// Number of columns.
+    pub cols: usize,
+    /// Elements of the matrix in row-major order.
+    pub elements: Vec<Interval<BOUND>>,
+}
+
+/// Global minimum enclosure struct.
+///
+/// See `IntervalVector::minimize` for details.
+#[derive(Clone, Debug)]
+pub struct Minimum<BOUND: PartialOrd> {
+    /// Interval that encloses the global minimum value.
+    pub value: Interval<BOUND>,
+    /// Sets that enclose the coordinates of all global minimizers, one for each coordinate.
+    pub minimizers: Vec<IntervalSet<BOUND>>,
+}
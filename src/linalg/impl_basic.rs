@@ -0,0 +1,384 @@
+use super::def::{
+    IntervalMatrix, IntervalVector, ParseIntervalMatrixError, ParseIntervalVectorError,
+};
+
+use fp::{Bound, Float};
+use interval::Interval;
+
+use std::fmt;
+use std::fmt::{Display, Formatter, Write};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// Splits `s` at each `sep` that is not enclosed in angle brackets.
+fn split_top_level(s: &str, sep: char) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            c if c == sep && depth == 0 => {
+                parts.push(s[start..i].trim());
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(s[start..].trim());
+    parts
+}
+
+/// Parses a comma-separated list of intervals. An empty string is an empty list.
+fn parse_intervals<BOUND: Bound>(s: &str, precision: usize) -> Option<Vec<Interval<BOUND>>> {
+    if s.is_empty() {
+        return Some(vec![]);
+    }
+    split_top_level(s, ',')
+        .iter()
+        .map(|v| Interval::from_str_with_prec(v, precision).ok())
+        .collect()
+}
+
+/// Writes `intervals` separated by commas.
+fn fmt_intervals<BOUND: Bound>(intervals: &[Interval<BOUND>], f: &mut Formatter) -> fmt::Result {
+    let mut iter = intervals.iter();
+    if let Some(i) = iter.next() {
+        Display::fmt(i, f)?;
+    }
+    for i in iter {
+        f.write_str(", ")?;
+        Display::fmt(i, f)?;
+    }
+    Ok(())
+}
+
+impl<BOUND: Bound> IntervalVector<BOUND> {
+    /// Constructs a vector from given elements.
+    #[inline]
+    pub fn new(elements: Vec<Interval<BOUND>>) -> Self {
+        Self { elements }
+    }
+
+    /// Constructs a vector of `len` zeros.
+    #[inline]
+    pub fn zeros(len: usize, precision: usize) -> Self {
+        Self::new(vec![Interval::zero(precision); len])
+    }
+
+    /// Constructs a vector by parsing a string.
+    ///
+    /// Accepts `VECTOR` according to the rule below.
+    ///
+    ///   VECTOR = '[' [INTERVAL (',' INTERVAL)*] ']'
+    pub fn from_str_with_prec(s: &str, precision: usize) -> Result<Self, ParseIntervalVectorError> {
+        let s = s.trim();
+        if !s.starts_with('[') {
+            return Err(ParseIntervalVectorError::MissingOpeningBracket);
+        }
+        if !s.ends_with(']') {
+            return Err(ParseIntervalVectorError::MissingClosingBracket);
+        }
+        let s = s[1..s.len() - 1].trim();
+        match parse_intervals(s, precision) {
+            Some(elements) => Ok(Self::new(elements)),
+            None => Err(ParseIntervalVectorError::IntervalsParseError),
+        }
+    }
+
+    /// Returns the number of elements of `self`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Whether `self` has no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns the precision of the elements of `self`, or 53 if it has none.
+    #[inline]
+    pub fn precision(&self) -> usize {
+        self.elements.first().map_or(53, Interval::precision)
+    }
+
+    /// Whether any element of `self` is NaN, in which case the box is empty.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.elements.iter().any(Interval::is_nan)
+    }
+
+    /// Returns the elementwise hull of `self` and `rhs`.
+    ///
+    /// Panics if `self` and `rhs` have different lengths.
+    pub fn hull(self, rhs: Self) -> Self {
+        assert_eq!(self.len(), rhs.len());
+        let elements = self.elements.into_iter().zip(rhs.elements);
+        Self::new(elements.map(|(x, y)| x.hull(y)).collect())
+    }
+
+    /// Returns the elementwise intersection of `self` and `rhs`.
+    ///
+    /// Elements that do not intersect are NaN in the result. Panics if `self` and `rhs` have
+    /// different lengths.
+    pub fn intersection(self, rhs: Self) -> Self {
+        assert_eq!(self.len(), rhs.len());
+        let elements = self.elements.into_iter().zip(rhs.elements);
+        Self::new(elements.map(|(x, y)| x.intersection(y)).collect())
+    }
+}
+
+impl<BOUND: Float> IntervalVector<BOUND> {
+    /// Returns the vector of the midpoints of the elements of `self`, as singleton intervals.
+    pub fn mid(&self) -> Self {
+        Self::new(
+            self.elements
+                .iter()
+                .map(|x| Interval::singleton(x.mid()))
+                .collect(),
+        )
+    }
+
+    /// Returns the radii of the elements of `self`.
+    pub fn rad(&self) -> Vec<BOUND> {
+        self.elements.iter().map(Interval::rad).collect()
+    }
+}
+
+impl<BOUND: Bound> IntervalMatrix<BOUND> {
+    /// Constructs a matrix from given elements in row-major order.
+    ///
+    /// Panics if there are not exactly `rows * cols` elements.
+    #[inline]
+    pub fn new(rows: usize, cols: usize, elements: Vec<Interval<BOUND>>) -> Self {
+        assert_eq!(rows * cols, elements.len());
+        Self {
+            rows,
+            cols,
+            elements,
+        }
+    }
+
+    /// Constructs a matrix from given rows.
+    ///
+    /// Panics if the rows do not all have the same number of elements.
+    pub fn from_rows(rows: Vec<Vec<Interval<BOUND>>>) -> Self {
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|row| row.len() == cols));
+        Self::new(rows.len(), cols, rows.into_iter().flatten().collect())
+    }
+
+    /// Constructs a matrix of zeros.
+    #[inline]
+    pub fn zeros(rows: usize, cols: usize, precision: usize) -> Self {
+        Self::new(rows, cols, vec![Interval::zero(precision); rows * cols])
+    }
+
+    /// Constructs the identity matrix of size `n`.
+    pub fn identity(n: usize, precision: usize) -> Self {
+        let mut m = Self::zeros(n, n, precision);
+        for i in 0..n {
+            m[(i, i)] = Interval::one(precision);
+        }
+        m
+    }
+
+    /// Constructs a matrix by parsing a string.
+    ///
+    /// Accepts `MATRIX` according to the rule below.
+    ///
+    ///   MATRIX = '[' [ROW (';' ROW)*] ']'
+    ///
+    ///   ROW = INTERVAL (',' INTERVAL)*
+    pub fn from_str_with_prec(s: &str, precision: usize) -> Result<Self, ParseIntervalMatrixError> {
+        let s = s.trim();
+        if !s.starts_with('[') {
+            return Err(ParseIntervalMatrixError::MissingOpeningBracket);
+        }
+        if !s.ends_with(']') {
+            return Err(ParseIntervalMatrixError::MissingClosingBracket);
+        }
+        let s = s[1..s.len() - 1].trim();
+        if s.is_empty() {
+            return Ok(Self::new(0, 0, vec![]));
+        }
+        let rows: Option<Vec<_>> = split_top_level(s, ';')
+            .iter()
+            .map(|row| parse_intervals(row, precision))
+            .collect();
+        match rows {
+            Some(rows) => {
+                let cols = rows[0].len();
+                if rows.iter().all(|row| row.len() == cols) {
+                    Ok(Self::from_rows(rows))
+                } else {
+                    Err(ParseIntervalMatrixError::RaggedRows)
+                }
+            }
+            None => Err(ParseIntervalMatrixError::IntervalsParseError),
+        }
+    }
+
+    /// Whether `self` is a square matrix.
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// Returns the precision of the elements of `self`, or 53 if it has none.
+    #[inline]
+    pub fn precision(&self) -> usize {
+        self.elements.first().map_or(53, Interval::precision)
+    }
+
+    /// Whether any element of `self` is NaN, in which case the matrix represents no matrices.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.elements.iter().any(Interval::is_nan)
+    }
+
+    /// Returns the `i`-th row of `self`.
+    #[inline]
+    pub fn row(&self, i: usize) -> &[Interval<BOUND>] {
+        assert!(i < self.rows);
+        &self.elements[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// Returns the `j`-th column of `self`.
+    pub fn column(&self, j: usize) -> IntervalVector<BOUND> {
+        assert!(j < self.cols);
+        IntervalVector::new((0..self.rows).map(|i| self[(i, j)].clone()).collect())
+    }
+
+    /// Returns the transpose of `self`.
+    pub fn transpose(&self) -> Self {
+        let elements = (0..self.cols)
+            .flat_map(|j| (0..self.rows).map(move |i| (i, j)))
+            .map(|ij| self[ij].clone())
+            .collect();
+        Self::new(self.cols, self.rows, elements)
+    }
+
+    /// Returns the elementwise hull of `self` and `rhs`.
+    ///
+    /// Panics if `self` and `rhs` have different shapes.
+    pub fn hull(self, rhs: Self) -> Self {
+        assert!(self.rows == rhs.rows && self.cols == rhs.cols);
+        let elements = self.elements.into_iter().zip(rhs.elements);
+        Self::new(
+            self.rows,
+            self.cols,
+            elements.map(|(x, y)| x.hull(y)).collect(),
+        )
+    }
+
+    /// Returns the elementwise intersection of `self` and `rhs`.
+    ///
+    /// Elements that do not intersect are NaN in the result. Panics if `self` and `rhs` have
+    /// different shapes.
+    pub fn intersection(self, rhs: Self) -> Self {
+        assert!(self.rows == rhs.rows && self.cols == rhs.cols);
+        let elements = self.elements.into_iter().zip(rhs.elements);
+        Self::new(
+            self.rows,
+            self.cols,
+            elements.map(|(x, y)| x.intersection(y)).collect(),
+        )
+    }
+}
+
+impl<BOUND: Float> IntervalMatrix<BOUND> {
+    /// Returns the matrix of the midpoints of the elements of `self`, as singleton intervals.
+    pub fn mid(&self) -> Self {
+        let elements = self.elements.iter().map(|x| Interval::singleton(x.mid()));
+        Self::new(self.rows, self.cols, elements.collect())
+    }
+
+    /// Returns the radii of the elements of `self` in row-major order.
+    pub fn rad(&self) -> Vec<BOUND> {
+        self.elements.iter().map(Interval::rad).collect()
+    }
+}
+
+impl<BOUND: Bound> Index<usize> for IntervalVector<BOUND> {
+    type Output = Interval<BOUND>;
+
+    #[inline]
+    fn index(&self, i: usize) -> &Interval<BOUND> {
+        &self.elements[i]
+    }
+}
+
+impl<BOUND: Bound> IndexMut<usize> for IntervalVector<BOUND> {
+    #[inline]
+    fn index_mut(&mut self, i: usize) -> &mut Interval<BOUND> {
+        &mut self.elements[i]
+    }
+}
+
+impl<BOUND: Bound> Index<(usize, usize)> for IntervalMatrix<BOUND> {
+    type Output = Interval<BOUND>;
+
+    #[inline]
+    fn index(&self, (i, j): (usize, usize)) -> &Interval<BOUND> {
+        assert!(i < self.rows && j < self.cols);
+        &self.elements[i * self.cols + j]
+    }
+}
+
+impl<BOUND: Bound> IndexMut<(usize, usize)> for IntervalMatrix<BOUND> {
+    #[inline]
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Interval<BOUND> {
+        assert!(i < self.rows && j < self.cols);
+        &mut self.elements[i * self.cols + j]
+    }
+}
+
+impl<BOUND: Bound> From<Vec<Interval<BOUND>>> for IntervalVector<BOUND> {
+    #[inline]
+    fn from(elements: Vec<Interval<BOUND>>) -> Self {
+        Self::new(elements)
+    }
+}
+
+impl<BOUND: Bound> FromStr for IntervalVector<BOUND> {
+    type Err = ParseIntervalVectorError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_with_prec(s, 53)
+    }
+}
+
+impl<BOUND: Bound> FromStr for IntervalMatrix<BOUND> {
+    type Err = ParseIntervalMatrixError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_with_prec(s, 53)
+    }
+}
+
+impl<BOUND: Bound> Display for IntervalVector<BOUND> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_char('[')?;
+        fmt_intervals(&self.elements, f)?;
+        f.write_char(']')
+    }
+}
+
+impl<BOUND: Bound> Display for IntervalMatrix<BOUND> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_char('[')?;
+        for i in 0..self.rows {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            fmt_intervals(self.row(i), f)?;
+        }
+        f.write_char(']')
+    }
+}
@@ -0,0 +1,17 @@
+use super::def::{IntervalMatrix, IntervalVector};
+
+use fp::Bound;
+
+impl<BOUND: Bound> PartialEq for IntervalVector<BOUND> {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.elements == rhs.elements
+    }
+}
+
+impl<BOUND: Bound> PartialEq for IntervalMatrix<BOUND> {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.rows == rhs.rows && self.cols == rhs.cols && self.elements == rhs.elements
+    }
+}
@@ -0,0 +1,249 @@
+use super::def::{IntervalMatrix, IntervalVector};
+
+use fp::{Bound, Float};
+use interval::Interval;
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Returns an upper bound of the sum of the magnitudes of `xs`, or NaN if any of them is NaN.
+fn sum_mag<'a, BOUND, I>(xs: I, precision: usize) -> BOUND
+where
+    BOUND: Float + 'a,
+    I: Iterator<Item = &'a Interval<BOUND>>,
+{
+    let mags: Vec<_> = xs.map(Interval::mag).collect();
+    BOUND::sum_hi(&mags, precision)
+}
+
+/// Returns an upper bound of the Euclidean norm of the magnitudes of `xs`, or NaN if any of them
+/// is NaN.
+fn hypot_mag<'a, BOUND, I>(xs: I, precision: usize) -> BOUND
+where
+    BOUND: Float + 'a,
+    I: Iterator<Item = &'a Interval<BOUND>>,
+{
+    let mut norm = BOUND::zero(precision);
+    for x in xs {
+        if x.is_nan() {
+            return BOUND::nan(precision);
+        }
+        norm = norm.hypot_hi(x.mag());
+    }
+    norm
+}
+
+/// Returns the greatest of `xs`, or NaN if any of them is NaN.
+fn max_or_nan<BOUND: Float, I: Iterator<Item = BOUND>>(xs: I, precision: usize) -> BOUND {
+    let mut max = BOUND::zero(precision);
+    for x in xs {
+        if x.is_nan() {
+            return x;
+        }
+        max = max.max(x);
+    }
+    max
+}
+
+impl<BOUND: Bound> Neg for IntervalVector<BOUND> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::new(self.elements.into_iter().map(Neg::neg).collect())
+    }
+}
+
+impl<BOUND: Bound> Add<Self> for IntervalVector<BOUND> {
+    type Output = Self;
+
+    /// Panics if `self` and `rhs` have different lengths.
+    fn add(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.len(), rhs.len());
+        let elements = self.elements.into_iter().zip(rhs.elements);
+        Self::new(elements.map(|(x, y)| x + y).collect())
+    }
+}
+
+impl<BOUND: Bound> Sub<Self> for IntervalVector<BOUND> {
+    type Output = Self;
+
+    /// Panics if `self` and `rhs` have different lengths.
+    fn sub(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.len(), rhs.len());
+        let elements = self.elements.into_iter().zip(rhs.elements);
+        Self::new(elements.map(|(x, y)| x - y).collect())
+    }
+}
+
+impl<BOUND: Bound> Mul<Interval<BOUND>> for IntervalVector<BOUND> {
+    type Output = Self;
+
+    fn mul(self, rhs: Interval<BOUND>) -> Self::Output {
+        let elements = self.elements.into_iter();
+        Self::new(elements.map(|x| x * rhs.clone()).collect())
+    }
+}
+
+impl<BOUND: Float> Div<Interval<BOUND>> for IntervalVector<BOUND> {
+    type Output = Self;
+
+    fn div(self, rhs: Interval<BOUND>) -> Self::Output {
+        let elements = self.elements.into_iter();
+        Self::new(elements.map(|x| x / rhs.clone()).collect())
+    }
+}
+
+impl<BOUND: Float> IntervalVector<BOUND> {
+    /// Computes the dot product of `self` and `rhs` and rounds each bound of the result only once.
+    ///
+    /// Panics if `self` and `rhs` have different lengths.
+    #[inline]
+    pub fn dot(&self, rhs: &Self) -> Interval<BOUND> {
+        Interval::dot(&self.elements, &rhs.elements, self.precision())
+    }
+
+    /// Returns an upper bound of the 1-norm of all vectors in `self`.
+    ///
+    /// Returns NaN if any element of `self` is NaN.
+    pub fn norm1(&self) -> BOUND {
+        sum_mag(self.elements.iter(), self.precision())
+    }
+
+    /// Returns an upper bound of the 2-norm of all vectors in `self`.
+    ///
+    /// Returns NaN if any element of `self` is NaN.
+    pub fn norm2(&self) -> BOUND {
+        hypot_mag(self.elements.iter(), self.precision())
+    }
+
+    /// Returns an upper bound of the infinity norm of all vectors in `self`.
+    ///
+    /// Returns NaN if any element of `self` is NaN.
+    pub fn norm_inf(&self) -> BOUND {
+        let precision = self.precision();
+        max_or_nan(self.elements.iter().map(Interval::mag), precision)
+    }
+}
+
+impl<BOUND: Bound> Neg for IntervalMatrix<BOUND> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let elements = self.elements.into_iter().map(Neg::neg).collect();
+        Self::new(self.rows, self.cols, elements)
+    }
+}
+
+impl<BOUND: Bound> Add<Self> for IntervalMatrix<BOUND> {
+    type Output = Self;
+
+    /// Panics if `self` and `rhs` have different shapes.
+    fn add(self, rhs: Self) -> Self::Output {
+        assert!(self.rows == rhs.rows && self.cols == rhs.cols);
+        let elements = self.elements.into_iter().zip(rhs.elements);
+        Self::new(self.rows, self.cols, elements.map(|(x, y)| x + y).collect())
+    }
+}
+
+impl<BOUND: Bound> Sub<Self> for IntervalMatrix<BOUND> {
+    type Output = Self;
+
+    /// Panics if `self` and `rhs` have different shapes.
+    fn sub(self, rhs: Self) -> Self::Output {
+        assert!(self.rows == rhs.rows && self.cols == rhs.cols);
+        let elements = self.elements.into_iter().zip(rhs.elements);
+        Self::new(self.rows, self.cols, elements.map(|(x, y)| x - y).collect())
+    }
+}
+
+impl<BOUND: Bound> Mul<Interval<BOUND>> for IntervalMatrix<BOUND> {
+    type Output = Self;
+
+    fn mul(self, rhs: Interval<BOUND>) -> Self::Output {
+        let elements = self.elements.into_iter();
+        Self::new(
+            self.rows,
+            self.cols,
+            elements.map(|x| x * rhs.clone()).collect(),
+        )
+    }
+}
+
+impl<BOUND: Float> Div<Interval<BOUND>> for IntervalMatrix<BOUND> {
+    type Output = Self;
+
+    fn div(self, rhs: Interval<BOUND>) -> Self::Output {
+        let elements = self.elements.into_iter();
+        Self::new(
+            self.rows,
+            self.cols,
+            elements.map(|x| x / rhs.clone()).collect(),
+        )
+    }
+}
+
+impl<BOUND: Float> Mul<IntervalVector<BOUND>> for IntervalMatrix<BOUND> {
+    type Output = IntervalVector<BOUND>;
+
+    /// Computes each element of the product with a dot product that rounds each bound only once.
+    ///
+    /// Panics if the number of columns of `self` is not the length of `rhs`.
+    fn mul(self, rhs: IntervalVector<BOUND>) -> Self::Output {
+        assert_eq!(self.cols, rhs.len());
+        let precision = self.precision();
+        let elements = (0..self.rows).map(|i| Interval::dot(self.row(i), &rhs.elements, precision));
+        IntervalVector::new(elements.collect())
+    }
+}
+
+impl<BOUND: Float> Mul<Self> for IntervalMatrix<BOUND> {
+    type Output = Self;
+
+    /// Computes each element of the product with a dot product that rounds each bound only once.
+    ///
+    /// Panics if the number of columns of `self` is not the number of rows of `rhs`.
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.cols, rhs.rows);
+        let precision = self.precision();
+        let rhs = rhs.transpose();
+        let elements = (0..self.rows)
+            .flat_map(|i| (0..rhs.rows).map(move |j| (i, j)))
+            .map(|(i, j)| Interval::dot(self.row(i), rhs.row(j), precision))
+            .collect();
+        Self::new(self.rows, rhs.rows, elements)
+    }
+}
+
+impl<BOUND: Float> IntervalMatrix<BOUND> {
+    /// Returns an upper bound of the 1-norm, that is the greatest column sum of magnitudes, of all
+    /// matrices in `self`.
+    ///
+    /// Returns NaN if any element of `self` is NaN.
+    pub fn norm1(&self) -> BOUND {
+        let precision = self.precision();
+        let sums = (0..self.cols).map(|j| {
+            let column = (0..self.rows).map(|i| &self[(i, j)]);
+            sum_mag(column, precision)
+        });
+        max_or_nan(sums, precision)
+    }
+
+    /// Returns an upper bound of the 2-norm of all matrices in `self`.
+    ///
+    /// The bound is the Frobenius norm of the magnitudes. Returns NaN if any element of `self` is
+    /// NaN.
+    pub fn norm2(&self) -> BOUND {
+        hypot_mag(self.elements.iter(), self.precision())
+    }
+
+    /// Returns an upper bound of the infinity norm, that is the greatest row sum of magnitudes, of
+    /// all matrices in `self`.
+    ///
+    /// Returns NaN if any element of `self` is NaN.
+    pub fn norm_inf(&self) -> BOUND {
+        let precision = self.precision();
+        let sums = (0..self.rows).map(|i| sum_mag(self.row(i).iter(), precision));
+        max_or_nan(sums, precision)
+    }
+}
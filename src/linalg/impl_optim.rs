@@ -0,0 +1,186 @@
+use super::def::{IntervalVector, Minimum};
+
+use dual::Dual;
+use fp::{Float, Sign};
+use interval::Interval;
+use intervalset::IntervalSet;
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Default maximum number of bisections made by `IntervalVector::minimize`.
+pub const DEFAULT_MINIMIZE_LIMIT: usize = 10000;
+
+/// Box in the priority queue of `IntervalVector::minimize_with_limits`, with a lower bound of the
+/// function over it.
+struct Candidate<BOUND: PartialOrd> {
+    lower: BOUND,
+    x: IntervalVector<BOUND>,
+}
+
+// Candidates are ordered by their lower bounds in reverse, so that `BinaryHeap` pops the least
+// one first. Lower bounds are never NaN.
+impl<BOUND: PartialOrd> PartialEq for Candidate<BOUND> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.lower == rhs.lower
+    }
+}
+
+impl<BOUND: PartialOrd> Eq for Candidate<BOUND> {}
+
+impl<BOUND: PartialOrd> PartialOrd for Candidate<BOUND> {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl<BOUND: PartialOrd> Ord for Candidate<BOUND> {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        rhs.lower
+            .partial_cmp(&self.lower)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl<BOUND: Float> IntervalVector<BOUND> {
+    /// Returns the greatest width of the elements of `self` rounded up, and the index of an
+    /// element of that width.
+    fn widest(&self) -> (BOUND, usize) {
+        let mut widest = (BOUND::zero(self.precision()), 0);
+        for (i, x) in self.elements.iter().enumerate() {
+            let width = x.hi.clone().sub_hi(x.lo.clone());
+            if width > widest.0 {
+                widest = (width, i);
+            }
+        }
+        widest
+    }
+
+    /// Bounds `f` over `self` from below with interval evaluation, and narrows `self` with the
+    /// monotonicity test.
+    ///
+    /// Lowers `upper` to the value of `f` at the midpoint of `self` rounded up. Returns `None` if
+    /// `self` is proved to hold no global minimizer of `f` over `domain`.
+    fn bound<F>(mut self, f: &F, domain: &Self, upper: &mut BOUND) -> Option<Candidate<BOUND>>
+    where
+        F: Fn(Vec<Dual<Interval<BOUND>>>) -> Dual<Interval<BOUND>>,
+    {
+        loop {
+            let fx = f(Dual::variables(self.elements.clone()));
+            if fx.value.is_nan() {
+                return None;
+            }
+            let m = self
+                .mid()
+                .elements
+                .into_iter()
+                .map(Dual::constant)
+                .collect();
+            let fm = f(m).value;
+            if !fm.is_nan() && fm.hi < *upper {
+                *upper = fm.hi;
+            }
+            if fx.value.lo > *upper {
+                return None;
+            }
+            let mut narrowed = false;
+            for i in 0..self.len() {
+                let d = fx.derivative(i);
+                if d.is_nan() || d.has_zero() {
+                    continue;
+                }
+                // `f` is strictly monotonic in the `i`-th coordinate, so a global minimizer in
+                // `self` can only lie on the face where `f` is least, and only if that face is
+                // on the boundary of `domain`.
+                let (face, end) = if d.lo.sign() == Sign::Positive {
+                    (self[i].lo.clone(), domain[i].lo.clone())
+                } else {
+                    (self[i].hi.clone(), domain[i].hi.clone())
+                };
+                if face != end {
+                    return None;
+                }
+                if !self[i].is_singleton() {
+                    self[i] = Interval::singleton(face);
+                    narrowed = true;
+                }
+            }
+            if !narrowed {
+                return Some(Candidate {
+                    lower: fx.value.lo,
+                    x: self,
+                });
+            }
+        }
+    }
+
+    /// Encloses the global minimum of `f` over `self` with branch and bound.
+    ///
+    /// The result is the same as `IntervalVector::minimize_with_limits` with zero tolerance and
+    /// `DEFAULT_MINIMIZE_LIMIT` bisections.
+    #[inline]
+    pub fn minimize<F>(self, f: F) -> Minimum<BOUND>
+    where
+        F: Fn(Vec<Dual<Interval<BOUND>>>) -> Dual<Interval<BOUND>>,
+    {
+        let tolerance = BOUND::zero(self.precision());
+        self.minimize_with_limits(f, tolerance, DEFAULT_MINIMIZE_LIMIT)
+    }
+
+    /// Encloses the global minimum of `f` over `self` with branch and bound.
+    ///
+    /// `f` takes one dual number for each element of `self` and must compute enclosures of the
+    /// range of the function and its gradient over them. Boxes are kept in a priority queue by the
+    /// lower bounds of `f` over them. The box with the least lower bound is bisected at the
+    /// midpoint of its widest element with `Interval::split`, until it is no wider than
+    /// `tolerance` or `limit` bisections have been made in total. The value of `f` at the midpoint
+    /// of each box, rounded up, is an upper bound of the minimum, and boxes whose lower bounds
+    /// exceed it are deleted. Boxes where `f` is strictly monotonic in a coordinate are deleted, or
+    /// reduced to their faces on the boundary of `self`.
+    ///
+    /// The value of the result encloses the global minimum, and the sets of minimizers enclose the
+    /// coordinates of all points of `self` where it is attained. The value is a NaN interval if
+    /// `f` is NaN over all of `self`.
+    pub fn minimize_with_limits<F>(self, f: F, tolerance: BOUND, limit: usize) -> Minimum<BOUND>
+    where
+        F: Fn(Vec<Dual<Interval<BOUND>>>) -> Dual<Interval<BOUND>>,
+    {
+        let precision = self.precision();
+        let mut upper = BOUND::infinity(precision);
+        let mut queue = BinaryHeap::new();
+        queue.extend(self.clone().bound(&f, &self, &mut upper));
+        let mut boxes = vec![];
+        let mut bisections = 0;
+        while let Some(c) = queue.pop() {
+            if c.lower > upper {
+                // All other boxes in the queue have even greater lower bounds.
+                break;
+            }
+            let (width, i) = c.x.widest();
+            if bisections >= limit || width <= tolerance {
+                boxes.push(c);
+                continue;
+            }
+            let (l, r) = c.x[i].clone().split(c.x[i].mid());
+            if l.is_nan() || r.is_nan() {
+                boxes.push(c);
+                continue;
+            }
+            bisections += 1;
+            for piece in [l, r] {
+                let mut y = c.x.clone();
+                y[i] = piece;
+                queue.extend(y.bound(&f, &self, &mut upper));
+            }
+        }
+        boxes.retain(|c| c.lower <= upper);
+        let value = match boxes.iter().map(|c| c.lower.clone()).reduce(BOUND::min) {
+            Some(lower) => Interval::new(lower, upper),
+            None => Interval::nan(precision),
+        };
+        let minimizers = (0..self.len())
+            .map(|i| IntervalSet::from_intervals(boxes.iter().map(|c| c.x[i].clone()).collect()))
+            .collect();
+        Minimum { value, minimizers }
+    }
+}
@@ -0,0 +1,286 @@
+use super::def::{IntervalMatrix, IntervalVector, SolveError};
+
+use fp::{Float, Round};
+use interval::Interval;
+
+use std::cmp::Ordering;
+
+/// Maximum number of Krawczyk iterations before giving up on proving regularity.
+const MAX_ITERATIONS: usize = 10;
+
+/// Relative widening of each iterate, as in `Interval::blow`.
+const INFLATION: f64 = 0.1;
+
+/// Computes an approximate inverse of the `n` by `n` matrix `a` in row-major order with
+/// Gauss-Jordan elimination rounded to the nearest.
+///
+/// Returns `None` if a pivot is zero or the inverse is not finite.
+fn approx_inverse<BOUND: Float>(
+    mut a: Vec<BOUND>,
+    n: usize,
+    precision: usize,
+) -> Option<Vec<BOUND>> {
+    let mut inv = vec![BOUND::zero(precision); n * n];
+    for i in 0..n {
+        inv[i * n + i] = BOUND::one(precision);
+    }
+    for k in 0..n {
+        let p = (k..n)
+            .max_by(|&i, &j| {
+                let x = a[i * n + k].clone().abs();
+                let y = a[j * n + k].clone().abs();
+                x.partial_cmp(&y).unwrap_or(Ordering::Equal)
+            })
+            .unwrap();
+        if a[p * n + k].is_zero() || !a[p * n + k].is_finite() {
+            return None;
+        }
+        for j in 0..n {
+            a.swap(p * n + j, k * n + j);
+            inv.swap(p * n + j, k * n + j);
+        }
+        let pivot = a[k * n + k].clone();
+        for j in 0..n {
+            a[k * n + j] = a[k * n + j].clone().div_r(pivot.clone(), Round::Nearest);
+            inv[k * n + j] = inv[k * n + j].clone().div_r(pivot.clone(), Round::Nearest);
+        }
+        for i in (0..n).filter(|&i| i != k) {
+            let f = -a[i * n + k].clone();
+            if f.is_zero() {
+                continue;
+            }
+            for j in 0..n {
+                let x = a[i * n + j].clone();
+                a[i * n + j] = f.clone().fma_r(a[k * n + j].clone(), x, Round::Nearest);
+                let x = inv[i * n + j].clone();
+                inv[i * n + j] = f.clone().fma_r(inv[k * n + j].clone(), x, Round::Nearest);
+            }
+        }
+    }
+    if inv.iter().all(BOUND::is_finite) {
+        Some(inv)
+    } else {
+        None
+    }
+}
+
+/// Whether `x` is in the interior of `y`.
+fn in_interior<BOUND: Float>(x: &Interval<BOUND>, y: &Interval<BOUND>) -> bool {
+    y.lo < x.lo && x.hi < y.hi
+}
+
+impl<BOUND: Float> IntervalMatrix<BOUND> {
+    /// Returns an approximate inverse of the midpoint matrix of `self`, as singleton intervals.
+    fn approx_inverse(&self) -> Result<Self, SolveError> {
+        let n = self.rows;
+        let mid = self.mid().elements.into_iter().map(|x| x.lo).collect();
+        match approx_inverse(mid, n, self.precision()) {
+            Some(r) => Ok(Self::new(
+                n,
+                n,
+                r.into_iter().map(Interval::singleton).collect(),
+            )),
+            None => Err(SolveError::SingularMidpoint),
+        }
+    }
+
+    /// Encloses the solutions of `self * X = b` with the Krawczyk operator, as in Rump's
+    /// verification method.
+    ///
+    /// With an approximate inverse `R` of the midpoint matrix and an approximate solution `x`, the
+    /// error of `x` is enclosed in `Z + C * Y`, where `Z = R * (b - self * x)` and `C = I - R *
+    /// self`. When that is in the interior of the inflated iterate `Y`, all matrices in `self` and
+    /// `R` are proved to be regular.
+    fn krawczyk(&self, b: &Self) -> Result<Self, SolveError> {
+        let n = self.rows;
+        let precision = self.precision();
+        let r = self.approx_inverse()?;
+        let x = (r.clone() * b.mid()).mid();
+        let z = r.clone() * (b.clone() - self.clone() * x.clone());
+        let c = Self::identity(n, precision) - r * self.clone();
+        let eps = BOUND::from_hi(INFLATION, precision);
+        let mut e = z.clone();
+        for _ in 0..MAX_ITERATIONS {
+            let y = e.elements.into_iter().map(|y| y.blow(eps.clone()));
+            let y = Self::new(e.rows, e.cols, y.collect());
+            e = z.clone() + c.clone() * y.clone();
+            if e.elements
+                .iter()
+                .zip(&y.elements)
+                .all(|(e, y)| in_interior(e, y))
+            {
+                return Ok(x + e);
+            }
+        }
+        Err(SolveError::RegularityNotProved)
+    }
+
+    /// Encloses the solutions of `self * x = b` for all matrices in `self` and vectors in `b` with
+    /// interval Gaussian elimination.
+    ///
+    /// Each pivot is the element of the greatest mignitude in its column. Dividing by a pivot that
+    /// contains zero would leave the solution unbounded, as `Interval::div_multi` shows, so such a
+    /// pivot is reported as `SolveError::ZeroPivot`. The enclosure is often much wider than those
+    /// of `solve_krawczyk` and `solve_hull`, but no approximate inverse is needed.
+    ///
+    /// Panics if `self` is not square or `b` does not have as many elements as `self` has rows.
+    pub fn solve_gauss(
+        &self,
+        b: &IntervalVector<BOUND>,
+    ) -> Result<IntervalVector<BOUND>, SolveError> {
+        assert!(self.is_square());
+        assert_eq!(self.rows, b.len());
+        let n = self.rows;
+        let precision = self.precision();
+        if self.is_nan() || b.is_nan() {
+            return Ok(IntervalVector::new(vec![Interval::nan(precision); n]));
+        }
+        let mut a = self.elements.clone();
+        let mut x = b.elements.clone();
+        for k in 0..n {
+            let p = (k..n)
+                .max_by(|&i, &j| {
+                    let x = a[i * n + k].mig();
+                    let y = a[j * n + k].mig();
+                    x.partial_cmp(&y).unwrap()
+                })
+                .unwrap();
+            if a[p * n + k].has_zero() {
+                return Err(SolveError::ZeroPivot);
+            }
+            for j in k..n {
+                a.swap(p * n + j, k * n + j);
+            }
+            x.swap(p, k);
+            for i in k + 1..n {
+                let f = a[i * n + k].clone() / a[k * n + k].clone();
+                for j in k + 1..n {
+                    a[i * n + j] = a[i * n + j].clone() - f.clone() * a[k * n + j].clone();
+                }
+                x[i] = x[i].clone() - f * x[k].clone();
+            }
+        }
+        for i in (0..n).rev() {
+            let s = Interval::dot(&a[i * n + i + 1..(i + 1) * n], &x[i + 1..], precision);
+            x[i] = (x[i].clone() - s) / a[i * n + i].clone();
+        }
+        Ok(IntervalVector::new(x))
+    }
+
+    /// Encloses the solutions of `self * x = b` for all matrices in `self` and vectors in `b` with
+    /// a Krawczyk-type verification method, and proves that all matrices in `self` are regular.
+    ///
+    /// Returns `SolveError::SingularMidpoint` if the midpoint matrix has no approximate inverse,
+    /// and `SolveError::RegularityNotProved` if the iteration does not contract, which happens when
+    /// `self` is too wide or ill-conditioned.
+    ///
+    /// Panics if `self` is not square or `b` does not have as many elements as `self` has rows.
+    pub fn solve_krawczyk(
+        &self,
+        b: &IntervalVector<BOUND>,
+    ) -> Result<IntervalVector<BOUND>, SolveError> {
+        assert!(self.is_square());
+        assert_eq!(self.rows, b.len());
+        if self.is_nan() || b.is_nan() {
+            let precision = self.precision();
+            return Ok(IntervalVector::new(vec![
+                Interval::nan(precision);
+                self.rows
+            ]));
+        }
+        let b = Self::new(b.len(), 1, b.elements.clone());
+        Ok(IntervalVector::new(self.krawczyk(&b)?.elements))
+    }
+
+    /// Encloses the interval hull of the solutions of `self * x = b` preconditioned with an
+    /// approximate inverse of the midpoint matrix, with the Hansen-Bliek-Rohn method.
+    ///
+    /// The preconditioned matrix `A` is proved to be an H-matrix, and the formulation of Ning and
+    /// Kearfott gives each element as `(b_i + <-beta_i, beta_i>) / (A_ii + <-alpha_i, alpha_i>)`
+    /// from the inverse of the comparison matrix of `A`, which is enclosed with the Krawczyk
+    /// method. The result is usually the tightest of the solvers and is the exact hull when `self`
+    /// is a singleton matrix preconditioned with its exact inverse.
+    ///
+    /// Returns the same errors as `solve_krawczyk`. Panics if `self` is not square or `b` does not
+    /// have as many elements as `self` has rows.
+    pub fn solve_hull(
+        &self,
+        b: &IntervalVector<BOUND>,
+    ) -> Result<IntervalVector<BOUND>, SolveError> {
+        assert!(self.is_square());
+        assert_eq!(self.rows, b.len());
+        let n = self.rows;
+        let precision = self.precision();
+        if self.is_nan() || b.is_nan() {
+            return Ok(IntervalVector::new(vec![Interval::nan(precision); n]));
+        }
+        let r = self.approx_inverse()?;
+        let a = r.clone() * self.clone();
+        let b = r * Self::new(n, 1, b.elements.clone());
+        // The comparison matrix is a Z-matrix, and the right-hand sides are the identity matrix and
+        // the magnitudes of `b`.
+        let mut m = Self::zeros(n, n, precision);
+        let mut rhs = Self::zeros(n, n + 1, precision);
+        for i in 0..n {
+            for j in 0..n {
+                m[(i, j)] = if i == j {
+                    Interval::singleton(a[(i, j)].mig())
+                } else {
+                    Interval::singleton(-a[(i, j)].mag())
+                };
+            }
+            rhs[(i, i)] = Interval::one(precision);
+            rhs[(i, n)] = Interval::singleton(b[(i, 0)].mag());
+        }
+        let inv = match m.krawczyk(&rhs) {
+            Ok(inv) => inv,
+            Err(_) => return Err(SolveError::RegularityNotProved),
+        };
+        // A regular Z-matrix `m` is an M-matrix if `m * v > 0` for some `v > 0`. The row sums of
+        // its inverse are such a vector if it is an M-matrix.
+        let ones = IntervalVector::new(vec![Interval::one(precision); n]);
+        let v: Vec<_> = (0..n)
+            .map(|i| Interval::dot(&inv.row(i)[..n], &ones.elements, precision))
+            .map(|v| Interval::singleton(v.mid()))
+            .collect();
+        let mv = m.clone() * IntervalVector::new(v.clone());
+        let zero = BOUND::zero(precision);
+        if !v.iter().chain(&mv.elements).all(|x| x.lo > zero) {
+            return Err(SolveError::RegularityNotProved);
+        }
+        let one = Interval::one(precision);
+        let mut x = vec![];
+        for i in 0..n {
+            let d = inv[(i, i)].clone();
+            let alpha = (m[(i, i)].clone() - one.clone() / d.clone())
+                .hi
+                .max(zero.clone());
+            let u = inv[(i, n)].clone();
+            let beta = (u / d - rhs[(i, n)].clone()).hi.max(zero.clone());
+            let den = a[(i, i)].clone() + Interval::new(-alpha.clone(), alpha);
+            if den.has_zero() {
+                return Err(SolveError::RegularityNotProved);
+            }
+            let num = b[(i, 0)].clone() + Interval::new(-beta.clone(), beta);
+            x.push(num / den);
+        }
+        Ok(IntervalVector::new(x))
+    }
+
+    /// Encloses the inverses of all matrices in `self` with the Krawczyk method, and proves that
+    /// they are all regular.
+    ///
+    /// Returns the same errors as `solve_krawczyk`. Panics if `self` is not square.
+    pub fn inverse(&self) -> Result<Self, SolveError> {
+        assert!(self.is_square());
+        if self.is_nan() {
+            let precision = self.precision();
+            return Ok(Self::new(
+                self.rows,
+                self.cols,
+                vec![Interval::nan(precision); self.rows * self.cols],
+            ));
+        }
+        self.krawczyk(&Self::identity(self.rows, self.precision()))
+    }
+}
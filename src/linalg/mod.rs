@@ -0,0 +1,15 @@
+mod def;
+mod impl_basic;
+mod impl_cmp;
+mod impl_ops;
+mod impl_optim;
+mod impl_solve;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::def::{
+    IntervalMatrix, IntervalVector, Minimum, ParseIntervalMatrixError, ParseIntervalVectorError,
+    SolveError,
+};
+pub use self::impl_optim::DEFAULT_MINIMIZE_LIMIT;
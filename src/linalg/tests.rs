@@ -0,0 +1,312 @@
+use super::{IntervalMatrix, IntervalVector, ParseIntervalMatrixError, ParseIntervalVectorError};
+
+use fp::Float;
+use interval::Interval;
+use mpfr::Mpfr;
+
+type B = Mpfr;
+type IV = Interval<B>;
+#[allow(clippy::upper_case_acronyms)]
+type VEC = IntervalVector<B>;
+#[allow(clippy::upper_case_acronyms)]
+type MAT = IntervalMatrix<B>;
+
+const PREC: usize = 53;
+
+macro_rules! assert_str_eq {
+    ($x:expr, $y:expr) => { assert_eq!($x, format!("{}", $y)) };
+    ($x:expr, $y:expr, $($arg:tt)+) => { assert_eq!($x, format!("{}", $y), $($arg)+) };
+}
+
+macro_rules! b {
+    ($s:expr) => {
+        B::from_str_with_prec($s, PREC).unwrap()
+    };
+}
+
+macro_rules! iv {
+    ($s:expr) => {
+        IV::from_str_with_prec($s, PREC).unwrap()
+    };
+}
+
+macro_rules! vec_ {
+    ($s:expr) => {
+        VEC::from_str_with_prec($s, PREC).unwrap()
+    };
+}
+
+macro_rules! mat {
+    ($s:expr) => {
+        MAT::from_str_with_prec($s, PREC).unwrap()
+    };
+}
+
+#[test]
+fn test_parse_display() {
+    assert_str_eq!("[]", vec_!("[]"));
+    assert_str_eq!("[<0, 1>, 2, -3]", vec_!("[<0, 1>, 2, -3]"));
+    assert_str_eq!("[<0, 1>, 2]", vec_!(" [ <0,1> ,2 ] "));
+    assert_str_eq!("[]", mat!("[]"));
+    assert_str_eq!("[1, 2; <3, 4>, 5]", mat!("[1, 2; <3,4>, 5]"));
+    assert_str_eq!("[1; 2]", mat!("[1; 2]"));
+    assert_eq!(2, mat!("[1; 2]").rows);
+    assert_eq!(1, mat!("[1; 2]").cols);
+}
+
+#[test]
+fn test_parse_errors() {
+    use std::str::FromStr;
+    match VEC::from_str("<0, 1>]") {
+        Err(ParseIntervalVectorError::MissingOpeningBracket) => {}
+        r => panic!("{:?}", r),
+    }
+    match VEC::from_str("[<0, 1>") {
+        Err(ParseIntervalVectorError::MissingClosingBracket) => {}
+        r => panic!("{:?}", r),
+    }
+    match VEC::from_str("[<1, 0>]") {
+        Err(ParseIntervalVectorError::IntervalsParseError) => {}
+        r => panic!("{:?}", r),
+    }
+    match MAT::from_str("[1, 2; 3]") {
+        Err(ParseIntervalMatrixError::RaggedRows) => {}
+        r => panic!("{:?}", r),
+    }
+    match MAT::from_str("[1, 2; 3, x]") {
+        Err(ParseIntervalMatrixError::IntervalsParseError) => {}
+        r => panic!("{:?}", r),
+    }
+}
+
+#[test]
+fn test_constructors() {
+    assert_str_eq!("[0, 0]", VEC::zeros(2, PREC));
+    assert_str_eq!("[1, 0; 0, 1]", MAT::identity(2, PREC));
+    assert_str_eq!(
+        "[1, 2; 3, 4]",
+        MAT::from_rows(vec![vec![iv!("1"), iv!("2")], vec![iv!("3"), iv!("4")]])
+    );
+    let m = mat!("[1, 2, 3; 4, 5, 6]");
+    assert_str_eq!("[1, 4; 2, 5; 3, 6]", m.transpose());
+    assert_str_eq!("[3, 6]", m.column(2));
+    assert_str_eq!("5", m[(1, 1)]);
+    assert_eq!(3, m.row(1).len());
+}
+
+#[test]
+#[should_panic]
+fn test_from_rows_ragged() {
+    MAT::from_rows(vec![vec![iv!("1"), iv!("2")], vec![iv!("3")]]);
+}
+
+#[test]
+fn test_partial_eq() {
+    assert_eq!(vec_!("[1, <2, 3>]"), vec_!("[1, <2, 3>]"));
+    assert_ne!(vec_!("[1, <2, 3>]"), vec_!("[1, <2, 4>]"));
+    assert_ne!(vec_!("[1]"), vec_!("[1, 2]"));
+    assert_ne!(mat!("[1, 2]"), mat!("[1; 2]"));
+}
+
+#[test]
+fn test_elementwise() {
+    let x = vec_!("[<0, 1>, 2]");
+    let y = vec_!("[1, <-1, 1>]");
+    assert_str_eq!("[<1, 2>, <1, 3>]", x.clone() + y.clone());
+    assert_str_eq!("[<-1, 0>, <1, 3>]", x.clone() - y.clone());
+    assert_str_eq!("[<-1, -0>, -2]", -x.clone());
+    assert_str_eq!("[<0, 2>, 4]", x.clone() * iv!("2"));
+    assert_str_eq!("[<0, 0.5>, 1]", x / iv!("2"));
+    let a = mat!("[1, <0, 1>; 2, 3]");
+    assert_str_eq!("[2, <0, 2>; 4, 6]", a.clone() + a.clone());
+    assert_str_eq!("[0, <-1, 1>; 0, 0]", a.clone() - a.clone());
+    assert_str_eq!("[-1, <-1, -0>; -2, -3]", -a.clone());
+    assert_str_eq!("[3, <0, 3>; 6, 9]", a * iv!("3"));
+}
+
+#[test]
+fn test_products() {
+    let a = mat!("[1, 2; 3, 4]");
+    let x = vec_!("[1, <-1, 1>]");
+    assert_str_eq!("[<-1, 3>, <-1, 7>]", a.clone() * x);
+    assert_str_eq!("[7, 10; 15, 22]", a.clone() * a.clone());
+    assert_str_eq!("[5, 11; 11, 25]", a.clone() * a.transpose());
+    let b = mat!("[1, 2, 3]");
+    assert_str_eq!("[1, 2, 3; 2, 4, 6]", mat!("[1; 2]") * b);
+    let x = vec_!("[1e16, 1, -1e16]");
+    assert_str_eq!("1", x.dot(&vec_!("[1, 1, 1]")));
+    assert_str_eq!("0", VEC::zeros(0, PREC).dot(&VEC::zeros(0, PREC)));
+}
+
+#[test]
+#[should_panic]
+fn test_product_wrong_shape() {
+    let _ = mat!("[1, 2; 3, 4]") * vec_!("[1, 2, 3]");
+}
+
+#[test]
+fn test_norms() {
+    let x = vec_!("[<-3, 1>, 4]");
+    assert_str_eq!("7", x.norm1());
+    assert_str_eq!("5", x.norm2());
+    assert_str_eq!("4", x.norm_inf());
+    assert!(vec_!("[1, NaN]").norm_inf().is_nan());
+    assert!(vec_!("[1, NaN]").norm2().is_nan());
+    let a = mat!("[1, <-2, 1>; 3, 4]");
+    assert_str_eq!("6", a.norm1());
+    assert_str_eq!("7", a.norm_inf());
+    assert_str_eq!("5.477225575051662", a.norm2());
+    assert!(mat!("[1, NaN]").norm1().is_nan());
+}
+
+#[test]
+fn test_hull_intersection() {
+    let x = vec_!("[<0, 1>, 2]");
+    let y = vec_!("[<2, 3>, <1, 4>]");
+    assert_str_eq!("[<0, 3>, <1, 4>]", x.clone().hull(y.clone()));
+    assert_str_eq!("[NaN, 2]", x.clone().intersection(y));
+    assert!(vec_!("[NaN, 2]").is_nan());
+    assert!(!x.is_nan());
+    let a = mat!("[<0, 1>, 2]");
+    let b = mat!("[<0.5, 3>, NaN]");
+    assert_str_eq!("[<0, 3>, 2]", a.clone().hull(b.clone()));
+    assert_str_eq!("[<0.5, 1>, NaN]", a.intersection(b));
+}
+
+#[test]
+fn test_mid_rad() {
+    let x = vec_!("[<0, 1>, 2, <-inf, inf>]");
+    assert_str_eq!("[0.5, 2, 0]", x.mid());
+    assert_eq!(vec![b!("0.5"), b!("0"), b!("inf")], x.rad());
+    let a = mat!("[<1, 3>, <-inf, 0>]");
+    let mid = a.mid();
+    assert_str_eq!("2", mid[(0, 0)]);
+    assert!(mid[(0, 1)].lo.is_finite() && mid[(0, 1)].lo < b!("-1e300"));
+    assert_eq!(vec![b!("1"), b!("inf")], a.rad());
+}
+
+fn encloses(x: &VEC, y: &VEC) -> bool {
+    x.elements
+        .iter()
+        .zip(&y.elements)
+        .all(|(x, y)| x.lo <= y.lo && y.hi <= x.hi)
+}
+
+#[test]
+fn test_solve_point() {
+    let a = mat!("[4, 1; 1, 3]");
+    let b = vec_!("[1, 2]");
+    let x = VEC::new(vec![iv!("1") / iv!("11"), iv!("7") / iv!("11")]);
+    for y in &[
+        a.solve_gauss(&b).unwrap(),
+        a.solve_krawczyk(&b).unwrap(),
+        a.solve_hull(&b).unwrap(),
+    ] {
+        assert!(encloses(y, &x), "{}", y);
+        assert!(y.rad().iter().all(|r| *r < b!("1e-14")), "{}", y);
+    }
+    let inv = a.inverse().unwrap();
+    let exact = mat!("[3, -1; -1, 4]") / iv!("11");
+    for (x, y) in inv.elements.iter().zip(&exact.elements) {
+        assert!(x.lo <= y.lo && y.hi <= x.hi && x.rad() < b!("1e-14"));
+    }
+}
+
+#[test]
+fn test_solve_interval() {
+    let a = mat!("[2, 0; 0, 4]");
+    let b = vec_!("[<1, 2>, <-4, 4>]");
+    let hull = vec_!("[<0.5, 1>, <-1, 1>]");
+    for y in &[
+        a.solve_gauss(&b).unwrap(),
+        a.solve_krawczyk(&b).unwrap(),
+        a.solve_hull(&b).unwrap(),
+    ] {
+        assert!(encloses(y, &hull), "{}", y);
+        assert!(
+            encloses(&vec_!("[<0.49, 1.01>, <-1.01, 1.01>]"), y),
+            "{}",
+            y
+        );
+    }
+    let a = mat!("[<3, 4>, <-1, 1>; <-1, 1>, <3, 4>]");
+    let b = vec_!("[<1, 2>, 1]");
+    let gauss = a.solve_gauss(&b).unwrap();
+    let krawczyk = a.solve_krawczyk(&b).unwrap();
+    let hull = a.solve_hull(&b).unwrap();
+    for (i, j) in &[(3, 3), (3, 4), (4, 3), (4, 4)] {
+        for (k, l) in &[(1, -1), (-1, 1), (1, 1), (-1, -1), (0, 0)] {
+            for c in &[1, 2] {
+                let m = mat!(&format!("[{}, {}; {}, {}]", i, k, l, j));
+                let x = m.solve_krawczyk(&vec_!(&format!("[{}, 1]", c))).unwrap();
+                assert!(encloses(&gauss, &x), "{} {}", gauss, x);
+                assert!(encloses(&krawczyk, &x), "{} {}", krawczyk, x);
+                assert!(encloses(&hull, &x), "{} {}", hull, x);
+            }
+        }
+    }
+    assert!(hull.norm_inf() <= krawczyk.norm_inf());
+}
+
+#[test]
+fn test_solve_errors() {
+    use super::SolveError;
+    let b = vec_!("[1, 1]");
+    let a = mat!("[<-1, 1>, 1; <-1, 1>, 2]");
+    assert_eq!(Err(SolveError::ZeroPivot), a.solve_gauss(&b));
+    let a = mat!("[1, 2; 2, 4]");
+    assert_eq!(Err(SolveError::SingularMidpoint), a.solve_krawczyk(&b));
+    assert_eq!(Err(SolveError::SingularMidpoint), a.solve_hull(&b));
+    assert_eq!(Err(SolveError::SingularMidpoint), a.inverse());
+    let a = mat!("[<-1, 3>, 0; 0, 1]");
+    assert_eq!(Err(SolveError::RegularityNotProved), a.solve_krawczyk(&b));
+    assert_eq!(Err(SolveError::RegularityNotProved), a.solve_hull(&b));
+    let a = mat!("[1, 0; 0, 1]");
+    assert_str_eq!("[NaN, NaN]", a.solve_hull(&vec_!("[1, NaN]")).unwrap());
+}
+
+#[test]
+fn test_minimize() {
+    use dual::Dual;
+    use transc::Transc;
+    type D = Dual<IV>;
+    let contains = |x: &IV, v: f64| x.lo <= B::from(v) && B::from(v) <= x.hi;
+    let f = |x: Vec<D>| {
+        let (u, v) = (x[0].clone() - iv!("1"), x[1].clone() + iv!("0.5"));
+        u.clone() * u + v.clone() * v
+    };
+    let m = vec_!("[<-2, 2>, <-2, 2>]").minimize_with_limits(f, b!("1e-6"), 10000);
+    assert!(
+        contains(&m.value, 0.0) && m.value.hi < b!("1e-12"),
+        "{}",
+        m.value
+    );
+    for (x, v) in m.minimizers.iter().zip(&[1.0, -0.5]) {
+        let hull = IV::minimal_cover(x.intervals.clone(), PREC);
+        assert!(contains(&hull, *v) && hull.rad() < b!("1e-5"), "{}", x);
+    }
+    let g = |x: Vec<D>| x[0].clone() + x[1].clone();
+    let m = vec_!("[<1, 2>, <-1, 3>]").minimize(g);
+    assert_str_eq!("0", m.value);
+    assert_str_eq!("1", m.minimizers[0]);
+    assert_str_eq!("-1", m.minimizers[1]);
+    let h = |x: Vec<D>| {
+        let u = x[0].clone() * x[0].clone() - iv!("1");
+        u.clone() * u
+    };
+    let m = vec_!("[<-2, 2>]").minimize_with_limits(h, b!("1e-6"), 10000);
+    assert!(contains(&m.value, 0.0), "{}", m.value);
+    let x = &m.minimizers[0];
+    assert_eq!(2, x.intervals.len(), "{}", x);
+    assert!(
+        contains(&x.intervals[0], -1.0) && contains(&x.intervals[1], 1.0),
+        "{}",
+        x
+    );
+    let m = vec_!("[<-2, -1>]").minimize(|x: Vec<D>| x[0].clone().log());
+    assert!(m.value.is_nan());
+    assert!(m.minimizers[0].is_empty());
+    let m = vec_!("[<-2, 2>, <-2, 2>]").minimize_with_limits(f, b!("0"), 0);
+    assert!(contains(&m.value, 0.0), "{}", m.value);
+    assert_str_eq!("<-2, 2>", m.minimizers[0]);
+}
@@ -1,6 +1,6 @@
 /// Struct and functions definitions for the GNU MPFR library. See
 /// http://www.mpfr.org/mpfr-current/mpfr.html for details.
-use libc::{c_char, c_double, c_int, c_long, c_ulong};
+use libc::{c_char, c_double, c_int, c_long, c_ulong, size_t};
 
 /// MPFR rounding mode enum.
 #[repr(C)]
@@ -58,6 +58,16 @@ extern "C" {
 
     // Conversion functions.
     pub fn mpfr_get_d(op: MpfrConstPtr, rnd: MpfrRnd) -> c_double;
+    pub fn mpfr_get_exp(x: MpfrConstPtr) -> MpfrExp;
+    pub fn mpfr_get_str(
+        s: *mut c_char,
+        expptr: *mut MpfrExp,
+        base: c_int,
+        n: size_t,
+        op: MpfrConstPtr,
+        rnd: MpfrRnd,
+    ) -> *mut c_char;
+    pub fn mpfr_free_str(s: *mut c_char);
 
     // Comparison functions.
     pub fn mpfr_cmp(op1: MpfrConstPtr, op2: MpfrConstPtr) -> c_int;
@@ -74,6 +84,14 @@ extern "C" {
     pub fn mpfr_unordered_p(op1: MpfrConstPtr, op2: MpfrConstPtr) -> c_int;
 
     // Integer and remainder related functions.
+    pub fn mpfr_rint(rop: MpfrPtr, op: MpfrConstPtr, rnd: MpfrRnd) -> c_int;
+    pub fn mpfr_ceil(rop: MpfrPtr, op: MpfrConstPtr) -> c_int;
+    pub fn mpfr_floor(rop: MpfrPtr, op: MpfrConstPtr) -> c_int;
+    pub fn mpfr_round(rop: MpfrPtr, op: MpfrConstPtr) -> c_int;
+    pub fn mpfr_trunc(rop: MpfrPtr, op: MpfrConstPtr) -> c_int;
+    pub fn mpfr_frac(rop: MpfrPtr, op: MpfrConstPtr, rnd: MpfrRnd) -> c_int;
+    pub fn mpfr_fmod(r: MpfrPtr, x: MpfrConstPtr, y: MpfrConstPtr, rnd: MpfrRnd) -> c_int;
+    pub fn mpfr_remainder(r: MpfrPtr, x: MpfrConstPtr, y: MpfrConstPtr, rnd: MpfrRnd) -> c_int;
     pub fn mpfr_integer_p(op: MpfrConstPtr) -> c_int;
 
     // Basic arithmetic functions.
@@ -83,7 +101,17 @@ extern "C" {
     pub fn mpfr_sub(rop: MpfrPtr, op1: MpfrConstPtr, op2: MpfrConstPtr, rnd: MpfrRnd) -> c_int;
     pub fn mpfr_mul(rop: MpfrPtr, op1: MpfrConstPtr, op2: MpfrConstPtr, rnd: MpfrRnd) -> c_int;
     pub fn mpfr_div(rop: MpfrPtr, op1: MpfrConstPtr, op2: MpfrConstPtr, rnd: MpfrRnd) -> c_int;
+    pub fn mpfr_fma(
+        rop: MpfrPtr,
+        op1: MpfrConstPtr,
+        op2: MpfrConstPtr,
+        op3: MpfrConstPtr,
+        rnd: MpfrRnd,
+    ) -> c_int;
+    pub fn mpfr_sum(rop: MpfrPtr, tab: *const MpfrPtr, n: c_ulong, rnd: MpfrRnd) -> c_int;
+    pub fn mpfr_hypot(rop: MpfrPtr, x: MpfrConstPtr, y: MpfrConstPtr, rnd: MpfrRnd) -> c_int;
     pub fn mpfr_pow(rop: MpfrPtr, op1: MpfrConstPtr, op2: MpfrConstPtr, rnd: MpfrRnd) -> c_int;
+    pub fn mpfr_mul_2si(rop: MpfrPtr, op1: MpfrConstPtr, op2: c_long, rnd: MpfrRnd) -> c_int;
 
     // Special functions.
     pub fn mpfr_log(rop: MpfrPtr, op: MpfrConstPtr, rnd: MpfrRnd) -> c_int;
@@ -92,4 +120,6 @@ extern "C" {
     // Miscellaneous Functions
     pub fn mpfr_min(rop: MpfrPtr, op1: MpfrConstPtr, op2: MpfrConstPtr, rnd: MpfrRnd) -> c_int;
     pub fn mpfr_max(rop: MpfrPtr, op1: MpfrConstPtr, op2: MpfrConstPtr, rnd: MpfrRnd) -> c_int;
+    pub fn mpfr_nextabove(x: MpfrPtr);
+    pub fn mpfr_nextbelow(x: MpfrPtr);
 }
@@ -3,14 +3,19 @@ use super::def::{Mpfr, ParseMpfrError};
 
 use fp::Float;
 
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 use std::fmt;
 use std::fmt::{Display, Formatter};
 use std::mem::MaybeUninit;
+use std::ptr;
 use std::str::FromStr;
 
 impl Mpfr {
     /// Constructs an uninitialized MPFR.
+    ///
+    /// # Safety
+    ///
+    /// The value of the result is NaN until it is explicitly set.
     #[inline]
     pub unsafe fn uninitialized(precision: usize) -> Self {
         let mut mpfr = MaybeUninit::uninit();
@@ -84,6 +89,21 @@ impl Mpfr {
     pub fn as_f64(&self, rounding_mode: MpfrRnd) -> f64 {
         unsafe { mpfr_get_d(&self.mpfr, rounding_mode) }
     }
+
+    /// Returns the `n` leading decimal digits of `self` and an exponent `e` such that `self` is
+    /// approximately `0.d1d2d3... * 10^e`. The digits are preceded by `-` if `self` is negative.
+    ///
+    /// `self` must be a finite number and `n` must be at least 2.
+    pub fn as_decimal(&self, n: usize, rounding_mode: MpfrRnd) -> (String, isize) {
+        assert!(self.is_finite() && n >= 2);
+        let mut exp: MpfrExp = 0;
+        unsafe {
+            let c = mpfr_get_str(ptr::null_mut(), &mut exp, 10, n, &self.mpfr, rounding_mode);
+            let digits = CStr::from_ptr(c).to_string_lossy().into_owned();
+            mpfr_free_str(c);
+            (digits, exp as isize)
+        }
+    }
 }
 
 impl Drop for Mpfr {
@@ -161,9 +181,9 @@ impl Display for Mpfr {
     }
 }
 
-impl Into<f64> for Mpfr {
+impl From<Mpfr> for f64 {
     #[inline]
-    fn into(self) -> f64 {
-        self.as_f64(MpfrRnd::HalfToEven)
+    fn from(val: Mpfr) -> Self {
+        val.as_f64(MpfrRnd::HalfToEven)
     }
 }
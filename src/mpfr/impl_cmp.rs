@@ -1,7 +1,13 @@
 use super::capi::*;
 use super::def::Mpfr;
 
+use fp;
+use fp::Float;
+
 use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::mem;
+use std::slice;
 
 impl PartialEq<Mpfr> for Mpfr {
     #[inline]
@@ -51,3 +57,40 @@ impl PartialOrd<Mpfr> for Mpfr {
         unsafe { mpfr_greaterequal_p(&self.mpfr, &other.mpfr) != 0 }
     }
 }
+
+impl fp::TotalOrd for Mpfr {
+    fn cmp_total(&self, rhs: &Mpfr) -> Ordering {
+        match (self.is_nan(), rhs.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => self.partial_cmp(rhs).unwrap(),
+        }
+    }
+
+    /// Hashes the sign, the exponent and the significant limbs.
+    ///
+    /// Trailing zero limbs are skipped, so that equal numbers of different precisions hash the
+    /// same.
+    fn hash_total<H: Hasher>(&self, state: &mut H) {
+        if self.is_nan() {
+            0u8.hash(state);
+        } else if self.is_zero() {
+            1u8.hash(state);
+        } else if self.is_infinite() {
+            2u8.hash(state);
+            self.mpfr._mpfr_sign.hash(state);
+        } else {
+            3u8.hash(state);
+            self.mpfr._mpfr_sign.hash(state);
+            self.mpfr._mpfr_exp.hash(state);
+            let limb_bits = 8 * mem::size_of::<MpLimb>();
+            let n = (self.mpfr._mpfr_prec as usize).div_ceil(limb_bits);
+            let limbs = unsafe { slice::from_raw_parts(self.mpfr._mpfr_d, n) };
+            let low = limbs.iter().position(|&limb| limb != 0).unwrap_or(n);
+            for limb in limbs[low..].iter().rev() {
+                limb.hash(state);
+            }
+        }
+    }
+}
@@ -2,10 +2,25 @@ use super::capi::*;
 use super::def::Mpfr;
 
 use fp;
-use fp::{Float, Sign};
+use fp::{Float, Round, Sign};
+
+use libc::{c_long, c_ulong};
 
 use std::ops::Neg;
 
+impl From<Round> for MpfrRnd {
+    #[inline]
+    fn from(round: Round) -> Self {
+        match round {
+            Round::Down => MpfrRnd::Down,
+            Round::Up => MpfrRnd::Up,
+            Round::Nearest => MpfrRnd::HalfToEven,
+            Round::TowardsZero => MpfrRnd::TowardsZero,
+            Round::AwayFromZero => MpfrRnd::AwayFromZero,
+        }
+    }
+}
+
 impl fp::From<f64> for Mpfr {
     #[inline]
     fn from_lo(val: f64, precision: usize) -> Self {
@@ -84,133 +99,224 @@ impl fp::Abs for Mpfr {
     }
 }
 
-impl fp::Add for Mpfr {
+impl fp::Integral for Mpfr {
     #[inline]
-    fn add_lo(mut self, rhs: Self) -> Self {
-        assert_eq!(self.precision(), rhs.precision());
-        unsafe {
-            mpfr_add(&mut self.mpfr, &self.mpfr, &rhs.mpfr, MpfrRnd::Down);
-        }
+    fn floor(mut self) -> Self {
+        unsafe { mpfr_floor(&mut self.mpfr, &self.mpfr) };
         self
     }
 
     #[inline]
-    fn add_hi(mut self, rhs: Self) -> Self {
-        assert_eq!(self.precision(), rhs.precision());
-        unsafe {
-            mpfr_add(&mut self.mpfr, &self.mpfr, &rhs.mpfr, MpfrRnd::Up);
+    fn ceil(mut self) -> Self {
+        unsafe { mpfr_ceil(&mut self.mpfr, &self.mpfr) };
+        self
+    }
+
+    #[inline]
+    fn round_ties_even(mut self) -> Self {
+        unsafe { mpfr_rint(&mut self.mpfr, &self.mpfr, MpfrRnd::HalfToEven) };
+        self
+    }
+
+    #[inline]
+    fn round_ties_away(mut self) -> Self {
+        unsafe { mpfr_round(&mut self.mpfr, &self.mpfr) };
+        self
+    }
+
+    #[inline]
+    fn trunc(mut self) -> Self {
+        unsafe { mpfr_trunc(&mut self.mpfr, &self.mpfr) };
+        self
+    }
+
+    #[inline]
+    fn frac(mut self) -> Self {
+        if self.is_infinite() {
+            return self.set_nan();
         }
+        unsafe { mpfr_frac(&mut self.mpfr, &self.mpfr, MpfrRnd::HalfToEven) };
         self
     }
 }
 
-impl fp::Sub for Mpfr {
+impl fp::Rem for Mpfr {
     #[inline]
-    fn sub_lo(mut self, rhs: Self) -> Self {
+    fn fmod_lo(mut self, rhs: Self) -> Self {
         assert_eq!(self.precision(), rhs.precision());
-        unsafe {
-            mpfr_sub(&mut self.mpfr, &self.mpfr, &rhs.mpfr, MpfrRnd::Down);
-        }
+        unsafe { mpfr_fmod(&mut self.mpfr, &self.mpfr, &rhs.mpfr, MpfrRnd::Down) };
         self
     }
 
     #[inline]
-    fn sub_hi(mut self, rhs: Self) -> Self {
+    fn fmod_hi(mut self, rhs: Self) -> Self {
         assert_eq!(self.precision(), rhs.precision());
-        unsafe {
-            mpfr_sub(&mut self.mpfr, &self.mpfr, &rhs.mpfr, MpfrRnd::Up);
-        }
+        unsafe { mpfr_fmod(&mut self.mpfr, &self.mpfr, &rhs.mpfr, MpfrRnd::Up) };
         self
     }
-}
 
-impl fp::Mul for Mpfr {
     #[inline]
-    fn mul_lo(mut self, rhs: Self) -> Self {
+    fn remainder_lo(mut self, rhs: Self) -> Self {
         assert_eq!(self.precision(), rhs.precision());
-        unsafe {
-            mpfr_mul(&mut self.mpfr, &self.mpfr, &rhs.mpfr, MpfrRnd::Down);
-        }
+        unsafe { mpfr_remainder(&mut self.mpfr, &self.mpfr, &rhs.mpfr, MpfrRnd::Down) };
         self
     }
 
     #[inline]
-    fn mul_hi(mut self, rhs: Self) -> Self {
+    fn remainder_hi(mut self, rhs: Self) -> Self {
         assert_eq!(self.precision(), rhs.precision());
-        unsafe {
-            mpfr_mul(&mut self.mpfr, &self.mpfr, &rhs.mpfr, MpfrRnd::Up);
-        }
+        unsafe { mpfr_remainder(&mut self.mpfr, &self.mpfr, &rhs.mpfr, MpfrRnd::Up) };
         self
     }
 }
 
-impl fp::Div for Mpfr {
+impl fp::Hypot for Mpfr {
     #[inline]
-    fn div_lo(mut self, rhs: Self) -> Self {
+    fn hypot_r(mut self, rhs: Self, round: Round) -> Self {
         assert_eq!(self.precision(), rhs.precision());
-        unsafe {
-            mpfr_div(&mut self.mpfr, &self.mpfr, &rhs.mpfr, MpfrRnd::Down);
-        }
+        unsafe { mpfr_hypot(&mut self.mpfr, &self.mpfr, &rhs.mpfr, round.into()) };
         self
     }
+}
+
+impl fp::Add for Mpfr {
+    #[inline]
+    fn add_r(self, rhs: Self, round: Round) -> Self {
+        self.add_r_exact(rhs, round).0
+    }
 
     #[inline]
-    fn div_hi(mut self, rhs: Self) -> Self {
+    fn add_r_exact(mut self, rhs: Self, round: Round) -> (Self, bool) {
         assert_eq!(self.precision(), rhs.precision());
-        unsafe {
-            mpfr_div(&mut self.mpfr, &self.mpfr, &rhs.mpfr, MpfrRnd::Up);
-        }
-        self
+        let t = unsafe { mpfr_add(&mut self.mpfr, &self.mpfr, &rhs.mpfr, round.into()) };
+        (self, t == 0)
     }
 }
 
-impl fp::Transc for Mpfr {
+impl fp::Sub for Mpfr {
     #[inline]
-    fn log_lo(mut self) -> Self {
-        unsafe {
-            mpfr_log(&mut self.mpfr, &self.mpfr, MpfrRnd::Down);
-        }
-        self
+    fn sub_r(self, rhs: Self, round: Round) -> Self {
+        self.sub_r_exact(rhs, round).0
     }
 
     #[inline]
-    fn log_hi(mut self) -> Self {
-        unsafe {
-            mpfr_log(&mut self.mpfr, &self.mpfr, MpfrRnd::Up);
-        }
-        self
+    fn sub_r_exact(mut self, rhs: Self, round: Round) -> (Self, bool) {
+        assert_eq!(self.precision(), rhs.precision());
+        let t = unsafe { mpfr_sub(&mut self.mpfr, &self.mpfr, &rhs.mpfr, round.into()) };
+        (self, t == 0)
     }
+}
 
+impl fp::Mul for Mpfr {
     #[inline]
-    fn exp_lo(mut self) -> Self {
-        unsafe {
-            mpfr_exp(&mut self.mpfr, &self.mpfr, MpfrRnd::Down);
-        }
-        self
+    fn mul_r(self, rhs: Self, round: Round) -> Self {
+        self.mul_r_exact(rhs, round).0
     }
 
     #[inline]
-    fn exp_hi(mut self) -> Self {
-        unsafe {
-            mpfr_exp(&mut self.mpfr, &self.mpfr, MpfrRnd::Up);
-        }
-        self
+    fn mul_r_exact(mut self, rhs: Self, round: Round) -> (Self, bool) {
+        assert_eq!(self.precision(), rhs.precision());
+        let t = unsafe { mpfr_mul(&mut self.mpfr, &self.mpfr, &rhs.mpfr, round.into()) };
+        (self, t == 0)
+    }
+}
+
+impl fp::Div for Mpfr {
+    #[inline]
+    fn div_r(self, rhs: Self, round: Round) -> Self {
+        self.div_r_exact(rhs, round).0
     }
 
-    fn pow_lo(mut self, rhs: Self) -> Self {
+    #[inline]
+    fn div_r_exact(mut self, rhs: Self, round: Round) -> (Self, bool) {
         assert_eq!(self.precision(), rhs.precision());
+        let t = unsafe { mpfr_div(&mut self.mpfr, &self.mpfr, &rhs.mpfr, round.into()) };
+        (self, t == 0)
+    }
+}
+
+impl fp::Fma for Mpfr {
+    #[inline]
+    fn fma_r(self, a: Self, b: Self, round: Round) -> Self {
+        self.fma_r_exact(a, b, round).0
+    }
+
+    #[inline]
+    fn fma_r_exact(mut self, a: Self, b: Self, round: Round) -> (Self, bool) {
+        assert_eq!(self.precision(), a.precision());
+        assert_eq!(self.precision(), b.precision());
+        let t = unsafe { mpfr_fma(&mut self.mpfr, &self.mpfr, &a.mpfr, &b.mpfr, round.into()) };
+        (self, t == 0)
+    }
+}
+
+impl fp::Sum for Mpfr {
+    fn sum_r(xs: &[Self], precision: usize, round: Round) -> Self {
+        // MPFR only reads the terms, but expects mutable pointers.
+        let tab: Vec<_> = xs
+            .iter()
+            .map(|x| &x.mpfr as *const MpfrStruct as *mut MpfrStruct)
+            .collect();
+        let mut res = unsafe { Mpfr::uninitialized(precision) };
         unsafe {
-            mpfr_pow(&mut self.mpfr, &self.mpfr, &rhs.mpfr, MpfrRnd::Down);
-        }
-        self
+            mpfr_sum(
+                &mut res.mpfr,
+                tab.as_ptr(),
+                tab.len() as c_ulong,
+                round.into(),
+            )
+        };
+        res
+    }
+
+    fn dot_r(xs: &[Self], ys: &[Self], precision: usize, round: Round) -> Self {
+        assert_eq!(xs.len(), ys.len());
+        // Each product is exact with the sum of the precisions of its factors.
+        let products: Vec<_> = xs
+            .iter()
+            .zip(ys)
+            .map(|(x, y)| {
+                let mut p = unsafe { Mpfr::uninitialized(x.precision() + y.precision()) };
+                unsafe { mpfr_mul(&mut p.mpfr, &x.mpfr, &y.mpfr, MpfrRnd::HalfToEven) };
+                p
+            })
+            .collect();
+        Self::sum_r(&products, precision, round)
     }
+}
 
-    fn pow_hi(mut self, rhs: Self) -> Self {
+impl fp::Transc for Mpfr {
+    #[inline]
+    fn log_r(self, round: Round) -> Self {
+        self.log_r_exact(round).0
+    }
+
+    #[inline]
+    fn exp_r(self, round: Round) -> Self {
+        self.exp_r_exact(round).0
+    }
+
+    #[inline]
+    fn pow_r(self, rhs: Self, round: Round) -> Self {
+        self.pow_r_exact(rhs, round).0
+    }
+
+    #[inline]
+    fn log_r_exact(mut self, round: Round) -> (Self, bool) {
+        let t = unsafe { mpfr_log(&mut self.mpfr, &self.mpfr, round.into()) };
+        (self, t == 0)
+    }
+
+    #[inline]
+    fn exp_r_exact(mut self, round: Round) -> (Self, bool) {
+        let t = unsafe { mpfr_exp(&mut self.mpfr, &self.mpfr, round.into()) };
+        (self, t == 0)
+    }
+
+    fn pow_r_exact(mut self, rhs: Self, round: Round) -> (Self, bool) {
         assert_eq!(self.precision(), rhs.precision());
-        unsafe {
-            mpfr_pow(&mut self.mpfr, &self.mpfr, &rhs.mpfr, MpfrRnd::Up);
-        }
-        self
+        let t = unsafe { mpfr_pow(&mut self.mpfr, &self.mpfr, &rhs.mpfr, round.into()) };
+        (self, t == 0)
     }
 }
 
@@ -296,4 +402,39 @@ impl Float for Mpfr {
     fn has_odd_denominator(&self) -> bool {
         (unsafe { mpfr_integer_p(&self.mpfr) }) != 0
     }
+
+    #[inline]
+    fn next_up(mut self) -> Self {
+        unsafe { mpfr_nextabove(&mut self.mpfr) };
+        self
+    }
+
+    #[inline]
+    fn next_down(mut self) -> Self {
+        unsafe { mpfr_nextbelow(&mut self.mpfr) };
+        self
+    }
+
+    #[inline]
+    fn frexp(mut self) -> (Self, isize) {
+        if self.is_zero() || !self.is_finite() {
+            return (self, 0);
+        }
+        let exp = unsafe { mpfr_get_exp(&self.mpfr) };
+        unsafe { mpfr_mul_2si(&mut self.mpfr, &self.mpfr, -exp, MpfrRnd::HalfToEven) };
+        (self, exp as isize)
+    }
+
+    #[inline]
+    fn ldexp(mut self, exp: isize) -> Self {
+        unsafe {
+            mpfr_mul_2si(
+                &mut self.mpfr,
+                &self.mpfr,
+                exp as c_long,
+                MpfrRnd::HalfToEven,
+            )
+        };
+        self
+    }
 }
@@ -8,6 +8,7 @@ mod impl_ops;
 mod impl_transc;
 
 #[cfg(test)]
+#[allow(clippy::neg_cmp_op_on_partial_ord, clippy::octal_escapes)]
 mod tests;
 
 pub use self::capi::MpfrRnd;
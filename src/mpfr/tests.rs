@@ -132,6 +132,43 @@ fn test_partial_ord_cmp() {
     assert!(mpfr!("NaN").partial_cmp(&mpfr!("NaN")).is_none());
 }
 
+#[test]
+fn test_total_ord() {
+    use fp::{Total, TotalOrd};
+    use std::cmp::Ordering;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher;
+    let hash = |x: &Mpfr| {
+        let mut state = DefaultHasher::new();
+        x.hash_total(&mut state);
+        state.finish()
+    };
+    let xs = [
+        mpfr!("-inf"),
+        mpfr!("-3"),
+        mpfr!("-0"),
+        mpfr!("0.5"),
+        mpfr!("inf"),
+        mpfr!("NaN"),
+    ];
+    for (i, x) in xs.iter().enumerate() {
+        for (j, y) in xs.iter().enumerate() {
+            assert_eq!(i.cmp(&j), x.cmp_total(y));
+        }
+    }
+    assert_eq!(Ordering::Equal, mpfr!("-0").cmp_total(&mpfr!("0")));
+    assert_eq!(hash(&mpfr!("-0")), hash(&mpfr!("0", 200)));
+    assert_eq!(hash(&mpfr!("NaN")), hash(&mpfr!("NaN", 200)));
+    assert_eq!(Total(mpfr!("0.75")), Total(mpfr!("0.75", 200)));
+    assert_eq!(hash(&mpfr!("0.75")), hash(&mpfr!("0.75", 200)));
+    assert_eq!(
+        hash(&mpfr!("-12345678901234567890123", 300)),
+        hash(&mpfr!("-12345678901234567890123", 400))
+    );
+    assert_ne!(hash(&mpfr!("0.75")), hash(&mpfr!("-0.75")));
+    assert_ne!(hash(&mpfr!("0.75")), hash(&mpfr!("1.5")));
+}
+
 #[test]
 fn test_partial_ord_rest() {
     assert!(mpfr!("0") <= mpfr!("0"));
@@ -269,6 +306,45 @@ fn test_abs() {
     assert_str_eq!("1", mpfr!("-1").abs());
 }
 
+#[test]
+fn test_integral() {
+    use fp::Integral;
+    assert_str_eq!("1", mpfr!("1.5").floor());
+    assert_str_eq!("-2", mpfr!("-1.5").floor());
+    assert_str_eq!("2", mpfr!("1.5").ceil());
+    assert_str_eq!("-1", mpfr!("-1.5").ceil());
+    assert_str_eq!("2", mpfr!("1.5").round_ties_even());
+    assert_str_eq!("0", mpfr!("0.5").round_ties_even());
+    assert_str_eq!("1", mpfr!("0.5").round_ties_away());
+    assert_str_eq!("-1", mpfr!("-0.5").round_ties_away());
+    assert_str_eq!("-1", mpfr!("-1.5").trunc());
+    assert_str_eq!("-0.5", mpfr!("-1.5").frac());
+    assert_str_eq!("inf", mpfr!("inf").floor());
+    assert_str_eq!("NaN", mpfr!("inf").frac());
+}
+
+#[test]
+fn test_rem() {
+    use fp::Rem;
+    assert_str_eq!("1", mpfr!("3").fmod_lo(mpfr!("2")));
+    assert_str_eq!("-1", mpfr!("-3").fmod_hi(mpfr!("2")));
+    assert_str_eq!("-1", mpfr!("3").remainder_lo(mpfr!("2")));
+    assert_str_eq!("1", mpfr!("-3").remainder_hi(mpfr!("-2")));
+    assert_str_eq!("1.5", mpfr!("1.5").fmod_lo(mpfr!("inf")));
+    assert_str_eq!("NaN", mpfr!("inf").fmod_hi(mpfr!("2")));
+    assert_str_eq!("NaN", mpfr!("1").remainder_lo(mpfr!("0")));
+}
+
+#[test]
+fn test_hypot() {
+    use fp::Hypot;
+    assert_str_eq!("3", mpfr!("3").hypot_lo(mpfr!("2")));
+    assert_str_eq!("4", mpfr!("3").hypot_hi(mpfr!("2")));
+    assert_str_eq!("1", mpfr!("1").hypot_lo(mpfr!("0.5")));
+    assert_str_eq!("1.5", mpfr!("1").hypot_hi(mpfr!("0.5")));
+    assert_str_eq!("inf", mpfr!("NaN").hypot_hi(mpfr!("-inf")));
+}
+
 #[test]
 fn test_add_lo() {
     use fp::Add;
@@ -367,6 +443,146 @@ fn test_pow_hi() {
     assert_str_eq!("0.75", mpfr!("0.5").pow_hi(mpfr!("0.75")));
 }
 
+#[test]
+fn test_add_exact() {
+    use fp::Add;
+    let (z, exact) = mpfr!("0.5").add_lo_exact(mpfr!("1"));
+    assert_str_eq!("1.5", z);
+    assert!(exact);
+    let (z, exact) = mpfr!("0.75").add_lo_exact(mpfr!("1"));
+    assert_str_eq!("1.5", z);
+    assert!(!exact);
+    let (z, exact) = mpfr!("0.75").add_hi_exact(mpfr!("1"));
+    assert_str_eq!("2", z);
+    assert!(!exact);
+}
+
+#[test]
+fn test_sub_exact() {
+    use fp::Sub;
+    let (z, exact) = mpfr!("2").sub_lo_exact(mpfr!("0.5"));
+    assert_str_eq!("1.5", z);
+    assert!(exact);
+    let (z, exact) = mpfr!("3").sub_hi_exact(mpfr!("0.75"));
+    assert_str_eq!("3", z);
+    assert!(!exact);
+}
+
+#[test]
+fn test_mul_exact() {
+    use fp::Mul;
+    let (z, exact) = mpfr!("1.5").mul_lo_exact(mpfr!("2"));
+    assert_str_eq!("3", z);
+    assert!(exact);
+    let (z, exact) = mpfr!("1.5").mul_hi_exact(mpfr!("1.5"));
+    assert_str_eq!("3", z);
+    assert!(!exact);
+}
+
+#[test]
+fn test_div_exact() {
+    use fp::Div;
+    let (z, exact) = mpfr!("3").div_lo_exact(mpfr!("2"));
+    assert_str_eq!("1.5", z);
+    assert!(exact);
+    let (z, exact) = mpfr!("1").div_lo_exact(mpfr!("3"));
+    assert_str_eq!("0.25", z);
+    assert!(!exact);
+}
+
+#[test]
+fn test_transc_exact() {
+    use fp::Transc;
+    let (z, exact) = mpfr!("1").log_lo_exact();
+    assert_str_eq!("0", z);
+    assert!(exact);
+    let (z, exact) = mpfr!("2").log_hi_exact();
+    assert_str_eq!("0.75", z);
+    assert!(!exact);
+    let (z, exact) = mpfr!("0").exp_lo_exact();
+    assert_str_eq!("1", z);
+    assert!(exact);
+    let (z, exact) = mpfr!("1").exp_hi_exact();
+    assert_str_eq!("3", z);
+    assert!(!exact);
+    let (z, exact) = mpfr!("2").pow_lo_exact(mpfr!("3"));
+    assert_str_eq!("8", z);
+    assert!(exact);
+    let (z, exact) = mpfr!("3").pow_lo_exact(mpfr!("2"));
+    assert_str_eq!("8", z);
+    assert!(!exact);
+}
+
+#[test]
+fn test_round() {
+    use fp::{Div, Round, Transc};
+    let modes = [
+        Round::Down,
+        Round::Up,
+        Round::Nearest,
+        Round::TowardsZero,
+        Round::AwayFromZero,
+    ];
+    let expected = [
+        ("0.5", "-0.75"),
+        ("0.75", "-0.5"),
+        ("0.75", "-0.75"),
+        ("0.5", "-0.5"),
+        ("0.75", "-0.75"),
+    ];
+    for (&round, &(pos, neg)) in modes.iter().zip(expected.iter()) {
+        assert_str_eq!(pos, mpfr!("2").div_r(mpfr!("3"), round), "{:?}", round);
+        assert_str_eq!(neg, mpfr!("-2").div_r(mpfr!("3"), round), "{:?}", round);
+    }
+    assert_str_eq!("0.75", mpfr!("2").log_r(Round::Nearest));
+    assert_str_eq!("0.5", mpfr!("2").log_r(Round::TowardsZero));
+    let (z, exact) = mpfr!("3").div_r_exact(mpfr!("2"), Round::Nearest);
+    assert_str_eq!("1.5", z);
+    assert!(exact);
+}
+
+#[test]
+fn test_fma() {
+    use fp::{Add, Fma, Mul};
+    assert_str_eq!("8", mpfr!("3").fma_lo(mpfr!("3"), mpfr!("1")));
+    assert_str_eq!("12", mpfr!("3").fma_hi(mpfr!("3"), mpfr!("1")));
+    assert_str_eq!("16", mpfr!("3").mul_hi(mpfr!("3")).add_hi(mpfr!("1")));
+    assert_str_eq!("-8", mpfr!("3").fma_lo(mpfr!("-3"), mpfr!("1")));
+    assert_str_eq!("NaN", mpfr!("inf").fma_lo(mpfr!("0"), mpfr!("1")));
+    let (z, exact) = mpfr!("1.5").fma_lo_exact(mpfr!("2"), mpfr!("1"));
+    assert_str_eq!("4", z);
+    assert!(exact);
+    assert!(!mpfr!("3").fma_hi_exact(mpfr!("3"), mpfr!("1")).1);
+}
+
+#[test]
+fn test_sum() {
+    use fp::Sum;
+    let xs = [mpfr!("1024"), mpfr!("1"), mpfr!("-1024")];
+    assert_str_eq!("1", Mpfr::sum_lo(&xs, PREC));
+    assert_str_eq!("1", Mpfr::sum_hi(&xs, PREC));
+    let xs = [mpfr!("3"), mpfr!("3"), mpfr!("3")];
+    assert_str_eq!("8", Mpfr::sum_lo(&xs, PREC));
+    assert_str_eq!("12", Mpfr::sum_hi(&xs, PREC));
+    assert_str_eq!("0", Mpfr::sum_lo(&[], PREC));
+    assert_str_eq!("inf", Mpfr::sum_lo(&[mpfr!("1"), mpfr!("inf")], PREC));
+    assert_str_eq!("NaN", Mpfr::sum_lo(&[mpfr!("-inf"), mpfr!("inf")], PREC));
+}
+
+#[test]
+fn test_dot() {
+    use fp::Sum;
+    let xs = [mpfr!("3"), mpfr!("3")];
+    let ys = [mpfr!("3"), mpfr!("-3")];
+    assert_str_eq!("-0", Mpfr::dot_lo(&xs, &ys, PREC));
+    assert_str_eq!("0", Mpfr::dot_hi(&xs, &ys, PREC));
+    let ys = [mpfr!("3"), mpfr!("0.5")];
+    assert_str_eq!("8", Mpfr::dot_lo(&xs, &ys, PREC));
+    assert_str_eq!("12", Mpfr::dot_hi(&xs, &ys, PREC));
+    assert_str_eq!("0", Mpfr::dot_hi(&[], &[], PREC));
+    assert_str_eq!("NaN", Mpfr::dot_hi(&[mpfr!("0")], &[mpfr!("inf")], PREC));
+}
+
 #[test]
 fn test_constants() {
     use fp::Float;
@@ -439,3 +655,57 @@ fn test_is_nan() {
     assert!(!mpfr!("-inf").is_nan());
     assert!(mpfr!("NaN").is_nan());
 }
+
+#[test]
+fn test_next_up() {
+    use fp::Float;
+    assert_str_eq!("1.5", mpfr!("1").next_up());
+    assert_str_eq!("-0.75", mpfr!("-1").next_up());
+    assert!(mpfr!("0").next_up() > mpfr!("0"));
+    assert_str_eq!("inf", mpfr!("inf").next_up());
+    assert_str_eq!("NaN", mpfr!("NaN").next_up());
+}
+
+#[test]
+fn test_next_down() {
+    use fp::Float;
+    assert_str_eq!("0.75", mpfr!("1").next_down());
+    assert_str_eq!("-1.5", mpfr!("-1").next_down());
+    assert!(mpfr!("0").next_down() < mpfr!("0"));
+    assert_str_eq!("-inf", mpfr!("-inf").next_down());
+    assert_str_eq!("NaN", mpfr!("NaN").next_down());
+}
+
+#[test]
+fn test_ulp() {
+    use fp::Float;
+    assert_str_eq!("0.5", mpfr!("1").ulp());
+    assert_str_eq!("1", mpfr!("-3").ulp());
+    assert_str_eq!("0.0000000000000002220446049250313", mpfr!("1", 53).ulp());
+    assert_str_eq!("inf", mpfr!("-inf").ulp());
+    assert_str_eq!("NaN", mpfr!("NaN").ulp());
+}
+
+#[test]
+fn test_frexp() {
+    use fp::Float;
+    let (m, e) = mpfr!("3").frexp();
+    assert_str_eq!("0.75", m);
+    assert_eq!(2, e);
+    let (m, e) = mpfr!("-0.25").frexp();
+    assert_str_eq!("-0.5", m);
+    assert_eq!(-1, e);
+    let (m, e) = mpfr!("0").frexp();
+    assert_str_eq!("0", m);
+    assert_eq!(0, e);
+    assert_str_eq!("0.5", mpfr!("1").mantissa());
+    assert_eq!(1, mpfr!("1").exponent());
+}
+
+#[test]
+fn test_ldexp() {
+    use fp::Float;
+    assert_str_eq!("3", mpfr!("0.75").ldexp(2));
+    assert_str_eq!("-0.25", mpfr!("-1").ldexp(-2));
+    assert_str_eq!("inf", mpfr!("inf").ldexp(-2));
+}
@@ -0,0 +1,554 @@
+use fp::Sign;
+
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+/// Base of the limbs that are used for decimal conversions.
+const DECIMAL_BASE: u32 = 1_000_000_000;
+
+/// Number of decimal digits in `DECIMAL_BASE`.
+const DECIMAL_DIGITS: usize = 9;
+
+/// An arbitrary-precision signed integer.
+///
+/// The magnitude is stored as little-endian 32-bit limbs without leading zero limbs, so that every
+/// integer has a unique representation. Zero is never negative.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    neg: bool,
+    mag: Vec<u32>,
+}
+
+/// Integer parsing error.
+#[derive(Debug, PartialEq)]
+pub struct ParseBigIntError;
+
+/// Removes the leading zero limbs of `v`.
+#[inline]
+fn trim(mut v: Vec<u32>) -> Vec<u32> {
+    while v.last() == Some(&0) {
+        v.pop();
+    }
+    v
+}
+
+fn cmp_mag(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut res = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0u64;
+    for (i, &x) in a.iter().enumerate() {
+        let s = x as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+        res.push(s as u32);
+        carry = s >> 32;
+    }
+    res.push(carry as u32);
+    trim(res)
+}
+
+/// Computes `a - b`, where `a >= b`.
+fn sub_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut res = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, &x) in a.iter().enumerate() {
+        let d = x as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        res.push(d as u32);
+        borrow = if d < 0 { 1 } else { 0 };
+    }
+    debug_assert_eq!(borrow, 0);
+    trim(res)
+}
+
+fn mul_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut res = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let t = res[i + j] as u64 + x as u64 * y as u64 + carry;
+            res[i + j] = t as u32;
+            carry = t >> 32;
+        }
+        res[i + b.len()] = carry as u32;
+    }
+    trim(res)
+}
+
+/// Computes `a * m + c` for single-limb `m` and `c`.
+fn mul_add_small(a: &[u32], m: u32, c: u32) -> Vec<u32> {
+    let mut res = Vec::with_capacity(a.len() + 1);
+    let mut carry = c as u64;
+    for &x in a {
+        let t = x as u64 * m as u64 + carry;
+        res.push(t as u32);
+        carry = t >> 32;
+    }
+    res.push(carry as u32);
+    trim(res)
+}
+
+/// Divides `a` by a single nonzero limb `d`.
+fn divrem_small(a: &[u32], d: u32) -> (Vec<u32>, u32) {
+    let mut q = vec![0u32; a.len()];
+    let mut r = 0u64;
+    for i in (0..a.len()).rev() {
+        let t = (r << 32) | a[i] as u64;
+        q[i] = (t / d as u64) as u32;
+        r = t % d as u64;
+    }
+    (trim(q), r as u32)
+}
+
+fn shl_mag(a: &[u32], n: usize) -> Vec<u32> {
+    if a.is_empty() {
+        return vec![];
+    }
+    let (limbs, bits) = (n / 32, n % 32);
+    let mut res = vec![0u32; limbs];
+    if bits == 0 {
+        res.extend_from_slice(a);
+    } else {
+        let mut carry = 0u32;
+        for &x in a {
+            res.push((x << bits) | carry);
+            carry = x >> (32 - bits);
+        }
+        res.push(carry);
+    }
+    trim(res)
+}
+
+fn shr_mag(a: &[u32], n: usize) -> Vec<u32> {
+    let (limbs, bits) = (n / 32, n % 32);
+    if limbs >= a.len() {
+        return vec![];
+    }
+    let a = &a[limbs..];
+    if bits == 0 {
+        return a.to_vec();
+    }
+    let mut res = Vec::with_capacity(a.len());
+    for i in 0..a.len() {
+        let hi = a.get(i + 1).map_or(0, |&x| x << (32 - bits));
+        res.push((a[i] >> bits) | hi);
+    }
+    trim(res)
+}
+
+/// Divides `a` by nonzero `b` using the algorithm D of Knuth.
+fn divrem_mag(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    assert!(!b.is_empty(), "division by zero");
+    if cmp_mag(a, b) == Ordering::Less {
+        return (vec![], a.to_vec());
+    }
+    if b.len() == 1 {
+        let (q, r) = divrem_small(a, b[0]);
+        return (q, trim(vec![r]));
+    }
+    // Normalize so that the most significant bit of the divisor is set.
+    let s = b[b.len() - 1].leading_zeros() as usize;
+    let bn = shl_mag(b, s);
+    let mut an = shl_mag(a, s);
+    an.resize(a.len() + 1, 0);
+    let n = bn.len();
+    let m = an.len() - n;
+    let mut q = vec![0u32; m];
+    let base = 1u64 << 32;
+    for j in (0..m).rev() {
+        let num = ((an[j + n] as u64) << 32) | an[j + n - 1] as u64;
+        let mut qhat = num / bn[n - 1] as u64;
+        let mut rhat = num % bn[n - 1] as u64;
+        while qhat >= base || qhat * bn[n - 2] as u64 > ((rhat << 32) | an[j + n - 2] as u64) {
+            qhat -= 1;
+            rhat += bn[n - 1] as u64;
+            if rhat >= base {
+                break;
+            }
+        }
+        let mut borrow = 0i64;
+        let mut carry = 0u64;
+        for i in 0..n {
+            let p = qhat * bn[i] as u64 + carry;
+            carry = p >> 32;
+            let t = an[i + j] as i64 - borrow - (p & 0xffff_ffff) as i64;
+            an[i + j] = t as u32;
+            borrow = if t < 0 { 1 } else { 0 };
+        }
+        let t = an[j + n] as i64 - borrow - carry as i64;
+        an[j + n] = t as u32;
+        if t < 0 {
+            // The estimate was one too large: add the divisor back.
+            qhat -= 1;
+            let mut c = 0u64;
+            for i in 0..n {
+                let s = an[i + j] as u64 + bn[i] as u64 + c;
+                an[i + j] = s as u32;
+                c = s >> 32;
+            }
+            an[j + n] = an[j + n].wrapping_add(c as u32);
+        }
+        q[j] = qhat as u32;
+    }
+    an.truncate(n);
+    (trim(q), shr_mag(&trim(an), s))
+}
+
+impl BigInt {
+    #[inline]
+    fn from_mag(neg: bool, mag: Vec<u32>) -> Self {
+        let neg = neg && !mag.is_empty();
+        BigInt { neg, mag }
+    }
+
+    /// Constructs zero.
+    #[inline]
+    pub fn zero() -> Self {
+        BigInt {
+            neg: false,
+            mag: vec![],
+        }
+    }
+
+    /// Constructs one.
+    #[inline]
+    pub fn one() -> Self {
+        BigInt {
+            neg: false,
+            mag: vec![1],
+        }
+    }
+
+    /// Whether `self` is zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.mag.is_empty()
+    }
+
+    /// Whether `self` is one.
+    #[inline]
+    pub fn is_one(&self) -> bool {
+        !self.neg && self.mag == [1]
+    }
+
+    /// Whether `self` is odd.
+    #[inline]
+    pub fn is_odd(&self) -> bool {
+        self.mag.first().is_some_and(|&x| x & 1 == 1)
+    }
+
+    /// Returns the sign of `self`.
+    #[inline]
+    pub fn sign(&self) -> Sign {
+        if self.mag.is_empty() {
+            Sign::Zero
+        } else if self.neg {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        }
+    }
+
+    /// Returns the absolute value of `self`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        BigInt {
+            neg: false,
+            mag: self.mag,
+        }
+    }
+
+    /// Returns the number of bits of the magnitude of `self`. This is zero for zero.
+    pub fn bits(&self) -> usize {
+        match self.mag.last() {
+            Some(&x) => self.mag.len() * 32 - x.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// Returns the number of trailing zero bits of `self`. This is zero for zero.
+    pub fn trailing_zeros(&self) -> usize {
+        match self.mag.iter().position(|&x| x != 0) {
+            Some(i) => i * 32 + self.mag[i].trailing_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// Computes `self * 2^n`.
+    #[inline]
+    pub fn shl(&self, n: usize) -> Self {
+        Self::from_mag(self.neg, shl_mag(&self.mag, n))
+    }
+
+    /// Computes `self / 2^n` rounded towards zero.
+    #[inline]
+    pub fn shr(&self, n: usize) -> Self {
+        Self::from_mag(self.neg, shr_mag(&self.mag, n))
+    }
+
+    /// Computes the quotient rounded towards zero and the remainder of `self / rhs`.
+    ///
+    /// The remainder has the sign of `self`. Panics if `rhs` is zero.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let (q, r) = divrem_mag(&self.mag, &rhs.mag);
+        (
+            Self::from_mag(self.neg != rhs.neg, q),
+            Self::from_mag(self.neg, r),
+        )
+    }
+
+    /// Computes the quotient rounded towards negative infinity and the remainder of
+    /// `self / rhs`.
+    ///
+    /// The remainder has the sign of `rhs`. Panics if `rhs` is zero.
+    pub fn div_rem_floor(&self, rhs: &Self) -> (Self, Self) {
+        let (q, r) = self.div_rem(rhs);
+        if !r.is_zero() && r.neg != rhs.neg {
+            (&q - &Self::one(), &r + rhs)
+        } else {
+            (q, r)
+        }
+    }
+
+    /// Computes the greatest common divisor of `self` and `rhs`, which is never negative.
+    pub fn gcd(&self, rhs: &Self) -> Self {
+        let mut a = self.mag.clone();
+        let mut b = rhs.mag.clone();
+        while !b.is_empty() {
+            let r = divrem_mag(&a, &b).1;
+            a = b;
+            b = r;
+        }
+        Self::from_mag(false, a)
+    }
+
+    /// Computes `self` raised to the power `exp`.
+    pub fn pow(&self, exp: u32) -> Self {
+        let mut res = Self::one();
+        let mut base = self.clone();
+        let mut e = exp;
+        while e > 0 {
+            if e & 1 == 1 {
+                res = &res * &base;
+            }
+            e >>= 1;
+            if e > 0 {
+                base = &base * &base;
+            }
+        }
+        res
+    }
+
+    /// Computes the square root of `self` rounded towards zero. `self` must not be negative.
+    pub fn isqrt(&self) -> Self {
+        assert!(!self.neg);
+        if self.is_zero() {
+            return Self::zero();
+        }
+        // Newton's iteration decreases monotonically from any starting point above the root.
+        let mut x = Self::one().shl(self.bits().div_ceil(2));
+        loop {
+            let y = (&x + &self.div_rem(&x).0).shr(1);
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    /// Computes the `n`-th root of `self` rounded towards zero. `self` must not be negative and `n`
+    /// must not be zero.
+    pub fn iroot(&self, n: u32) -> Self {
+        assert!(!self.neg && n > 0);
+        if self.is_zero() || n == 1 {
+            return self.clone();
+        }
+        // Newton's iteration decreases monotonically from any starting point above the root.
+        let m = Self::from(n as u64);
+        let m1 = Self::from(n as u64 - 1);
+        let mut x = Self::one().shl(self.bits().div_ceil(n as usize));
+        loop {
+            let y = (&(&m1 * &x) + &self.div_rem(&x.pow(n - 1)).0).div_rem(&m).0;
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    /// Returns the magnitude of `self` as an `u64` if it fits.
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.mag.len() {
+            0 => Some(0),
+            1 => Some(self.mag[0] as u64),
+            2 => Some(self.mag[0] as u64 | (self.mag[1] as u64) << 32),
+            _ => None,
+        }
+    }
+
+    /// Returns `self` as an `i128` if it fits.
+    pub fn to_i128(&self) -> Option<i128> {
+        if self.mag.len() > 4 {
+            return None;
+        }
+        let mag = self
+            .mag
+            .iter()
+            .rev()
+            .fold(0u128, |acc, &x| (acc << 32) | x as u128);
+        if self.neg {
+            0i128.checked_sub_unsigned(mag)
+        } else {
+            i128::try_from(mag).ok()
+        }
+    }
+}
+
+impl From<u64> for BigInt {
+    #[inline]
+    fn from(val: u64) -> Self {
+        Self::from_mag(false, trim(vec![val as u32, (val >> 32) as u32]))
+    }
+}
+
+impl From<i64> for BigInt {
+    #[inline]
+    fn from(val: i64) -> Self {
+        let mag = BigInt::from(val.unsigned_abs()).mag;
+        Self::from_mag(val < 0, mag)
+    }
+}
+
+impl From<u128> for BigInt {
+    #[inline]
+    fn from(val: u128) -> Self {
+        let mag = (0..4).map(|i| (val >> (32 * i)) as u32).collect();
+        Self::from_mag(false, trim(mag))
+    }
+}
+
+impl From<i128> for BigInt {
+    #[inline]
+    fn from(val: i128) -> Self {
+        let mag = BigInt::from(val.unsigned_abs()).mag;
+        Self::from_mag(val < 0, mag)
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut chunks = Vec::<u32>::new();
+        let mut mag = self.mag.clone();
+        while !mag.is_empty() {
+            let (q, r) = divrem_small(&mag, DECIMAL_BASE);
+            chunks.push(r);
+            mag = q;
+        }
+        let mut s = String::from(if self.neg { "-" } else { "" });
+        match chunks.pop() {
+            Some(c) => s.push_str(&c.to_string()),
+            None => s.push('0'),
+        }
+        for c in chunks.iter().rev() {
+            s.push_str(&format!("{:09}", c));
+        }
+        f.pad_integral(true, "", &s)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (neg, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+            return Err(ParseBigIntError);
+        }
+        let mut mag = Vec::<u32>::new();
+        let first = digits.len() % DECIMAL_DIGITS;
+        let mut start = 0;
+        let mut end = if first == 0 { DECIMAL_DIGITS } else { first };
+        while start < digits.len() {
+            let chunk = digits[start..end]
+                .parse::<u32>()
+                .map_err(|_| ParseBigIntError)?;
+            let m = 10u32.pow((end - start) as u32);
+            mag = mul_add_small(&mag, m, chunk);
+            start = end;
+            end += DECIMAL_DIGITS;
+        }
+        Ok(Self::from_mag(neg, mag))
+    }
+}
+
+impl PartialOrd for BigInt {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.neg, other.neg) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_mag(&self.mag, &other.mag),
+            (true, true) => cmp_mag(&other.mag, &self.mag),
+        }
+    }
+}
+
+impl Neg for BigInt {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::from_mag(!self.neg, self.mag)
+    }
+}
+
+impl<'a> Add<&'a BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: &'a BigInt) -> Self::Output {
+        if self.neg == rhs.neg {
+            return BigInt::from_mag(self.neg, add_mag(&self.mag, &rhs.mag));
+        }
+        match cmp_mag(&self.mag, &rhs.mag) {
+            Ordering::Less => BigInt::from_mag(rhs.neg, sub_mag(&rhs.mag, &self.mag)),
+            _ => BigInt::from_mag(self.neg, sub_mag(&self.mag, &rhs.mag)),
+        }
+    }
+}
+
+impl<'a> Sub<&'a BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn sub(self, rhs: &'a BigInt) -> Self::Output {
+        self + &-rhs.clone()
+    }
+}
+
+impl<'a> Mul<&'a BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn mul(self, rhs: &'a BigInt) -> Self::Output {
+        BigInt::from_mag(self.neg != rhs.neg, mul_mag(&self.mag, &rhs.mag))
+    }
+}
@@ -0,0 +1,28 @@
+use super::bigint::BigInt;
+
+/// Rational parsing error enum.
+#[derive(Debug, PartialEq)]
+pub enum ParseRationalError {
+    /// The string is neither a decimal number, a fraction nor a special value.
+    InvalidSyntax,
+    /// The denominator of a fraction is zero.
+    ZeroDenominator,
+    /// The decimal exponent is too large to be represented exactly.
+    ExponentOutOfRange,
+}
+
+/// Rational struct.
+///
+/// Represents an exact rational number `num / den`, positive infinity (`1 / 0`), negative infinity
+/// (`-1 / 0`) or NaN (`0 / 0`). Finite numbers are always reduced with a positive denominator, so
+/// that every value has a unique representation. Arithmetic is exact; `precision` is the number of
+/// bits that transcendental functions are computed with.
+#[derive(Clone, Debug)]
+pub struct Rational {
+    /// Numerator.
+    pub num: BigInt,
+    /// Denominator.
+    pub den: BigInt,
+    /// Precision of transcendental functions in bits.
+    pub precision: usize,
+}
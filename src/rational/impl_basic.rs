@@ -0,0 +1,302 @@
+use super::bigint::BigInt;
+use super::def::{ParseRationalError, Rational};
+
+use fp;
+use fp::{Float, Round, Sign};
+use mpfr::{Mpfr, MpfrRnd};
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Precision of rationals that are constructed without an explicit precision.
+const DEFAULT_PRECISION: usize = 53;
+
+/// Largest decimal exponent accepted by the parser.
+const MAX_DECIMAL_EXPONENT: u32 = 100_000;
+
+impl Rational {
+    /// Constructs the rational `num / den` with given precision.
+    ///
+    /// The fraction is reduced. A zero denominator gives positive infinity, negative infinity or
+    /// NaN depending on the sign of the numerator.
+    pub fn new(num: BigInt, den: BigInt, precision: usize) -> Self {
+        if den.is_zero() {
+            let num = match num.sign() {
+                Sign::Negative => -BigInt::one(),
+                Sign::Zero => BigInt::zero(),
+                Sign::Positive => BigInt::one(),
+            };
+            return Rational {
+                num,
+                den,
+                precision,
+            };
+        }
+        let g = num.gcd(&den);
+        let (mut num, mut den) = if g.is_one() {
+            (num, den)
+        } else {
+            (num.div_rem(&g).0, den.div_rem(&g).0)
+        };
+        if den.sign() == Sign::Negative {
+            num = -num;
+            den = -den;
+        }
+        Rational {
+            num,
+            den,
+            precision,
+        }
+    }
+
+    /// Constructs the integer `n` with given precision.
+    #[inline]
+    pub fn from_integer(n: BigInt, precision: usize) -> Self {
+        Rational {
+            num: n,
+            den: BigInt::one(),
+            precision,
+        }
+    }
+
+    /// Constructs `num * 2^exp` with given precision.
+    pub fn from_dyadic(num: BigInt, exp: isize, precision: usize) -> Self {
+        if exp >= 0 || num.is_zero() {
+            Self::from_integer(num.shl(exp.max(0) as usize), precision)
+        } else {
+            let shift = num.trailing_zeros().min(exp.unsigned_abs());
+            Rational {
+                num: num.shr(shift),
+                den: BigInt::one().shl(exp.unsigned_abs() - shift),
+                precision,
+            }
+        }
+    }
+
+    /// Rounds `self` to `self.precision` significant bits in the direction of `round`.
+    pub fn round_to_prec(&self, round: Round) -> Self {
+        if !self.is_finite() || self.is_zero() {
+            return self.clone();
+        }
+        let (m, e) = self.clone().frexp();
+        let (q, r) = m.num.shl(self.precision).div_rem_floor(&m.den);
+        let negative = self.sign() == Sign::Negative;
+        let up = !r.is_zero()
+            && match round {
+                Round::Down => false,
+                Round::Up => true,
+                Round::TowardsZero => negative,
+                Round::AwayFromZero => !negative,
+                Round::Nearest => match r.shl(1).cmp(&m.den) {
+                    Ordering::Less => false,
+                    Ordering::Equal => q.is_odd(),
+                    Ordering::Greater => true,
+                },
+            };
+        let q = if up { &q + &BigInt::one() } else { q };
+        Self::from_dyadic(q, e - self.precision as isize, self.precision)
+    }
+
+    /// Constructs a rational from an `f64` with given precision. The conversion is exact.
+    pub fn from_f64_with_prec(val: f64, precision: usize) -> Self {
+        if val.is_nan() {
+            return Self::nan(precision);
+        }
+        if val.is_infinite() {
+            return if val > 0.0 {
+                Self::infinity(precision)
+            } else {
+                Self::neg_infinity(precision)
+            };
+        }
+        let bits = val.to_bits();
+        let biased = ((bits >> 52) & 0x7ff) as isize;
+        let frac = bits & ((1 << 52) - 1);
+        let (mantissa, exp) = if biased == 0 {
+            (frac, -1074)
+        } else {
+            (frac | (1 << 52), biased - 1075)
+        };
+        let num = BigInt::from(mantissa);
+        let num = if val < 0.0 { -num } else { num };
+        Self::from_dyadic(num, exp, precision)
+    }
+
+    /// Constructs a rational with given precision from the exact value of `x`.
+    pub fn from_mpfr(x: &Mpfr, precision: usize) -> Self {
+        if !x.is_finite() {
+            return Self::from_f64_with_prec(x.as_f64(MpfrRnd::HalfToEven), precision);
+        }
+        // Peel off the leading 53 bits of the remainder until nothing is left.
+        let mut res = Self::zero(precision);
+        let mut rem = x.clone();
+        while !rem.is_zero() {
+            let (m, e) = rem.clone().frexp();
+            let chunk = m.as_f64(MpfrRnd::TowardsZero);
+            let part = Mpfr::from_custom(chunk, rem.precision(), MpfrRnd::HalfToEven).ldexp(e);
+            res = res + Self::from_f64_with_prec(chunk, precision).ldexp(e);
+            rem = fp::Sub::sub_lo(rem, part);
+        }
+        res
+    }
+
+    /// Converts the numerator and the denominator of a finite `self` exactly to MPFRs with at
+    /// least given precision. Returns the special value as an MPFR otherwise.
+    fn mpfr_fraction(&self, precision: usize) -> Result<(Mpfr, Mpfr), Mpfr> {
+        if !self.is_finite() {
+            let val = if self.is_nan() {
+                f64::NAN
+            } else if self.is_infinity() {
+                f64::INFINITY
+            } else {
+                f64::NEG_INFINITY
+            };
+            return Err(Mpfr::from_custom(val, precision, MpfrRnd::HalfToEven));
+        }
+        let wide = precision.max(self.num.bits()).max(self.den.bits()).max(2);
+        let exact =
+            |n: &BigInt| Mpfr::from_str_custom(&n.to_string(), wide, MpfrRnd::HalfToEven).unwrap();
+        Ok((exact(&self.num), exact(&self.den)))
+    }
+
+    /// Rounds `self` down or up to an MPFR with given precision, and tells whether the result is
+    /// exact.
+    pub fn to_mpfr(&self, precision: usize, up: bool) -> (Mpfr, bool) {
+        match self.mpfr_fraction(precision) {
+            Ok((num, den)) => {
+                let (q, q_exact) = if up {
+                    fp::Div::div_hi_exact(num, den)
+                } else {
+                    fp::Div::div_lo_exact(num, den)
+                };
+                let rnd = if up { MpfrRnd::Up } else { MpfrRnd::Down };
+                let res = unsafe { Mpfr::uninitialized(precision) }.set(&q, rnd);
+                let exact = q_exact && res == q;
+                (res, exact)
+            }
+            Err(special) => (special, true),
+        }
+    }
+
+    /// Rounds `self` to the nearest MPFR with given precision, approximately.
+    pub fn to_mpfr_nearest(&self, precision: usize) -> Mpfr {
+        match self.mpfr_fraction(precision) {
+            Ok((num, den)) => {
+                let q = num / den;
+                unsafe { Mpfr::uninitialized(precision) }.set(&q, MpfrRnd::HalfToEven)
+            }
+            Err(special) => special,
+        }
+    }
+
+    /// Constructs a rational by parsing a string with given precision.
+    ///
+    /// Accepts `RATIONAL` according to the rule below.
+    ///
+    ///   RATIONAL = DECIMAL | DECIMAL '/' DECIMAL | ['+' | '-'] ('inf' | 'infinity') | 'NaN'
+    ///
+    /// where `DECIMAL` is a decimal number with an optional fractional part and an optional
+    /// exponent, such as `-1.25e-3`. The result is exact.
+    pub fn from_str_with_prec(s: &str, precision: usize) -> Result<Self, ParseRationalError> {
+        let s = s.trim();
+        match s.to_ascii_lowercase().as_str() {
+            "nan" | "+nan" | "-nan" => return Ok(Self::nan(precision)),
+            "inf" | "+inf" | "infinity" | "+infinity" => return Ok(Self::infinity(precision)),
+            "-inf" | "-infinity" => return Ok(Self::neg_infinity(precision)),
+            _ => {}
+        }
+        match s.find('/') {
+            Some(i) => {
+                let (n1, d1) = parse_decimal(s[..i].trim())?;
+                let (n2, d2) = parse_decimal(s[i + 1..].trim())?;
+                if n2.is_zero() {
+                    return Err(ParseRationalError::ZeroDenominator);
+                }
+                Ok(Self::new(&n1 * &d2, &d1 * &n2, precision))
+            }
+            None => {
+                let (num, den) = parse_decimal(s)?;
+                Ok(Self::new(num, den, precision))
+            }
+        }
+    }
+}
+
+/// Parses a decimal number such as `-1.25e-3` into an unreduced fraction.
+fn parse_decimal(s: &str) -> Result<(BigInt, BigInt), ParseRationalError> {
+    let (mantissa, exp) = match s.find(['e', 'E']) {
+        Some(i) => {
+            let exp = s[i + 1..]
+                .parse::<i64>()
+                .map_err(|_| ParseRationalError::InvalidSyntax)?;
+            (&s[..i], exp)
+        }
+        None => (s, 0),
+    };
+    let (neg, mantissa) = match mantissa.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, mantissa.strip_prefix('+').unwrap_or(mantissa)),
+    };
+    let (int, frac) = match mantissa.find('.') {
+        Some(i) => (&mantissa[..i], &mantissa[i + 1..]),
+        None => (mantissa, ""),
+    };
+    if int.is_empty() && frac.is_empty()
+        || !int.bytes().chain(frac.bytes()).all(|c| c.is_ascii_digit())
+    {
+        return Err(ParseRationalError::InvalidSyntax);
+    }
+    let exp = exp - frac.len() as i64;
+    if exp.unsigned_abs() > MAX_DECIMAL_EXPONENT as u64 {
+        return Err(ParseRationalError::ExponentOutOfRange);
+    }
+    let digits = BigInt::from_str(&format!("{}{}", int, frac)).unwrap();
+    let num = if neg { -digits } else { digits };
+    let scale = BigInt::from(10u64).pow(exp.unsigned_abs() as u32);
+    if exp >= 0 {
+        Ok((&num * &scale, BigInt::one()))
+    } else {
+        Ok((num, scale))
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.is_nan() {
+            f.pad("NaN")
+        } else if self.is_infinity() {
+            f.pad("inf")
+        } else if self.is_neg_infinity() {
+            f.pad("-inf")
+        } else if self.den.is_one() {
+            self.num.fmt(f)
+        } else {
+            f.pad(&format!("{}/{}", self.num, self.den))
+        }
+    }
+}
+
+impl FromStr for Rational {
+    type Err = ParseRationalError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_with_prec(s, DEFAULT_PRECISION)
+    }
+}
+
+impl From<f64> for Rational {
+    #[inline]
+    fn from(val: f64) -> Self {
+        Self::from_f64_with_prec(val, DEFAULT_PRECISION)
+    }
+}
+
+impl From<Rational> for f64 {
+    #[inline]
+    fn from(val: Rational) -> Self {
+        val.to_mpfr_nearest(53).as_f64(MpfrRnd::HalfToEven)
+    }
+}
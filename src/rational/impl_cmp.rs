@@ -0,0 +1,46 @@
+use super::def::Rational;
+
+use fp;
+use fp::Float;
+
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+impl PartialEq<Rational> for Rational {
+    #[inline]
+    fn eq(&self, other: &Rational) -> bool {
+        // Finite values and infinities have unique representations.
+        !self.is_nan() && self.num == other.num && self.den == other.den
+    }
+}
+
+impl PartialOrd<Rational> for Rational {
+    fn partial_cmp(&self, other: &Rational) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            None // self or other is NaN and they cannot be compared
+        } else if self.is_finite() && other.is_finite() {
+            Some((&self.num * &other.den).cmp(&(&other.num * &self.den)))
+        } else {
+            // Infinities compare by the sign of their numerators.
+            let rank = |x: &Rational| if x.is_finite() { 0 } else { x.num.sign() as i8 };
+            Some(rank(self).cmp(&rank(other)))
+        }
+    }
+}
+
+impl fp::TotalOrd for Rational {
+    fn cmp_total(&self, rhs: &Rational) -> Ordering {
+        match (self.is_nan(), rhs.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => self.partial_cmp(rhs).unwrap(),
+        }
+    }
+
+    /// Hashes the numerator and the denominator, which are unique for every value.
+    fn hash_total<H: Hasher>(&self, state: &mut H) {
+        self.num.hash(state);
+        self.den.hash(state);
+    }
+}
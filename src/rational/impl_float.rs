@@ -0,0 +1,448 @@
+use super::bigint::BigInt;
+use super::def::{ParseRationalError, Rational};
+
+use fp;
+use fp::{Float, Round, Sign};
+use mpfr::MpfrRnd;
+
+use std::cmp::Ordering;
+use std::ops::Neg;
+
+/// Exponent of the step that `next_up` and `next_down` take from zero.
+///
+/// There is no smallest positive rational, so the smallest positive subnormal `f64` is used.
+const ZERO_ULP_EXP: isize = -1074;
+
+impl Rational {
+    /// Tells whether `self` is the square of a rational, and returns its square root if so.
+    fn exact_sqrt(&self) -> Option<Self> {
+        let num = self.num.isqrt();
+        let den = self.den.isqrt();
+        if &num * &num == self.num && &den * &den == self.den {
+            Some(Rational {
+                num,
+                den,
+                precision: self.precision,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Computes `self - n * rhs`, where `n` is `self / rhs` rounded to an integer by `round`.
+    fn rem_with<F>(self, rhs: Self, round: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        let precision = self.precision.max(rhs.precision);
+        if self.is_finite() && rhs.is_infinite() {
+            return Rational { precision, ..self };
+        }
+        if !self.is_finite() || !rhs.is_finite() || rhs.is_zero() {
+            return Self::nan(precision);
+        }
+        let n = round(self.clone() / rhs.clone());
+        self - n * rhs
+    }
+
+    /// Computes `sqrt(self^2 + rhs^2)` and rounds the result down, up or to the nearest.
+    fn hypot_with(self, rhs: Self, round: Round) -> Self {
+        let precision = self.precision.max(rhs.precision);
+        if self.is_infinite() || rhs.is_infinite() {
+            return Self::infinity(precision);
+        }
+        if self.is_nan() || rhs.is_nan() {
+            return Self::nan(precision);
+        }
+        let x = fp::Abs::abs(self);
+        let y = fp::Abs::abs(rhs);
+        let s = x.clone() * x + y.clone() * y;
+        if let Some(res) = s.exact_sqrt() {
+            return Rational { precision, ..res };
+        }
+        // Scale the sum of squares by 4^k so that its root has at least `precision + 1` bits. The
+        // root is not rational, so it lies strictly between the integer root and the next integer.
+        let k = precision as isize + 2 - s.clone().frexp().1.div_euclid(2);
+        let s = s.ldexp(2 * k);
+        let q = s.num.div_rem_floor(&s.den).0.isqrt();
+        let res = match round {
+            Round::Down => Self::from_dyadic(q, -k, precision),
+            Round::Up => Self::from_dyadic(&q + &BigInt::one(), -k, precision),
+            _ => Self::from_dyadic(&q.shl(1) + &BigInt::one(), -k - 1, precision),
+        };
+        res.round_to_prec(round)
+    }
+
+    /// Returns `2^(self.exponent() - self.precision)`, the spacing of floats with `precision`
+    /// bits around `self`, or a tiny step for zero.
+    fn step(&self) -> Self {
+        let exp = if self.is_zero() {
+            ZERO_ULP_EXP
+        } else {
+            self.exponent() - self.precision as isize
+        };
+        Self::one(self.precision).ldexp(exp)
+    }
+}
+
+impl fp::From<f64> for Rational {
+    #[inline]
+    fn from_lo(val: f64, precision: usize) -> Self {
+        Self::from_f64_with_prec(val, precision)
+    }
+
+    #[inline]
+    fn from_hi(val: f64, precision: usize) -> Self {
+        Self::from_f64_with_prec(val, precision)
+    }
+}
+
+impl fp::FromStr for Rational {
+    #[inline]
+    fn from_str_lo(s: &str, precision: usize) -> Result<Self, ParseRationalError> {
+        Self::from_str_with_prec(s, precision)
+    }
+
+    #[inline]
+    fn from_str_hi(s: &str, precision: usize) -> Result<Self, ParseRationalError> {
+        Self::from_str_with_prec(s, precision)
+    }
+}
+
+impl fp::Into<f64> for Rational {
+    #[inline]
+    fn into_lo(self) -> f64 {
+        self.to_mpfr(53, false).0.as_f64(MpfrRnd::Down)
+    }
+
+    #[inline]
+    fn into_hi(self) -> f64 {
+        self.to_mpfr(53, true).0.as_f64(MpfrRnd::Up)
+    }
+}
+
+impl fp::MinMax for Rational {
+    #[inline]
+    fn min(self, rhs: Self) -> Self {
+        if rhs.is_nan() || self <= rhs {
+            self
+        } else {
+            rhs
+        }
+    }
+
+    #[inline]
+    fn max(self, rhs: Self) -> Self {
+        if rhs.is_nan() || self >= rhs {
+            self
+        } else {
+            rhs
+        }
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Rational {
+            num: -self.num,
+            ..self
+        }
+    }
+}
+
+impl fp::Abs for Rational {
+    #[inline]
+    fn abs(self) -> Self {
+        Rational {
+            num: self.num.abs(),
+            ..self
+        }
+    }
+}
+
+impl fp::Integral for Rational {
+    fn floor(self) -> Self {
+        if !self.is_finite() || self.den.is_one() {
+            return self;
+        }
+        let q = self.num.div_rem_floor(&self.den).0;
+        Self::from_integer(q, self.precision)
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        -(-self).floor()
+    }
+
+    fn round_ties_even(self) -> Self {
+        if !self.is_finite() || self.den.is_one() {
+            return self;
+        }
+        let (q, r) = self.num.div_rem_floor(&self.den);
+        let up = match r.shl(1).cmp(&self.den) {
+            Ordering::Less => false,
+            Ordering::Equal => q.is_odd(),
+            Ordering::Greater => true,
+        };
+        let q = if up { &q + &BigInt::one() } else { q };
+        Self::from_integer(q, self.precision)
+    }
+
+    fn round_ties_away(self) -> Self {
+        if !self.is_finite() || self.den.is_one() {
+            return self;
+        }
+        let (q, r) = self.num.div_rem_floor(&self.den);
+        let up = match r.shl(1).cmp(&self.den) {
+            Ordering::Less => false,
+            Ordering::Equal => self.num.sign() == Sign::Positive,
+            Ordering::Greater => true,
+        };
+        let q = if up { &q + &BigInt::one() } else { q };
+        Self::from_integer(q, self.precision)
+    }
+
+    fn trunc(self) -> Self {
+        if !self.is_finite() || self.den.is_one() {
+            return self;
+        }
+        let q = self.num.div_rem(&self.den).0;
+        Self::from_integer(q, self.precision)
+    }
+
+    fn frac(self) -> Self {
+        if !self.is_finite() {
+            return Self::nan(self.precision);
+        }
+        let r = self.num.div_rem(&self.den).1;
+        Self::new(r, self.den, self.precision)
+    }
+}
+
+impl fp::Rem for Rational {
+    #[inline]
+    fn fmod_lo(self, rhs: Self) -> Self {
+        self.rem_with(rhs, fp::Integral::trunc)
+    }
+
+    #[inline]
+    fn fmod_hi(self, rhs: Self) -> Self {
+        self.rem_with(rhs, fp::Integral::trunc)
+    }
+
+    #[inline]
+    fn remainder_lo(self, rhs: Self) -> Self {
+        self.rem_with(rhs, fp::Integral::round_ties_even)
+    }
+
+    #[inline]
+    fn remainder_hi(self, rhs: Self) -> Self {
+        self.rem_with(rhs, fp::Integral::round_ties_even)
+    }
+}
+
+impl fp::Hypot for Rational {
+    #[inline]
+    fn hypot_r(self, rhs: Self, round: Round) -> Self {
+        round
+            .resolve(|r| (self.clone().hypot_with(rhs.clone(), r), false))
+            .0
+    }
+}
+
+impl fp::Add for Rational {
+    #[inline]
+    fn add_r_exact(self, rhs: Self, _: Round) -> (Self, bool) {
+        (self + rhs, true)
+    }
+}
+
+impl fp::Sub for Rational {
+    #[inline]
+    fn sub_r_exact(self, rhs: Self, _: Round) -> (Self, bool) {
+        (self - rhs, true)
+    }
+}
+
+impl fp::Mul for Rational {
+    #[inline]
+    fn mul_r_exact(self, rhs: Self, _: Round) -> (Self, bool) {
+        (self * rhs, true)
+    }
+}
+
+impl fp::Div for Rational {
+    #[inline]
+    fn div_r_exact(self, rhs: Self, _: Round) -> (Self, bool) {
+        (self / rhs, true)
+    }
+}
+
+impl fp::Fma for Rational {
+    #[inline]
+    fn fma_r_exact(self, a: Self, b: Self, _: Round) -> (Self, bool) {
+        (self * a + b, true)
+    }
+}
+
+impl fp::Sum for Rational {
+    fn sum_r(xs: &[Self], precision: usize, _: Round) -> Self {
+        xs.iter().fold(Self::zero(precision), |s, x| s + x.clone())
+    }
+
+    fn dot_r(xs: &[Self], ys: &[Self], precision: usize, _: Round) -> Self {
+        assert_eq!(xs.len(), ys.len());
+        xs.iter()
+            .zip(ys)
+            .fold(Self::zero(precision), |s, (x, y)| s + x.clone() * y.clone())
+    }
+}
+
+impl Float for Rational {
+    #[inline]
+    fn zero(precision: usize) -> Self {
+        Self::from_integer(BigInt::zero(), precision)
+    }
+
+    #[inline]
+    fn neg_zero(precision: usize) -> Self {
+        Self::zero(precision) // rationals have a single zero
+    }
+
+    #[inline]
+    fn one(precision: usize) -> Self {
+        Self::from_integer(BigInt::one(), precision)
+    }
+
+    #[inline]
+    fn infinity(precision: usize) -> Self {
+        Rational {
+            num: BigInt::one(),
+            den: BigInt::zero(),
+            precision,
+        }
+    }
+
+    #[inline]
+    fn neg_infinity(precision: usize) -> Self {
+        Rational {
+            num: -BigInt::one(),
+            den: BigInt::zero(),
+            precision,
+        }
+    }
+
+    #[inline]
+    fn nan(precision: usize) -> Self {
+        Rational {
+            num: BigInt::zero(),
+            den: BigInt::zero(),
+            precision,
+        }
+    }
+
+    #[inline]
+    fn sign(&self) -> Sign {
+        self.num.sign()
+    }
+
+    #[inline]
+    fn precision(&self) -> usize {
+        self.precision
+    }
+
+    #[inline]
+    fn is_finite(&self) -> bool {
+        !self.den.is_zero()
+    }
+
+    #[inline]
+    fn is_infinite(&self) -> bool {
+        self.den.is_zero() && !self.num.is_zero()
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.num.is_zero() && !self.den.is_zero()
+    }
+
+    #[inline]
+    fn is_infinity(&self) -> bool {
+        self.den.is_zero() && self.num.sign() == Sign::Positive
+    }
+
+    #[inline]
+    fn is_neg_infinity(&self) -> bool {
+        self.den.is_zero() && self.num.sign() == Sign::Negative
+    }
+
+    #[inline]
+    fn is_nan(&self) -> bool {
+        self.den.is_zero() && self.num.is_zero()
+    }
+
+    #[inline]
+    fn has_odd_denominator(&self) -> bool {
+        self.is_finite() && self.den.is_odd()
+    }
+
+    /// Returns the next float with `precision` bits above `self`.
+    ///
+    /// There is no finite rational below all others, so negative infinity is returned as is.
+    fn next_up(self) -> Self {
+        if !self.is_finite() {
+            return self;
+        }
+        let step = if self.sign() == Sign::Negative && self.mantissa() == -Self::from(0.5) {
+            self.step().ldexp(-1)
+        } else {
+            self.step()
+        };
+        self + step
+    }
+
+    /// Returns the next float with `precision` bits below `self`.
+    ///
+    /// There is no finite rational above all others, so positive infinity is returned as is.
+    fn next_down(self) -> Self {
+        -(-self).next_up()
+    }
+
+    fn frexp(self) -> (Self, isize) {
+        if !self.is_finite() || self.is_zero() {
+            return (self, 0);
+        }
+        let e = self.num.bits() as isize - self.den.bits() as isize;
+        // Now 2^(e - 1) < |self| < 2^(e + 1); find on which side of 2^e it is.
+        let num = self.num.clone().abs();
+        let above = if e >= 0 {
+            num >= self.den.shl(e as usize)
+        } else {
+            num.shl(e.unsigned_abs()) >= self.den
+        };
+        let e = if above { e + 1 } else { e };
+        (self.ldexp(-e), e)
+    }
+
+    fn ldexp(self, exp: isize) -> Self {
+        if !self.is_finite() || self.is_zero() {
+            return self;
+        }
+        let shift = exp.unsigned_abs();
+        let (num, den) = if exp >= 0 {
+            let s = self.den.trailing_zeros().min(shift);
+            (self.num.shl(shift - s), self.den.shr(s))
+        } else {
+            let s = self.num.trailing_zeros().min(shift);
+            (self.num.shr(s), self.den.shl(shift - s))
+        };
+        Rational {
+            num,
+            den,
+            precision: self.precision,
+        }
+    }
+}
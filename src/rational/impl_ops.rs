@@ -0,0 +1,75 @@
+use super::def::Rational;
+
+use fp::Float;
+
+use std::ops::{Add, Div, Mul, Sub};
+
+impl Rational {
+    /// Returns an `f64` that behaves like `self` under IEEE 754 arithmetic with zeros, infinities
+    /// and NaN: the special value itself, or the sign of a nonzero finite value.
+    #[inline]
+    fn class(&self) -> f64 {
+        if self.is_nan() {
+            f64::NAN
+        } else {
+            self.sign() as i8 as f64 * if self.is_finite() { 1.0 } else { f64::INFINITY }
+        }
+    }
+
+    /// Computes `op` on the classes of `self` and `rhs`, for operations whose result is zero, an
+    /// infinity or NaN.
+    #[inline]
+    fn special<F>(&self, rhs: &Self, op: F) -> Self
+    where
+        F: FnOnce(f64, f64) -> f64,
+    {
+        let precision = self.precision.max(rhs.precision);
+        Self::from_f64_with_prec(op(self.class(), rhs.class()), precision)
+    }
+}
+
+impl Add<Rational> for Rational {
+    type Output = Rational;
+
+    fn add(self, other: Rational) -> Self::Output {
+        if !self.is_finite() || !other.is_finite() {
+            return self.special(&other, |x, y| x + y);
+        }
+        let precision = self.precision.max(other.precision);
+        let num = &(&self.num * &other.den) + &(&other.num * &self.den);
+        Rational::new(num, &self.den * &other.den, precision)
+    }
+}
+
+impl Sub<Rational> for Rational {
+    type Output = Rational;
+
+    #[inline]
+    fn sub(self, other: Rational) -> Self::Output {
+        self + -other
+    }
+}
+
+impl Mul<Rational> for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Rational) -> Self::Output {
+        if !self.is_finite() || !other.is_finite() {
+            return self.special(&other, |x, y| x * y);
+        }
+        let precision = self.precision.max(other.precision);
+        Rational::new(&self.num * &other.num, &self.den * &other.den, precision)
+    }
+}
+
+impl Div<Rational> for Rational {
+    type Output = Rational;
+
+    fn div(self, other: Rational) -> Self::Output {
+        if !self.is_finite() || !other.is_finite() || other.is_zero() {
+            return self.special(&other, |x, y| x / y);
+        }
+        let precision = self.precision.max(other.precision);
+        Rational::new(&self.num * &other.den, &self.den * &other.num, precision)
+    }
+}
@@ -0,0 +1,350 @@
+use super::bigint::BigInt;
+use super::def::Rational;
+
+use fp;
+use fp::{Float, Round, Sign};
+use transc::Transc;
+
+use std::cmp::Ordering;
+
+/// Largest number of bits that an exact integer power may have.
+const MAX_EXACT_POW_BITS: usize = 1 << 16;
+
+/// Number of bits beyond the precision that transcendental functions are computed with.
+const GUARD_BITS: usize = 32;
+
+/// Largest magnitude of an argument whose exponential is computed.
+///
+/// The exponentials of larger arguments are bounded by the exponential of this one and by
+/// infinity or zero.
+const EXP_ARG_MAX: u64 = 1 << 16;
+
+/// Computes `floor(x * 2^w)` for a finite `x`.
+fn to_fixed(x: &Rational, w: usize) -> BigInt {
+    x.num.shl(w).div_rem_floor(&x.den).0
+}
+
+/// Computes `ln(2) * 2^w` rounded down with an error below 2, for `w` of at least 32.
+fn ln2_fixed(w: usize) -> BigInt {
+    // ln(2) is the sum of 2^-n / n over n > 0. Every term is off by less than 2 ulps and the
+    // tail beyond `wp` by less than 1 ulp, which the guard bits absorb.
+    let guard = (usize::BITS - w.leading_zeros()) as usize + 2;
+    let wp = w + guard;
+    let one = BigInt::one().shl(wp);
+    let mut sum = BigInt::zero();
+    for n in 1..=wp {
+        sum = &sum + &one.shr(n).div_rem(&BigInt::from(n as u64)).0;
+    }
+    sum.shr(guard)
+}
+
+/// Encloses `exp(x)` for a finite `x` with `|x| <= EXP_ARG_MAX`, computing with `w` bits after
+/// the binary point.
+fn exp_bounds(x: &Rational, w: usize) -> (Rational, Rational) {
+    // Reduce to exp(x) = 2^k * exp(r), where r = x - k * ln(2) is in [0, ln(2)) up to tiny
+    // errors. The fixed-point r is off by less than 3 ulps.
+    let k = to_fixed(x, 64).div_rem_floor(&ln2_fixed(64)).0;
+    let k_bits = k.bits() + 2;
+    let r = &to_fixed(x, w) - &(&ln2_fixed(w + k_bits) * &k).shr(k_bits);
+    // Sum the Taylor series. Every term is off by less than 4 ulps, and so is the tail once a
+    // term vanishes. exp(r) is below 3, so the error of r costs less than 10 ulps.
+    let one = BigInt::one().shl(w);
+    let mut sum = one.clone();
+    let mut term = one;
+    let mut n = 0u64;
+    while !term.is_zero() {
+        n += 1;
+        term = (&term * &r).shr(w).div_rem(&BigInt::from(n)).0;
+        sum = &sum + &term;
+    }
+    let err = BigInt::from(8 * n + 32);
+    let exp = k.to_i128().unwrap() as isize - w as isize;
+    (
+        Rational::from_dyadic(&sum - &err, exp, x.precision),
+        Rational::from_dyadic(&sum + &err, exp, x.precision),
+    )
+}
+
+/// Encloses `log(x)` for a finite positive `x`, computing with `w` bits after the binary point.
+fn log_bounds(x: &Rational, w: usize) -> (Rational, Rational) {
+    // Reduce to log(x) = log(m) + e * ln(2), where m is in [1/sqrt(2), sqrt(2)).
+    let (mut m, mut e) = x.clone().frexp();
+    if (&m.num * &m.num).shl(1) < &m.den * &m.den {
+        m = m.ldexp(1);
+        e -= 1;
+    }
+    // log(m) = 2 * atanh(z) with |z| < 0.18. The fixed-point z costs less than 3 ulps, every
+    // term of the power series is off by less than 3 ulps, and so is the tail once a power
+    // vanishes.
+    let one = Rational::one(x.precision);
+    let z = to_fixed(&((m.clone() - one.clone()) / (m + one)), w);
+    let z2 = (&z * &z).shr(w);
+    let mut sum = z.clone();
+    let mut power = z;
+    let mut n = 0u64;
+    while !power.is_zero() {
+        n += 1;
+        power = (&power * &z2).shr(w);
+        sum = &sum + &power.div_rem(&BigInt::from(2 * n + 1)).0;
+    }
+    let e = BigInt::from(e as i64);
+    let e_bits = e.bits() + 2;
+    let value = &sum.shl(1) + &(&ln2_fixed(w + e_bits) * &e).shr(e_bits);
+    let err = BigInt::from(8 * n + 32);
+    let exp = -(w as isize);
+    (
+        Rational::from_dyadic(&value - &err, exp, x.precision),
+        Rational::from_dyadic(&value + &err, exp, x.precision),
+    )
+}
+
+/// Rounds the enclosure `[lo, hi]` of a result down, up or to the nearest.
+fn round_bounds(lo: Rational, hi: Rational, round: Round) -> Rational {
+    match round {
+        Round::Down => lo.round_to_prec(Round::Down),
+        Round::Up => hi.round_to_prec(Round::Up),
+        _ => (lo + hi).ldexp(-1).round_to_prec(Round::Nearest),
+    }
+}
+
+impl Rational {
+    /// Computes `self^n` exactly if `n` is an integer and the result is not too large.
+    fn pow_integer(&self, n: &BigInt) -> Option<Self> {
+        let size = self.num.bits() + self.den.bits();
+        let e = n.to_u64()?;
+        if e.saturating_mul(size as u64) > MAX_EXACT_POW_BITS as u64 {
+            return None;
+        }
+        let num = self.num.pow(e as u32);
+        let den = self.den.pow(e as u32);
+        Some(if n.sign() == Sign::Negative {
+            Rational::new(den, num, self.precision)
+        } else {
+            Rational::new(num, den, self.precision)
+        })
+    }
+
+    /// Tells whether a positive `self` is the `n`-th power of a rational, and returns its `n`-th
+    /// root if so.
+    fn exact_root(&self, n: u32) -> Option<Self> {
+        let num = self.num.iroot(n);
+        let den = self.den.iroot(n);
+        if num.pow(n) == self.num && den.pow(n) == self.den {
+            Some(Rational {
+                num,
+                den,
+                precision: self.precision,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Computes `self^rhs` exactly when the result is zero, one, an infinity, NaN or an exact
+    /// integer power, following the special cases of C99 `pow`.
+    fn pow_exact(&self, rhs: &Self) -> Option<Self> {
+        let precision = self.precision.max(rhs.precision);
+        let one = Self::one(precision);
+        if rhs.is_zero() || *self == one {
+            return Some(one);
+        }
+        if self.is_nan() || rhs.is_nan() {
+            return Some(Self::nan(precision));
+        }
+        let is_integer = rhs.den.is_one();
+        let is_odd = is_integer && rhs.num.is_odd();
+        let with_sign = |x: Self, neg: bool| if neg { -x } else { x };
+        let rhs_positive = rhs.sign() == Sign::Positive;
+        if rhs.is_infinite() {
+            let grows = match fp::Abs::abs(self.clone()).partial_cmp(&one) {
+                Some(Ordering::Less) => !rhs_positive,
+                Some(Ordering::Equal) => return Some(one),
+                _ => rhs_positive,
+            };
+            return Some(if grows {
+                Self::infinity(precision)
+            } else {
+                Self::zero(precision)
+            });
+        }
+        if self.is_infinite() || self.is_zero() {
+            let neg = self.sign() == Sign::Negative && is_odd;
+            let grows = rhs_positive == self.is_infinite();
+            let res = if grows {
+                Self::infinity(precision)
+            } else {
+                Self::zero(precision)
+            };
+            return Some(with_sign(res, neg));
+        }
+        if self.sign() == Sign::Negative && !is_integer {
+            return Some(Self::nan(precision));
+        }
+        if is_integer {
+            return self
+                .pow_integer(&rhs.num)
+                .map(|res| Rational { precision, ..res });
+        }
+        // Otherwise the power is rational only if the root of `self` is, and an integer above one
+        // has no roots of a degree higher than its number of bits.
+        let n = rhs.den.to_u64()?;
+        if n > self.num.bits().max(self.den.bits()) as u64 {
+            return None;
+        }
+        let root = self.exact_root(n as u32)?;
+        root.pow_integer(&rhs.num)
+            .map(|res| Rational { precision, ..res })
+    }
+
+    /// Computes `self^rhs` for a positive finite `self` and a finite `rhs` whose power is not
+    /// rational, and rounds the result down, up or to the nearest.
+    ///
+    /// The logarithm is enclosed with enough bits for the exponential of its product with `rhs`.
+    fn pow_positive(self, rhs: Self, round: Round) -> Self {
+        let precision = self.precision.max(rhs.precision);
+        let e = rhs.clone().frexp().1.max(0) as usize;
+        let (lo, hi) = log_bounds(&self, precision + GUARD_BITS + e + 2);
+        let (lo, hi) = if rhs.sign() == Sign::Positive {
+            (rhs.clone() * lo, rhs * hi)
+        } else {
+            (rhs.clone() * hi, rhs * lo)
+        };
+        let (lo, hi) = (Rational { precision, ..lo }, Rational { precision, ..hi });
+        match round {
+            Round::Down => lo.exp_with(Round::Down).0,
+            Round::Up => hi.exp_with(Round::Up).0,
+            _ => (lo + hi).ldexp(-1).exp_with(Round::Nearest).0,
+        }
+    }
+
+    /// Computes `self^rhs`, rounds the result down, up or to the nearest, and tells whether the
+    /// result is exact.
+    fn pow_with(self, rhs: Self, round: Round) -> (Self, bool) {
+        if let Some(res) = self.pow_exact(&rhs) {
+            return (res, true);
+        }
+        if self.sign() == Sign::Negative {
+            // The exponent is an integer here, so the sign of the result is known.
+            if rhs.num.is_odd() {
+                let round = match round {
+                    Round::Down => Round::Up,
+                    Round::Up => Round::Down,
+                    _ => round,
+                };
+                (-(-self).pow_positive(rhs, round), false)
+            } else {
+                ((-self).pow_positive(rhs, round), false)
+            }
+        } else {
+            (self.pow_positive(rhs, round), false)
+        }
+    }
+
+    /// Computes `log(self)`, rounds the result down, up or to the nearest, and tells whether the
+    /// result is exact.
+    ///
+    /// The logarithm is enclosed with more and more bits until the enclosure is tight relative to
+    /// the result, which takes more bits the closer `self` is to one.
+    fn log_with(self, round: Round) -> (Self, bool) {
+        let precision = self.precision;
+        if self.is_nan() || self.sign() == Sign::Negative {
+            return (Self::nan(precision), true);
+        }
+        if self.is_zero() {
+            return (-Self::infinity(precision), true);
+        }
+        if self.is_infinite() || self.num == self.den {
+            let res = if self.is_infinite() {
+                self
+            } else {
+                Self::zero(precision)
+            };
+            return (res, true);
+        }
+        let max_work = 2 * (precision + GUARD_BITS + self.num.bits() + self.den.bits());
+        let mut w = precision + GUARD_BITS;
+        loop {
+            let (lo, hi) = log_bounds(&self, w);
+            let size = fp::MinMax::max(fp::Abs::abs(lo.clone()), fp::Abs::abs(hi.clone()));
+            if w >= max_work || (hi.clone() - lo.clone()).ldexp(precision as isize + 2) <= size {
+                return (round_bounds(lo, hi, round), false);
+            }
+            w *= 2;
+        }
+    }
+
+    /// Computes `exp(self)`, rounds the result down, up or to the nearest, and tells whether the
+    /// result is exact.
+    fn exp_with(self, round: Round) -> (Self, bool) {
+        let precision = self.precision;
+        if self.is_nan() {
+            return (self, true);
+        }
+        if self.is_zero() {
+            return (Self::one(precision), true);
+        }
+        if self.is_infinite() {
+            let res = if self.sign() == Sign::Positive {
+                self
+            } else {
+                Self::zero(precision)
+            };
+            return (res, true);
+        }
+        let max = Self::from_integer(BigInt::from(EXP_ARG_MAX), precision);
+        if self > max {
+            let res = if round == Round::Down {
+                max.exp_with(Round::Down).0
+            } else {
+                Self::infinity(precision)
+            };
+            return (res, false);
+        }
+        if self < -max.clone() {
+            let res = if round == Round::Up {
+                (-max).exp_with(Round::Up).0
+            } else {
+                Self::zero(precision)
+            };
+            return (res, false);
+        }
+        let (lo, hi) = exp_bounds(&self, precision + GUARD_BITS);
+        (round_bounds(lo, hi, round), false)
+    }
+}
+
+impl Transc for Rational {
+    type Output = Self;
+
+    #[inline]
+    fn log(self) -> Self::Output {
+        self.log_with(Round::Nearest).0
+    }
+
+    #[inline]
+    fn exp(self) -> Self::Output {
+        self.exp_with(Round::Nearest).0
+    }
+
+    #[inline]
+    fn pow(self, rhs: Self) -> Self::Output {
+        self.pow_with(rhs, Round::Nearest).0
+    }
+}
+
+impl fp::Transc for Rational {
+    #[inline]
+    fn log_r_exact(self, round: Round) -> (Self, bool) {
+        round.resolve(|r| self.clone().log_with(r))
+    }
+
+    #[inline]
+    fn exp_r_exact(self, round: Round) -> (Self, bool) {
+        round.resolve(|r| self.clone().exp_with(r))
+    }
+
+    #[inline]
+    fn pow_r_exact(self, rhs: Self, round: Round) -> (Self, bool) {
+        round.resolve(|r| self.clone().pow_with(rhs.clone(), r))
+    }
+}
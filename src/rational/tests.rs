@@ -0,0 +1,457 @@
+use super::{BigInt, ParseBigIntError, ParseRationalError, Rational};
+
+use fp;
+use fp::{Float, Integral, Sign};
+use mpfr::Mpfr;
+use transc::Transc;
+
+use std::str::FromStr;
+
+const PREC: usize = 53;
+
+macro_rules! assert_str_eq {
+    ($x:expr, $y:expr) => { assert_eq!($x, format!("{}", $y)) };
+    ($x:expr, $y:expr, $($arg:tt)+) => { assert_eq!($x, format!("{}", $y), $($arg)+) };
+}
+
+macro_rules! int {
+    ($v:expr) => {
+        BigInt::from_str($v).unwrap()
+    };
+}
+
+macro_rules! q {
+    ($v:expr) => {
+        Rational::from_str_with_prec($v, PREC).unwrap()
+    };
+}
+
+#[test]
+fn test_bigint_from_str() {
+    assert_str_eq!("0", int!("0"));
+    assert_str_eq!("0", int!("-0"));
+    assert_str_eq!("42", int!("+42"));
+    assert_str_eq!("-1000000000", int!("-1000000000"));
+    assert_str_eq!(
+        "-123456789012345678901234567890",
+        int!("-123456789012345678901234567890")
+    );
+    assert_str_eq!("7", int!("000000000000000000007"));
+    assert_eq!(Err(ParseBigIntError), BigInt::from_str(""));
+    assert_eq!(Err(ParseBigIntError), BigInt::from_str("-"));
+    assert_eq!(Err(ParseBigIntError), BigInt::from_str("1.5"));
+    assert_eq!(Err(ParseBigIntError), BigInt::from_str("1 2"));
+}
+
+#[test]
+fn test_bigint_ops() {
+    let a = int!("340282366920938463463374607431768211456"); // 2^128
+    let b = int!("18446744073709551617"); // 2^64 + 1
+    assert_str_eq!("340282366920938463481821351505477763073", &a + &b);
+    assert_str_eq!("340282366920938463444927863358058659839", &a - &b);
+    assert_str_eq!("-340282366920938463444927863358058659839", &b - &a);
+    assert_str_eq!("0", &a - &a);
+    assert_str_eq!(
+        "6277101735386680764176071790128604879565730051895802724352",
+        &a * &b
+    );
+    assert_str_eq!("-6", &int!("-2") * &int!("3"));
+    assert_str_eq!(
+        "340282366920938463463374607431768211456",
+        int!("2").pow(128)
+    );
+    assert_str_eq!("1", int!("-7").pow(0));
+    assert_str_eq!("-343", int!("-7").pow(3));
+    assert_eq!(129, a.bits());
+    assert_eq!(128, a.trailing_zeros());
+    assert_eq!(a, BigInt::one().shl(128));
+    assert_eq!(BigInt::one(), a.shr(128));
+    assert_str_eq!("-2", int!("-5").shr(1));
+    assert_eq!(Some(u64::MAX), int!("18446744073709551615").to_u64());
+    assert_eq!(None, b.to_u64());
+}
+
+#[test]
+fn test_bigint_div_rem() {
+    let check = |a: &str, b: &str, q: &str, r: &str| {
+        let (qq, rr) = int!(a).div_rem(&int!(b));
+        assert_str_eq!(q, qq, "{} / {}", a, b);
+        assert_str_eq!(r, rr, "{} % {}", a, b);
+    };
+    check("7", "2", "3", "1");
+    check("-7", "2", "-3", "-1");
+    check("7", "-2", "-3", "1");
+    check("1", "3", "0", "1");
+    check(
+        "340282366920938463463374607431768211455",
+        "18446744073709551617",
+        "18446744073709551615",
+        "0",
+    );
+    check(
+        "123456789012345678901234567890123456789012345678901234567890",
+        "987654321098765432109876543210",
+        "124999998860937500014238281249",
+        "935329860093532986009353298600",
+    );
+    let (q, r) = int!("-7").div_rem_floor(&int!("2"));
+    assert_str_eq!("-4", q);
+    assert_str_eq!("1", r);
+    let (q, r) = int!("7").div_rem_floor(&int!("-2"));
+    assert_str_eq!("-4", q);
+    assert_str_eq!("-1", r);
+}
+
+#[test]
+fn test_bigint_gcd_isqrt() {
+    assert_str_eq!("6", int!("-12").gcd(&int!("18")));
+    assert_str_eq!("5", int!("0").gcd(&int!("-5")));
+    assert_str_eq!(
+        "36893488147419103234",
+        int!("6277101735386680764176071790128604879565730051895802724352")
+            .gcd(&int!("36893488147419103234"))
+    );
+    assert_str_eq!("0", int!("0").isqrt());
+    assert_str_eq!("1", int!("3").isqrt());
+    assert_str_eq!("2", int!("4").isqrt());
+    assert_str_eq!(
+        "18446744073709551616",
+        int!("340282366920938463463374607431768211456").isqrt()
+    );
+    assert_str_eq!(
+        "18446744073709551615",
+        int!("340282366920938463463374607431768211455").isqrt()
+    );
+    assert_str_eq!("0", int!("0").iroot(3));
+    assert_str_eq!("2", int!("26").iroot(3));
+    assert_str_eq!("3", int!("27").iroot(3));
+    assert_str_eq!(
+        "2",
+        int!("340282366920938463463374607431768211456").iroot(128)
+    );
+    assert_str_eq!(
+        "1",
+        int!("340282366920938463463374607431768211455").iroot(128)
+    );
+}
+
+#[test]
+fn test_new() {
+    assert_str_eq!("2/3", Rational::new(int!("4"), int!("6"), PREC));
+    assert_str_eq!("-2/3", Rational::new(int!("4"), int!("-6"), PREC));
+    assert_str_eq!("2", Rational::new(int!("-4"), int!("-2"), PREC));
+    assert_str_eq!("0", Rational::new(int!("0"), int!("-2"), PREC));
+    assert_str_eq!("inf", Rational::new(int!("3"), int!("0"), PREC));
+    assert_str_eq!("-inf", Rational::new(int!("-3"), int!("0"), PREC));
+    assert_str_eq!("NaN", Rational::new(int!("0"), int!("0"), PREC));
+}
+
+#[test]
+fn test_from_str() {
+    assert_str_eq!("1/10", q!("0.1"));
+    assert_str_eq!("-1/3", q!("-1/3"));
+    assert_str_eq!("3/4", q!("1.5 / 2"));
+    assert_str_eq!("12300", q!("1.23e4"));
+    assert_str_eq!("-1/800", q!("-1.25e-3"));
+    assert_str_eq!("5", q!("5."));
+    assert_str_eq!("1/2", q!(".5"));
+    assert_str_eq!("inf", q!("inf"));
+    assert_str_eq!("-inf", q!("-Infinity"));
+    assert_str_eq!("NaN", q!("NaN"));
+    assert_eq!(
+        Err(ParseRationalError::ZeroDenominator),
+        Rational::from_str("1/0")
+    );
+    assert_eq!(
+        Err(ParseRationalError::InvalidSyntax),
+        Rational::from_str("")
+    );
+    assert_eq!(
+        Err(ParseRationalError::InvalidSyntax),
+        Rational::from_str(".")
+    );
+    assert_eq!(
+        Err(ParseRationalError::InvalidSyntax),
+        Rational::from_str("1e")
+    );
+    assert_eq!(
+        Err(ParseRationalError::InvalidSyntax),
+        Rational::from_str("<1, 2>")
+    );
+    assert_eq!(
+        Err(ParseRationalError::ExponentOutOfRange),
+        Rational::from_str("1e1000000")
+    );
+}
+
+#[test]
+fn test_from_f64() {
+    assert_str_eq!(
+        "1/10",
+        q!("0.1") * Rational::from(10.0) / Rational::from(10.0)
+    );
+    assert_str_eq!("3602879701896397/36028797018963968", Rational::from(0.1));
+    assert_str_eq!("-5/4", Rational::from(-1.25));
+    assert_str_eq!("0", Rational::from(-0.0));
+    assert_eq!(Rational::one(PREC).ldexp(-1074), Rational::from(5e-324));
+    assert_str_eq!("inf", Rational::from(f64::INFINITY));
+    assert!(Rational::from(f64::NAN).is_nan());
+}
+
+#[test]
+fn test_into_f64() {
+    let third = q!("1/3");
+    assert_eq!(1.0 / 3.0, f64::from(third.clone()));
+    assert_eq!(1.0 / 3.0, fp::Into::<f64>::into_lo(third.clone()));
+    assert_eq!(Float::next_up(1.0 / 3.0), fp::Into::<f64>::into_hi(third));
+    assert_eq!(0.1, fp::Into::<f64>::into_hi(Rational::from(0.1)));
+    assert_eq!(0.1, fp::Into::<f64>::into_lo(Rational::from(0.1)));
+    assert_eq!(f64::MAX, fp::Into::<f64>::into_lo(q!("1e400")));
+    assert_eq!(f64::INFINITY, fp::Into::<f64>::into_hi(q!("1e400")));
+    assert_eq!(0.0, fp::Into::<f64>::into_lo(q!("1e-400")));
+    assert_eq!(5e-324, fp::Into::<f64>::into_hi(q!("1e-400")));
+}
+
+#[test]
+fn test_mpfr_conversions() {
+    let (x, exact) = q!("1/3").to_mpfr(PREC, false);
+    assert!(!exact);
+    assert_eq!(1.0 / 3.0, f64::from(x));
+    let (x, exact) = q!("3/8").to_mpfr(2, true);
+    assert!(exact);
+    assert_eq!(0.375, f64::from(x));
+    let x = Mpfr::from_str_with_prec("0.1", 300).unwrap();
+    let y = Rational::from_mpfr(&x, PREC);
+    assert_eq!(x, y.to_mpfr(300, false).0);
+    assert!(y.to_mpfr(300, true).1);
+}
+
+#[test]
+fn test_cmp() {
+    assert!(q!("1/3") < q!("0.34"));
+    assert!(q!("-1/3") > q!("-0.34"));
+    assert!(q!("2/4") == q!("0.5"));
+    assert!(q!("-inf") < q!("-1e100"));
+    assert!(q!("inf") > q!("1e100"));
+    assert!(q!("inf") == q!("inf"));
+    assert!(q!("NaN") != q!("NaN"));
+    assert!(!(q!("NaN") < q!("1")));
+    assert!(!(q!("NaN") >= q!("1")));
+    assert_eq!(Sign::Negative, q!("-inf").sign());
+    assert_eq!(Sign::Zero, q!("0").sign());
+    assert_eq!(Sign::Positive, q!("1/7").sign());
+}
+
+#[test]
+fn test_ops() {
+    assert_str_eq!("5/6", q!("1/2") + q!("1/3"));
+    assert_str_eq!("1/6", q!("1/2") - q!("1/3"));
+    assert_str_eq!("1/6", q!("1/2") * q!("1/3"));
+    assert_str_eq!("3/2", q!("1/2") / q!("1/3"));
+    assert_str_eq!("3/10", q!("0.1") + q!("0.2"));
+    assert_str_eq!("0", q!("1/3") - q!("1/3"));
+    assert_str_eq!("inf", q!("inf") + q!("1"));
+    assert_str_eq!("NaN", q!("inf") - q!("inf"));
+    assert_str_eq!("NaN", q!("0") * q!("-inf"));
+    assert_str_eq!("-inf", q!("-2") * q!("inf"));
+    assert_str_eq!("inf", q!("1") / q!("0"));
+    assert_str_eq!("-inf", q!("-1") / q!("0"));
+    assert_str_eq!("NaN", q!("0") / q!("0"));
+    assert_str_eq!("0", q!("-1") / q!("inf"));
+    assert_str_eq!("NaN", q!("NaN") + q!("1"));
+    let (x, exact) = fp::Add::add_lo_exact(q!("1/3"), q!("1/7"));
+    assert_str_eq!("10/21", x);
+    assert!(exact);
+    assert_eq!(
+        fp::Div::div_lo(q!("1"), q!("3")),
+        fp::Div::div_hi(q!("1"), q!("3"))
+    );
+    assert_eq!(64, (q!("1") + Rational::one(64)).precision());
+}
+
+#[test]
+fn test_integral() {
+    let check = |x: &str, floor: &str, ceil: &str, even: &str, away: &str, trunc: &str| {
+        assert_str_eq!(floor, q!(x).floor(), "floor({})", x);
+        assert_str_eq!(ceil, q!(x).ceil(), "ceil({})", x);
+        assert_str_eq!(even, q!(x).round_ties_even(), "round_ties_even({})", x);
+        assert_str_eq!(away, q!(x).round_ties_away(), "round_ties_away({})", x);
+        assert_str_eq!(trunc, q!(x).trunc(), "trunc({})", x);
+    };
+    check("7/3", "2", "3", "2", "2", "2");
+    check("-7/3", "-3", "-2", "-2", "-2", "-2");
+    check("5/2", "2", "3", "2", "3", "2");
+    check("-5/2", "-3", "-2", "-2", "-3", "-2");
+    check("7/2", "3", "4", "4", "4", "3");
+    check("-7/2", "-4", "-3", "-4", "-4", "-3");
+    check("3", "3", "3", "3", "3", "3");
+    check("inf", "inf", "inf", "inf", "inf", "inf");
+    assert_str_eq!("1/3", q!("7/3").frac());
+    assert_str_eq!("-1/3", q!("-7/3").frac());
+    assert_str_eq!("NaN", q!("-inf").frac());
+}
+
+#[test]
+fn test_rem() {
+    use fp::Rem;
+    assert_str_eq!("1/6", q!("7/6").fmod_lo(q!("1/2")));
+    assert_str_eq!("-1/6", q!("-7/6").fmod_hi(q!("1/2")));
+    assert_str_eq!("1/6", q!("7/6").remainder_lo(q!("1/2")));
+    assert_str_eq!("-1/4", q!("7/4").remainder_hi(q!("1/2")));
+    assert_str_eq!("5/3", q!("5/3").fmod_lo(q!("inf")));
+    assert_str_eq!("NaN", q!("5/3").fmod_lo(q!("0")));
+    assert_str_eq!("NaN", q!("inf").remainder_lo(q!("1")));
+}
+
+#[test]
+fn test_hypot() {
+    use fp::Hypot;
+    assert_str_eq!("5/12", q!("1/4").hypot_lo(q!("-1/3")));
+    assert_str_eq!("5/12", q!("1/4").hypot_hi(q!("-1/3")));
+    let lo = q!("1").hypot_lo(q!("1"));
+    let hi = q!("1").hypot_hi(q!("1"));
+    assert!(lo < hi);
+    assert!(lo.clone() * lo < q!("2"));
+    assert!(hi.clone() * hi > q!("2"));
+    assert_str_eq!("inf", q!("NaN").hypot_lo(q!("-inf")));
+    assert_str_eq!("NaN", q!("NaN").hypot_hi(q!("1")));
+    let lo = q!("1/3").hypot_lo(q!("1/7"));
+    let hi = q!("1/3").hypot_hi(q!("1/7"));
+    assert!(hi.clone() - lo.clone() <= q!("1").ldexp(-54));
+    let s = q!("1/9") + q!("1/49");
+    assert!(lo.clone() * lo < s && hi.clone() * hi > s);
+}
+
+#[test]
+fn test_fma_sum() {
+    use fp::{Fma, Sum};
+    assert_eq!(
+        (q!("7/6"), true),
+        q!("1/2").fma_lo_exact(q!("1/3"), q!("1"))
+    );
+    assert_str_eq!("NaN", q!("inf").fma_hi(q!("0"), q!("1")));
+    let xs = [q!("1/3"), q!("1/6"), q!("-1/2")];
+    assert_str_eq!("0", Rational::sum_lo(&xs, PREC));
+    assert_str_eq!("1/18", Rational::dot_hi(&xs, &xs, PREC) - q!("1/3"));
+    assert_str_eq!("NaN", Rational::sum_hi(&[q!("inf"), q!("-inf")], PREC));
+}
+
+#[test]
+fn test_transc() {
+    use fp::Transc as FpTransc;
+    let x = q!("2");
+    let (lo, lo_exact) = x.clone().log_lo_exact();
+    let (hi, hi_exact) = x.clone().log_hi_exact();
+    assert!(!lo_exact && !hi_exact);
+    assert!(lo < hi);
+    assert!(f64::from(lo) <= 2f64.ln() && 2f64.ln() <= f64::from(hi));
+    assert_eq!(q!("0"), q!("1").log_lo_exact().0);
+    assert!(q!("1").log_hi_exact().1);
+    assert_str_eq!("-inf", q!("0").log_lo());
+    assert_str_eq!("NaN", q!("-1").log_hi());
+    let lo = q!("1/3").exp_lo();
+    let hi = q!("1/3").exp_hi();
+    assert!(lo < hi);
+    assert!(f64::from(lo) <= (1.0f64 / 3.0).exp() && (1.0f64 / 3.0).exp() <= f64::from(hi));
+    assert_str_eq!("1", q!("0").exp());
+    assert_str_eq!("0", q!("-inf").exp_hi());
+    for &(x, log, exp) in &[
+        ("1/10", 0.1f64.ln(), 0.1f64.exp()),
+        ("100", 100f64.ln(), 100f64.exp()),
+        ("-700", f64::NAN, (-700f64).exp()),
+    ] {
+        let lo = f64::from(q!(x).exp_lo());
+        let hi = f64::from(q!(x).exp_hi());
+        assert!(
+            lo <= exp * (1.0 + 1e-15) && exp * (1.0 - 1e-15) <= hi,
+            "exp({})",
+            x
+        );
+        assert!(hi - lo <= hi * 1e-15, "exp({})", x);
+        if !log.is_nan() {
+            let lo = f64::from(q!(x).log_lo());
+            let hi = f64::from(q!(x).log_hi());
+            assert!(lo <= log + 1e-15 && log - 1e-15 <= hi, "log({})", x);
+        }
+    }
+    let x = q!("1") + q!("1").ldexp(-100);
+    let lo = x.clone().log_lo();
+    let hi = x.log_hi();
+    let tiny = q!("1").ldexp(-100);
+    assert!(lo < tiny && tiny < hi && hi - lo < tiny.ldexp(-50));
+    assert!(q!("100000").exp_lo() > q!("1").ldexp(94000));
+    assert_str_eq!("inf", q!("100000").exp_hi());
+    assert_str_eq!("0", q!("-100000").exp_lo());
+}
+
+#[test]
+fn test_pow() {
+    use fp::Transc as FpTransc;
+    assert_eq!((q!("8/27"), true), q!("2/3").pow_lo_exact(q!("3")));
+    assert_eq!((q!("9/4"), true), q!("-2/3").pow_hi_exact(q!("-2")));
+    assert_str_eq!("-27/8", q!("-2/3").pow(q!("-3")));
+    assert_eq!((q!("2"), true), q!("4").pow_lo_exact(q!("1/2")));
+    assert_str_eq!("NaN", q!("-4").pow_lo(q!("1/2")));
+    assert_str_eq!("1", q!("NaN").pow_lo(q!("0")));
+    assert_str_eq!("1", q!("1").pow_hi(q!("NaN")));
+    assert_str_eq!("inf", q!("0").pow_lo(q!("-1/3")));
+    assert_str_eq!("0", q!("inf").pow_lo(q!("-1/3")));
+    assert_str_eq!("-inf", q!("-inf").pow_lo(q!("3")));
+    assert_str_eq!("0", q!("1/2").pow_hi(q!("inf")));
+    assert_str_eq!("inf", q!("-2").pow_lo(q!("inf")));
+    assert_str_eq!("1", q!("-1").pow_lo(q!("-inf")));
+    let lo = q!("1/3").pow_lo(q!("1/3"));
+    let hi = q!("1/3").pow_hi(q!("1/3"));
+    assert!(lo < hi);
+    let exact = (1.0f64 / 3.0).powf(1.0 / 3.0);
+    assert!(f64::from(lo) <= exact && exact <= f64::from(hi));
+    let lo = q!("-1/3").pow_lo(q!("3"));
+    assert_str_eq!("-1/27", lo);
+    assert_eq!((q!("1/2"), true), q!("8").pow_lo_exact(q!("-1/3")));
+    assert_eq!((q!("243/32"), true), q!("9/4").pow_hi_exact(q!("5/2")));
+    let (lo, lo_exact) = q!("2").pow_lo_exact(q!("1/2"));
+    let (hi, hi_exact) = q!("2").pow_hi_exact(q!("1/2"));
+    assert!(!lo_exact && !hi_exact);
+    assert!(lo.clone() * lo.clone() < q!("2") && hi.clone() * hi.clone() > q!("2"));
+    assert!(hi - lo <= q!("1").ldexp(-52));
+    let lo = q!("-3/2").pow_lo(q!("100001"));
+    let hi = q!("-3/2").pow_hi(q!("100001"));
+    assert!(lo < hi && hi < q!("0"));
+}
+
+#[test]
+fn test_frexp_ldexp() {
+    let check = |x: &str, m: &str, e: isize| {
+        let (mm, ee) = q!(x).frexp();
+        assert_str_eq!(m, mm, "frexp({})", x);
+        assert_eq!(e, ee, "frexp({})", x);
+        assert_eq!(q!(x), q!(m).ldexp(e));
+    };
+    check("1", "1/2", 1);
+    check("3/4", "3/4", 0);
+    check("-1/3", "-2/3", -1);
+    check("7", "7/8", 3);
+    check("1/1024", "1/2", -9);
+    let (m, e) = q!("0").frexp();
+    assert_str_eq!("0", m);
+    assert_eq!(0, e);
+    assert_str_eq!("inf", q!("inf").ldexp(5));
+    assert_str_eq!("3/4", q!("3").ldexp(-2));
+    assert_str_eq!("12", q!("3").ldexp(2));
+}
+
+#[test]
+fn test_next_up_down() {
+    let x = Rational::one(2);
+    assert_str_eq!("3/2", x.clone().next_up());
+    assert_str_eq!("3/4", x.clone().next_down());
+    assert_str_eq!("-3/4", (-x.clone()).next_up());
+    assert_str_eq!("-3/2", (-x).next_down());
+    assert_eq!(Rational::one(2).ldexp(-1074), Rational::zero(2).next_up());
+    assert_str_eq!("inf", q!("inf").next_up());
+    assert_str_eq!("-inf", q!("-inf").next_up());
+    assert_str_eq!("NaN", q!("NaN").next_down());
+    assert!(q!("1/3").has_odd_denominator());
+    assert!(!q!("1/4").has_odd_denominator());
+    assert!(!q!("inf").has_odd_denominator());
+}
@@ -0,0 +1,15 @@
+/// Slope struct for slope arithmetic.
+///
+/// Represents a univariate function `f` evaluated over an input `x` with a center `c`: `range`
+/// encloses `f(x)`, `center` encloses `f(c)`, and `slope` encloses `(f(x) - f(c)) / (x - c)`, so
+/// that `f(x)` is also enclosed in `center + slope * (x - c)`. When the center is the whole input,
+/// `slope` encloses the derivative of `f` over `x`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Slope<T> {
+    /// Function value over the input.
+    pub range: T,
+    /// Function value at the center.
+    pub center: T,
+    /// Slope of the function between the input and the center.
+    pub slope: T,
+}
@@ -0,0 +1,53 @@
+use super::def::Slope;
+
+use fp::Bound;
+use interval::Interval;
+
+impl<BOUND: Bound> Slope<Interval<BOUND>> {
+    /// Constructs a slope from the ranges of a function over the input and at the center, and the
+    /// slope between them.
+    #[inline]
+    pub fn new(range: Interval<BOUND>, center: Interval<BOUND>, slope: Interval<BOUND>) -> Self {
+        Slope {
+            range,
+            center,
+            slope,
+        }
+    }
+
+    /// Constructs a constant, whose slope is zero.
+    #[inline]
+    pub fn constant(value: Interval<BOUND>) -> Self {
+        let precision = value.precision();
+        Self::new(value.clone(), value, Interval::zero(precision))
+    }
+
+    /// Constructs the independent variable over `x` with center `c`, whose slope is one.
+    ///
+    /// `c` is usually a point in `x`, such as its midpoint. With `c` equal to `x`, slope arithmetic
+    /// computes derivatives.
+    #[inline]
+    pub fn variable(x: Interval<BOUND>, c: Interval<BOUND>) -> Self {
+        let precision = x.precision();
+        Self::new(x, c, Interval::one(precision))
+    }
+
+    /// Returns the precision of the range.
+    #[inline]
+    pub fn precision(&self) -> usize {
+        self.range.precision()
+    }
+
+    /// Whether the range, center or slope is NaN.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.range.is_nan() || self.center.is_nan() || self.slope.is_nan()
+    }
+}
+
+impl<BOUND: Bound> From<Interval<BOUND>> for Slope<Interval<BOUND>> {
+    #[inline]
+    fn from(value: Interval<BOUND>) -> Self {
+        Self::constant(value)
+    }
+}
@@ -0,0 +1,106 @@
+use super::def::Slope;
+
+use fp::{Bound, Float};
+use interval::Interval;
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+impl<BOUND: Bound> Neg for Slope<Interval<BOUND>> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::new(-self.range, -self.center, -self.slope)
+    }
+}
+
+impl<BOUND: Bound> Add<Self> for Slope<Interval<BOUND>> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.range + rhs.range,
+            self.center + rhs.center,
+            self.slope + rhs.slope,
+        )
+    }
+}
+
+impl<BOUND: Bound> Sub<Self> for Slope<Interval<BOUND>> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.range - rhs.range,
+            self.center - rhs.center,
+            self.slope - rhs.slope,
+        )
+    }
+}
+
+impl<BOUND: Bound> Mul<Self> for Slope<Interval<BOUND>> {
+    type Output = Self;
+
+    /// Computes the slope of `f * g` as `sf * g(x) + f(c) * sg`, because `f(x) * g(x) - f(c) *
+    /// g(c)` is `(f(x) - f(c)) * g(x) + f(c) * (g(x) - g(c))`.
+    fn mul(self, rhs: Self) -> Self::Output {
+        let slope = self.slope * rhs.range.clone() + self.center.clone() * rhs.slope;
+        Self::new(self.range * rhs.range, self.center * rhs.center, slope)
+    }
+}
+
+impl<BOUND: Float> Div<Self> for Slope<Interval<BOUND>> {
+    type Output = Self;
+
+    /// Computes the slope of `f / g` as `(sf - (f(c) / g(c)) * sg) / g(x)`.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        let center = self.center / rhs.center;
+        let slope = (self.slope - center.clone() * rhs.slope) / rhs.range.clone();
+        Self::new(self.range / rhs.range, center, slope)
+    }
+}
+
+impl<BOUND: Bound> Add<Interval<BOUND>> for Slope<Interval<BOUND>> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Interval<BOUND>) -> Self::Output {
+        Self::new(self.range + rhs.clone(), self.center + rhs, self.slope)
+    }
+}
+
+impl<BOUND: Bound> Sub<Interval<BOUND>> for Slope<Interval<BOUND>> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Interval<BOUND>) -> Self::Output {
+        Self::new(self.range - rhs.clone(), self.center - rhs, self.slope)
+    }
+}
+
+impl<BOUND: Bound> Mul<Interval<BOUND>> for Slope<Interval<BOUND>> {
+    type Output = Self;
+
+    fn mul(self, rhs: Interval<BOUND>) -> Self::Output {
+        Self::new(
+            self.range * rhs.clone(),
+            self.center * rhs.clone(),
+            self.slope * rhs,
+        )
+    }
+}
+
+impl<BOUND: Float> Div<Interval<BOUND>> for Slope<Interval<BOUND>> {
+    type Output = Self;
+
+    fn div(self, rhs: Interval<BOUND>) -> Self::Output {
+        Self::new(
+            self.range / rhs.clone(),
+            self.center / rhs.clone(),
+            self.slope / rhs,
+        )
+    }
+}
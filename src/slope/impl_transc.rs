@@ -0,0 +1,42 @@
+use super::def::Slope;
+
+use dual::Dual;
+use fp::Float;
+use interval::Interval;
+use transc::Transc;
+
+impl<BOUND: Float> Slope<Interval<BOUND>> {
+    /// Returns the hull of the range and the center of `self` with the slope as its derivative.
+    ///
+    /// By the mean value theorem, the slope of `h(f)` for a differentiable `h` is enclosed in the
+    /// derivative of `h(f)` over this hull.
+    fn hull_dual(&self) -> Dual<Interval<BOUND>> {
+        let hull = self.range.clone().hull(self.center.clone());
+        Dual::new(hull, vec![self.slope.clone()])
+    }
+}
+
+impl<BOUND: Float> Transc for Slope<Interval<BOUND>> {
+    type Output = Self;
+
+    fn log(self) -> Self::Output {
+        let slope = self.hull_dual().log().derivative(0);
+        Self::new(self.range.log(), self.center.log(), slope)
+    }
+
+    fn exp(self) -> Self::Output {
+        let slope = self.hull_dual().exp().derivative(0);
+        Self::new(self.range.exp(), self.center.exp(), slope)
+    }
+
+    /// Computes the slope of `f^g` from the partial derivatives of `x^y` over the hulls of the
+    /// ranges and the centers of `f` and `g`, as in `Dual::pow`.
+    fn pow(self, rhs: Self) -> Self::Output {
+        let slope = self.hull_dual().pow(rhs.hull_dual()).derivative(0);
+        Self::new(
+            self.range.pow(rhs.range),
+            self.center.pow(rhs.center),
+            slope,
+        )
+    }
+}
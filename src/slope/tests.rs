@@ -0,0 +1,85 @@
+use super::Slope;
+
+use interval::Interval;
+use mpfr::Mpfr;
+use transc::Transc;
+
+type B = Mpfr;
+type IV = Interval<B>;
+type S = Slope<IV>;
+
+const PREC: usize = 53;
+
+macro_rules! assert_str_eq {
+    ($x:expr, $y:expr) => { assert_eq!($x, format!("{}", $y)) };
+    ($x:expr, $y:expr, $($arg:tt)+) => { assert_eq!($x, format!("{}", $y), $($arg)+) };
+}
+
+macro_rules! iv {
+    ($s:expr) => {
+        IV::from_str_with_prec($s, PREC).unwrap()
+    };
+}
+
+macro_rules! c {
+    ($s:expr) => {
+        S::constant(iv!($s))
+    };
+}
+
+fn encloses(x: &IV, y: &IV) -> bool {
+    x.lo <= y.lo && y.hi <= x.hi
+}
+
+#[test]
+fn test_constructors() {
+    let x = S::variable(iv!("<0, 1>"), iv!("0.5"));
+    assert_str_eq!("<0, 1>", x.range);
+    assert_str_eq!("0.5", x.center);
+    assert_str_eq!("1", x.slope);
+    assert_str_eq!("0", c!("2").slope);
+    assert_eq!(c!("2"), S::from(iv!("2")));
+    assert!(S::variable(iv!("NaN"), iv!("0")).is_nan());
+    assert!(!x.is_nan());
+}
+
+#[test]
+fn test_ops() {
+    let x = S::variable(iv!("<0, 1>"), iv!("0.5"));
+    let y = x.clone() - x.clone() * x.clone();
+    assert_str_eq!("<-1, 1>", y.range);
+    assert_str_eq!("0.25", y.center);
+    assert_str_eq!("<-0.5, 0.5>", y.slope);
+    let x = S::variable(iv!("<0, 1>"), iv!("<0, 1>"));
+    let y = x.clone() - x.clone() * x;
+    assert_str_eq!("<-1, 1>", y.slope);
+    let x = S::variable(iv!("<1, 2>"), iv!("1"));
+    let y = c!("1") / x.clone();
+    assert_str_eq!("<0.5, 1>", y.range);
+    assert_str_eq!("1", y.center);
+    assert_str_eq!("<-1, -0.5>", y.slope);
+    let y = -(x.clone() + iv!("1")) * iv!("2") / iv!("4") - iv!("1");
+    assert_str_eq!("<-2.5, -2>", y.range);
+    assert_str_eq!("-2", y.center);
+    assert_str_eq!("-0.5", y.slope);
+    let y = x.clone() + x;
+    assert_str_eq!("2", y.slope);
+}
+
+#[test]
+fn test_transc() {
+    let x = S::variable(iv!("<0, 1>"), iv!("0"));
+    let y = x.clone().exp();
+    assert_str_eq!("1", y.center);
+    assert!(encloses(&y.slope, &(iv!("1").exp() - iv!("1"))));
+    assert!(encloses(&y.slope, &iv!("1")));
+    let y = (x.clone() + iv!("1")).log();
+    assert!(encloses(&y.slope, &iv!("2").log()));
+    let y = (x.clone() + iv!("1")).pow(c!("2"));
+    assert_str_eq!("<1, 4>", y.range);
+    assert_str_eq!("1", y.center);
+    assert_str_eq!("<2, 4>", y.slope);
+    let y = c!("2").pow(x);
+    assert_str_eq!("<1, 2>", y.range);
+    assert!(encloses(&y.slope, &iv!("1")));
+}